@@ -0,0 +1,65 @@
+// Filtr CRT (`src/crt.rs`) na gotowym obrazie: wygięcie ekranu, linie
+// skanowania, maska luminoforu i lekka poświata jasnych miejsc.
+
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+struct CrtSettings {
+    curvature: f32,
+    scanlines: f32,
+    glow: f32,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: CrtSettings;
+
+const TAU: f32 = 6.2831853;
+// Wysokość jednej linii skanowania w pikselach ekranu.
+const SCANLINE_PX: f32 = 3.0;
+
+// Wypukły ekran: punkty dalej od środka uciekają na zewnątrz.
+fn curve(uv: vec2<f32>) -> vec2<f32> {
+    let centered = uv * 2.0 - 1.0;
+    let bent = centered * (1.0 + centered.yx * centered.yx * settings.curvature);
+    return bent * 0.5 + 0.5;
+}
+
+fn sample(uv: vec2<f32>) -> vec3<f32> {
+    // Bez pochodnych – próbkujemy po warunkowym wyjściu z funkcji.
+    return textureSampleLevel(screen_texture, texture_sampler, uv, 0.0).rgb;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let uv = curve(in.uv);
+    if (any(uv < vec2<f32>(0.0)) || any(uv > vec2<f32>(1.0))) {
+        return vec4<f32>(0.0, 0.0, 0.0, 1.0);
+    }
+
+    let size = vec2<f32>(textureDimensions(screen_texture));
+    let texel = 1.0 / size;
+    var color = sample(uv);
+
+    // Poświata: rozmyte otoczenie dodane tam, gdzie jest jasno.
+    var blur = vec3<f32>(0.0);
+    blur += sample(uv + vec2<f32>(2.0, 0.0) * texel);
+    blur += sample(uv - vec2<f32>(2.0, 0.0) * texel);
+    blur += sample(uv + vec2<f32>(0.0, 2.0) * texel);
+    blur += sample(uv - vec2<f32>(0.0, 2.0) * texel);
+    blur *= 0.25;
+    color += blur * smoothstep(0.5, 1.0, max(blur.r, max(blur.g, blur.b))) * settings.glow;
+
+    // Linie skanowania i pionowe paski luminoforu RGB.
+    let scan = 0.5 + 0.5 * sin(uv.y * size.y / SCANLINE_PX * TAU);
+    color *= mix(1.0, scan, settings.scanlines);
+    let phosphor = u32(in.position.x) % 3u;
+    var mask = vec3<f32>(0.85);
+    mask[phosphor] = 1.15;
+    color *= mask;
+
+    // Przyciemnione rogi kineskopu.
+    let edge = uv * (1.0 - uv);
+    color *= clamp(pow(edge.x * edge.y * 20.0, 0.25), 0.0, 1.0);
+
+    return vec4<f32>(color, 1.0);
+}
@@ -0,0 +1,100 @@
+// Animowane niebo motywu "Sky" (`src/sky.rs`): gradient zależny od pory dnia,
+// dryfujące chmury z szumu oraz słońce albo księżyc na łuku nad horyzontem.
+
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+
+struct Sky {
+    // 0..0.75 – dzień (słońce od wschodu do zachodu), 0.75..1 – noc (księżyc).
+    time_of_day: f32,
+    cloud_offset: f32,
+    aspect: f32,
+}
+
+@group(2) @binding(0) var<uniform> sky: Sky;
+
+const DAY_END: f32 = 0.75;
+const PI: f32 = 3.14159265;
+
+fn hash(p: vec2<f32>) -> f32 {
+    return fract(sin(dot(p, vec2<f32>(127.1, 311.7))) * 43758.5453);
+}
+
+fn noise(p: vec2<f32>) -> f32 {
+    let i = floor(p);
+    let f = fract(p);
+    let u = f * f * (3.0 - 2.0 * f);
+    let a = hash(i);
+    let b = hash(i + vec2<f32>(1.0, 0.0));
+    let c = hash(i + vec2<f32>(0.0, 1.0));
+    let d = hash(i + vec2<f32>(1.0, 1.0));
+    return mix(mix(a, b, u.x), mix(c, d, u.x), u.y);
+}
+
+fn fbm(p: vec2<f32>) -> f32 {
+    var value = 0.0;
+    var amplitude = 0.5;
+    var q = p;
+    for (var octave = 0; octave < 4; octave++) {
+        value += amplitude * noise(q);
+        q *= 2.0;
+        amplitude *= 0.5;
+    }
+    return value;
+}
+
+// Pozycja ciała na łuku (x od lewej do prawej, y od horyzontu w górę) w `uv`.
+fn arc_position(progress: f32) -> vec2<f32> {
+    let angle = mix(0.1, 0.9, progress) * PI;
+    return vec2<f32>(0.5 - 0.42 * cos(angle), 0.75 - 0.6 * sin(angle));
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    let uv = in.uv;
+    let t = fract(sky.time_of_day);
+
+    // Jasność dnia z łagodnym świtem i zmierzchem na granicach nocy.
+    let daylight = smoothstep(0.0, 0.06, t) * (1.0 - smoothstep(DAY_END - 0.06, DAY_END, t));
+    // Ciepłe barwy przy wschodzie i zachodzie.
+    let warmth = (1.0 - smoothstep(0.05, 0.2, t)) + smoothstep(DAY_END - 0.2, DAY_END - 0.03, t);
+
+    let day_top = vec3<f32>(0.25, 0.6, 0.9);
+    let day_horizon = vec3<f32>(0.75, 0.9, 0.97);
+    let dusk_horizon = vec3<f32>(1.0, 0.6, 0.4);
+    let night_top = vec3<f32>(0.02, 0.04, 0.14);
+    let night_horizon = vec3<f32>(0.12, 0.16, 0.35);
+
+    let horizon = mix(day_horizon, dusk_horizon, clamp(warmth, 0.0, 1.0) * daylight);
+    let top = mix(night_top, day_top, daylight);
+    var color = mix(top, mix(night_horizon, horizon, daylight), smoothstep(0.0, 1.0, uv.y));
+
+    // Gwiazdy tylko nocą, w górnej części nieba.
+    let star_cell = floor(uv * vec2<f32>(sky.aspect, 1.0) * 120.0);
+    let star = step(0.997, hash(star_cell)) * (1.0 - daylight) * (1.0 - uv.y);
+    color += vec3<f32>(star);
+
+    // Słońce w dzień, księżyc nocą.
+    let scale = vec2<f32>(sky.aspect, 1.0);
+    if (t < DAY_END) {
+        let sun = arc_position(t / DAY_END);
+        let dist = length((uv - sun) * scale);
+        let sun_color = mix(vec3<f32>(1.0, 0.95, 0.7), vec3<f32>(1.0, 0.55, 0.3), clamp(warmth, 0.0, 1.0));
+        color = mix(color, sun_color, 1.0 - smoothstep(0.05, 0.055, dist));
+        color += sun_color * 0.25 * (1.0 - smoothstep(0.05, 0.25, dist));
+    } else {
+        let moon = arc_position((t - DAY_END) / (1.0 - DAY_END));
+        let dist = length((uv - moon) * scale);
+        // Sierp: tarcza minus przesunięta tarcza.
+        let disc = 1.0 - smoothstep(0.035, 0.04, dist);
+        let shadow = 1.0 - smoothstep(0.03, 0.035, length((uv - moon - vec2<f32>(0.012, -0.008)) * scale));
+        color = mix(color, vec3<f32>(0.95, 0.95, 0.85), disc * (1.0 - shadow));
+    }
+
+    // Chmury dryfują w lewo; nocą są ciemniejsze i przezroczystsze.
+    let cloud_uv = vec2<f32>(uv.x * sky.aspect + sky.cloud_offset, uv.y * 2.5);
+    let density = smoothstep(0.5, 0.75, fbm(cloud_uv * 2.0)) * (1.0 - smoothstep(0.55, 0.8, uv.y));
+    let cloud_color = mix(vec3<f32>(0.3, 0.32, 0.42), vec3<f32>(1.0, 0.98, 0.95), daylight);
+    color = mix(color, cloud_color, density * mix(0.5, 0.9, daylight));
+
+    return vec4<f32>(color, 1.0);
+}
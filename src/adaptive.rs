@@ -0,0 +1,385 @@
+// Adaptacyjna trudność (asysta, domyślnie wyłączona). Po każdej zwykłej
+// rundzie patrzymy na ostatnie wyniki i przyczyny śmierci. Gdy gracz kilka
+// rund z rzędu wyraźnie odbiega od docelowego odsetka udanych rund, następne
+// rundy dostają o `STEP_PERCENT` szersze lub węższe przerwy albo wolniejsze
+// lub szybsze rury. Ekran końca gry pokazuje dostosowanie bieżącej i następnej
+// rundy.
+//
+// Runda z dostosowaniem ma inne rury, niż wynika z jej ziarna, więc nie
+// nagrywamy jej powtórki, a wynik oznaczamy jak wynik z asystą. Tryby
+// specjalne, wyścigi i oglądanie zostają bez zmian.
+
+use std::collections::VecDeque;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::buttons::button_text;
+use crate::coop::CoopRun;
+use crate::core::PipeParams;
+use crate::display::WORLD_SIZE;
+use crate::game_over::GameOverUI;
+use crate::kids::KidsMode;
+use crate::mirror::MirrorRun;
+use crate::physics::Collider;
+use crate::player::Player;
+use crate::royale::RoyaleRun;
+use crate::score::Score;
+use crate::settings::Settings;
+use crate::spectate::Spectating;
+use crate::tournament::WeeklyRun;
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+const STEP_PERCENT: i8 = 5;
+const MAX_PERCENT: i8 = 15;
+// Ile ostatnich rund bierzemy pod uwagę i od ilu zaczynamy dostosowywać.
+const RECENT_RUNS: usize = 5;
+const MIN_RUNS: usize = 3;
+// Runda jest udana od tylu punktów; celujemy w połowę udanych rund.
+const SUCCESS_SCORE: i32 = 10;
+const TARGET_SUCCESS_RATE: f32 = 0.5;
+const TOLERANCE: f32 = 0.2;
+
+// Zmiana przerwy i prędkości rur w procentach zwykłych wartości.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Adjustment {
+    pub gap_percent: i8,
+    pub speed_percent: i8,
+}
+
+impl Adjustment {
+    fn is_neutral(self) -> bool {
+        self == Self::default()
+    }
+
+    fn clamped(self) -> Self {
+        Self {
+            gap_percent: self.gap_percent.clamp(-MAX_PERCENT, MAX_PERCENT),
+            speed_percent: self.speed_percent.clamp(-MAX_PERCENT, MAX_PERCENT),
+        }
+    }
+
+    pub fn label(self) -> String {
+        if self.is_neutral() {
+            return "none".to_string();
+        }
+        let mut parts = Vec::new();
+        if self.gap_percent != 0 {
+            parts.push(format!("gaps {:+}%", self.gap_percent));
+        }
+        if self.speed_percent != 0 {
+            parts.push(format!("speed {:+}%", self.speed_percent));
+        }
+        parts.join(", ")
+    }
+}
+
+// Parametry rur w rundzie z dostosowaniem.
+pub fn adjust(mut params: PipeParams, run: Option<&AdaptiveRun>) -> PipeParams {
+    if let Some(AdaptiveRun(adjustment)) = run {
+        params.gap *= 1.0 + f32::from(adjustment.gap_percent) / 100.0;
+        params.speed *= 1.0 + f32::from(adjustment.speed_percent) / 100.0;
+    }
+    params
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeathCause {
+    Obstacle,
+    Boundary,
+}
+
+impl DeathCause {
+    pub fn of(transform: &Transform, collider: &Collider) -> Self {
+        if crate::core::out_of_bounds(
+            transform.translation.y,
+            collider.half_size.y,
+            WORLD_SIZE.y / 2.0,
+            -WORLD_SIZE.y / 2.0,
+        ) {
+            Self::Boundary
+        } else {
+            Self::Obstacle
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Obstacle => "obstacle",
+            Self::Boundary => "boundary",
+        }
+    }
+}
+
+// Historia ostatnich rund i dostosowanie dla następnej.
+#[derive(Resource, Debug, Default)]
+pub struct AdaptiveDifficulty {
+    recent: VecDeque<(i32, DeathCause)>,
+    current: Adjustment,
+}
+
+impl AdaptiveDifficulty {
+    pub fn current(&self) -> Adjustment {
+        self.current
+    }
+
+    fn record(&mut self, score: i32, cause: DeathCause) {
+        self.recent.push_back((score, cause));
+        if self.recent.len() > RECENT_RUNS {
+            self.recent.pop_front();
+        }
+    }
+
+    fn successes(&self) -> usize {
+        self.recent
+            .iter()
+            .filter(|(score, _)| *score >= SUCCESS_SCORE)
+            .count()
+    }
+
+    // Jeden krok w stronę docelowego odsetka udanych rund. Po zmianie
+    // historia zaczyna się od nowa, żeby ocenić już nowe ustawienie.
+    fn adapt(&mut self) {
+        if self.recent.len() < MIN_RUNS {
+            return;
+        }
+        let rate = self.successes() as f32 / self.recent.len() as f32;
+        let obstacle_deaths = self
+            .recent
+            .iter()
+            .filter(|(_, cause)| *cause == DeathCause::Obstacle)
+            .count();
+        let mut next = self.current;
+        if rate < TARGET_SUCCESS_RATE - TOLERANCE {
+            // Śmierci na rurach – szersze przerwy; na suficie i podłodze – więcej
+            // czasu na reakcję.
+            if obstacle_deaths * 2 >= self.recent.len() {
+                next.gap_percent += STEP_PERCENT;
+            } else {
+                next.speed_percent -= STEP_PERCENT;
+            }
+        } else if rate > TARGET_SUCCESS_RATE + TOLERANCE {
+            // Najpierw cofamy ułatwienia, dopiero potem utrudniamy.
+            if next.gap_percent > 0 {
+                next.gap_percent -= STEP_PERCENT;
+            } else if next.speed_percent < 0 {
+                next.speed_percent += STEP_PERCENT;
+            } else {
+                next.gap_percent -= STEP_PERCENT;
+            }
+        }
+        let next = next.clamped();
+        if next != self.current {
+            self.current = next;
+            self.recent.clear();
+        }
+    }
+}
+
+// Dostosowanie obowiązujące w bieżącej rundzie; brak zasobu to zwykła runda.
+#[derive(Resource, Debug)]
+pub struct AdaptiveRun(pub Adjustment);
+
+// Rundy, których asysta nie dotyczy.
+#[derive(SystemParam)]
+struct SpecialRun<'w> {
+    weekly: Option<Res<'w, WeeklyRun>>,
+    mirror: Option<Res<'w, MirrorRun>>,
+    coop: Option<Res<'w, CoopRun>>,
+    royale: Option<Res<'w, RoyaleRun>>,
+    kids: Option<Res<'w, KidsMode>>,
+    spectating: Option<Res<'w, Spectating>>,
+}
+
+impl SpecialRun<'_> {
+    fn any(&self, settings: &Settings) -> bool {
+        self.weekly.is_some()
+            || self.mirror.is_some()
+            || self.coop.is_some()
+            || self.royale.is_some()
+            || self.kids.is_some()
+            || self.spectating.is_some()
+            || settings.ai.rivals > 0
+    }
+}
+
+#[derive(Component)]
+pub struct AdaptiveButton;
+
+pub fn adaptive_button_label(settings: &Settings) -> String {
+    let state = if settings.assists.adaptive {
+        "On"
+    } else {
+        "Off"
+    };
+    format!("Adaptive: {state}")
+}
+
+pub struct AdaptivePlugin;
+
+impl Plugin for AdaptivePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AdaptiveDifficulty>()
+            .add_systems(
+                OnEnter(AppState::Playing),
+                start_adaptive_run
+                    .after(crate::restart_game)
+                    .before(crate::replay::start_recording),
+            )
+            .add_systems(
+                OnEnter(AppState::GameOver),
+                (record_outcome, spawn_adaptive_summary).chain(),
+            )
+            .add_systems(
+                Update,
+                adaptive_button_system.run_if(in_state(AppState::Menu)),
+            );
+    }
+}
+
+fn start_adaptive_run(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    special: SpecialRun,
+    adaptive: Res<AdaptiveDifficulty>,
+) {
+    let adjustment = adaptive.current();
+    if settings.assists.adaptive && !special.any(&settings) && !adjustment.is_neutral() {
+        commands.insert_resource(AdaptiveRun(adjustment));
+    } else {
+        commands.remove_resource::<AdaptiveRun>();
+    }
+}
+
+fn record_outcome(
+    settings: Res<Settings>,
+    special: SpecialRun,
+    score: Res<Score>,
+    player: Query<(&Transform, &Collider), With<Player>>,
+    mut adaptive: ResMut<AdaptiveDifficulty>,
+) {
+    if !settings.assists.adaptive || special.any(&settings) {
+        return;
+    }
+    let Ok((transform, collider)) = player.get_single() else {
+        return;
+    };
+    adaptive.record(score.0, DeathCause::of(transform, collider));
+    adaptive.adapt();
+}
+
+fn spawn_adaptive_summary(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    special: SpecialRun,
+    run: Option<Res<AdaptiveRun>>,
+    adaptive: Res<AdaptiveDifficulty>,
+) {
+    if !settings.assists.adaptive || special.any(&settings) {
+        return;
+    }
+    let this_run = run.map_or_else(Adjustment::default, |run| run.0);
+    let text = format!(
+        "Adaptive difficulty: {}\nNext run: {} ({} of {} recent runs reached {SUCCESS_SCORE})",
+        this_run.label(),
+        adaptive.current().label(),
+        adaptive.successes(),
+        adaptive.recent.len(),
+    );
+    commands.spawn((
+        Text::new(text),
+        TextColor(Color::WHITE),
+        TextFont {
+            font_size: 16.0,
+            ..Default::default()
+        },
+        TextLayout::new_with_justify(JustifyText::Center),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(70.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..Default::default()
+        },
+        GameOverUI,
+    ));
+}
+
+fn adaptive_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, Option<&Children>),
+        (Changed<Interaction>, With<AdaptiveButton>),
+    >,
+    mut text_query: Query<&mut Text>,
+    mut settings: ResMut<Settings>,
+) {
+    for (interaction, mut bg_color, children) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                settings.assists.adaptive = !settings.assists.adaptive;
+                if let Some(mut text) = button_text(children, &mut text_query) {
+                    text.0 = adaptive_button_label(&settings);
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn played(runs: &[(i32, DeathCause)]) -> AdaptiveDifficulty {
+        let mut adaptive = AdaptiveDifficulty::default();
+        for &(score, cause) in runs {
+            adaptive.record(score, cause);
+            adaptive.adapt();
+        }
+        adaptive
+    }
+
+    #[test]
+    fn struggling_on_pipes_widens_gaps_and_on_the_floor_slows_pipes() {
+        let pipes = played(&[(2, DeathCause::Obstacle); 3]);
+        assert_eq!(pipes.current().gap_percent, STEP_PERCENT);
+        assert_eq!(pipes.current().speed_percent, 0);
+
+        let floor = played(&[(2, DeathCause::Boundary); 3]);
+        assert_eq!(floor.current().speed_percent, -STEP_PERCENT);
+    }
+
+    #[test]
+    fn doing_well_first_undoes_help_then_tightens_and_stays_in_bounds() {
+        let mut adaptive = played(&[(2, DeathCause::Obstacle); 3]);
+        for _ in 0..3 {
+            adaptive.record(30, DeathCause::Obstacle);
+            adaptive.adapt();
+        }
+        assert!(adaptive.current().is_neutral());
+
+        for _ in 0..30 {
+            adaptive.record(30, DeathCause::Obstacle);
+            adaptive.adapt();
+        }
+        assert_eq!(adaptive.current().gap_percent, -MAX_PERCENT);
+    }
+
+    #[test]
+    fn on_target_players_are_left_alone() {
+        let adaptive = played(&[
+            (20, DeathCause::Obstacle),
+            (3, DeathCause::Obstacle),
+            (15, DeathCause::Boundary),
+            (4, DeathCause::Obstacle),
+        ]);
+        assert!(adaptive.current().is_neutral());
+        assert_eq!(adaptive.current().label(), "none");
+    }
+}
@@ -0,0 +1,399 @@
+// Wyścig offline z rywalami sterowanymi przez komputer. Rywale lecą przez te
+// same rury co gracz (to samo ziarno rundy), rysowani jak duch, i zbierają
+// własne punkty. Umiejętności rywala to opóźnienie reakcji i częstość błędów.
+
+use std::collections::{HashSet, VecDeque};
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::assets::GameAssets;
+use crate::buttons::button_text;
+use crate::core::{FLAP_IMPULSE, GRAVITY};
+use crate::display::WORLD_SIZE;
+use crate::ghost::ghost_sprite;
+use crate::handicap::Handicap;
+use crate::hazards::Hazard;
+use crate::interpolation::Interpolated;
+use crate::pause::PauseState;
+use crate::physics::{Collider, Velocity};
+use crate::player::{HoverIdle, Player};
+use crate::rng::RunRng;
+use crate::royale::{ROYALE_RIVALS, RoyaleRun};
+use crate::run_save::PendingResume;
+use crate::score::{Score, ScoreZone};
+use crate::settings::Settings;
+use crate::text_style::OutlinedText;
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+pub const MAX_RIVALS: u8 = 3;
+
+const RIVAL_HALF_SIZE: Vec2 = Vec2::new(16.0, 16.0);
+
+// Rywal celuje trochę poniżej środka przerwy, bo machnięcie i tak go podrzuci.
+const AIM_BELOW_CENTER: f32 = 12.0;
+
+const RIVAL_TINTS: [Color; MAX_RIVALS as usize] = [
+    Color::srgb(1.0, 0.45, 0.45),
+    Color::srgb(0.45, 0.6, 1.0),
+    Color::srgb(0.5, 1.0, 0.5),
+];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AiSkill {
+    #[default]
+    Rookie,
+    Pro,
+    Ace,
+}
+
+impl AiSkill {
+    pub fn next(self) -> Self {
+        match self {
+            AiSkill::Rookie => AiSkill::Pro,
+            AiSkill::Pro => AiSkill::Ace,
+            AiSkill::Ace => AiSkill::Rookie,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            AiSkill::Rookie => "Rookie",
+            AiSkill::Pro => "Pro",
+            AiSkill::Ace => "Ace",
+        }
+    }
+
+    // Czas od decyzji do machnięcia.
+    fn reaction_secs(self) -> f32 {
+        match self {
+            AiSkill::Rookie => 0.2,
+            AiSkill::Pro => 0.12,
+            AiSkill::Ace => 0.05,
+        }
+    }
+
+    // Szansa, że rywal zignoruje decyzję o machnięciu.
+    fn error_rate(self) -> f64 {
+        match self {
+            AiSkill::Rookie => 0.15,
+            AiSkill::Pro => 0.05,
+            AiSkill::Ace => 0.01,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AiRaceSettings {
+    // 0 wyłącza rywali.
+    pub rivals: u8,
+    pub skill: AiSkill,
+    // Handicap gracza w wyścigu z rywalami.
+    pub handicap: Handicap,
+}
+
+#[derive(Component)]
+pub struct RivalsButton;
+
+#[derive(Component)]
+pub struct RivalSkillButton;
+
+#[derive(Component)]
+struct AiBird {
+    number: u8,
+    dy: f32,
+    alive: bool,
+    score: i32,
+    passed: HashSet<Entity>,
+    // Sekundy do zaplanowanych machnięć.
+    pending: VecDeque<f32>,
+    // Własny generator, żeby błędy rywali nie zmieniały rur gracza.
+    rng: StdRng,
+}
+
+#[derive(Component)]
+struct StandingsText;
+
+// Lot rywali w `FixedUpdate`; inne tryby (np. `royale.rs`) ustawiają się
+// względem tego zbioru.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct RivalsFlight;
+
+// Wysyłane, gdy rywal się rozbije.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct RivalOut {
+    pub number: u8,
+}
+
+pub struct AiRacePlugin;
+
+impl Plugin for AiRacePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<RivalOut>()
+            .add_systems(
+                OnEnter(AppState::Playing),
+                spawn_rivals
+                    .after(crate::restart_game)
+                    .after(crate::rng::reseed_run_rng),
+            )
+            .add_systems(OnEnter(AppState::Menu), despawn_rivals)
+            .add_systems(
+                Update,
+                (rivals_button_system, rival_skill_button_system).run_if(in_state(AppState::Menu)),
+            )
+            .add_systems(
+                FixedUpdate,
+                fly_rivals
+                    .in_set(RivalsFlight)
+                    .after(crate::physics::move_system)
+                    .run_if(in_state(PauseState::Running)),
+            )
+            .add_systems(Update, update_standings.run_if(in_state(AppState::Playing)));
+    }
+}
+
+pub fn rivals_button_label(settings: &Settings) -> String {
+    match settings.ai.rivals {
+        0 => "Rivals: off".to_string(),
+        rivals => format!("Rivals: {rivals}"),
+    }
+}
+
+pub fn rival_skill_button_label(settings: &Settings) -> String {
+    format!("AI: {}", settings.ai.skill.label())
+}
+
+fn rivals_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, Option<&Children>),
+        (Changed<Interaction>, With<RivalsButton>),
+    >,
+    mut text_query: Query<&mut Text>,
+    mut settings: ResMut<Settings>,
+) {
+    for (interaction, mut bg_color, children) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                settings.ai.rivals = (settings.ai.rivals + 1) % (MAX_RIVALS + 1);
+                if let Some(mut text) = button_text(children, &mut text_query) {
+                    text.0 = rivals_button_label(&settings);
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+fn rival_skill_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, Option<&Children>),
+        (Changed<Interaction>, With<RivalSkillButton>),
+    >,
+    mut text_query: Query<&mut Text>,
+    mut settings: ResMut<Settings>,
+) {
+    for (interaction, mut bg_color, children) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                settings.ai.skill = settings.ai.skill.next();
+                if let Some(mut text) = button_text(children, &mut text_query) {
+                    text.0 = rival_skill_button_label(&settings);
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+// Wznowiona runda nie ma rywali – nie wiemy, gdzie by teraz byli. W battle
+// royale rywali jest zawsze `ROYALE_RIVALS`, a tabelę zastępuje kill-feed.
+fn spawn_rivals(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    settings: Res<Settings>,
+    rng: Res<RunRng>,
+    pending_resume: Option<Res<PendingResume>>,
+    royale: Option<Res<RoyaleRun>>,
+    old: Query<Entity, Or<(With<AiBird>, With<StandingsText>)>>,
+) {
+    for entity in old.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    let rivals = if royale.is_some() {
+        ROYALE_RIVALS
+    } else {
+        settings.ai.rivals.min(MAX_RIVALS)
+    };
+    if rivals == 0 || pending_resume.is_some() {
+        return;
+    }
+
+    for number in 1..=rivals {
+        let tint = RIVAL_TINTS[usize::from(number - 1) % RIVAL_TINTS.len()];
+        commands.spawn((
+            ghost_sprite(&assets, tint),
+            AiBird {
+                number,
+                dy: 0.0,
+                alive: true,
+                score: 0,
+                passed: HashSet::new(),
+                pending: VecDeque::new(),
+                rng: StdRng::seed_from_u64(rng.seed() ^ u64::from(number)),
+            },
+            Interpolated::default(),
+            Transform::from_xyz(0.0, 0.0, 0.8),
+        ));
+    }
+    if royale.is_some() {
+        return;
+    }
+    commands.spawn((
+        Text2d::new(""),
+        OutlinedText::new(Color::WHITE),
+        TextFont {
+            font_size: 16.0,
+            ..Default::default()
+        },
+        Transform::from_xyz(0.0, 195.0, 10.0),
+        StandingsText,
+    ));
+}
+
+fn despawn_rivals(
+    mut commands: Commands,
+    query: Query<Entity, Or<(With<AiBird>, With<StandingsText>)>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// Rywale startują razem z graczem, bo dopiero wtedy pojawiają się rury.
+fn fly_rivals(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    player: Query<Has<HoverIdle>, With<Player>>,
+    mut rivals: Query<(&mut AiBird, &mut Transform, &mut Visibility)>,
+    zones: Query<(Entity, &Transform, &Collider, &Velocity), (With<ScoreZone>, Without<AiBird>)>,
+    hazards: Query<(&Transform, &Collider), (With<Hazard>, Without<AiBird>)>,
+    mut out: EventWriter<RivalOut>,
+) {
+    if player.get_single().unwrap_or(true) {
+        return;
+    }
+    let dt = time.delta_secs();
+    let skill = settings.ai.skill;
+
+    for (mut bird, mut transform, mut visibility) in rivals.iter_mut() {
+        if !bird.alive {
+            continue;
+        }
+        let y = transform.translation.y;
+
+        // Najbliższa przerwa, która jeszcze nie minęła rywala – w trybie
+        // lustrzanym nadlatują z obu stron.
+        let target = zones
+            .iter()
+            .map(|(_, zone_transform, collider, velocity)| {
+                (collider.center(zone_transform), velocity.dx)
+            })
+            .filter(|(center, dx)| -center.x * dx.signum() >= -RIVAL_HALF_SIZE.x)
+            .map(|(center, _)| center)
+            .min_by(|a, b| a.x.abs().total_cmp(&b.x.abs()))
+            .map_or(0.0, |center| center.y - AIM_BELOW_CENTER);
+        if bird.pending.is_empty() && y < target && bird.dy <= 0.0 {
+            let mistake = bird.rng.gen_bool(skill.error_rate());
+            if !mistake {
+                bird.pending.push_back(skill.reaction_secs());
+            }
+        }
+
+        let mut flap = false;
+        for delay in bird.pending.iter_mut() {
+            *delay -= dt;
+        }
+        while bird.pending.front().is_some_and(|&delay| delay <= 0.0) {
+            bird.pending.pop_front();
+            flap = true;
+        }
+        if flap {
+            bird.dy = FLAP_IMPULSE;
+        }
+        bird.dy += GRAVITY * dt;
+        transform.translation.y += bird.dy * dt;
+
+        let center = transform.translation.truncate();
+        let crashed = crate::core::out_of_bounds(
+            center.y,
+            RIVAL_HALF_SIZE.y,
+            WORLD_SIZE.y / 2.0,
+            -WORLD_SIZE.y / 2.0,
+        ) || hazards.iter().any(|(hazard_transform, collider)| {
+            crate::core::aabb_overlap(
+                center.into(),
+                RIVAL_HALF_SIZE.into(),
+                collider.center(hazard_transform).into(),
+                collider.half_size.into(),
+            )
+        });
+        if crashed {
+            bird.alive = false;
+            *visibility = Visibility::Hidden;
+            out.send(RivalOut {
+                number: bird.number,
+            });
+            continue;
+        }
+
+        for (zone, zone_transform, collider, _) in zones.iter() {
+            if !bird.passed.contains(&zone)
+                && crate::core::aabb_overlap(
+                    center.into(),
+                    RIVAL_HALF_SIZE.into(),
+                    collider.center(zone_transform).into(),
+                    collider.half_size.into(),
+                )
+            {
+                bird.passed.insert(zone);
+                bird.score += 1;
+            }
+        }
+    }
+}
+
+fn update_standings(
+    score: Res<Score>,
+    rivals: Query<&AiBird>,
+    mut query: Query<&mut Text2d, With<StandingsText>>,
+) {
+    let mut rivals: Vec<_> = rivals.iter().collect();
+    rivals.sort_by_key(|bird| bird.number);
+    let place = 1 + rivals.iter().filter(|bird| bird.score > score.0).count();
+
+    let mut label = format!("Place {place}/{}", rivals.len() + 1);
+    for bird in &rivals {
+        let out = if bird.alive { "" } else { " (out)" };
+        label.push_str(&format!("   AI {}: {}{out}", bird.number, bird.score));
+    }
+    for mut text in query.iter_mut() {
+        if text.0 != label {
+            text.0 = label.clone();
+        }
+    }
+}
@@ -0,0 +1,172 @@
+// Ozdobne obiekty w tle (chmury, odległe ptaki, balony). Nie mają kolizji,
+// lecą własnym tempem między tłem a rurami i znikają za lewą krawędzią.
+
+use std::ops::Range;
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::AppState;
+use crate::assets::GameAssets;
+use crate::display::PlayArea;
+use crate::interpolation::Interpolated;
+use crate::pause::PauseState;
+use crate::physics::Velocity;
+use crate::settings::Settings;
+
+// Warstwa między tłem (z = -1) a rurami (z = 0).
+const DECORATION_Z: f32 = -0.5;
+
+// Odstęp między kolejnymi ozdobami (sekundy).
+const SPAWN_INTERVAL: Range<f32> = 1.5..4.0;
+
+#[derive(Component)]
+pub struct Decoration;
+
+#[derive(Debug, Clone, Copy)]
+enum DecorationKind {
+    Cloud,
+    DistantBird,
+    Balloon,
+}
+
+impl DecorationKind {
+    fn random(rng: &mut impl Rng) -> Self {
+        match rng.gen_range(0..10) {
+            0..6 => DecorationKind::Cloud,
+            6..9 => DecorationKind::DistantBird,
+            _ => DecorationKind::Balloon,
+        }
+    }
+
+    // Zakres prędkości w lewo – im dalej, tym wolniej.
+    fn speed(self) -> Range<f32> {
+        match self {
+            DecorationKind::Cloud => 15.0..35.0,
+            DecorationKind::DistantBird => 40.0..70.0,
+            DecorationKind::Balloon => 20.0..30.0,
+        }
+    }
+}
+
+#[derive(Resource)]
+struct AmbientTimer(Timer);
+
+impl Default for AmbientTimer {
+    fn default() -> Self {
+        AmbientTimer(Timer::from_seconds(SPAWN_INTERVAL.start, TimerMode::Once))
+    }
+}
+
+pub struct AmbientPlugin;
+
+impl Plugin for AmbientPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AmbientTimer>()
+            .add_systems(OnEnter(AppState::Playing), clear_decorations)
+            .add_systems(
+                Update,
+                (spawn_decorations, despawn_offscreen_decorations)
+                    .run_if(in_state(PauseState::Running)),
+            );
+    }
+}
+
+fn clear_decorations(
+    mut commands: Commands,
+    mut timer: ResMut<AmbientTimer>,
+    query: Query<Entity, With<Decoration>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    *timer = AmbientTimer::default();
+}
+
+fn spawn_decorations(
+    mut commands: Commands,
+    mut timer: ResMut<AmbientTimer>,
+    time: Res<Time>,
+    settings: Res<Settings>,
+    assets: Res<GameAssets>,
+    play_area: Res<PlayArea>,
+) {
+    // Tryb oszczędzania baterii obywa się bez ozdób.
+    if settings.low_power || !timer.0.tick(time.delta()).finished() {
+        return;
+    }
+
+    let mut rng = rand::thread_rng();
+    timer.0 = Timer::from_seconds(rng.gen_range(SPAWN_INTERVAL), TimerMode::Once);
+
+    let kind = DecorationKind::random(&mut rng);
+    let half_height = play_area.size.y / 2.0;
+    let x = play_area.half_width() + 60.0;
+    let y = rng.gen_range(0.0..half_height - 40.0);
+    let speed = rng.gen_range(kind.speed());
+
+    let mut decoration = commands.spawn((
+        Decoration,
+        Velocity {
+            dx: -speed,
+            dy: 0.0,
+        },
+        Interpolated::default(),
+        Transform::from_xyz(x, y, DECORATION_Z),
+        Visibility::default(),
+    ));
+
+    match kind {
+        DecorationKind::Cloud => {
+            let white = Color::srgba(1.0, 1.0, 1.0, 0.7);
+            decoration.with_children(|parent| {
+                for (offset, size) in [
+                    (Vec2::new(-22.0, -4.0), Vec2::new(36.0, 18.0)),
+                    (Vec2::new(0.0, 4.0), Vec2::new(44.0, 28.0)),
+                    (Vec2::new(24.0, -4.0), Vec2::new(34.0, 16.0)),
+                ] {
+                    parent.spawn((
+                        Sprite::from_color(white, size),
+                        Transform::from_translation(offset.extend(0.0)),
+                    ));
+                }
+            });
+        }
+        DecorationKind::DistantBird => {
+            decoration.insert((
+                Sprite {
+                    image: assets.bird.clone(),
+                    color: Color::srgba(0.2, 0.2, 0.3, 0.6),
+                    ..Default::default()
+                },
+                Transform::from_xyz(x, y, DECORATION_Z).with_scale(Vec3::splat(0.4)),
+            ));
+        }
+        DecorationKind::Balloon => {
+            let color = Color::hsl(rng.gen_range(0.0..360.0), 0.7, 0.6);
+            decoration.with_children(|parent| {
+                parent.spawn((
+                    Sprite::from_color(color, Vec2::new(20.0, 26.0)),
+                    Transform::default(),
+                ));
+                parent.spawn((
+                    Sprite::from_color(Color::srgb(0.3, 0.3, 0.3), Vec2::new(1.0, 18.0)),
+                    Transform::from_xyz(0.0, -22.0, 0.0),
+                ));
+            });
+        }
+    }
+}
+
+fn despawn_offscreen_decorations(
+    mut commands: Commands,
+    play_area: Res<PlayArea>,
+    query: Query<(Entity, &Transform), With<Decoration>>,
+) {
+    let left_edge = -play_area.half_width() - 80.0;
+    for (entity, transform) in query.iter() {
+        if transform.translation.x < left_edge {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
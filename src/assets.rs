@@ -0,0 +1,211 @@
+// Wszystkie obrazki gry ładowane raz, na starcie (stan `AppState::Loading`).
+// Spawnowanie korzysta wyłącznie z uchwytów z `GameAssets`, więc restart rundy
+// nie dotyka już `AssetServer`.
+//
+// Obrazek, który się nie wczytał, zastępujemy jednolitym prostokątem w jego
+// rozmiarze (zamiast niewidocznych rur), a brakujące pliki pokazujemy w toście.
+
+use bevy::asset::{LoadState, RenderAssetUsages, UntypedAssetId};
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::utils::HashMap;
+
+use crate::AppState;
+use crate::procedural::ProceduralArt;
+use crate::setup_wizard::FirstLaunch;
+use crate::theme::{Theme, ThemeCatalog};
+use crate::toast::Toast;
+
+const BIRD_PATH: &str = "sprites/bluebird-midflap.png";
+// Klatki machania skrzydłami (skrzydło w górze, w środku, w dole).
+const BIRD_FRAME_PATHS: [&str; 3] = [
+    "sprites/bluebird-upflap.png",
+    BIRD_PATH,
+    "sprites/bluebird-downflap.png",
+];
+// Uniesienie skrzydła w kolejnych klatkach rysowanego ptaka.
+const PROCEDURAL_WING_LIFTS: [f32; 3] = [3.0, 0.0, -3.0];
+
+// Zastępcze obrazki: rozmiar oryginału i kolor RGBA.
+const BIRD_PLACEHOLDER: (UVec2, [u8; 4]) = (UVec2::new(34, 24), [250, 200, 40, 255]);
+const BACKGROUND_PLACEHOLDER: (UVec2, [u8; 4]) = (UVec2::new(288, 512), [80, 190, 205, 255]);
+const PIPE_PLACEHOLDER: (UVec2, [u8; 4]) = (UVec2::new(52, 320), [90, 170, 50, 255]);
+const DIGIT_PLACEHOLDER: (UVec2, [u8; 4]) = (UVec2::new(24, 36), [255, 255, 255, 255]);
+
+#[derive(Debug, Clone)]
+pub struct ThemeAssets {
+    pub background: Handle<Image>,
+    pub pipe: Handle<Image>,
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct GameAssets {
+    pub bird: Handle<Image>,
+    pub bird_frames: [Handle<Image>; 3],
+    // Cyfry wyniku, indeksowane wartością cyfry.
+    pub digits: [Handle<Image>; 10],
+    themes: HashMap<Theme, ThemeAssets>,
+}
+
+impl GameAssets {
+    // Obrazki motywu; nieznany motyw (np. usunięta paczka) dostaje wygląd dzienny.
+    pub fn theme(&self, theme: &Theme) -> &ThemeAssets {
+        self.themes
+            .get(theme)
+            .unwrap_or_else(|| &self.themes[&Theme::Day])
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = UntypedAssetId> + '_ {
+        std::iter::once(self.bird.id().untyped())
+            .chain(self.bird_frames.iter().map(|frame| frame.id().untyped()))
+            .chain(self.digits.iter().map(|digit| digit.id().untyped()))
+            .chain(
+                self.themes.values().flat_map(|assets| {
+                    [assets.background.id().untyped(), assets.pipe.id().untyped()]
+                }),
+            )
+    }
+
+    // Każdy obrazek z zastępczym wyglądem na wypadek braku pliku.
+    fn images_with_placeholders(
+        &self,
+    ) -> impl Iterator<Item = (&Handle<Image>, (UVec2, [u8; 4]))> + '_ {
+        std::iter::once((&self.bird, BIRD_PLACEHOLDER))
+            .chain(
+                self.bird_frames
+                    .iter()
+                    .map(|frame| (frame, BIRD_PLACEHOLDER)),
+            )
+            .chain(self.digits.iter().map(|digit| (digit, DIGIT_PLACEHOLDER)))
+            .chain(self.themes.values().flat_map(|assets| {
+                [
+                    (&assets.background, BACKGROUND_PLACEHOLDER),
+                    (&assets.pipe, PIPE_PLACEHOLDER),
+                ]
+            }))
+    }
+}
+
+pub struct GameAssetsPlugin;
+
+impl Plugin for GameAssetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, load_game_assets)
+            .add_systems(Update, wait_for_assets.run_if(in_state(AppState::Loading)));
+    }
+}
+
+pub fn load_game_assets(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    catalog: Res<ThemeCatalog>,
+    procedural: Option<Res<ProceduralArt>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    // Bez plików obrazki powstają od razu w pamięci.
+    if procedural.is_some() {
+        let themes = catalog
+            .0
+            .iter()
+            .map(|theme| {
+                let assets = ThemeAssets {
+                    background: images.add(crate::procedural::background_image(theme)),
+                    pipe: images.add(crate::procedural::pipe_image(theme)),
+                };
+                (theme.clone(), assets)
+            })
+            .collect();
+        commands.insert_resource(GameAssets {
+            bird: images.add(crate::procedural::bird_image()),
+            bird_frames: PROCEDURAL_WING_LIFTS
+                .map(|lift| images.add(crate::procedural::bird_frame(lift))),
+            digits: std::array::from_fn(|digit| images.add(crate::procedural::digit_image(digit))),
+            themes,
+        });
+        return;
+    }
+
+    let themes = catalog
+        .0
+        .iter()
+        .map(|theme| {
+            let assets = ThemeAssets {
+                background: asset_server.load(theme.background_path()),
+                pipe: asset_server.load(theme.pipe_path()),
+            };
+            (theme.clone(), assets)
+        })
+        .collect();
+
+    commands.insert_resource(GameAssets {
+        bird: asset_server.load(BIRD_PATH),
+        bird_frames: BIRD_FRAME_PATHS.map(|path| asset_server.load(path)),
+        digits: std::array::from_fn(|digit| asset_server.load(format!("sprites/{digit}.png"))),
+        themes,
+    });
+}
+
+// Przechodzimy do menu, gdy każdy obrazek jest gotowy albo wiadomo, że się nie wczyta.
+fn wait_for_assets(
+    assets: Res<GameAssets>,
+    asset_server: Res<AssetServer>,
+    mut images: ResMut<Assets<Image>>,
+    mut toasts: EventWriter<Toast>,
+    first_launch: Option<Res<FirstLaunch>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    // Obrazki narysowane w kodzie są w `Assets` od razu, bez stanu wczytywania.
+    let finished = assets.ids().all(|id| {
+        images.contains(id.typed::<Image>())
+            || matches!(
+                asset_server.get_load_state(id),
+                Some(LoadState::Loaded | LoadState::Failed(_))
+            )
+    });
+
+    if !finished {
+        return;
+    }
+
+    let mut missing = Vec::new();
+    for (handle, (size, color)) in assets.images_with_placeholders() {
+        if !matches!(
+            asset_server.get_load_state(handle.id()),
+            Some(LoadState::Failed(_))
+        ) {
+            continue;
+        }
+        let path = asset_server
+            .get_path(handle.id())
+            .map_or_else(|| format!("{:?}", handle.id()), |path| path.to_string());
+        warn!("Brak obrazka {path}, używamy zastępczego");
+        images.insert(handle.id(), placeholder_image(size, color));
+        if !missing.contains(&path) {
+            missing.push(path);
+        }
+    }
+    if !missing.is_empty() {
+        toasts.send(Toast(format!("Missing files: {}", missing.join(", "))));
+    }
+
+    // Przy pierwszym uruchomieniu menu poprzedza kreator ustawień.
+    next_state.set(if first_launch.is_some() {
+        AppState::Setup
+    } else {
+        AppState::Menu
+    });
+}
+
+fn placeholder_image(size: UVec2, color: [u8; 4]) -> Image {
+    Image::new_fill(
+        Extent3d {
+            width: size.x,
+            height: size.y,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &color,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
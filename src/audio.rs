@@ -0,0 +1,345 @@
+// Dźwięk: globalne wyciszanie (klawisz M) ze wskaźnikiem w rogu ekranu oraz
+// klasyczne efekty (skrzydło, punkt, uderzenie, śmierć, "swoosh").
+//
+// Efekty to zdarzenia `Sfx` wysyłane w odpowiedzi na zdarzenia rozgrywki
+// i zmiany stanu; systemy gry nie odtwarzają dźwięków same. Zapętlone dźwięki
+// rundy (wiatr) są śledzonymi encjami – pauza je wstrzymuje, a wznowienie
+// puszcza dalej od tego samego miejsca.
+
+use bevy::asset::LoadState;
+use bevy::audio::Volume;
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::kids::KidBounce;
+use crate::music::MusicVoice;
+use crate::mutators::{MutatedRun, Mutator};
+use crate::pause::PauseState;
+use crate::player::Flapped;
+use crate::procedural::ProceduralArt;
+use crate::score::PointScored;
+use crate::settings::Settings;
+use crate::toast::Toast;
+use crate::tournament::{Modifier, WeeklyRun};
+
+const WIND_LOOP_PATH: &str = "audio/wind.ogg";
+
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sfx {
+    Wing,
+    Point,
+    Hit,
+    Die,
+    Swoosh,
+}
+
+impl Sfx {
+    const ALL: [Sfx; 5] = [Sfx::Wing, Sfx::Point, Sfx::Hit, Sfx::Die, Sfx::Swoosh];
+
+    fn path(self) -> &'static str {
+        match self {
+            Sfx::Wing => "audio/wing.ogg",
+            Sfx::Point => "audio/point.ogg",
+            Sfx::Hit => "audio/hit.ogg",
+            Sfx::Die => "audio/die.ogg",
+            Sfx::Swoosh => "audio/swoosh.ogg",
+        }
+    }
+}
+
+// Zbiory systemów dźwięku, względem których ustawiają się inne moduły.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum AudioSet {
+    // Głośność efektów i ikona wyciszenia; muzykę `music.rs` ustawia po nim.
+    Mute,
+}
+
+pub struct SoundPlugin;
+
+impl Plugin for SoundPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Sfx>()
+            .add_systems(Startup, (spawn_mute_indicator, apply_mute).chain())
+            .add_systems(
+                Update,
+                (mute_hotkey_system, apply_mute.in_set(AudioSet::Mute)).chain(),
+            )
+            .add_systems(Update, (flap_sfx, point_sfx, bounce_sfx))
+            .add_systems(OnEnter(AppState::GameOver), death_sfx)
+            .add_systems(OnEnter(AppState::Menu), swoosh_sfx)
+            .add_systems(OnEnter(AppState::Playing), swoosh_sfx);
+    }
+}
+
+fn flap_sfx(mut flapped: EventReader<Flapped>, mut sfx: EventWriter<Sfx>) {
+    for _ in flapped.read() {
+        sfx.send(Sfx::Wing);
+    }
+}
+
+fn point_sfx(mut scored: EventReader<PointScored>, mut sfx: EventWriter<Sfx>) {
+    for _ in scored.read() {
+        sfx.send(Sfx::Point);
+    }
+}
+
+// W trybie dla dzieci zderzenie kończy się odbiciem, ale nadal słychać uderzenie.
+fn bounce_sfx(mut bounces: EventReader<KidBounce>, mut sfx: EventWriter<Sfx>) {
+    if bounces.read().last().is_some() {
+        sfx.send(Sfx::Hit);
+    }
+}
+
+fn death_sfx(mut sfx: EventWriter<Sfx>) {
+    sfx.send(Sfx::Hit);
+    sfx.send(Sfx::Die);
+}
+
+fn swoosh_sfx(mut sfx: EventWriter<Sfx>) {
+    sfx.send(Sfx::Swoosh);
+}
+
+// Odtwarzanie efektów – tylko w oknie gry (jak fanfara w `milestone.rs`);
+// bezgłowe środowiska nie mają wyjścia audio.
+pub struct SfxPlaybackPlugin;
+
+impl Plugin for SfxPlaybackPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, load_sfx)
+            .add_systems(
+                Update,
+                (
+                    drop_failed_sfx.run_if(resource_exists::<SfxHandles>),
+                    play_sfx,
+                )
+                    .chain(),
+            )
+            .add_systems(Update, sync_wind_loop.run_if(in_state(PauseState::Running)))
+            .add_systems(OnEnter(PauseState::Paused), pause_gameplay_loops)
+            .add_systems(OnEnter(PauseState::Running), resume_gameplay_loops);
+    }
+}
+
+#[derive(Resource)]
+struct SfxHandles {
+    effects: Vec<(Sfx, Handle<AudioSource>)>,
+    wind: Option<Handle<AudioSource>>,
+}
+
+// Zapętlony szum wiatru w rundach z wiatrem; znika razem z rundą.
+#[derive(Component)]
+#[require(StateScoped<AppState>(|| StateScoped(AppState::Playing)))]
+struct WindLoop;
+
+fn load_sfx(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    procedural: Option<Res<ProceduralArt>>,
+) {
+    // `--no-assets` działa z gołego repozytorium – wtedy gramy bez efektów.
+    if procedural.is_some() {
+        return;
+    }
+    let effects = Sfx::ALL
+        .into_iter()
+        .map(|sfx| (sfx, asset_server.load(sfx.path())))
+        .collect();
+    commands.insert_resource(SfxHandles {
+        effects,
+        wind: Some(asset_server.load(WIND_LOOP_PATH)),
+    });
+}
+
+// Dźwięk, który się nie wczytał, milczy: usuwamy jego uchwyt (inaczej każdy
+// efekt zostawiałby odtwarzacz, który nigdy nie zagra ani nie zniknie),
+// a brakujące pliki pokazujemy w toście jak brakujące obrazki.
+fn drop_failed_sfx(
+    asset_server: Res<AssetServer>,
+    mut handles: ResMut<SfxHandles>,
+    mut toasts: EventWriter<Toast>,
+) {
+    let failed = |handle: &Handle<AudioSource>| load_failed(&asset_server, handle);
+    if !handles.effects.iter().any(|(_, handle)| failed(handle))
+        && !handles.wind.as_ref().is_some_and(failed)
+    {
+        return;
+    }
+    let mut missing = Vec::new();
+    handles.effects.retain(|(_, handle)| {
+        let keep = !failed(handle);
+        if !keep {
+            missing.push(audio_path(&asset_server, handle));
+        }
+        keep
+    });
+    if let Some(wind) = handles.wind.take_if(|wind| failed(wind)) {
+        missing.push(audio_path(&asset_server, &wind));
+    }
+    for path in &missing {
+        warn!("Brak dźwięku {path}, gramy bez niego");
+    }
+    toasts.send(Toast(format!("Missing files: {}", missing.join(", "))));
+}
+
+// Czy plik dźwięku się nie wczytał (brak pliku albo nieobsługiwany format).
+pub fn load_failed(asset_server: &AssetServer, handle: &Handle<AudioSource>) -> bool {
+    matches!(
+        asset_server.get_load_state(handle.id()),
+        Some(LoadState::Failed(_))
+    )
+}
+
+pub fn audio_path(asset_server: &AssetServer, handle: &Handle<AudioSource>) -> String {
+    asset_server
+        .get_path(handle.id())
+        .map_or_else(|| format!("{:?}", handle.id()), |path| path.to_string())
+}
+
+fn play_sfx(
+    mut commands: Commands,
+    mut requested: EventReader<Sfx>,
+    handles: Option<Res<SfxHandles>>,
+    settings: Res<Settings>,
+) {
+    let Some(handles) = handles else {
+        requested.clear();
+        return;
+    };
+    for sfx in requested.read() {
+        let Some((_, handle)) = handles.effects.iter().find(|(entry, _)| entry == sfx) else {
+            continue;
+        };
+        commands.spawn((
+            AudioPlayer(handle.clone()),
+            PlaybackSettings::DESPAWN.with_volume(Volume::new(settings.volume.sfx)),
+        ));
+    }
+}
+
+// Wiatr wieje w rundach z mutatorem "Wind" i w tygodniu wiatru.
+fn sync_wind_loop(
+    mut commands: Commands,
+    handles: Option<Res<SfxHandles>>,
+    settings: Res<Settings>,
+    mutated: Option<Res<MutatedRun>>,
+    weekly: Option<Res<WeeklyRun>>,
+    loops: Query<Entity, With<WindLoop>>,
+) {
+    let windy = mutated.is_some_and(|run| run.has(Mutator::Wind))
+        || weekly.is_some_and(|run| run.is(Modifier::Wind));
+    match (windy, loops.get_single()) {
+        (true, Err(_)) => {
+            let Some(wind) = handles.and_then(|handles| handles.wind.clone()) else {
+                return;
+            };
+            commands.spawn((
+                AudioPlayer(wind),
+                PlaybackSettings::LOOP.with_volume(Volume::new(settings.volume.sfx)),
+                WindLoop,
+            ));
+        }
+        (false, Ok(entity)) => commands.entity(entity).despawn(),
+        _ => {}
+    }
+}
+
+fn pause_gameplay_loops(loops: Query<&AudioSink, With<WindLoop>>) {
+    for sink in loops.iter() {
+        sink.pause();
+    }
+}
+
+fn resume_gameplay_loops(loops: Query<&AudioSink, With<WindLoop>>) {
+    for sink in loops.iter() {
+        sink.play();
+    }
+}
+
+// Ikona przekreślonego głośnika widoczna, gdy dźwięk jest wyciszony.
+#[derive(Component)]
+struct MuteIndicator;
+
+fn spawn_mute_indicator(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(10.0),
+                right: Val::Px(10.0),
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(1.0),
+                ..Default::default()
+            },
+            Visibility::Hidden,
+            GlobalZIndex(10),
+            MuteIndicator,
+        ))
+        .with_children(|parent| {
+            // Korpus głośnika
+            parent.spawn((
+                Node {
+                    width: Val::Px(6.0),
+                    height: Val::Px(8.0),
+                    ..Default::default()
+                },
+                BackgroundColor(Color::WHITE),
+            ));
+            // Lejek głośnika
+            parent.spawn((
+                Node {
+                    width: Val::Px(6.0),
+                    height: Val::Px(18.0),
+                    ..Default::default()
+                },
+                BackgroundColor(Color::WHITE),
+                BorderRadius::right(Val::Px(4.0)),
+            ));
+            parent.spawn((
+                Text::new("x"),
+                TextColor(Color::srgb(1.0, 0.3, 0.3)),
+                TextFont {
+                    font_size: 18.0,
+                    ..Default::default()
+                },
+            ));
+        });
+}
+
+fn mute_hotkey_system(keyboard_input: Res<ButtonInput<KeyCode>>, mut settings: ResMut<Settings>) {
+    if keyboard_input.just_pressed(settings.keybinds.mute) {
+        settings.muted = !settings.muted;
+    }
+}
+
+// Synchronizuje głośność i ikonę ze stanem `Settings::muted`.
+fn apply_mute(
+    settings: Res<Settings>,
+    mut global_volume: ResMut<GlobalVolume>,
+    sinks: Query<&AudioSink, Without<MusicVoice>>,
+    mut indicator: Query<&mut Visibility, With<MuteIndicator>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    let volume = if settings.muted {
+        0.0
+    } else {
+        settings.volume.master
+    };
+    global_volume.volume = Volume::new(volume);
+    // GlobalVolume dotyczy tylko nowych dźwięków – trwające efekty (i wiatr)
+    // ustawiamy ręcznie, z głośnością efektów. Muzykę co klatkę ustawia
+    // `music.rs`.
+    for sink in sinks.iter() {
+        sink.set_volume(volume * settings.volume.sfx);
+    }
+
+    for mut visibility in indicator.iter_mut() {
+        *visibility = if settings.muted {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
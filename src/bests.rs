@@ -0,0 +1,301 @@
+// Rekordy gracza zapisywane w pliku `bests.ron` w katalogu profilu, osobno dla każdego trybu gry
+// i poziomu trudności – wynik z łatwego poziomu nie przykrywa rekordu z trudnego.
+// Obok rekordów trzymamy lokalną tablicę wyników z warunkami każdej rundy.
+
+use std::collections::HashMap;
+use std::fs;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::AppState;
+use crate::adaptive::AdaptiveRun;
+use crate::handicap::Handicap;
+use crate::mutators::{MutatedRun, Mutators};
+use crate::player::Player;
+use crate::profiles::ActiveProfile;
+use crate::replay::LastReplay;
+use crate::rng::RunRng;
+use crate::score::Score;
+use crate::settings::{Difficulty, Settings};
+use crate::spectate::Spectating;
+
+const BESTS_FILE: &str = "bests.ron";
+
+// Ile najlepszych rund pamięta tablica wyników (łącznie, dla wszystkich kategorii).
+const LEADERBOARD_SIZE: usize = 100;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GameMode {
+    #[default]
+    Classic,
+    // Turniej tygodniowy (`tournament.rs`).
+    Weekly,
+    // Fale z obu stron ekranu (`mirror.rs`).
+    Mirror,
+    // Wspinaczka na obróconym ekranie (`tower.rs`).
+    Tower,
+    // Dwa ptaki na linie (`coop.rs`).
+    Coop,
+    // Ostatni ptak w powietrzu wygrywa (`royale.rs`).
+    Royale,
+}
+
+impl GameMode {
+    // Nazwa trybu w linkach z wyzwaniem (`?mode=`).
+    pub fn id(self) -> &'static str {
+        match self {
+            GameMode::Classic => "classic",
+            GameMode::Weekly => "weekly",
+            GameMode::Mirror => "mirror",
+            GameMode::Tower => "tower",
+            GameMode::Coop => "coop",
+            GameMode::Royale => "royale",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "classic" => Some(GameMode::Classic),
+            "weekly" => Some(GameMode::Weekly),
+            "mirror" => Some(GameMode::Mirror),
+            "tower" => Some(GameMode::Tower),
+            "coop" => Some(GameMode::Coop),
+            "royale" => Some(GameMode::Royale),
+            _ => None,
+        }
+    }
+}
+
+// Tryb bieżącej rundy.
+#[derive(Resource, Default)]
+pub struct RunMode(pub GameMode);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BestKey {
+    pub mode: GameMode,
+    pub difficulty: Difficulty,
+}
+
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PersonalBests {
+    scores: HashMap<BestKey, i32>,
+    leaderboard: Vec<LeaderboardEntry>,
+}
+
+// Runda na tablicy wyników razem z warunkami, w jakich padł wynik.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    pub score: i32,
+    pub key: BestKey,
+    // Włączona asysta "leniency" – wyniki z nią i bez niej nie są porównywalne.
+    pub assists: bool,
+    pub seed: u64,
+    // Mutatory własnej gry (`mutators.rs`); pusty zestaw to zwykła runda.
+    #[serde(default)]
+    pub mutators: Mutators,
+    // Zakodowana powtórka (`replay::Replay::encode`); pusta, gdy runda jej nie miała.
+    #[serde(default)]
+    pub replay: Vec<u8>,
+}
+
+impl PersonalBests {
+    pub fn get(&self, key: BestKey) -> i32 {
+        self.scores.get(&key).copied().unwrap_or(0)
+    }
+
+    // Zapisuje wynik; zwraca `true`, jeśli to nowy rekord w tej kategorii.
+    pub fn submit(&mut self, key: BestKey, score: i32) -> bool {
+        if score <= self.get(key) {
+            return false;
+        }
+        self.scores.insert(key, score);
+        true
+    }
+
+    pub fn add_entry(&mut self, entry: LeaderboardEntry) {
+        if entry.score <= 0 {
+            return;
+        }
+        let index = self
+            .leaderboard
+            .partition_point(|other| other.score >= entry.score);
+        self.leaderboard.insert(index, entry);
+        self.leaderboard.truncate(LEADERBOARD_SIZE);
+    }
+
+    // Najlepsze rundy w danej kategorii, od najwyższego wyniku.
+    pub fn leaderboard(&self, key: BestKey) -> impl Iterator<Item = &LeaderboardEntry> {
+        self.leaderboard
+            .iter()
+            .filter(move |entry| entry.key == key)
+    }
+
+    pub fn load(profile: &ActiveProfile) -> Self {
+        let path = profile.path(BESTS_FILE);
+        let Ok(contents) = fs::read_to_string(&path) else {
+            return PersonalBests::default();
+        };
+
+        ron::from_str(&contents).unwrap_or_else(|err| {
+            warn!("Nie udało się odczytać {}: {err}", path.display());
+            PersonalBests::default()
+        })
+    }
+
+    pub fn save(&self, profile: &ActiveProfile) {
+        let contents = match ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()) {
+            Ok(contents) => contents,
+            Err(err) => {
+                warn!("Nie udało się zserializować rekordów: {err}");
+                return;
+            }
+        };
+
+        let path = profile.path(BESTS_FILE);
+        if let Err(err) = fs::write(&path, contents) {
+            warn!("Nie udało się zapisać {}: {err}", path.display());
+        }
+    }
+}
+
+// Wynik ostatniej rundy na tle rekordu z jej kategorii.
+#[derive(Resource, Default)]
+pub struct RunRecord {
+    pub best: i32,
+    pub new_record: bool,
+}
+
+pub struct BestsPlugin;
+
+impl Plugin for BestsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PersonalBests>()
+            .init_resource::<RunMode>()
+            .init_resource::<RunRecord>()
+            // Oglądana runda znajomego nie trafia do rekordów widza.
+            .add_systems(
+                OnEnter(AppState::GameOver),
+                record_best.run_if(not(resource_exists::<Spectating>)),
+            );
+    }
+}
+
+pub fn current_key(mode: &RunMode, settings: &Settings) -> BestKey {
+    BestKey {
+        mode: mode.0,
+        difficulty: settings.difficulty,
+    }
+}
+
+pub fn record_best(
+    score: Res<Score>,
+    mode: Res<RunMode>,
+    settings: Res<Settings>,
+    rng: Res<RunRng>,
+    replay: Res<LastReplay>,
+    mut bests: ResMut<PersonalBests>,
+    mut record: ResMut<RunRecord>,
+    handicap: Query<(), (With<Handicap>, With<Player>)>,
+    adaptive: Option<Res<AdaptiveRun>>,
+    mutated: Option<Res<MutatedRun>>,
+) {
+    let key = current_key(&mode, &settings);
+    record.new_record = bests.submit(key, score.0);
+    record.best = bests.get(key);
+    bests.add_entry(LeaderboardEntry {
+        score: score.0,
+        key,
+        // Wynik z handicapem albo adaptacyjną trudnością oznaczamy jak wynik z asystą.
+        assists: settings.assists.leniency || !handicap.is_empty() || adaptive.is_some(),
+        seed: rng.seed(),
+        mutators: mutated.map(|mutated| mutated.mutators).unwrap_or_default(),
+        replay: replay
+            .0
+            .as_ref()
+            .and_then(|replay| match replay.encode() {
+                Ok(bytes) => Some(bytes),
+                Err(err) => {
+                    warn!("Nie udało się zapisać powtórki: {err}");
+                    None
+                }
+            })
+            .unwrap_or_default(),
+    });
+}
+
+// Zapis rekordów na dysk przy każdej zmianie. Dodawany tylko w `main`,
+// tak jak zapis ustawień.
+pub struct BestsPersistencePlugin;
+
+impl Plugin for BestsPersistencePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Last,
+            save_bests.run_if(
+                resource_changed::<PersonalBests>.and(not(resource_added::<PersonalBests>)),
+            ),
+        );
+    }
+}
+
+fn save_bests(bests: Res<PersonalBests>, profile: Res<ActiveProfile>) {
+    bests.save(&profile);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bests_are_kept_per_difficulty() {
+        let mut bests = PersonalBests::default();
+        let normal = BestKey {
+            mode: GameMode::Classic,
+            difficulty: Difficulty::Normal,
+        };
+        let hard = BestKey {
+            difficulty: Difficulty::Hard,
+            ..normal
+        };
+
+        assert!(bests.submit(normal, 12));
+        assert!(!bests.submit(normal, 10));
+        assert!(bests.submit(hard, 5));
+
+        assert_eq!(bests.get(normal), 12);
+        assert_eq!(bests.get(hard), 5);
+
+        let text = ron::to_string(&bests).unwrap();
+        let loaded: PersonalBests = ron::from_str(&text).unwrap();
+        assert_eq!(loaded.get(hard), 5);
+    }
+
+    #[test]
+    fn leaderboard_is_sorted_and_filtered() {
+        let mut bests = PersonalBests::default();
+        let normal = BestKey {
+            mode: GameMode::Classic,
+            difficulty: Difficulty::Normal,
+        };
+        let hard = BestKey {
+            difficulty: Difficulty::Hard,
+            ..normal
+        };
+        for (score, key) in [(7, normal), (20, hard), (15, normal), (0, normal)] {
+            bests.add_entry(LeaderboardEntry {
+                score,
+                key,
+                assists: false,
+                seed: 1,
+                mutators: Mutators::default(),
+                replay: Vec::new(),
+            });
+        }
+
+        let scores: Vec<_> = bests.leaderboard(normal).map(|entry| entry.score).collect();
+        assert_eq!(scores, [15, 7]);
+        assert_eq!(bests.leaderboard(hard).count(), 1);
+    }
+}
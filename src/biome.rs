@@ -0,0 +1,216 @@
+// Biomy w trakcie rundy: co `PIPES_PER_BIOME` rur tło płynnie przewija się
+// do kolejnego biomu (las → miasto → pustynia → noc), a nowe rury dostają jego
+// zabarwienie. Grafika tła pochodzi z motywu rundy, biom ją tylko przyciemnia
+// lub podmienia.
+
+use bevy::prelude::*;
+
+use crate::assets::GameAssets;
+use crate::display::PlayArea;
+use crate::pause::PauseState;
+use crate::scheduler::{Scheduler, TriggerId};
+use crate::score::Score;
+use crate::settings::Settings;
+use crate::theme::{ActiveTheme, Theme};
+use crate::{AppState, Background, RunEntity};
+
+pub const PIPES_PER_BIOME: i32 = 30;
+
+// Czas przewijania tła między biomami (sekundy).
+const TRANSITION_SECS: f32 = 2.0;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Biome {
+    #[default]
+    Forest,
+    City,
+    Desert,
+    Night,
+}
+
+impl Biome {
+    const ORDER: [Biome; 4] = [Biome::Forest, Biome::City, Biome::Desert, Biome::Night];
+
+    // Biom obowiązujący przy danym wyniku; po nocy cykl zaczyna się od nowa.
+    pub fn at_score(score: i32) -> Biome {
+        let stage = (score.max(0) / PIPES_PER_BIOME) as usize;
+        Self::ORDER[stage % Self::ORDER.len()]
+    }
+
+    fn background_tint(self) -> Color {
+        match self {
+            Biome::Forest => Color::WHITE,
+            Biome::City => Color::srgb(0.8, 0.84, 0.92),
+            Biome::Desert => Color::srgb(1.0, 0.88, 0.62),
+            Biome::Night => Color::WHITE,
+        }
+    }
+
+    pub fn pipe_tint(self) -> Color {
+        match self {
+            Biome::Forest => Color::WHITE,
+            Biome::City => Color::srgb(0.78, 0.8, 0.85),
+            Biome::Desert => Color::srgb(1.0, 0.85, 0.6),
+            Biome::Night => Color::srgb(0.6, 0.65, 0.85),
+        }
+    }
+
+    // Tło biomu: pierwszy biom to po prostu motyw rundy, noc ma własną grafikę.
+    pub fn background(self, assets: &GameAssets, theme: &Theme) -> (Handle<Image>, Color) {
+        match self {
+            Biome::Forest => (
+                assets.theme(theme).background.clone(),
+                theme.background_tint(),
+            ),
+            Biome::Night => (
+                assets.theme(&Theme::Night).background.clone(),
+                self.background_tint(),
+            ),
+            _ => (
+                assets.theme(theme).background.clone(),
+                self.background_tint(),
+            ),
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct CurrentBiome(pub Biome);
+
+// Tło w trakcie przewijania do `target_x`; z `despawn` znika po dotarciu na
+// miejsce. Stałe tło sceny (bez `RunEntity`) zamiast znikać czeka za kadrem,
+// aż `restart_game` przywróci je w następnej rundzie.
+#[derive(Component)]
+struct BiomeScroll {
+    target_x: f32,
+    speed: f32,
+    despawn: bool,
+}
+
+pub struct BiomePlugin;
+
+impl Plugin for BiomePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CurrentBiome>()
+            .add_systems(
+                OnEnter(AppState::Playing),
+                reset_biome.after(crate::scheduler::reset_scheduler),
+            )
+            .add_systems(
+                Update,
+                (advance_biome, scroll_backgrounds)
+                    .chain()
+                    .run_if(in_state(PauseState::Running)),
+            );
+    }
+}
+
+fn reset_biome(
+    mut commands: Commands,
+    mut biome: ResMut<CurrentBiome>,
+    mut scheduler: ResMut<Scheduler>,
+    scrolling: Query<Entity, With<BiomeScroll>>,
+) {
+    biome.0 = Biome::default();
+    scheduler.every_points(TriggerId::BiomeChange, PIPES_PER_BIOME);
+    for entity in scrolling.iter() {
+        commands.entity(entity).remove::<BiomeScroll>();
+    }
+}
+
+fn advance_biome(
+    mut commands: Commands,
+    (score, mut scheduler): (Res<Score>, ResMut<Scheduler>),
+    mut biome: ResMut<CurrentBiome>,
+    (assets, theme): (Res<GameAssets>, Res<ActiveTheme>),
+    play_area: Res<PlayArea>,
+    settings: Res<Settings>,
+    mut backgrounds: Query<(Entity, &mut Transform, Has<RunEntity>), With<Background>>,
+) {
+    if !scheduler.take(TriggerId::BiomeChange) {
+        return;
+    }
+    let next = Biome::at_score(score.0);
+    if next == biome.0 {
+        return;
+    }
+    biome.0 = next;
+    // Niebo z shadera samo przechodzi w noc, tła nie podmieniamy.
+    if theme.0 == Theme::Sky {
+        return;
+    }
+
+    let width = play_area.size.x;
+    let speed = width / TRANSITION_SECS;
+    let instant = settings.accessibility.reduced_motion;
+
+    // Stare tło odjeżdża w lewo, nowe wjeżdża z prawej.
+    for (entity, mut transform, run_entity) in backgrounds.iter_mut() {
+        if instant && run_entity {
+            commands.entity(entity).despawn_recursive();
+        } else if instant {
+            transform.translation.x = -width;
+        } else {
+            commands.entity(entity).insert(BiomeScroll {
+                target_x: transform.translation.x - width,
+                speed,
+                despawn: true,
+            });
+        }
+    }
+
+    let (image, color) = next.background(&assets, &theme.0);
+    let start_x = if instant { 0.0 } else { width };
+    let mut background = commands.spawn((
+        Sprite {
+            image,
+            color,
+            custom_size: Some(play_area.size),
+            ..Default::default()
+        },
+        Transform::from_xyz(start_x, 0.0, -1.0),
+        Background,
+        RunEntity,
+    ));
+    if !instant {
+        background.insert(BiomeScroll {
+            target_x: 0.0,
+            speed,
+            despawn: false,
+        });
+    }
+}
+
+fn scroll_backgrounds(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Transform, &BiomeScroll, Has<RunEntity>)>,
+) {
+    for (entity, mut transform, scroll, run_entity) in query.iter_mut() {
+        let step = scroll.speed * time.delta_secs();
+        transform.translation.x = (transform.translation.x - step).max(scroll.target_x);
+
+        if transform.translation.x <= scroll.target_x {
+            if scroll.despawn && run_entity {
+                commands.entity(entity).despawn_recursive();
+            } else {
+                commands.entity(entity).remove::<BiomeScroll>();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn biome_changes_every_thirty_pipes_and_cycles() {
+        assert_eq!(Biome::at_score(0), Biome::Forest);
+        assert_eq!(Biome::at_score(29), Biome::Forest);
+        assert_eq!(Biome::at_score(30), Biome::City);
+        assert_eq!(Biome::at_score(60), Biome::Desert);
+        assert_eq!(Biome::at_score(90), Biome::Night);
+        assert_eq!(Biome::at_score(120), Biome::Forest);
+    }
+}
@@ -0,0 +1,178 @@
+// Mruganie i błyski: `Blink` przez zadany czas przygasza sprite albo węzeł UI
+// co pół okresu (klatki nietykalności) lub zabarwia go kolorem, który płynnie
+// wraca do pierwotnego (trafienie). Po czasie kolor wraca do stanu sprzed
+// efektu, a komponent usuwa się sam.
+//
+// Z tego samego komponentu korzysta biały błysk całego ekranu przy śmierci,
+// jak w oryginalnym Flappy Bird.
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::game_over::GameOverUI;
+use crate::settings::Settings;
+
+// Krycie encji w "zgaszonej" fazie mrugania.
+const FLICKER_ALPHA: f32 = 0.25;
+const DEATH_FLASH_SECS: f32 = 0.3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlinkStyle {
+    // Naprzemiennie pełne i przygaszone krycie; `period` to cały cykl.
+    Flicker { period: f32 },
+    // Kolor na starcie, zanikający do pierwotnego.
+    Flash(Color),
+}
+
+#[derive(Component, Debug, Clone)]
+pub struct Blink {
+    style: BlinkStyle,
+    duration: f32,
+    elapsed: f32,
+    // Kolor sprzed efektu, zapamiętany przy pierwszej klatce.
+    base: Option<Color>,
+}
+
+impl Blink {
+    pub fn flicker(duration: f32, period: f32) -> Self {
+        Self::new(BlinkStyle::Flicker { period }, duration)
+    }
+
+    pub fn flash(color: Color, duration: f32) -> Self {
+        Self::new(BlinkStyle::Flash(color), duration)
+    }
+
+    fn new(style: BlinkStyle, duration: f32) -> Self {
+        Self {
+            style,
+            duration,
+            elapsed: 0.0,
+            base: None,
+        }
+    }
+
+    fn finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    fn color_at(&self, base: Color) -> Color {
+        match self.style {
+            BlinkStyle::Flicker { period } => {
+                let dimmed = period > 0.0 && (self.elapsed / (period / 2.0)) as u32 % 2 == 1;
+                if dimmed {
+                    base.with_alpha(base.alpha() * FLICKER_ALPHA)
+                } else {
+                    base
+                }
+            }
+            BlinkStyle::Flash(color) => {
+                let t = if self.duration <= 0.0 {
+                    1.0
+                } else {
+                    (self.elapsed / self.duration).clamp(0.0, 1.0)
+                };
+                color.mix(&base, t)
+            }
+        }
+    }
+
+    // Krok efektu; zwraca `true`, gdy kolor wrócił już do pierwotnego.
+    fn advance(&mut self, color: &mut Color, dt: f32) -> bool {
+        let base = *self.base.get_or_insert(*color);
+        self.elapsed += dt;
+        if self.finished() {
+            *color = base;
+            return true;
+        }
+        *color = self.color_at(base);
+        false
+    }
+}
+
+pub struct BlinkPlugin;
+
+impl Plugin for BlinkPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (blink_sprites, blink_ui_nodes))
+            .add_systems(
+                OnEnter(AppState::GameOver),
+                flash_on_death.after(crate::game_over::on_enter_game_over),
+            );
+    }
+}
+
+fn blink_sprites(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Sprite, &mut Blink)>,
+) {
+    for (entity, mut sprite, mut blink) in query.iter_mut() {
+        if blink.advance(&mut sprite.color, time.delta_secs()) {
+            commands.entity(entity).remove::<Blink>();
+        }
+    }
+}
+
+fn blink_ui_nodes(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut BackgroundColor, &mut Blink), Without<Sprite>>,
+) {
+    for (entity, mut background, mut blink) in query.iter_mut() {
+        if blink.advance(&mut background.0, time.delta_secs()) {
+            commands.entity(entity).remove::<Blink>();
+        }
+    }
+}
+
+// Błysk leży nad ekranem końca gry i znika razem z nim. W trybie
+// ograniczonego ruchu go pomijamy.
+fn flash_on_death(mut commands: Commands, settings: Res<Settings>) {
+    if settings.accessibility.reduced_motion {
+        return;
+    }
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..Default::default()
+        },
+        BackgroundColor(Color::WHITE.with_alpha(0.0)),
+        GlobalZIndex(30),
+        Blink::flash(Color::WHITE, DEATH_FLASH_SECS),
+        GameOverUI,
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flicker_alternates_and_restores_the_base_color() {
+        let mut blink = Blink::flicker(1.0, 0.2);
+        let mut color = Color::WHITE;
+
+        blink.advance(&mut color, 0.05);
+        assert_eq!(color.alpha(), 1.0);
+        blink.advance(&mut color, 0.1);
+        assert_eq!(color.alpha(), FLICKER_ALPHA);
+        assert!(blink.advance(&mut color, 1.0));
+        assert_eq!(color, Color::WHITE);
+    }
+
+    #[test]
+    fn flash_fades_back_to_the_base_color() {
+        let base = Color::srgb(1.0, 0.84, 0.2);
+        let mut blink = Blink::flash(Color::WHITE, 1.0);
+        let mut color = base;
+
+        blink.advance(&mut color, 0.0);
+        assert_eq!(color, Color::WHITE);
+        assert!(!blink.advance(&mut color, 0.5));
+        assert_ne!(color, base);
+        assert!(blink.advance(&mut color, 0.5));
+        assert_eq!(color, base);
+    }
+}
@@ -0,0 +1,198 @@
+// Bańka ochronna wokół ptaka, który niesie młotek (`hammer.rs`) – jedyną
+// osłonę przed zderzeniem. Bańkę steruje wyłącznie stan osłony: pojawia się,
+// gdy ptak dostaje `Hammer`, faluje, gdy ptak otrze się o przeszkodę, i pęka
+// na odłamki, gdy młotek zostanie zużyty.
+
+use std::f32::consts::TAU;
+
+use bevy::math::curve::EaseFunction;
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::hammer::{Debris, Hammer};
+use crate::hazards::Hazard;
+use crate::pause::PauseState;
+use crate::physics::{Collider, LifeTime, Velocity};
+use crate::player::Player;
+use crate::settings::Settings;
+use crate::tween::ScaleTween;
+
+const BUBBLE_SIZE: f32 = 52.0;
+const BUBBLE_ALPHA: f32 = 0.45;
+// Odległość od przeszkody, przy której liczymy otarcie.
+const GRAZE_MARGIN: f32 = 8.0;
+const RIPPLE_SECS: f32 = 0.35;
+const RIPPLE_SCALE: f32 = 1.25;
+const RIPPLE_ALPHA: f32 = 0.9;
+const SHARDS: usize = 10;
+const SHARD_SECS: f32 = 0.5;
+const SHARD_SIZE: Vec2 = Vec2::new(8.0, 3.0);
+const SHARD_COLOR: Color = Color::srgba(0.85, 0.95, 1.0, 0.8);
+
+#[derive(Resource)]
+struct BubbleImage(Handle<Image>);
+
+#[derive(Component)]
+pub struct Bubble;
+
+// Trwające falowanie bańki po otarciu.
+#[derive(Component)]
+struct Ripple {
+    elapsed: f32,
+}
+
+pub struct BubblePlugin;
+
+impl Plugin for BubblePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, paint_bubble)
+            .add_systems(
+                FixedUpdate,
+                ripple_on_graze
+                    .after(crate::physics::move_system)
+                    .run_if(in_state(PauseState::Running)),
+            )
+            .add_systems(
+                Update,
+                (show_bubble, shatter_bubble, fade_ripple).run_if(in_state(PauseState::Running)),
+            );
+    }
+}
+
+fn paint_bubble(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    commands.insert_resource(BubbleImage(images.add(crate::procedural::bubble_image())));
+}
+
+fn show_bubble(
+    mut commands: Commands,
+    image: Res<BubbleImage>,
+    settings: Res<Settings>,
+    armed: Query<Entity, (With<Player>, Added<Hammer>)>,
+) {
+    for player in armed.iter() {
+        let start = if settings.accessibility.reduced_motion {
+            Vec3::ONE
+        } else {
+            Vec3::ZERO
+        };
+        commands.entity(player).with_child((
+            Sprite {
+                image: image.0.clone(),
+                color: Color::WHITE.with_alpha(BUBBLE_ALPHA),
+                custom_size: Some(Vec2::splat(BUBBLE_SIZE)),
+                ..Default::default()
+            },
+            ScaleTween::new(start, Vec3::ONE, 0.2, EaseFunction::BackOut),
+            Transform::from_xyz(0.0, 0.0, 0.1),
+            Bubble,
+        ));
+    }
+}
+
+// Otarcie: ptak tuż przy przeszkodzie, ale jeszcze jej nie dotyka (dotknięcie
+// rozbija ją młotkiem). Faluje tylko przy wejściu w otarcie.
+fn ripple_on_graze(
+    mut commands: Commands,
+    player_query: Query<(&Transform, &Collider, &Children), (With<Player>, With<Hammer>)>,
+    hazards: Query<(&Transform, &Collider), With<Hazard>>,
+    bubbles: Query<Entity, With<Bubble>>,
+    settings: Res<Settings>,
+    mut was_grazing: Local<bool>,
+) {
+    let Ok((player_transform, player_collider, children)) = player_query.get_single() else {
+        *was_grazing = false;
+        return;
+    };
+
+    let center = player_collider.center(player_transform);
+    let grazing = hazards.iter().any(|(transform, collider)| {
+        let hazard_center = collider.center(transform);
+        let near = crate::core::aabb_overlap(
+            center.into(),
+            (player_collider.half_size + GRAZE_MARGIN).into(),
+            hazard_center.into(),
+            collider.half_size.into(),
+        );
+        let touching = crate::core::aabb_overlap(
+            center.into(),
+            player_collider.half_size.into(),
+            hazard_center.into(),
+            collider.half_size.into(),
+        );
+        near && !touching
+    });
+
+    if grazing && !*was_grazing {
+        for bubble in bubbles.iter_many(children) {
+            let mut bubble = commands.entity(bubble);
+            bubble.insert(Ripple { elapsed: 0.0 });
+            if !settings.accessibility.reduced_motion {
+                bubble.insert(ScaleTween::new(
+                    Vec3::splat(RIPPLE_SCALE),
+                    Vec3::ONE,
+                    RIPPLE_SECS,
+                    EaseFunction::ElasticOut,
+                ));
+            }
+        }
+    }
+    *was_grazing = grazing;
+}
+
+fn fade_ripple(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut ripples: Query<(Entity, &mut Ripple, &mut Sprite)>,
+) {
+    for (entity, mut ripple, mut sprite) in ripples.iter_mut() {
+        ripple.elapsed += time.delta_secs();
+        let t = (ripple.elapsed / RIPPLE_SECS).min(1.0);
+        sprite
+            .color
+            .set_alpha(RIPPLE_ALPHA + (BUBBLE_ALPHA - RIPPLE_ALPHA) * t);
+        if t >= 1.0 {
+            commands.entity(entity).remove::<Ripple>();
+        }
+    }
+}
+
+// Młotek zużyty (albo runda się skończyła): bańka pęka na odłamki.
+fn shatter_bubble(
+    mut commands: Commands,
+    mut disarmed: RemovedComponents<Hammer>,
+    bubbles: Query<(Entity, &Parent, &GlobalTransform), With<Bubble>>,
+    settings: Res<Settings>,
+) {
+    let disarmed: Vec<Entity> = disarmed.read().collect();
+    if disarmed.is_empty() {
+        return;
+    }
+    let mut rng = rand::thread_rng();
+    for (bubble, parent, transform) in bubbles.iter() {
+        if !disarmed.contains(&parent.get()) {
+            continue;
+        }
+        commands.entity(bubble).despawn_recursive();
+        if settings.accessibility.reduced_motion {
+            continue;
+        }
+        let center = transform.translation();
+        for i in 0..SHARDS {
+            let angle = TAU * i as f32 / SHARDS as f32;
+            let direction = Vec2::from_angle(angle);
+            let position = center.truncate() + direction * BUBBLE_SIZE / 2.0;
+            let speed = rng.gen_range(120.0..220.0);
+            commands.spawn((
+                Sprite::from_color(SHARD_COLOR, SHARD_SIZE),
+                Velocity {
+                    dx: direction.x * speed,
+                    dy: direction.y * speed,
+                },
+                Debris::new(rng.gen_range(-12.0..12.0)),
+                LifeTime(SHARD_SECS),
+                Transform::from_translation(position.extend(center.z))
+                    .with_rotation(Quat::from_rotation_z(angle + TAU / 4.0)),
+            ));
+        }
+    }
+}
@@ -0,0 +1,93 @@
+// Wspólna obsługa prostych przycisków: kolory przy najechaniu i naciśnięciu
+// oraz zdarzenie `ButtonPressed` z akcją przycisku. O tym, co akcja robi,
+// decyduje moduł, do którego należy (menu, ekran końca gry…).
+//
+// Tu jest też `button_text` – bezpieczne szukanie napisu wśród dzieci
+// przycisku, z którego korzystają przełączniki zmieniające etykietę.
+
+use bevy::ecs::query::QueryFilter;
+use bevy::log::warn_once;
+use bevy::prelude::*;
+
+use crate::{HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ButtonAction {
+    // Nowa runda z menu.
+    StartGame,
+    // Kolejna runda z ekranu końca gry.
+    Restart,
+    // Zamknięcie gry.
+    Exit,
+}
+
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ButtonPressed(pub ButtonAction);
+
+pub struct ButtonsPlugin;
+
+impl Plugin for ButtonsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ButtonPressed>().add_systems(
+            Update,
+            (
+                button_interaction_system,
+                exit_on_press.after(button_interaction_system),
+            ),
+        );
+    }
+}
+
+pub fn button_interaction_system(
+    mut interaction_query: Query<
+        (
+            &Interaction,
+            &ButtonAction,
+            &mut BackgroundColor,
+            &mut BorderColor,
+        ),
+        Changed<Interaction>,
+    >,
+    mut pressed: EventWriter<ButtonPressed>,
+) {
+    for (interaction, action, mut bg_color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                border_color.0 = Color::srgb(1.0, 0.0, 0.0);
+                pressed.send(ButtonPressed(*action));
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+fn exit_on_press(mut pressed: EventReader<ButtonPressed>, mut exit: EventWriter<AppExit>) {
+    if pressed.read().any(|event| event.0 == ButtonAction::Exit) {
+        exit.send(AppExit::Success);
+    }
+}
+
+// Napis przycisku: pierwsze dziecko z `Text`, niezależnie od kolejności dzieci.
+// Przycisk bez napisu (np. sama ikona) zostawiamy bez zmian, z ostrzeżeniem.
+pub fn button_text<'a, F: QueryFilter>(
+    children: Option<&Children>,
+    texts: &'a mut Query<&mut Text, F>,
+) -> Option<Mut<'a, Text>> {
+    let label = children
+        .into_iter()
+        .flat_map(|children| children.iter())
+        .find(|child| texts.contains(**child));
+    let Some(&label) = label else {
+        warn_once!("Przycisk bez napisu – pomijamy zmianę etykiety");
+        return None;
+    };
+    texts.get_mut(label).ok()
+}
@@ -0,0 +1,180 @@
+// Nowy rekord osobisty: na ekranie końca gry z dolnych rogów strzela konfetti,
+// a u góry wskakuje kołyszący się napis "NEW BEST!". O rekordzie decyduje
+// `RunRecord::new_record`, ustawiane na końcu rundy porównaniem z zapisanym
+// najlepszym wynikiem. W trybie ograniczonego ruchu zostaje sam napis, a przy
+// oszczędzaniu baterii napis bez konfetti.
+//
+// Konfetti to węzły UI nad nakładką końca gry – zwykłe sprite'y świata
+// zniknęłyby pod jej przyciemnieniem.
+
+use bevy::math::curve::EaseFunction;
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::AppState;
+use crate::bests::RunRecord;
+use crate::game_over::GameOverUI;
+use crate::settings::Settings;
+use crate::text_style::OutlinedText;
+use crate::tween::{ScaleTween, UiBobTween};
+
+const BANNER_COLOR: Color = Color::srgb(1.0, 0.85, 0.25);
+const BANNER_POP_SECS: f32 = 0.45;
+// Kołysanie napisu: odstęp od góry ekranu w pikselach i czas przejścia.
+const BANNER_TOP_PX: (f32, f32) = (24.0, 36.0);
+const BANNER_BOB_HALF_PERIOD_SECS: f32 = 0.6;
+const CONFETTI_PER_CANNON: usize = 40;
+const CONFETTI_SECS: f32 = 2.5;
+// Pozycje i prędkości konfetti liczymy w procentach ekranu.
+const CONFETTI_GRAVITY: f32 = 120.0;
+const CONFETTI_SIZE_PX: Vec2 = Vec2::new(8.0, 14.0);
+const CONFETTI_COLORS: [Color; 5] = [
+    Color::srgb(1.0, 0.3, 0.3),
+    Color::srgb(1.0, 0.85, 0.2),
+    Color::srgb(0.3, 0.85, 0.4),
+    Color::srgb(0.3, 0.6, 1.0),
+    Color::srgb(0.85, 0.4, 1.0),
+];
+
+#[derive(Component)]
+pub struct NewBestBanner;
+
+#[derive(Component)]
+struct ConfettiPiece {
+    position: Vec2,
+    velocity: Vec2,
+    spin: f32,
+    age: f32,
+}
+
+pub struct CelebrationPlugin;
+
+impl Plugin for CelebrationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(AppState::GameOver),
+            celebrate_new_best.after(crate::game_over::on_enter_game_over),
+        )
+        .add_systems(
+            Update,
+            animate_confetti.run_if(in_state(AppState::GameOver)),
+        );
+    }
+}
+
+fn celebrate_new_best(mut commands: Commands, record: Res<RunRecord>, settings: Res<Settings>) {
+    if !record.new_record {
+        return;
+    }
+    let animated = !settings.accessibility.reduced_motion;
+
+    let mut banner = commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(BANNER_TOP_PX.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..Default::default()
+        },
+        GlobalZIndex(1),
+        NewBestBanner,
+        GameOverUI,
+    ));
+    if animated {
+        banner.insert((
+            Transform::from_scale(Vec3::ZERO),
+            ScaleTween::new(
+                Vec3::ZERO,
+                Vec3::ONE,
+                BANNER_POP_SECS,
+                EaseFunction::BackOut,
+            ),
+            UiBobTween::new(
+                BANNER_TOP_PX.0,
+                BANNER_TOP_PX.1,
+                BANNER_BOB_HALF_PERIOD_SECS,
+                EaseFunction::SineInOut,
+            ),
+        ));
+    }
+    banner.with_child((
+        Text::new("NEW BEST!"),
+        OutlinedText::new(BANNER_COLOR),
+        TextFont {
+            font_size: 64.0,
+            ..Default::default()
+        },
+    ));
+
+    if !animated || settings.low_power {
+        return;
+    }
+    let mut rng = rand::thread_rng();
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..Default::default()
+            },
+            GlobalZIndex(2),
+            GameOverUI,
+        ))
+        .with_children(|parent| {
+            // Dwie armatki w dolnych rogach, strzelające w głąb ekranu.
+            for (x, direction) in [(5.0, 1.0), (95.0, -1.0)] {
+                for i in 0..CONFETTI_PER_CANNON {
+                    let velocity = Vec2::new(
+                        direction * rng.gen_range(10.0..45.0),
+                        -rng.gen_range(80.0..135.0),
+                    );
+                    parent.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Percent(x),
+                            top: Val::Percent(100.0),
+                            width: Val::Px(CONFETTI_SIZE_PX.x),
+                            height: Val::Px(CONFETTI_SIZE_PX.y),
+                            ..Default::default()
+                        },
+                        BackgroundColor(CONFETTI_COLORS[i % CONFETTI_COLORS.len()]),
+                        ConfettiPiece {
+                            position: Vec2::new(x, 100.0),
+                            velocity,
+                            spin: rng.gen_range(-10.0..10.0),
+                            age: 0.0,
+                        },
+                    ));
+                }
+            }
+        });
+}
+
+fn animate_confetti(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut pieces: Query<(
+        Entity,
+        &mut ConfettiPiece,
+        &mut Node,
+        &mut Transform,
+        &mut BackgroundColor,
+    )>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut piece, mut node, mut transform, mut color) in pieces.iter_mut() {
+        piece.age += dt;
+        if piece.age >= CONFETTI_SECS {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        piece.velocity.y += CONFETTI_GRAVITY * dt;
+        let step = piece.velocity * dt;
+        piece.position += step;
+        node.left = Val::Percent(piece.position.x);
+        node.top = Val::Percent(piece.position.y);
+        transform.rotate_z(piece.spin * dt);
+        color.0.set_alpha(1.0 - piece.age / CONFETTI_SECS);
+    }
+}
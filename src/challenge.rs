@@ -0,0 +1,119 @@
+// Wyzwania w wersji przeglądarkowej: link z `?seed=...&mode=...` ustawia ziarno
+// i tryb rundy, a przycisk "Copy link" na ekranie końca gry buduje taki link
+// dla właśnie rozegranej rundy.
+
+use bevy::prelude::*;
+
+use crate::bests::{GameMode, RunMode};
+use crate::rng::{SeedChoice, parse_seed};
+use crate::seed_display::seed_label;
+use crate::toast::Toast;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Challenge {
+    pub seed: Option<u64>,
+    pub mode: Option<GameMode>,
+}
+
+impl Challenge {
+    // Parametry z części zapytania adresu (`?seed=...&mode=...`); nieznane
+    // albo błędne parametry są pomijane.
+    pub fn from_query(query: &str) -> Self {
+        let mut challenge = Challenge::default();
+        for pair in query.trim_start_matches('?').split('&') {
+            match pair.split_once('=') {
+                Some(("seed", value)) => challenge.seed = parse_seed(value),
+                Some(("mode", value)) => challenge.mode = GameMode::from_id(value),
+                _ => {}
+            }
+        }
+        challenge
+    }
+}
+
+// Link do wyzwania z danym ziarnem; `None` poza przeglądarką.
+pub fn challenge_link(seed: u64, mode: GameMode) -> Option<String> {
+    let page = backend::page_url()?;
+    Some(format!(
+        "{page}?seed={}&mode={}",
+        seed_label(seed),
+        mode.id()
+    ))
+}
+
+pub struct ChallengePlugin;
+
+impl Plugin for ChallengePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, apply_challenge_link);
+    }
+}
+
+fn apply_challenge_link(
+    mut seed_choice: ResMut<SeedChoice>,
+    mut run_mode: ResMut<RunMode>,
+    mut toasts: EventWriter<Toast>,
+) {
+    let Some(query) = backend::query() else {
+        return;
+    };
+    let challenge = Challenge::from_query(&query);
+
+    if let Some(mode) = challenge.mode {
+        run_mode.0 = mode;
+    }
+    if let Some(seed) = challenge.seed {
+        seed_choice.0 = Some(seed);
+        toasts.send(Toast(format!("Challenge seed {}", seed_label(seed))));
+    }
+}
+
+// Przeglądarka: adres bieżącej strony.
+#[cfg(target_arch = "wasm32")]
+mod backend {
+    pub fn query() -> Option<String> {
+        web_sys::window()?.location().search().ok()
+    }
+
+    // Adres strony bez zapytania i kotwicy.
+    pub fn page_url() -> Option<String> {
+        let location = web_sys::window()?.location();
+        Some(format!(
+            "{}{}",
+            location.origin().ok()?,
+            location.pathname().ok()?
+        ))
+    }
+}
+
+// Wersja natywna nie ma adresu strony, więc wyzwania z linku jej nie dotyczą.
+#[cfg(not(target_arch = "wasm32"))]
+mod backend {
+    pub fn query() -> Option<String> {
+        None
+    }
+
+    pub fn page_url() -> Option<String> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_parameters_configure_the_challenge() {
+        assert_eq!(
+            Challenge::from_query("?mode=classic&seed=00000000000000ff&lang=pl"),
+            Challenge {
+                seed: Some(255),
+                mode: Some(GameMode::Classic),
+            }
+        );
+        assert_eq!(
+            Challenge::from_query("?seed=nope&mode=zen"),
+            Challenge::default()
+        );
+    }
+}
@@ -0,0 +1,225 @@
+// Kody w menu odblokowujące ukryte dodatki wyglądu. Kody wpisuje się
+// strzałkami i A/B (albo krzyżakiem i przyciskami pada). Odblokowane dodatki
+// zapisujemy w ustawieniach, więc zostają na stałe.
+//
+//   ↑ ↑ ↓ ↓ ← → ← → B A   – złoty ptak
+//   ← → ← → ↑ ↓ ↑ ↓ A B   – tęczowy ślad
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::AppState;
+use crate::input::MenuInput;
+use crate::kids::KidsMode;
+use crate::player::{HoverIdle, Player};
+use crate::score::Score;
+use crate::settings::Settings;
+use crate::toast::Toast;
+
+use MenuInput::{A, B, Down, Left, Right, Up};
+
+const GOLDEN_BIRD_CODE: [MenuInput; 10] = [Up, Up, Down, Down, Left, Right, Left, Right, B, A];
+const RAINBOW_TRAIL_CODE: [MenuInput; 10] = [Left, Right, Left, Right, Up, Down, Up, Down, A, B];
+
+const GOLD: Color = Color::srgb(1.0, 0.84, 0.2);
+
+// Co ile sekund zostawiamy kropkę śladu i jak długo ona znika.
+const TRAIL_INTERVAL_SECS: f32 = 0.03;
+const TRAIL_FADE_SECS: f32 = 0.4;
+const TRAIL_DOT_SIZE: f32 = 10.0;
+// Pełny obrót barw tęczy w sekundach.
+const RAINBOW_CYCLE_SECS: f32 = 1.5;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Cosmetics {
+    pub golden_bird: bool,
+    pub rainbow_trail: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cheat {
+    GoldenBird,
+    RainbowTrail,
+}
+
+impl Cheat {
+    const ALL: [(Cheat, &'static [MenuInput]); 2] = [
+        (Cheat::GoldenBird, &GOLDEN_BIRD_CODE),
+        (Cheat::RainbowTrail, &RAINBOW_TRAIL_CODE),
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            Cheat::GoldenBird => "Golden bird",
+            Cheat::RainbowTrail => "Rainbow trail",
+        }
+    }
+
+    fn unlock(self, cosmetics: &mut Cosmetics) {
+        match self {
+            Cheat::GoldenBird => cosmetics.golden_bird = true,
+            Cheat::RainbowTrail => cosmetics.rainbow_trail = true,
+        }
+    }
+}
+
+// Ostatnie wejścia; kod pasuje, gdy jest ich końcówką.
+#[derive(Resource, Debug, Default)]
+pub struct CheatDetector {
+    recent: VecDeque<MenuInput>,
+}
+
+impl CheatDetector {
+    pub fn push(&mut self, input: MenuInput) -> Option<Cheat> {
+        let longest = Cheat::ALL.iter().map(|(_, code)| code.len()).max()?;
+        if self.recent.len() == longest {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(input);
+
+        let (cheat, _) = Cheat::ALL.iter().find(|(_, code)| {
+            self.recent.len() >= code.len()
+                && self
+                    .recent
+                    .iter()
+                    .skip(self.recent.len() - code.len())
+                    .eq(code.iter())
+        })?;
+        self.recent.clear();
+        Some(*cheat)
+    }
+}
+
+#[derive(Component)]
+struct TrailDot {
+    age: f32,
+}
+
+#[derive(Resource, Default)]
+struct TrailClock {
+    since_dot: f32,
+    elapsed: f32,
+}
+
+pub struct CheatsPlugin;
+
+impl Plugin for CheatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CheatDetector>()
+            .init_resource::<TrailClock>()
+            .add_systems(Update, detect_cheats.run_if(in_state(AppState::Menu)))
+            .add_systems(
+                OnEnter(AppState::Playing),
+                gild_bird.after(crate::restart_game),
+            )
+            .add_systems(
+                Update,
+                (
+                    drop_trail_dots.run_if(in_state(AppState::Playing)),
+                    fade_trail_dots,
+                ),
+            );
+    }
+}
+
+fn detect_cheats(
+    mut menu_input: EventReader<MenuInput>,
+    mut detector: ResMut<CheatDetector>,
+    mut settings: ResMut<Settings>,
+    mut toasts: EventWriter<Toast>,
+) {
+    for &input in menu_input.read() {
+        let Some(cheat) = detector.push(input) else {
+            continue;
+        };
+        cheat.unlock(&mut settings.cosmetics);
+        toasts.send(Toast(format!("Unlocked: {}", cheat.label())));
+    }
+}
+
+fn gild_bird(settings: Res<Settings>, mut player: Query<&mut Sprite, With<Player>>) {
+    if !settings.cosmetics.golden_bird {
+        return;
+    }
+    for mut sprite in player.iter_mut() {
+        sprite.color = GOLD;
+    }
+}
+
+// Ślad zostaje dopiero w locie, nie w fazie "Get Ready".
+fn drop_trail_dots(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<Settings>,
+    mut clock: ResMut<TrailClock>,
+    player: Query<&Transform, (With<Player>, Without<HoverIdle>)>,
+) {
+    if !settings.cosmetics.rainbow_trail {
+        return;
+    }
+    let Ok(transform) = player.get_single() else {
+        return;
+    };
+    clock.elapsed += time.delta_secs();
+    clock.since_dot += time.delta_secs();
+    if clock.since_dot < TRAIL_INTERVAL_SECS {
+        return;
+    }
+    clock.since_dot = 0.0;
+
+    let hue = (clock.elapsed / RAINBOW_CYCLE_SECS).fract() * 360.0;
+    commands.spawn((
+        Sprite::from_color(Color::hsl(hue, 1.0, 0.6), Vec2::splat(TRAIL_DOT_SIZE)),
+        Transform::from_translation(transform.translation.truncate().extend(0.5)),
+        TrailDot { age: 0.0 },
+    ));
+}
+
+// Kropki przesuwają się w lewo razem ze światem i znikają.
+fn fade_trail_dots(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<Settings>,
+    kids: Option<Res<KidsMode>>,
+    score: Res<Score>,
+    mut dots: Query<(Entity, &mut TrailDot, &mut Sprite, &mut Transform)>,
+) {
+    let speed = crate::kids::run_curve(&settings, kids.is_some())
+        .params_at(score.0)
+        .speed;
+    for (entity, mut dot, mut sprite, mut transform) in dots.iter_mut() {
+        dot.age += time.delta_secs();
+        if dot.age >= TRAIL_FADE_SECS {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        transform.translation.x += speed * time.delta_secs();
+        sprite.color.set_alpha(1.0 - dot.age / TRAIL_FADE_SECS);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_unlock_after_the_full_sequence() {
+        let mut detector = CheatDetector::default();
+
+        // Pomyłka na początku nie przeszkadza – liczy się końcówka.
+        assert_eq!(detector.push(A), None);
+        let (last, rest) = GOLDEN_BIRD_CODE.split_last().unwrap();
+        for &input in rest {
+            assert_eq!(detector.push(input), None);
+        }
+        assert_eq!(detector.push(*last), Some(Cheat::GoldenBird));
+
+        for &input in &RAINBOW_TRAIL_CODE[..9] {
+            assert_eq!(detector.push(input), None);
+        }
+        assert_eq!(detector.push(B), Some(Cheat::RainbowTrail));
+    }
+}
@@ -0,0 +1,51 @@
+// Schowek systemowy. Jak przy wibracjach, o realizacji decyduje backend
+// danej platformy.
+
+// Przeglądarka: Clipboard API. Zapis jest asynchroniczny – zakładamy, że się uda;
+// odczyt wymaga obietnicy (Promise), więc wklejanie przez skrót nie jest obsługiwane.
+#[cfg(target_arch = "wasm32")]
+mod backend {
+    pub fn copy(text: &str) -> Result<(), String> {
+        let window = web_sys::window().ok_or("brak okna przeglądarki")?;
+        let _ = window.navigator().clipboard().write_text(text);
+        Ok(())
+    }
+
+    pub fn paste() -> Result<String, String> {
+        Err("odczyt schowka nie jest dostępny w przeglądarce".to_string())
+    }
+
+    // Obrazki w schowku przeglądarki wymagają ClipboardItem z obietnicą – pomijamy.
+    pub fn copy_image(_width: usize, _height: usize, _rgba: &[u8]) -> Result<(), String> {
+        Err("kopiowanie obrazków nie jest dostępne w przeglądarce".to_string())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod backend {
+    pub fn copy(text: &str) -> Result<(), String> {
+        arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.set_text(text))
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn paste() -> Result<String, String> {
+        arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.get_text())
+            .map_err(|err| err.to_string())
+    }
+
+    // Piksele RGBA, wiersz po wierszu.
+    pub fn copy_image(width: usize, height: usize, rgba: &[u8]) -> Result<(), String> {
+        let image = arboard::ImageData {
+            width,
+            height,
+            bytes: rgba.into(),
+        };
+        arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.set_image(image))
+            .map_err(|err| err.to_string())
+    }
+}
+
+pub use backend::{copy, copy_image, paste};
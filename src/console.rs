@@ -0,0 +1,325 @@
+// Konsola deweloperska rozwijana klawiszem ` (grawis), dostępna w buildzie
+// debug. Komendy trzymamy w rejestrze: każda to jednorazowy system Bevy,
+// który dostaje słowa komendy i zwraca tekst odpowiedzi albo błędu. Moduły
+// dodają własne komendy przez `ConsoleAppExt::register_console_command`.
+//
+//   set gravity -500        score 100
+//   spawn pattern tunnel    state gameover
+
+use std::collections::BTreeMap;
+
+use bevy::ecs::system::SystemId;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::{ButtonState, InputSystem};
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::core::{Extra, Pattern, PatternPlan};
+use crate::physics::Gravity;
+use crate::pipes::PipeSpawner;
+use crate::score::Score;
+
+const CONSOLE_KEY: KeyCode = KeyCode::Backquote;
+
+// Tyle ostatnich linii zostaje na ekranie.
+const HISTORY_LINES: usize = 12;
+
+// Słowa komendy (bez jej nazwy) → odpowiedź albo opis błędu.
+pub type ConsoleArgs = Vec<String>;
+pub type ConsoleReply = Result<String, String>;
+
+struct ConsoleCommand {
+    help: &'static str,
+    system: SystemId<In<ConsoleArgs>, ConsoleReply>,
+}
+
+#[derive(Resource, Default)]
+pub struct ConsoleCommands(BTreeMap<&'static str, ConsoleCommand>);
+
+pub trait ConsoleAppExt {
+    fn register_console_command<M>(
+        &mut self,
+        name: &'static str,
+        help: &'static str,
+        system: impl IntoSystem<In<ConsoleArgs>, ConsoleReply, M> + 'static,
+    ) -> &mut Self;
+}
+
+impl ConsoleAppExt for App {
+    fn register_console_command<M>(
+        &mut self,
+        name: &'static str,
+        help: &'static str,
+        system: impl IntoSystem<In<ConsoleArgs>, ConsoleReply, M> + 'static,
+    ) -> &mut Self {
+        let system = self.world_mut().register_system(system);
+        self.init_resource::<ConsoleCommands>();
+        self.world_mut()
+            .resource_mut::<ConsoleCommands>()
+            .0
+            .insert(name, ConsoleCommand { help, system });
+        self
+    }
+}
+
+#[derive(Resource, Default)]
+struct Console {
+    open: bool,
+    input: String,
+    history: Vec<String>,
+    // Wpisane linie czekające na wykonanie.
+    submitted: Vec<String>,
+}
+
+#[derive(Component)]
+struct ConsolePanel;
+
+#[derive(Component)]
+struct ConsoleText;
+
+pub struct ConsolePlugin;
+
+impl Plugin for ConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Console>()
+            .init_resource::<ConsoleCommands>()
+            .add_systems(Startup, spawn_console)
+            .add_systems(
+                PreUpdate,
+                (console_typing, run_submitted).chain().after(InputSystem),
+            )
+            .add_systems(Update, refresh_console.run_if(resource_changed::<Console>))
+            .register_console_command("help", "list commands", help_command)
+            .register_console_command("set", "set gravity <value>", set_command)
+            .register_console_command("score", "score <points>", score_command)
+            .register_console_command(
+                "spawn",
+                "spawn pattern <pipes|spikes|lowgravity|tunnel>",
+                spawn_command,
+            )
+            .register_console_command("state", "state <menu|playing|gameover>", state_command);
+    }
+}
+
+fn spawn_console(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+            GlobalZIndex(100),
+            Visibility::Hidden,
+            ConsolePanel,
+        ))
+        .with_child((
+            Text::default(),
+            TextColor(Color::WHITE),
+            TextFont {
+                font_size: 16.0,
+                ..Default::default()
+            },
+            ConsoleText,
+        ));
+}
+
+// Otwarta konsola zabiera klawiaturę grze – spacja w komendzie nie jest machnięciem.
+fn console_typing(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut keyboard_input: ResMut<ButtonInput<KeyCode>>,
+    mut console: ResMut<Console>,
+) {
+    if keyboard_input.just_pressed(CONSOLE_KEY) {
+        console.open = !console.open;
+        keyboard_events.clear();
+        keyboard_input.reset_all();
+        return;
+    }
+    if !console.open {
+        keyboard_events.clear();
+        return;
+    }
+
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Backspace => {
+                console.input.pop();
+            }
+            Key::Enter => {
+                let line = std::mem::take(&mut console.input);
+                if !line.trim().is_empty() {
+                    console.submitted.push(line);
+                }
+            }
+            Key::Escape => console.open = false,
+            Key::Space => console.input.push(' '),
+            Key::Character(text) => console.input.push_str(text),
+            _ => {}
+        }
+    }
+    keyboard_input.reset_all();
+}
+
+fn run_submitted(world: &mut World) {
+    let submitted = std::mem::take(&mut world.resource_mut::<Console>().submitted);
+    for line in submitted {
+        let reply = execute(world, &line);
+        let mut console = world.resource_mut::<Console>();
+        console.history.push(format!("> {line}"));
+        console.history.push(match reply {
+            Ok(reply) => reply,
+            Err(err) => format!("error: {err}"),
+        });
+    }
+}
+
+fn execute(world: &mut World, line: &str) -> ConsoleReply {
+    let mut words = line.split_whitespace().map(str::to_string);
+    let name = words.next().unwrap_or_default();
+    let system = world
+        .resource::<ConsoleCommands>()
+        .0
+        .get(name.as_str())
+        .map(|command| command.system)
+        .ok_or_else(|| format!("unknown command '{name}', try 'help'"))?;
+    world
+        .run_system_with_input(system, words.collect())
+        .map_err(|err| err.to_string())?
+}
+
+fn refresh_console(
+    console: Res<Console>,
+    mut panel: Query<&mut Visibility, With<ConsolePanel>>,
+    mut text: Query<&mut Text, With<ConsoleText>>,
+) {
+    for mut visibility in panel.iter_mut() {
+        *visibility = if console.open {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+
+    let skip = console.history.len().saturating_sub(HISTORY_LINES);
+    let mut lines: Vec<&str> = console
+        .history
+        .iter()
+        .skip(skip)
+        .map(String::as_str)
+        .collect();
+    let prompt = format!("> {}_", console.input);
+    lines.push(&prompt);
+    for mut text in text.iter_mut() {
+        text.0 = lines.join("\n");
+    }
+}
+
+// Jedno słowo argumentu sparsowane jako liczba.
+fn number_arg<T: std::str::FromStr>(
+    args: &[String],
+    index: usize,
+    usage: &str,
+) -> Result<T, String> {
+    args.get(index)
+        .and_then(|arg| arg.parse().ok())
+        .ok_or_else(|| format!("usage: {usage}"))
+}
+
+fn help_command(In(_): In<ConsoleArgs>, commands: Res<ConsoleCommands>) -> ConsoleReply {
+    Ok(commands
+        .0
+        .iter()
+        .map(|(name, command)| format!("{name}: {}", command.help))
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+// Grawitacja zostaje zmieniona do końca działania gry.
+fn set_command(In(args): In<ConsoleArgs>, mut gravity: ResMut<Gravity>) -> ConsoleReply {
+    match args.first().map(String::as_str) {
+        Some("gravity") => {
+            gravity.0 = number_arg(&args, 1, "set gravity <value>")?;
+            Ok(format!("gravity = {}", gravity.0))
+        }
+        Some(name) => Err(format!("unknown variable '{name}'")),
+        None => Err("usage: set gravity <value>".to_string()),
+    }
+}
+
+fn score_command(In(args): In<ConsoleArgs>, mut score: ResMut<Score>) -> ConsoleReply {
+    score.0 = number_arg(&args, 0, "score <points>")?;
+    Ok(format!("score = {}", score.0))
+}
+
+fn spawn_command(
+    In(args): In<ConsoleArgs>,
+    mut commands: Commands,
+    mut spawner: PipeSpawner,
+    state: Res<State<AppState>>,
+) -> ConsoleReply {
+    let usage = "spawn pattern <pipes|spikes|lowgravity|tunnel>";
+    if args.first().map(String::as_str) != Some("pattern") {
+        return Err(format!("usage: {usage}"));
+    }
+    let pattern = match args.get(1).map(String::as_str) {
+        Some("pipes") => Pattern::Pipes(Extra::None),
+        Some("spikes") => Pattern::Pipes(Extra::Spikes { top: true }),
+        Some("lowgravity") => Pattern::Pipes(Extra::LowGravity),
+        Some("tunnel") => Pattern::Tunnel,
+        Some(name) => return Err(format!("unknown pattern '{name}'")),
+        None => return Err(format!("usage: {usage}")),
+    };
+    if *state.get() != AppState::Playing {
+        return Err("patterns can only be spawned during a run".to_string());
+    }
+    spawner.spawn_planned(
+        &mut commands,
+        PatternPlan {
+            center_y: 0.0,
+            pattern,
+        },
+    );
+    Ok(format!("spawned {}", args[1]))
+}
+
+fn state_command(
+    In(args): In<ConsoleArgs>,
+    mut next_state: ResMut<NextState<AppState>>,
+) -> ConsoleReply {
+    let state = match args.first().map(String::as_str) {
+        Some("menu") => AppState::Menu,
+        Some("playing") => AppState::Playing,
+        Some("gameover") => AppState::GameOver,
+        _ => return Err("usage: state <menu|playing|gameover>".to_string()),
+    };
+    next_state.set(state);
+    Ok(format!("state -> {state:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commands_run_through_the_registry() {
+        let mut app = App::new();
+        app.insert_resource(Score(0)).register_console_command(
+            "score",
+            "score <points>",
+            score_command,
+        );
+        let world = app.world_mut();
+
+        assert_eq!(execute(world, "score 100"), Ok("score = 100".to_string()));
+        assert_eq!(world.resource::<Score>().0, 100);
+        assert!(execute(world, "score lots").is_err());
+        assert!(execute(world, "jump").is_err());
+    }
+}
@@ -0,0 +1,272 @@
+// Warianty sterowania wybierane w menu i zapamiętywane w ustawieniach.
+
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use serde::{Deserialize, Serialize};
+
+use crate::buttons::button_text;
+use crate::input::FlapInput;
+use crate::pause::PauseState;
+use crate::physics::Velocity;
+use crate::player::{HoverIdle, Player};
+use crate::settings::Settings;
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON, RunStarted};
+
+// Siła nośna szybowania (jednostki/s²), częściowo równoważąca grawitację.
+const GLIDE_LIFT: f32 = 260.0;
+
+// Zryw: prędkość pozioma, czas trwania i odnowienie (sekundy).
+const DASH_SPEED: f32 = 500.0;
+const DASH_SECS: f32 = 0.15;
+const DASH_COOLDOWN: f32 = 1.5;
+// Jak szybko ptak wraca na swoją kolumnę po zrywie (1/s).
+const DRIFT_BACK_RATE: f32 = 3.0;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ControlScheme {
+    // Każde naciśnięcie to pojedyncze machnięcie.
+    #[default]
+    Classic,
+    // Jak Classic, ale przytrzymanie przycisku spowalnia opadanie.
+    Glide,
+    // Przytrzymaj i puść: im dłużej ładowane, tym mocniejsze machnięcie.
+    Charge,
+    // Jak Classic, plus zryw do przodu (Shift) z czasem odnowienia.
+    Dash,
+}
+
+impl ControlScheme {
+    pub fn label(self) -> &'static str {
+        match self {
+            ControlScheme::Classic => "Classic",
+            ControlScheme::Glide => "Glide",
+            ControlScheme::Charge => "Charge",
+            ControlScheme::Dash => "Dash",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            ControlScheme::Classic => ControlScheme::Glide,
+            ControlScheme::Glide => ControlScheme::Charge,
+            ControlScheme::Charge => ControlScheme::Dash,
+            ControlScheme::Dash => ControlScheme::Classic,
+        }
+    }
+}
+
+// Sterowanie obowiązujące w bieżącej rundzie – zmiana ustawień w trakcie
+// gry zadziała dopiero od następnej rundy.
+#[derive(Resource, Default)]
+pub struct RunControls(pub ControlScheme);
+
+#[derive(Component)]
+pub struct ControlsButton;
+
+#[derive(Resource, Default)]
+struct DashState {
+    // Naciśnięcie czekające na najbliższy krok symulacji.
+    requested: bool,
+    active: f32,
+    cooldown: f32,
+}
+
+// Pasek naładowania nad ptakiem (dziecko encji gracza).
+#[derive(Component)]
+struct ChargeMeter;
+
+#[derive(Component)]
+struct ChargeMeterFill;
+
+const METER_SIZE: Vec2 = Vec2::new(40.0, 5.0);
+
+pub fn controls_button_label(settings: &Settings) -> String {
+    format!("Controls: {}", settings.controls.label())
+}
+
+pub struct ControlsPlugin;
+
+impl Plugin for ControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunControls>()
+            .init_resource::<DashState>()
+            .add_systems(
+                OnEnter(AppState::Playing),
+                (lock_run_controls, reset_dash.after(crate::restart_game)),
+            )
+            .add_systems(
+                Update,
+                controls_button_system.run_if(in_state(AppState::Menu)),
+            )
+            .add_systems(
+                Update,
+                (read_dash_input, attach_charge_meter, update_charge_meter)
+                    .run_if(in_state(PauseState::Running)),
+            )
+            .add_systems(
+                FixedUpdate,
+                (glide_system, dash_system).run_if(in_state(PauseState::Running)),
+            );
+    }
+}
+
+pub fn lock_run_controls(
+    settings: Res<Settings>,
+    mut controls: ResMut<RunControls>,
+    mut flap_input: ResMut<FlapInput>,
+) {
+    controls.0 = settings.controls;
+    // Puszczenie przycisku z menu nie może wystrzelić naładowanego machnięcia.
+    flap_input.released_after = None;
+}
+
+// Zryw i jego odnowienie nie przechodzą na następną rundę.
+fn reset_dash(mut run_started: EventReader<RunStarted>, mut dash: ResMut<DashState>) {
+    if run_started.read().last().is_some() {
+        *dash = DashState::default();
+    }
+}
+
+fn controls_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, Option<&Children>),
+        (Changed<Interaction>, With<ControlsButton>),
+    >,
+    mut text_query: Query<&mut Text>,
+    mut settings: ResMut<Settings>,
+) {
+    for (interaction, mut bg_color, children) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                settings.controls = settings.controls.next();
+                if let Some(mut text) = button_text(children, &mut text_query) {
+                    text.0 = controls_button_label(&settings);
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+fn attach_charge_meter(
+    mut commands: Commands,
+    controls: Res<RunControls>,
+    players: Query<Entity, Added<Player>>,
+) {
+    if controls.0 != ControlScheme::Charge {
+        return;
+    }
+
+    for player in players.iter() {
+        commands.entity(player).with_children(|parent| {
+            parent
+                .spawn((
+                    Sprite::from_color(Color::srgba(0.0, 0.0, 0.0, 0.6), METER_SIZE),
+                    Transform::from_xyz(0.0, 28.0, 0.5),
+                    Visibility::Hidden,
+                    ChargeMeter,
+                ))
+                .with_child((
+                    Sprite {
+                        color: Color::srgb(1.0, 0.8, 0.1),
+                        custom_size: Some(Vec2::new(0.0, METER_SIZE.y)),
+                        anchor: Anchor::CenterLeft,
+                        ..Default::default()
+                    },
+                    Transform::from_xyz(-METER_SIZE.x / 2.0, 0.0, 0.1),
+                    ChargeMeterFill,
+                ));
+        });
+    }
+}
+
+// Pasek widać tylko podczas ładowania; wypełnienie rośnie z czasem przytrzymania.
+fn update_charge_meter(
+    flap_input: Res<FlapInput>,
+    mut meters: Query<&mut Visibility, With<ChargeMeter>>,
+    mut fills: Query<&mut Sprite, With<ChargeMeterFill>>,
+) {
+    for mut visibility in meters.iter_mut() {
+        *visibility = if flap_input.pressed {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+
+    let level = crate::core::charge_level(flap_input.held_for);
+    for mut sprite in fills.iter_mut() {
+        sprite.custom_size = Some(Vec2::new(METER_SIZE.x * level, METER_SIZE.y));
+    }
+}
+
+// Przytrzymany przycisk podczas opadania daje stałą, niewielką siłę nośną.
+fn glide_system(
+    controls: Res<RunControls>,
+    flap_input: Res<FlapInput>,
+    time: Res<Time>,
+    mut query: Query<&mut Velocity, (With<Player>, Without<HoverIdle>)>,
+) {
+    if controls.0 != ControlScheme::Glide || !flap_input.pressed {
+        return;
+    }
+
+    for mut velocity in query.iter_mut() {
+        if velocity.dy < 0.0 {
+            velocity.dy += GLIDE_LIFT * time.delta_secs();
+        }
+    }
+}
+
+// Zryw przesuwa ptaka do przodu względem rur; potem ptak płynnie wraca na x = 0,
+// bo kolizje i strefy punktów liczone są z jego faktycznej pozycji.
+fn read_dash_input(
+    controls: Res<RunControls>,
+    settings: Res<Settings>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut dash: ResMut<DashState>,
+) {
+    if controls.0 != ControlScheme::Dash {
+        return;
+    }
+
+    dash.requested |= keyboard_input.just_pressed(settings.keybinds.dash)
+        || gamepads
+            .iter()
+            .any(|gamepad| gamepad.just_pressed(GamepadButton::West));
+}
+
+fn dash_system(
+    controls: Res<RunControls>,
+    time: Res<Time>,
+    mut dash: ResMut<DashState>,
+    mut query: Query<(&mut Velocity, &Transform), (With<Player>, Without<HoverIdle>)>,
+) {
+    if controls.0 != ControlScheme::Dash {
+        return;
+    }
+
+    dash.active = (dash.active - time.delta_secs()).max(0.0);
+    dash.cooldown = (dash.cooldown - time.delta_secs()).max(0.0);
+
+    let requested = std::mem::take(&mut dash.requested);
+    if requested && dash.cooldown == 0.0 && !query.is_empty() {
+        dash.active = DASH_SECS;
+        dash.cooldown = DASH_COOLDOWN;
+    }
+
+    for (mut velocity, transform) in query.iter_mut() {
+        velocity.dx = if dash.active > 0.0 {
+            DASH_SPEED
+        } else {
+            -transform.translation.x * DRIFT_BACK_RATE
+        };
+    }
+}
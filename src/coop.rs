@@ -0,0 +1,392 @@
+// Tryb kooperacji "Co-op tether": dwa ptaki na jednym ekranie połączone
+// elastyczną liną. Drugi gracz macha klawiszem Enter. Lina jest luźna do
+// długości spoczynkowej, potem ciągnie oba ptaki ku sobie (sprężyna
+// z tłumieniem), więc machnięcie jednego szarpie też drugiego.
+//
+// Partner nie jest `Player` – wiele systemów zakłada jednego gracza – tylko
+// osobną encją z własną grawitacją, kolizjami i punktami. Zderzenie któregoś
+// z ptaków kończy wspólną rundę, a punkty obu ptaków trafiają do jednego
+// wyniku. Rundy zapisujemy jako tryb `GameMode::Coop`; powtórek nie nagrywamy.
+
+use bevy::prelude::*;
+
+use crate::assets::GameAssets;
+use crate::bests::{GameMode, RunMode};
+use crate::core::FLAP_IMPULSE;
+use crate::display::WORLD_SIZE;
+use crate::hazards::Hazard;
+use crate::interpolation::Interpolated;
+use crate::kids::KidsMode;
+use crate::menu::Menu;
+use crate::pause::PauseState;
+use crate::physics::{Collider, Gravity, Sensor, Velocity};
+use crate::player::{HoverIdle, Player};
+use crate::score::{PointScored, ScoreZone, Scoreable};
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON, RunEntity};
+
+const PARTNER_FLAP_KEY: KeyCode = KeyCode::Enter;
+const PARTNER_X: f32 = -60.0;
+const PARTNER_TINT: Color = Color::srgb(0.6, 0.8, 1.0);
+const PARTNER_HALF_SIZE: Vec2 = Vec2::new(16.0, 16.0);
+
+// Lina: długość bez naciągu, sztywność (1/s²) i tłumienie (1/s).
+const TETHER_REST_LENGTH: f32 = 90.0;
+const TETHER_STIFFNESS: f32 = 30.0;
+const TETHER_DAMPING: f32 = 4.0;
+const TETHER_THICKNESS: f32 = 3.0;
+const TETHER_COLOR: Color = Color::srgb(0.95, 0.9, 0.75);
+// Kolor liny naciągniętej o `TETHER_REST_LENGTH`.
+const TETHER_TAUT_COLOR: Color = Color::srgb(1.0, 0.35, 0.3);
+
+// Obecny, gdy gracze wybrali kooperację; znika po powrocie do menu.
+#[derive(Resource, Default)]
+pub struct CoopRun;
+
+#[derive(Component)]
+#[require(RunEntity)]
+pub struct CoopPartner;
+
+#[derive(Component)]
+#[require(RunEntity)]
+struct Tether;
+
+// Strefa, którą partner już zaliczył (gracz ma własne `Scoreable::passed`).
+#[derive(Component)]
+struct PassedByPartner;
+
+// Machnięcie partnera zbierane w `PreUpdate` i zużywane przez symulację.
+#[derive(Resource, Default)]
+struct PartnerFlap(bool);
+
+#[derive(Component)]
+struct CoopButton;
+
+pub struct CoopPlugin;
+
+impl Plugin for CoopPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PartnerFlap>()
+            .add_systems(
+                OnEnter(AppState::Menu),
+                (
+                    end_coop_run,
+                    spawn_coop_button.after(crate::menu::setup_menu),
+                ),
+            )
+            .add_systems(Update, coop_button_system.run_if(in_state(AppState::Menu)))
+            .add_systems(
+                OnEnter(AppState::Playing),
+                start_coop_run
+                    .after(crate::restart_game)
+                    .run_if(resource_exists::<CoopRun>),
+            )
+            .add_systems(
+                PreUpdate,
+                read_partner_flap
+                    .after(bevy::input::InputSystem)
+                    .run_if(resource_exists::<CoopRun>),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    partner_input_system.before(crate::physics::move_system),
+                    partner_gravity_system
+                        .after(partner_input_system)
+                        .before(crate::physics::move_system),
+                    tether_system
+                        .after(partner_gravity_system)
+                        .after(crate::physics::gravity_system)
+                        .after(crate::player::player_input_system)
+                        .before(crate::physics::move_system),
+                    partner_collision_system.after(crate::physics::move_system),
+                    partner_score_system
+                        .after(crate::physics::move_system)
+                        .before(crate::score::apply_score_events),
+                )
+                    .run_if(in_state(PauseState::Running).and(resource_exists::<CoopRun>)),
+            )
+            .add_systems(
+                PostUpdate,
+                draw_tether
+                    .after(crate::interpolation::blend_rendered)
+                    .before(TransformSystem::TransformPropagate)
+                    .run_if(resource_exists::<CoopRun>),
+            );
+    }
+}
+
+// Dodatkowe przyspieszenie pionowe (ku partnerowi) dla ptaka na początku
+// liny, gdy drugi koniec jest przesunięty o `offset`, a ich prędkości
+// pionowe różnią się o `relative_dy`. Drugi ptak dostaje przeciwne.
+// Luźna lina nie działa, a naciągnięta tylko ciągnie, nigdy nie pcha.
+fn tether_pull(offset: Vec2, relative_dy: f32) -> f32 {
+    let length = offset.length();
+    if length <= TETHER_REST_LENGTH {
+        return 0.0;
+    }
+    let direction = offset / length;
+    let stretch = length - TETHER_REST_LENGTH;
+    let pull = TETHER_STIFFNESS * stretch + TETHER_DAMPING * relative_dy * direction.y;
+    pull.max(0.0) * direction.y
+}
+
+fn start_coop_run(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    mut mode: ResMut<RunMode>,
+    mut flap: ResMut<PartnerFlap>,
+) {
+    mode.0 = GameMode::Coop;
+    flap.0 = false;
+    commands.spawn((
+        Sprite {
+            image: assets.bird.clone(),
+            color: PARTNER_TINT,
+            ..Default::default()
+        },
+        Velocity { dx: 0.0, dy: 0.0 },
+        Collider {
+            half_size: PARTNER_HALF_SIZE,
+            offset: Vec2::ZERO,
+        },
+        CoopPartner,
+        HoverIdle::at(0.0),
+        Interpolated::default(),
+        Transform::from_xyz(PARTNER_X, 0.0, 1.0),
+    ));
+    commands.spawn((
+        Sprite::from_color(TETHER_COLOR, Vec2::ONE),
+        Transform::from_xyz(PARTNER_X / 2.0, 0.0, 0.9),
+        Tether,
+    ));
+}
+
+fn end_coop_run(mut commands: Commands, coop: Option<Res<CoopRun>>, mut mode: ResMut<RunMode>) {
+    if coop.is_none() {
+        return;
+    }
+    commands.remove_resource::<CoopRun>();
+    mode.0 = GameMode::Classic;
+}
+
+fn read_partner_flap(keyboard_input: Res<ButtonInput<KeyCode>>, mut flap: ResMut<PartnerFlap>) {
+    if keyboard_input.just_pressed(PARTNER_FLAP_KEY) {
+        flap.0 = true;
+    }
+}
+
+// Machnięcie któregokolwiek gracza kończy fazę "Get Ready" obu ptaków.
+fn partner_input_system(
+    mut commands: Commands,
+    mut flap: ResMut<PartnerFlap>,
+    gravity: Res<Gravity>,
+    mut partner: Query<(Entity, &mut Velocity, Has<HoverIdle>), With<CoopPartner>>,
+    player: Query<(Entity, Has<HoverIdle>), With<Player>>,
+) {
+    let Ok((partner_entity, mut velocity, partner_idle)) = partner.get_single_mut() else {
+        return;
+    };
+    let Ok((player_entity, player_idle)) = player.get_single() else {
+        return;
+    };
+
+    if std::mem::take(&mut flap.0) {
+        velocity.dy = -gravity.0.signum() * FLAP_IMPULSE;
+        commands.entity(partner_entity).remove::<HoverIdle>();
+        commands.entity(player_entity).remove::<HoverIdle>();
+    } else if partner_idle && !player_idle {
+        commands.entity(partner_entity).remove::<HoverIdle>();
+    }
+}
+
+fn partner_gravity_system(
+    time: Res<Time>,
+    gravity: Res<Gravity>,
+    mut partner: Query<&mut Velocity, (With<CoopPartner>, Without<HoverIdle>)>,
+) {
+    for mut velocity in partner.iter_mut() {
+        velocity.dy += gravity.0 * time.delta_secs();
+    }
+}
+
+fn tether_system(
+    time: Res<Time>,
+    mut player: Query<(&Transform, &mut Velocity), (With<Player>, Without<HoverIdle>)>,
+    mut partner: Query<
+        (&Transform, &mut Velocity),
+        (With<CoopPartner>, Without<Player>, Without<HoverIdle>),
+    >,
+) {
+    let Ok((player_transform, mut player_velocity)) = player.get_single_mut() else {
+        return;
+    };
+    let Ok((partner_transform, mut partner_velocity)) = partner.get_single_mut() else {
+        return;
+    };
+
+    let offset = (partner_transform.translation - player_transform.translation).truncate();
+    let pull = tether_pull(offset, partner_velocity.dy - player_velocity.dy) * time.delta_secs();
+    player_velocity.dy += pull;
+    partner_velocity.dy -= pull;
+}
+
+// Te same zasady co dla gracza, bez asysty "leniency". W trybie dla dzieci
+// partner wisi na linie i nie przegrywa.
+fn partner_collision_system(
+    partner: Query<(&Transform, &Collider), With<CoopPartner>>,
+    hazards: Query<(&Transform, &Collider), (With<Hazard>, Without<CoopPartner>)>,
+    kids: Option<Res<KidsMode>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if kids.is_some() {
+        return;
+    }
+    let Ok((transform, collider)) = partner.get_single() else {
+        return;
+    };
+
+    let center = collider.center(transform);
+    let crashed = crate::core::out_of_bounds(
+        center.y,
+        collider.half_size.y,
+        WORLD_SIZE.y / 2.0,
+        -WORLD_SIZE.y / 2.0,
+    ) || hazards.iter().any(|(hazard_transform, hazard_collider)| {
+        crate::core::aabb_overlap(
+            center.into(),
+            collider.half_size.into(),
+            hazard_collider.center(hazard_transform).into(),
+            hazard_collider.half_size.into(),
+        )
+    });
+    if crashed {
+        debug!("Partner się rozbił");
+        next_state.set(AppState::GameOver);
+    }
+}
+
+fn partner_score_system(
+    mut commands: Commands,
+    partner: Query<(&Transform, &Collider), With<CoopPartner>>,
+    zones: Query<
+        (Entity, &Transform, &Collider),
+        (
+            With<ScoreZone>,
+            With<Scoreable>,
+            With<Sensor>,
+            Without<PassedByPartner>,
+        ),
+    >,
+    mut scored: EventWriter<PointScored>,
+) {
+    let Ok((partner_transform, partner_collider)) = partner.get_single() else {
+        return;
+    };
+
+    for (zone, zone_transform, zone_collider) in zones.iter() {
+        if crate::core::aabb_overlap(
+            partner_collider.center(partner_transform).into(),
+            partner_collider.half_size.into(),
+            zone_collider.center(zone_transform).into(),
+            zone_collider.half_size.into(),
+        ) {
+            commands.entity(zone).insert(PassedByPartner);
+            scored.send(PointScored { zone });
+        }
+    }
+}
+
+// Lina rysowana między interpolowanymi pozycjami ptaków; czerwienieje
+// z naciągiem.
+fn draw_tether(
+    player: Query<&Transform, (With<Player>, Without<Tether>)>,
+    partner: Query<&Transform, (With<CoopPartner>, Without<Tether>)>,
+    mut tether: Query<(&mut Transform, &mut Sprite), With<Tether>>,
+) {
+    let (Ok(player), Ok(partner)) = (player.get_single(), partner.get_single()) else {
+        return;
+    };
+    let Ok((mut transform, mut sprite)) = tether.get_single_mut() else {
+        return;
+    };
+
+    let from = player.translation.truncate();
+    let to = partner.translation.truncate();
+    let offset = to - from;
+    let length = offset.length();
+    let midpoint = (from + to) / 2.0;
+
+    transform.translation = midpoint.extend(transform.translation.z);
+    transform.rotation = Quat::from_rotation_z(offset.y.atan2(offset.x));
+    transform.scale = Vec3::new(length, TETHER_THICKNESS, 1.0);
+    let stretch = ((length - TETHER_REST_LENGTH) / TETHER_REST_LENGTH).clamp(0.0, 1.0);
+    sprite.color = TETHER_COLOR.mix(&TETHER_TAUT_COLOR, stretch);
+}
+
+fn spawn_coop_button(mut commands: Commands) {
+    commands
+        .spawn((
+            Button,
+            Interaction::default(),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(20.0),
+                top: Val::Px(120.0),
+                padding: UiRect::axes(Val::Px(16.0), Val::Px(10.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(NORMAL_BUTTON),
+            BorderRadius::all(Val::Px(8.0)),
+            CoopButton,
+            Menu,
+        ))
+        .with_child((Text::new("Co-op tether"), TextColor(Color::WHITE)));
+}
+
+fn coop_button_system(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<CoopButton>),
+    >,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, mut bg_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                commands.init_resource::<CoopRun>();
+                next_state.set(AppState::Playing);
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slack_tether_does_nothing_and_taut_one_pulls_together() {
+        assert_eq!(tether_pull(Vec2::new(-60.0, 50.0), 0.0), 0.0);
+
+        // Partner wysoko nad graczem: gracz ciągnięty w górę.
+        let pull = tether_pull(Vec2::new(0.0, 150.0), 0.0);
+        assert_eq!(pull, TETHER_STIFFNESS * 60.0);
+        assert!(tether_pull(Vec2::new(0.0, -150.0), 0.0) < 0.0);
+    }
+
+    #[test]
+    fn taut_tether_never_pushes() {
+        // Ptaki szybko zbliżają się do siebie – tłumienie nie odwraca siły.
+        assert_eq!(tether_pull(Vec2::new(0.0, 100.0), -1000.0), 0.0);
+    }
+}
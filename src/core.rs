@@ -0,0 +1,474 @@
+// Czysta matematyka rozgrywki – bez ECS i bez Bevy.
+// Wołają ją systemy gry: fizyka i kolizje (`physics.rs`, `score.rs`), rury
+// i wzory (`pipes.rs`, `patterns.rs`, `rng.rs`), sterowanie (`player.rs`,
+// `input.rs`, `controls.rs`) oraz tryby i rywale (`ai_race.rs`, `coop.rs`,
+// `tournament.rs` i inne). Same testy nie potrzebują App.
+
+use std::ops::RangeInclusive;
+
+use rand::Rng;
+use rand::rngs::StdRng;
+
+// Parametry pojedynczej pary rur.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PipeParams {
+    pub gap: f32,
+    pub speed: f32,
+}
+
+// Krzywa trudności: od wartości startowych do docelowych w miarę zdobywania punktów.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DifficultyCurve {
+    pub start: PipeParams,
+    pub end: PipeParams,
+    // Wynik, przy którym osiągamy pełną trudność.
+    pub full_at_score: i32,
+}
+
+impl DifficultyCurve {
+    // Oryginalna gra: stała przerwa i prędkość przez całą rundę.
+    pub const CLASSIC: DifficultyCurve = DifficultyCurve {
+        start: PipeParams {
+            gap: 100.0,
+            speed: -100.0,
+        },
+        end: PipeParams {
+            gap: 100.0,
+            speed: -100.0,
+        },
+        full_at_score: 0,
+    };
+
+    pub const EASY: DifficultyCurve = DifficultyCurve {
+        start: PipeParams {
+            gap: 130.0,
+            speed: -90.0,
+        },
+        end: PipeParams {
+            gap: 110.0,
+            speed: -130.0,
+        },
+        full_at_score: 80,
+    };
+
+    pub const HARD: DifficultyCurve = DifficultyCurve {
+        start: PipeParams {
+            gap: 90.0,
+            speed: -120.0,
+        },
+        end: PipeParams {
+            gap: 70.0,
+            speed: -200.0,
+        },
+        full_at_score: 40,
+    };
+
+    // Tryb dla dzieci: ogromne przerwy i powolne rury, prawie bez zmian w czasie.
+    pub const KIDS: DifficultyCurve = DifficultyCurve {
+        start: PipeParams {
+            gap: 220.0,
+            speed: -70.0,
+        },
+        end: PipeParams {
+            gap: 200.0,
+            speed: -85.0,
+        },
+        full_at_score: 100,
+    };
+
+    // Postęp trudności w przedziale 0..=1.
+    pub fn progress(&self, score: i32) -> f32 {
+        if self.full_at_score <= 0 {
+            return 1.0;
+        }
+        (score as f32 / self.full_at_score as f32).clamp(0.0, 1.0)
+    }
+
+    pub fn params_at(&self, score: i32) -> PipeParams {
+        let t = self.progress(score);
+        PipeParams {
+            gap: lerp(self.start.gap, self.end.gap, t),
+            speed: lerp(self.start.speed, self.end.speed, t),
+        }
+    }
+}
+
+pub fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+// Przyspieszenie grawitacyjne ptaka (jednostki/s²).
+pub const GRAVITY: f32 = -350.0;
+
+// Prędkość pionowa nadawana przez zwykłe machnięcie.
+pub const FLAP_IMPULSE: f32 = 150.0;
+
+// Czas ładowania, po którym machnięcie ma pełną siłę.
+pub const FULL_CHARGE_SECS: f32 = 0.6;
+
+// Stopień naładowania 0..=1 po przytrzymaniu przycisku przez `held_secs`.
+pub fn charge_level(held_secs: f32) -> f32 {
+    (held_secs / FULL_CHARGE_SECS).clamp(0.0, 1.0)
+}
+
+// Siła machnięcia po puszczeniu przycisku w trybie ładowania.
+pub fn charge_impulse(held_secs: f32) -> f32 {
+    lerp(90.0, 260.0, charge_level(held_secs))
+}
+
+// Mnożnik siły machnięcia z nacisku analogowego spustu (0..=1).
+pub fn analog_flap_scale(pressure: f32) -> f32 {
+    lerp(0.6, 1.3, pressure.clamp(0.0, 1.0))
+}
+
+// Zakres, w którym losujemy środek przerwy między rurami.
+pub const GAP_CENTER_RANGE: RangeInclusive<f32> = -130.0..=130.0;
+
+// Po co było losowanie – do dziennika losowań rundy (`rng::RunRng::audit`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawPurpose {
+    // Losowanie bez podanego celu (np. przewijanie przy wznawianiu rundy).
+    Other,
+    GapCenter,
+    TunnelRoll,
+    SpikesRoll,
+    SpikeSide,
+    LowGravityRoll,
+    HammerRoll,
+}
+
+impl DrawPurpose {
+    pub fn label(self) -> &'static str {
+        match self {
+            DrawPurpose::Other => "other",
+            DrawPurpose::GapCenter => "gap_center",
+            DrawPurpose::TunnelRoll => "tunnel_roll",
+            DrawPurpose::SpikesRoll => "spikes_roll",
+            DrawPurpose::SpikeSide => "spike_side",
+            DrawPurpose::LowGravityRoll => "low_gravity_roll",
+            DrawPurpose::HammerRoll => "hammer_roll",
+        }
+    }
+}
+
+// Generator, któremu można powiedzieć, po co będą następne losowania.
+// Zwykłe generatory to ignorują; `RunRng` zapisuje to w dzienniku.
+pub trait TaggedRng: Rng {
+    fn tag(&mut self, _purpose: DrawPurpose) {}
+}
+
+impl TaggedRng for StdRng {}
+
+// Losowy środek przerwy w zadanym zakresie.
+pub fn random_gap_center<R: Rng>(rng: &mut R, range: RangeInclusive<f32>) -> f32 {
+    rng.gen_range(range)
+}
+
+// Szanse na poszczególne wzory przeszkód.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PatternOdds {
+    // Tunel pojawia się dopiero od tego wyniku.
+    pub tunnel_min_score: i32,
+    pub tunnel: f64,
+    pub spikes: f64,
+    pub low_gravity: f64,
+}
+
+// Dodatek w połowie drogi między parą rur a następną.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Extra {
+    None,
+    Spikes { top: bool },
+    LowGravity,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pattern {
+    Tunnel,
+    Pipes(Extra),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PatternPlan {
+    pub center_y: f32,
+    pub pattern: Pattern,
+}
+
+// Rzut z prawdopodobieństwem `p`, oznaczony celem (samo oznaczenie niczego
+// nie losuje).
+fn roll<R: TaggedRng>(rng: &mut R, purpose: DrawPurpose, p: f64) -> bool {
+    rng.tag(purpose);
+    rng.gen_bool(p)
+}
+
+// Losuje kolejny wzór przeszkód. Kolejność losowań jest częścią formatu
+// ziarna: zmiana tutaj zmienia układ rur dla wszystkich zapisanych ziaren.
+pub fn plan_pattern<R: TaggedRng>(rng: &mut R, score: i32, odds: &PatternOdds) -> PatternPlan {
+    rng.tag(DrawPurpose::GapCenter);
+    let center_y = random_gap_center(rng, GAP_CENTER_RANGE);
+    let pattern =
+        if score >= odds.tunnel_min_score && roll(rng, DrawPurpose::TunnelRoll, odds.tunnel) {
+            Pattern::Tunnel
+        } else if roll(rng, DrawPurpose::SpikesRoll, odds.spikes) {
+            Pattern::Pipes(Extra::Spikes {
+                top: roll(rng, DrawPurpose::SpikeSide, 0.5),
+            })
+        } else if roll(rng, DrawPurpose::LowGravityRoll, odds.low_gravity) {
+            Pattern::Pipes(Extra::LowGravity)
+        } else {
+            Pattern::Pipes(Extra::None)
+        };
+    PatternPlan { center_y, pattern }
+}
+
+// Wyloty górnej i dolnej rury dla przerwy wyśrodkowanej na `center_y`.
+pub fn pipe_mouths(center_y: f32, gap: f32) -> (f32, f32) {
+    (center_y + gap / 2.0, center_y - gap / 2.0)
+}
+
+// Środki przerwy kolejnych segmentów tunelu: łagodna sinusoida wokół
+// `start`, przycięta do dozwolonego zakresu.
+pub fn tunnel_centers(start: f32, segments: usize, range: RangeInclusive<f32>) -> Vec<f32> {
+    (0..segments)
+        .map(|i| {
+            let center = start + 70.0 * (i as f32 * 0.45).sin();
+            center.clamp(*range.start(), *range.end())
+        })
+        .collect()
+}
+
+// Czas, po którym rura wystartowana w `spawn_x` opuści ekran po drugiej stronie –
+// tak samo dla rur lecących w lewo i (w trybie lustrzanym) w prawo.
+pub fn pipe_lifetime(spawn_x: f32, speed: f32) -> f32 {
+    2.0 * spawn_x.abs() / speed.abs().max(1.0) + 1.0
+}
+
+// Test nachodzenia dwóch prostokątów (środek + połowa rozmiaru).
+pub fn aabb_overlap(
+    a_center: [f32; 2],
+    a_half: [f32; 2],
+    b_center: [f32; 2],
+    b_half: [f32; 2],
+) -> bool {
+    (a_center[0] - b_center[0]).abs() <= a_half[0] + b_half[0]
+        && (a_center[1] - b_center[1]).abs() <= a_half[1] + b_half[1]
+}
+
+// Głębokość wzajemnego wniknięcia dwóch prostokątów w osiach x i y (jeśli nachodzą).
+pub fn penetration(
+    a_center: [f32; 2],
+    a_half: [f32; 2],
+    b_center: [f32; 2],
+    b_half: [f32; 2],
+) -> Option<[f32; 2]> {
+    let x = a_half[0] + b_half[0] - (a_center[0] - b_center[0]).abs();
+    let y = a_half[1] + b_half[1] - (a_center[1] - b_center[1]).abs();
+    (x >= 0.0 && y >= 0.0).then_some([x, y])
+}
+
+// Kolizja ciągła: punkt poruszający się od `start` do `end` (pozycja względna
+// względem środka prostokąta) kontra prostokąt o połowie rozmiaru `half`.
+// Zwraca ułamek drogi 0..=1, w którym nastąpiło pierwsze zetknięcie.
+pub fn sweep_entry(start: [f32; 2], end: [f32; 2], half: [f32; 2]) -> Option<f32> {
+    let mut t_enter = 0.0f32;
+    let mut t_exit = 1.0f32;
+
+    for axis in 0..2 {
+        let delta = end[axis] - start[axis];
+        if delta.abs() < f32::EPSILON {
+            if start[axis].abs() > half[axis] {
+                return None;
+            }
+            continue;
+        }
+
+        let t1 = (-half[axis] - start[axis]) / delta;
+        let t2 = (half[axis] - start[axis]) / delta;
+        t_enter = t_enter.max(t1.min(t2));
+        t_exit = t_exit.min(t1.max(t2));
+        if t_enter > t_exit {
+            return None;
+        }
+    }
+
+    Some(t_enter)
+}
+
+// Czy prostokąt o środku `y` i połowie wysokości `half_height` wychodzi poza pionowe granice.
+pub fn out_of_bounds(y: f32, half_height: f32, top: f32, bottom: f32) -> bool {
+    y + half_height > top || y - half_height < bottom
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use super::*;
+
+    #[test]
+    fn classic_curve_starts_at_original_values() {
+        let params = DifficultyCurve::CLASSIC.params_at(0);
+        assert_eq!(params.gap, 100.0);
+        assert_eq!(params.speed, -100.0);
+    }
+
+    #[test]
+    fn classic_curve_stays_constant() {
+        let curve = DifficultyCurve::CLASSIC;
+        assert_eq!(curve.params_at(100), curve.params_at(0));
+    }
+
+    #[test]
+    fn curve_saturates_at_full_score() {
+        let curve = DifficultyCurve::HARD;
+        assert_eq!(curve.params_at(curve.full_at_score), curve.end);
+        assert_eq!(curve.params_at(curve.full_at_score * 10), curve.end);
+    }
+
+    #[test]
+    fn charge_impulse_grows_with_hold_and_caps() {
+        assert_eq!(charge_impulse(0.0), 90.0);
+        assert!(charge_impulse(0.3) > charge_impulse(0.1));
+        assert_eq!(charge_impulse(FULL_CHARGE_SECS), 260.0);
+        assert_eq!(charge_impulse(10.0), 260.0);
+    }
+
+    #[test]
+    fn analog_flap_scale_spans_range() {
+        assert_eq!(analog_flap_scale(0.0), 0.6);
+        assert_eq!(analog_flap_scale(1.0), 1.3);
+        assert_eq!(analog_flap_scale(2.0), 1.3);
+    }
+
+    #[test]
+    fn pipes_leave_exact_gap() {
+        let (top, bottom) = pipe_mouths(20.0, 100.0);
+        assert_eq!(top - bottom, 100.0);
+        assert_eq!((top + bottom) / 2.0, 20.0);
+    }
+
+    #[test]
+    fn tunnel_starts_at_given_center_and_stays_in_range() {
+        let centers = tunnel_centers(100.0, 14, -120.0..=120.0);
+        assert_eq!(centers.len(), 14);
+        assert_eq!(centers[0], 100.0);
+        assert!(centers.iter().all(|c| (-120.0..=120.0).contains(c)));
+        // Sąsiednie segmenty nie skaczą – korytarz da się przelecieć.
+        assert!(centers.windows(2).all(|w| (w[1] - w[0]).abs() < 35.0));
+    }
+
+    #[test]
+    fn pattern_odds_pick_the_pattern() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut odds = PatternOdds {
+            tunnel_min_score: 5,
+            tunnel: 1.0,
+            spikes: 0.0,
+            low_gravity: 0.0,
+        };
+        assert_eq!(
+            plan_pattern(&mut rng, 4, &odds).pattern,
+            Pattern::Pipes(Extra::None)
+        );
+        assert_eq!(plan_pattern(&mut rng, 5, &odds).pattern, Pattern::Tunnel);
+
+        odds.tunnel = 0.0;
+        odds.low_gravity = 1.0;
+        assert_eq!(
+            plan_pattern(&mut rng, 5, &odds).pattern,
+            Pattern::Pipes(Extra::LowGravity)
+        );
+    }
+
+    #[test]
+    fn touching_boxes_overlap() {
+        assert!(aabb_overlap([0.0, 0.0], [1.0, 1.0], [2.0, 0.0], [1.0, 1.0]));
+        assert!(!aabb_overlap(
+            [0.0, 0.0],
+            [1.0, 1.0],
+            [2.1, 0.0],
+            [1.0, 1.0]
+        ));
+    }
+
+    #[test]
+    fn penetration_reports_overlap_depth() {
+        assert_eq!(
+            penetration([0.0, 0.0], [10.0, 10.0], [18.0, 5.0], [10.0, 10.0]),
+            Some([2.0, 15.0])
+        );
+        assert_eq!(
+            penetration([0.0, 0.0], [10.0, 10.0], [21.0, 0.0], [10.0, 10.0]),
+            None
+        );
+    }
+
+    #[test]
+    fn sweep_finds_entry_fraction() {
+        // Od x=-20 do x=0 przez prostokąt o krawędzi w x=-10: wejście w połowie drogi.
+        assert_eq!(
+            sweep_entry([-20.0, 0.0], [0.0, 0.0], [10.0, 10.0]),
+            Some(0.5)
+        );
+        assert_eq!(sweep_entry([0.0, 0.0], [1.0, 0.0], [10.0, 10.0]), Some(0.0));
+        assert_eq!(sweep_entry([-20.0, 30.0], [0.0, 30.0], [10.0, 10.0]), None);
+    }
+
+    #[test]
+    fn bounds_check_uses_half_height() {
+        assert!(!out_of_bounds(284.0, 16.0, 300.0, -300.0));
+        assert!(out_of_bounds(285.0, 16.0, 300.0, -300.0));
+        assert!(out_of_bounds(-285.0, 16.0, 300.0, -300.0));
+    }
+
+    #[test]
+    fn seeded_gap_centers_are_reproducible() {
+        let mut a = StdRng::seed_from_u64(7);
+        let mut b = StdRng::seed_from_u64(7);
+        for _ in 0..10 {
+            assert_eq!(
+                random_gap_center(&mut a, -130.0..=130.0),
+                random_gap_center(&mut b, -130.0..=130.0)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn gap_center_stays_in_range(seed in any::<u64>()) {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let center = random_gap_center(&mut rng, -130.0..=130.0);
+            prop_assert!((-130.0..=130.0).contains(&center));
+        }
+
+        #[test]
+        fn difficulty_is_monotonic(score in 0..200i32) {
+            for curve in [DifficultyCurve::EASY, DifficultyCurve::CLASSIC, DifficultyCurve::HARD] {
+                let now = curve.params_at(score);
+                let next = curve.params_at(score + 1);
+                prop_assert!(next.gap <= now.gap);
+                prop_assert!(next.speed <= now.speed);
+            }
+        }
+
+        #[test]
+        fn overlap_is_symmetric(
+            ax in -500.0f32..500.0, ay in -500.0f32..500.0,
+            bx in -500.0f32..500.0, by in -500.0f32..500.0,
+            hw in 0.0f32..100.0, hh in 0.0f32..100.0,
+        ) {
+            prop_assert_eq!(
+                aabb_overlap([ax, ay], [hw, hh], [bx, by], [hh, hw]),
+                aabb_overlap([bx, by], [hh, hw], [ax, ay], [hw, hh])
+            );
+        }
+
+        #[test]
+        fn gap_width_is_preserved(center in -130.0f32..130.0, gap in 50.0f32..200.0) {
+            let (top, bottom) = pipe_mouths(center, gap);
+            prop_assert!((top - bottom - gap).abs() < 1e-3);
+        }
+    }
+}
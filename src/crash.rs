@@ -0,0 +1,321 @@
+// Raport po awarii. Hak paniki zapisuje do `crash-report.txt` komunikat,
+// backtrace, stan gry, wynik, ziarno i ostatnie zdarzenia rozgrywki
+// (`gameplay_log.rs`). Przy następnym uruchomieniu menu pokazuje okienko
+// z propozycją otwarcia raportu.
+//
+// Kontekst trzymamy w globalnym zasobie, bo hak paniki nie ma dostępu do świata.
+
+use std::collections::VecDeque;
+use std::fs;
+use std::sync::{Mutex, TryLockError};
+
+use bevy::log::BoxedLayer;
+use bevy::prelude::*;
+use bevy::utils::tracing::field::{Field, Visit};
+use bevy::utils::tracing::{Event as TracingEvent, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+use crate::menu::Menu;
+use crate::rng::RunRng;
+use crate::score::Score;
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+const REPORT_PATH: &str = "crash-report.txt";
+// Raport już pokazany graczowi – zostaje do wglądu, ale bez okienka.
+const SEEN_REPORT_PATH: &str = "crash-report.seen.txt";
+
+const MAX_EVENTS: usize = 50;
+
+#[derive(Default)]
+struct CrashContext {
+    state: String,
+    score: i32,
+    seed: Option<u64>,
+    events: VecDeque<String>,
+}
+
+static CONTEXT: Mutex<Option<CrashContext>> = Mutex::new(None);
+
+fn with_context(update: impl FnOnce(&mut CrashContext)) {
+    // Zatruty mutex (panika w trakcie zapisu) nie może zablokować raportu.
+    let mut context = CONTEXT.lock().unwrap_or_else(|err| err.into_inner());
+    update(context.get_or_insert_with(CrashContext::default));
+}
+
+// Instaluje hak paniki; domyślny hak nadal wypisuje komunikat na stderr.
+pub fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let backtrace = std::backtrace::Backtrace::force_capture();
+        // Panika mogła wypaść w trakcie aktualizacji kontekstu – wtedy
+        // zapisujemy raport bez niego, zamiast czekać na własną blokadę.
+        let report = match CONTEXT.try_lock() {
+            Ok(context) => format_report(context.as_ref(), &info.to_string(), &backtrace),
+            Err(TryLockError::Poisoned(err)) => {
+                format_report(err.into_inner().as_ref(), &info.to_string(), &backtrace)
+            }
+            Err(TryLockError::WouldBlock) => format_report(None, &info.to_string(), &backtrace),
+        };
+        if let Err(err) = fs::write(REPORT_PATH, report) {
+            eprintln!("Nie udało się zapisać raportu awarii: {err}");
+        }
+        previous(info);
+    }));
+}
+
+fn format_report(
+    context: Option<&CrashContext>,
+    panic: &str,
+    backtrace: &impl std::fmt::Display,
+) -> String {
+    let Some(context) = context else {
+        return format!(
+            "Flappy Bird {} crash report\npanic: {panic}\n\nbacktrace:\n{backtrace}\n",
+            env!("CARGO_PKG_VERSION")
+        );
+    };
+    let seed = context
+        .seed
+        .map_or_else(|| "-".to_string(), |seed| format!("{seed:016x}"));
+    let events: String = context
+        .events
+        .iter()
+        .map(|event| format!("  {event}\n"))
+        .collect();
+    format!(
+        "Flappy Bird {} crash report\n\
+         panic: {panic}\n\
+         state: {}\n\
+         score: {}\n\
+         seed: {seed}\n\n\
+         last {} gameplay events:\n\
+         {events}\n\
+         backtrace:\n{backtrace}\n",
+        env!("CARGO_PKG_VERSION"),
+        context.state,
+        context.score,
+        context.events.len(),
+    )
+}
+
+// Warstwa `tracing` zapamiętująca ostatnie zdarzenia z celu `gameplay`.
+pub fn event_layer() -> BoxedLayer {
+    Box::new(GameplayEvents)
+}
+
+struct GameplayEvents;
+
+impl<S: Subscriber> Layer<S> for GameplayEvents {
+    fn on_event(&self, event: &TracingEvent<'_>, _ctx: Context<'_, S>) {
+        if event.metadata().target() != "gameplay" {
+            return;
+        }
+        let mut fields = FieldList::default();
+        event.record(&mut fields);
+        with_context(|context| {
+            if context.events.len() == MAX_EVENTS {
+                context.events.pop_front();
+            }
+            context.events.push_back(fields.0);
+        });
+    }
+}
+
+#[derive(Default)]
+struct FieldList(String);
+
+impl Visit for FieldList {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            return;
+        }
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        self.0.push_str(&format!("{}={value:?}", field.name()));
+    }
+}
+
+#[derive(Resource)]
+struct PendingCrashNotice;
+
+#[derive(Component)]
+struct CrashNotice;
+
+#[derive(Component)]
+enum CrashNoticeButton {
+    Open,
+    Dismiss,
+}
+
+// Dodawany tylko w grze z oknem, obok haka paniki.
+pub struct CrashReportPlugin;
+
+impl Plugin for CrashReportPlugin {
+    fn build(&self, app: &mut App) {
+        if fs::metadata(REPORT_PATH).is_ok() {
+            app.insert_resource(PendingCrashNotice);
+        }
+        app.add_systems(Last, update_context)
+            .add_systems(
+                OnEnter(AppState::Menu),
+                spawn_crash_notice
+                    .after(crate::menu::setup_menu)
+                    .run_if(resource_exists::<PendingCrashNotice>),
+            )
+            .add_systems(
+                Update,
+                crash_notice_buttons.run_if(in_state(AppState::Menu)),
+            );
+    }
+}
+
+fn update_context(state: Res<State<AppState>>, score: Res<Score>, rng: Res<RunRng>) {
+    with_context(|context| {
+        context.state = format!("{:?}", state.get());
+        context.score = score.0;
+        context.seed = Some(rng.seed());
+    });
+}
+
+fn spawn_crash_notice(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            GlobalZIndex(50),
+            CrashNotice,
+            Menu,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(12.0),
+                        padding: UiRect::all(Val::Px(20.0)),
+                        ..Default::default()
+                    },
+                    BackgroundColor(Color::srgb(0.12, 0.12, 0.12)),
+                    BorderRadius::all(Val::Px(8.0)),
+                ))
+                .with_children(|dialog| {
+                    dialog.spawn((
+                        Text::new(format!(
+                            "Sorry, the game crashed last time.\nA report was saved to {REPORT_PATH}."
+                        )),
+                        TextColor(Color::WHITE),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                    ));
+                    dialog
+                        .spawn(Node {
+                            column_gap: Val::Px(12.0),
+                            ..Default::default()
+                        })
+                        .with_children(|row| {
+                            for (label, action) in [
+                                ("Open report", CrashNoticeButton::Open),
+                                ("Dismiss", CrashNoticeButton::Dismiss),
+                            ] {
+                                row.spawn((
+                                    Button,
+                                    Interaction::default(),
+                                    Node {
+                                        width: Val::Px(160.0),
+                                        height: Val::Px(44.0),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..Default::default()
+                                    },
+                                    BackgroundColor(NORMAL_BUTTON),
+                                    action,
+                                ))
+                                .with_child((Text::new(label), TextColor(Color::WHITE)));
+                            }
+                        });
+                });
+        });
+}
+
+fn crash_notice_buttons(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &CrashNoticeButton),
+        Changed<Interaction>,
+    >,
+    notices: Query<Entity, With<CrashNotice>>,
+) {
+    for (interaction, mut bg_color, button) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                // Po obejrzeniu raport zostaje, ale okienko już się nie pojawi.
+                let path = match fs::rename(REPORT_PATH, SEEN_REPORT_PATH) {
+                    Ok(()) => SEEN_REPORT_PATH,
+                    Err(err) => {
+                        warn!("Nie udało się przenieść raportu awarii: {err}");
+                        REPORT_PATH
+                    }
+                };
+                if let CrashNoticeButton::Open = button {
+                    open_report(path);
+                }
+                commands.remove_resource::<PendingCrashNotice>();
+                for notice in notices.iter() {
+                    commands.entity(notice).despawn_recursive();
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+// Otwiera raport w domyślnym programie systemu.
+fn open_report(path: &str) {
+    let path = fs::canonicalize(path).unwrap_or_else(|_| path.into());
+    let result = if cfg!(target_os = "windows") {
+        std::process::Command::new("notepad").arg(&path).spawn()
+    } else if cfg!(target_os = "macos") {
+        std::process::Command::new("open").arg(&path).spawn()
+    } else {
+        std::process::Command::new("xdg-open").arg(&path).spawn()
+    };
+    if let Err(err) = result {
+        warn!("Nie udało się otworzyć {}: {err}", path.display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_lists_context_and_recent_events() {
+        let context = CrashContext {
+            state: "Playing".to_string(),
+            score: 7,
+            seed: Some(0xabc),
+            events: VecDeque::from(["event=\"pipe_scored\" score=7".to_string()]),
+        };
+        let report = format_report(Some(&context), "boom", &"frames");
+
+        assert!(report.contains("panic: boom"));
+        assert!(report.contains("state: Playing"));
+        assert!(report.contains("score: 7"));
+        assert!(report.contains("seed: 0000000000000abc"));
+        assert!(report.contains("  event=\"pipe_scored\" score=7"));
+        assert!(report.contains("backtrace:\nframes"));
+    }
+}
@@ -0,0 +1,278 @@
+// Filtr CRT (`assets/shaders/crt.wgsl`): przejście po tonemappingu, które
+// wygina obraz, dodaje linie skanowania i poświatę – wygląd starego automatu.
+// Włączany w ustawieniach; kamera dostaje wtedy komponent `CrtSettings`.
+//
+// Tylko gra z oknem dodaje ten plugin.
+
+use bevy::core_pipeline::core_2d::graph::{Core2d, Node2d};
+use bevy::core_pipeline::fullscreen_vertex_shader::fullscreen_shader_vertex_state;
+use bevy::ecs::query::QueryItem;
+use bevy::image::BevyDefault;
+use bevy::prelude::*;
+use bevy::render::RenderApp;
+use bevy::render::extract_component::{
+    ComponentUniforms, DynamicUniformIndex, ExtractComponentPlugin, UniformComponentPlugin,
+};
+use bevy::render::render_graph::{
+    NodeRunError, RenderGraphApp, RenderGraphContext, RenderLabel, ViewNode, ViewNodeRunner,
+};
+use bevy::render::render_resource::binding_types::{sampler, texture_2d, uniform_buffer};
+use bevy::render::render_resource::{
+    BindGroupEntries, BindGroupLayout, BindGroupLayoutEntries, CachedRenderPipelineId,
+    ColorTargetState, ColorWrites, FragmentState, MultisampleState, Operations, PipelineCache,
+    PrimitiveState, RenderPassColorAttachment, RenderPassDescriptor, RenderPipelineDescriptor,
+    Sampler, SamplerBindingType, SamplerDescriptor, ShaderStages, TextureFormat, TextureSampleType,
+};
+use bevy::render::renderer::{RenderContext, RenderDevice};
+use bevy::render::view::ViewTarget;
+
+use crate::buttons::button_text;
+use crate::settings::Settings;
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+const CRT_SHADER: &str = "shaders/crt.wgsl";
+
+// Jak `SkyUniform` w `sky.rs`: funkcje sprawdzające z `ShaderType` nie są
+// nigdzie wołane, więc wyłączenie `dead_code` obejmuje tylko ten moduł.
+#[allow(dead_code)]
+mod uniform {
+    use bevy::prelude::*;
+    use bevy::render::extract_component::ExtractComponent;
+    use bevy::render::render_resource::ShaderType;
+
+    #[derive(Component, Clone, Copy, ExtractComponent, ShaderType)]
+    pub struct CrtSettings {
+        // Siła wygięcia ekranu (0 = płaski).
+        curvature: f32,
+        // Jak mocno przyciemniamy przerwy między liniami (0..1).
+        scanlines: f32,
+        glow: f32,
+    }
+
+    impl Default for CrtSettings {
+        fn default() -> Self {
+            Self {
+                curvature: 0.08,
+                scanlines: 0.35,
+                glow: 0.6,
+            }
+        }
+    }
+}
+
+pub use uniform::CrtSettings;
+
+#[derive(Component)]
+pub struct CrtButton;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+struct CrtLabel;
+
+pub fn crt_button_label(settings: &Settings) -> String {
+    let state = if settings.crt_filter { "On" } else { "Off" };
+    format!("CRT: {state}")
+}
+
+pub struct CrtPlugin;
+
+impl Plugin for CrtPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            ExtractComponentPlugin::<CrtSettings>::default(),
+            UniformComponentPlugin::<CrtSettings>::default(),
+        ))
+        .add_systems(
+            Update,
+            (
+                crt_button_system.run_if(in_state(AppState::Menu)),
+                apply_crt_filter,
+            ),
+        );
+
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+        render_app
+            .add_render_graph_node::<ViewNodeRunner<CrtNode>>(Core2d, CrtLabel)
+            .add_render_graph_edges(
+                Core2d,
+                (
+                    Node2d::Tonemapping,
+                    CrtLabel,
+                    Node2d::EndMainPassPostProcessing,
+                ),
+            );
+    }
+
+    fn finish(&self, app: &mut App) {
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+        render_app.init_resource::<CrtPipeline>();
+    }
+}
+
+fn crt_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, Option<&Children>),
+        (Changed<Interaction>, With<CrtButton>),
+    >,
+    mut text_query: Query<&mut Text>,
+    mut settings: ResMut<Settings>,
+) {
+    for (interaction, mut bg_color, children) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                settings.crt_filter = !settings.crt_filter;
+                if let Some(mut text) = button_text(children, &mut text_query) {
+                    text.0 = crt_button_label(&settings);
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+fn apply_crt_filter(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    cameras: Query<(Entity, Has<CrtSettings>), With<Camera2d>>,
+) {
+    for (camera, enabled) in cameras.iter() {
+        if settings.crt_filter && !enabled {
+            commands.entity(camera).insert(CrtSettings::default());
+        } else if !settings.crt_filter && enabled {
+            commands.entity(camera).remove::<CrtSettings>();
+        }
+    }
+}
+
+#[derive(Default)]
+struct CrtNode;
+
+impl ViewNode for CrtNode {
+    type ViewQuery = (
+        &'static ViewTarget,
+        &'static CrtSettings,
+        &'static DynamicUniformIndex<CrtSettings>,
+    );
+
+    fn run(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        (view_target, _settings, settings_index): QueryItem<Self::ViewQuery>,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        let crt = world.resource::<CrtPipeline>();
+        // Format tekstury zależy od tego, czy kamera jest w HDR (bloom).
+        let pipeline_id = if view_target.is_hdr() {
+            crt.hdr_pipeline
+        } else {
+            crt.pipeline
+        };
+        // Shader mógł się jeszcze nie skompilować – wtedy klatka bez filtra.
+        let Some(pipeline) = world
+            .resource::<PipelineCache>()
+            .get_render_pipeline(pipeline_id)
+        else {
+            return Ok(());
+        };
+        let Some(settings_binding) = world
+            .resource::<ComponentUniforms<CrtSettings>>()
+            .uniforms()
+            .binding()
+        else {
+            return Ok(());
+        };
+
+        let post_process = view_target.post_process_write();
+        let bind_group = render_context.render_device().create_bind_group(
+            "crt_bind_group",
+            &crt.layout,
+            &BindGroupEntries::sequential((post_process.source, &crt.sampler, settings_binding)),
+        );
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some("crt_pass"),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: post_process.destination,
+                resolve_target: None,
+                ops: Operations::default(),
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        render_pass.set_render_pipeline(pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[settings_index.index()]);
+        render_pass.draw(0..3, 0..1);
+        Ok(())
+    }
+}
+
+#[derive(Resource)]
+struct CrtPipeline {
+    layout: BindGroupLayout,
+    sampler: Sampler,
+    pipeline: CachedRenderPipelineId,
+    hdr_pipeline: CachedRenderPipelineId,
+}
+
+impl FromWorld for CrtPipeline {
+    fn from_world(world: &mut World) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+        let layout = render_device.create_bind_group_layout(
+            "crt_bind_group_layout",
+            &BindGroupLayoutEntries::sequential(
+                ShaderStages::FRAGMENT,
+                (
+                    texture_2d(TextureSampleType::Float { filterable: true }),
+                    sampler(SamplerBindingType::Filtering),
+                    uniform_buffer::<CrtSettings>(true),
+                ),
+            ),
+        );
+        let sampler = render_device.create_sampler(&SamplerDescriptor::default());
+        let shader = world.load_asset(CRT_SHADER);
+
+        let mut queue = |format: TextureFormat| {
+            world
+                .resource_mut::<PipelineCache>()
+                .queue_render_pipeline(RenderPipelineDescriptor {
+                    label: Some("crt_pipeline".into()),
+                    layout: vec![layout.clone()],
+                    vertex: fullscreen_shader_vertex_state(),
+                    fragment: Some(FragmentState {
+                        shader: shader.clone(),
+                        shader_defs: vec![],
+                        entry_point: "fragment".into(),
+                        targets: vec![Some(ColorTargetState {
+                            format,
+                            blend: None,
+                            write_mask: ColorWrites::ALL,
+                        })],
+                    }),
+                    primitive: PrimitiveState::default(),
+                    depth_stencil: None,
+                    multisample: MultisampleState::default(),
+                    push_constant_ranges: vec![],
+                    zero_initialize_workgroup_memory: false,
+                })
+        };
+        let pipeline = queue(TextureFormat::bevy_default());
+        let hdr_pipeline = queue(ViewTarget::TEXTURE_FORMAT_HDR);
+
+        Self {
+            layout,
+            sampler,
+            pipeline,
+            hdr_pipeline,
+        }
+    }
+}
@@ -0,0 +1,268 @@
+// Eksport rozgrywki jako zbioru danych dla uczenia maszynowego: w każdym kroku
+// symulacji jeden wiersz (obserwacja, akcja, nagroda). Włączany flagą
+// `--record-dataset katalog` albo – w bezgłowym App – wstawieniem zasobu
+// `DatasetExport`. Każda runda trafia do osobnego pliku
+// `run-<ziarno>-<n>.csv.gz` (CSV skompresowany gzipem).
+//
+// Schemat (wersja 1), kolumny w tej kolejności:
+//   tick         – numer kroku w rundzie, od 0
+//   bird_y       – wysokość ptaka przed krokiem (środek świata = 0, w górę dodatnio)
+//   bird_dy      – prędkość pionowa ptaka przed krokiem (jednostki/s)
+//   pipe1_dx     – odległość w poziomie do najbliższej przerwy przed ptakiem
+//   pipe1_gap_y  – wysokość środka tej przerwy
+//   pipe1_gap    – wysokość tej przerwy
+//   pipe2_dx, pipe2_gap_y, pipe2_gap – to samo dla następnej przerwy
+//   action       – 1, jeśli w tym kroku ptak machnął, inaczej 0
+//   reward       – punkty zdobyte w kroku, -1 za śmierć
+//   done         – 1 w ostatnim kroku rundy
+// Brak przerwy zapisujemy jako dx = szerokość świata, gap_y = 0, gap = 0.
+// Długości w jednostkach świata (600 w pionie), czas kroku wg `tick_rate`.
+
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use flate2::Compression;
+use flate2::write::GzEncoder;
+
+use crate::AppState;
+use crate::display::WORLD_SIZE;
+use crate::interpolation::Interpolated;
+use crate::pause::PauseState;
+use crate::physics::{Collider, Velocity, boundary_collision_system, collision_system};
+use crate::player::{Flapped, Player, player_input_system};
+use crate::rng::RunRng;
+use crate::score::{Score, ScoreZone, apply_score_events};
+
+const RECORD_FLAG: &str = "--record-dataset";
+
+pub const SCHEMA_HEADER: &str = "tick,bird_y,bird_dy,pipe1_dx,pipe1_gap_y,pipe1_gap,\
+                                 pipe2_dx,pipe2_gap_y,pipe2_gap,action,reward,done";
+
+// Katalog na pliki zbioru danych; bez tego zasobu nic nie zapisujemy.
+#[derive(Resource, Clone)]
+pub struct DatasetExport {
+    pub dir: PathBuf,
+}
+
+impl DatasetExport {
+    // Katalog z `--record-dataset`, jeśli gra została z nim uruchomiona.
+    pub fn requested() -> Option<Self> {
+        let mut args = std::env::args().skip_while(|arg| arg != RECORD_FLAG);
+        args.next()?;
+        args.next().map(|dir| Self { dir: dir.into() })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Observation {
+    pub bird_y: f32,
+    pub bird_dy: f32,
+    // Dwie najbliższe przerwy przed ptakiem: (dx, środek, wysokość).
+    pub pipes: [(f32, f32, f32); 2],
+}
+
+impl Observation {
+    fn row(&self, tick: u32, action: bool, reward: i32, done: bool) -> String {
+        let [(dx1, y1, gap1), (dx2, y2, gap2)] = self.pipes;
+        format!(
+            "{tick},{:.2},{:.2},{dx1:.2},{y1:.2},{gap1:.2},{dx2:.2},{y2:.2},{gap2:.2},{},{reward},{}",
+            self.bird_y,
+            self.bird_dy,
+            u8::from(action),
+            u8::from(done),
+        )
+    }
+}
+
+#[derive(Resource, Default)]
+struct DatasetWriter {
+    file: Option<GzEncoder<BufWriter<File>>>,
+    tick: u32,
+    runs: u32,
+    observation: Observation,
+    last_score: i32,
+    // Ostatni wiersz rundy już zapisany (do zmiany stanu może minąć jeszcze krok).
+    done: bool,
+}
+
+pub struct DatasetPlugin;
+
+impl Plugin for DatasetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DatasetWriter>()
+            .add_systems(
+                OnEnter(AppState::Playing),
+                open_run_file.run_if(resource_exists::<DatasetExport>),
+            )
+            .add_systems(OnExit(AppState::Playing), close_run_file)
+            .add_systems(
+                FixedUpdate,
+                (
+                    observe.before(player_input_system),
+                    write_tick
+                        .after(apply_score_events)
+                        .after(collision_system)
+                        .after(boundary_collision_system),
+                )
+                    .run_if(in_state(PauseState::Running).and(resource_exists::<DatasetExport>)),
+            );
+    }
+}
+
+fn open_run_file(export: Res<DatasetExport>, rng: Res<RunRng>, mut writer: ResMut<DatasetWriter>) {
+    writer.runs += 1;
+    writer.tick = 0;
+    writer.last_score = 0;
+    writer.done = false;
+    let path = export
+        .dir
+        .join(format!("run-{:016x}-{}.csv.gz", rng.seed(), writer.runs));
+
+    let opened = fs::create_dir_all(&export.dir)
+        .and_then(|()| File::create(&path))
+        .and_then(|file| {
+            let mut encoder = GzEncoder::new(BufWriter::new(file), Compression::default());
+            writeln!(encoder, "{SCHEMA_HEADER}")?;
+            Ok(encoder)
+        });
+    writer.file = match opened {
+        Ok(encoder) => Some(encoder),
+        Err(err) => {
+            warn!("Nie udało się utworzyć {}: {err}", path.display());
+            None
+        }
+    };
+}
+
+fn close_run_file(mut writer: ResMut<DatasetWriter>) {
+    let Some(encoder) = writer.file.take() else {
+        return;
+    };
+    if let Err(err) = encoder.finish().and_then(|mut file| file.flush()) {
+        warn!("Nie udało się zapisać zbioru danych: {err}");
+    }
+}
+
+impl Observation {
+    // Obserwacja z pozycji ptaka i stref punktowych (środków przerw).
+    pub fn new<'a>(
+        bird: (&Transform, &Velocity),
+        zones: impl Iterator<Item = (&'a Transform, &'a Collider)>,
+    ) -> Self {
+        let (transform, velocity) = bird;
+        let bird_x = transform.translation.x;
+
+        let mut ahead: Vec<_> = zones
+            .map(|(zone_transform, collider)| {
+                let center = collider.center(zone_transform);
+                (center.x - bird_x, center.y, collider.half_size.y * 2.0)
+            })
+            .filter(|&(dx, _, _)| dx >= 0.0)
+            .collect();
+        ahead.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let missing = (WORLD_SIZE.x, 0.0, 0.0);
+
+        Self {
+            bird_y: transform.translation.y,
+            bird_dy: velocity.dy,
+            pipes: [
+                ahead.first().copied().unwrap_or(missing),
+                ahead.get(1).copied().unwrap_or(missing),
+            ],
+        }
+    }
+
+    // Obserwacja bieżącego stanu świata (poza systemami, np. w `ffi`).
+    // Pozycje bierzemy z ostatniego kroku symulacji, a nie narysowane.
+    pub fn of_world(world: &mut World) -> Option<Self> {
+        let simulated = |transform: &Transform, interpolated: &Interpolated| {
+            Transform::from_translation(interpolated.simulated(transform))
+        };
+        // Kopie kolizji – zapytanie o ptaka niżej potrzebuje `&mut World`.
+        let zones: Vec<_> = world
+            .query_filtered::<(&Transform, &Interpolated, &Collider), With<ScoreZone>>()
+            .iter(world)
+            .map(|(transform, interpolated, collider)| {
+                let collider = Collider {
+                    half_size: collider.half_size,
+                    offset: collider.offset,
+                };
+                (simulated(transform, interpolated), collider)
+            })
+            .collect();
+        let (transform, interpolated, velocity) = world
+            .query_filtered::<(&Transform, &Interpolated, &Velocity), With<Player>>()
+            .get_single(world)
+            .ok()?;
+        let bird = simulated(transform, interpolated);
+        Some(Self::new(
+            (&bird, velocity),
+            zones
+                .iter()
+                .map(|(transform, collider)| (transform, collider)),
+        ))
+    }
+}
+
+// Stan świata przed krokiem.
+fn observe(
+    player: Query<(&Transform, &Velocity), With<Player>>,
+    zones: Query<(&Transform, &Collider), With<ScoreZone>>,
+    mut writer: ResMut<DatasetWriter>,
+) {
+    let Ok(bird) = player.get_single() else {
+        return;
+    };
+    writer.observation = Observation::new(bird, zones.iter());
+}
+
+fn write_tick(
+    mut flapped: EventReader<Flapped>,
+    score: Res<Score>,
+    next_state: Res<NextState<AppState>>,
+    mut writer: ResMut<DatasetWriter>,
+) {
+    let action = flapped.read().count() > 0;
+    if writer.done {
+        return;
+    }
+    let done = matches!(*next_state, NextState::Pending(AppState::GameOver));
+    let reward = if done {
+        -1
+    } else {
+        score.0 - writer.last_score
+    };
+    let row = writer.observation.row(writer.tick, action, reward, done);
+    writer.last_score = score.0;
+    writer.tick += 1;
+    writer.done = done;
+
+    let Some(file) = writer.file.as_mut() else {
+        return;
+    };
+    if let Err(err) = writeln!(file, "{row}") {
+        warn!("Nie udało się zapisać zbioru danych: {err}");
+        writer.file = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_follow_the_schema() {
+        let observation = Observation {
+            bird_y: 12.5,
+            bird_dy: -40.0,
+            pipes: [(80.0, -20.0, 100.0), (WORLD_SIZE.x, 0.0, 0.0)],
+        };
+        let row = observation.row(3, true, 1, false);
+
+        assert_eq!(row.split(',').count(), SCHEMA_HEADER.split(',').count());
+        assert!(row.starts_with("3,12.50,-40.00,80.00,-20.00,100.00,"));
+        assert!(row.ends_with(",1,1,0"));
+    }
+}
@@ -0,0 +1,190 @@
+// Narzędzia deweloperskie, dostępne tylko w buildzie debug.
+//
+// F8 włącza tryb krok po kroku: symulacja stoi, a każda kropka (`.`) wykonuje
+// dokładnie jeden krok `FixedUpdate`. W tym trybie rysujemy prostokąty
+// kolizji i wektory prędkości – do badania przypadków brzegowych kolizji.
+//
+// Nawiasy kwadratowe zmieniają tempo symulacji (`GameSpeed`) od 0.1× do 5×,
+// np. do odtwarzania błędów zależnych od czasu i sprawdzania tempa poziomów.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeSystem;
+
+use crate::console::{ConsoleAppExt, ConsoleArgs, ConsoleReply};
+use crate::interpolation::Interpolated;
+use crate::physics::{Collider, Velocity};
+use crate::toast::Toast;
+
+const STEP_TOGGLE_KEY: KeyCode = KeyCode::F8;
+const STEP_KEY: KeyCode = KeyCode::Period;
+const SLOWER_KEY: KeyCode = KeyCode::BracketLeft;
+const FASTER_KEY: KeyCode = KeyCode::BracketRight;
+
+// Kolejne tempa symulacji pod nawiasami.
+const GAME_SPEEDS: [f32; 9] = [0.1, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0, 5.0];
+
+// Długość wektora prędkości na ekranie: tyle sekund ruchu.
+const VELOCITY_ARROW_SECS: f32 = 0.25;
+
+const COLLIDER_COLOR: Color = Color::srgb(1.0, 0.2, 0.2);
+const VELOCITY_COLOR: Color = Color::srgb(0.2, 1.0, 0.4);
+
+// Mnożnik tempa symulacji (czasu wirtualnego).
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct GameSpeed(pub f32);
+
+impl Default for GameSpeed {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl GameSpeed {
+    // Następne tempo z `GAME_SPEEDS` w górę albo w dół (bez wychodzenia poza zakres).
+    fn stepped(self, faster: bool) -> Self {
+        let next = if faster {
+            GAME_SPEEDS.iter().find(|&&speed| speed > self.0)
+        } else {
+            GAME_SPEEDS.iter().rev().find(|&&speed| speed < self.0)
+        };
+        Self(next.copied().unwrap_or(self.0))
+    }
+}
+
+#[derive(Resource, Default)]
+struct FrameStepping {
+    enabled: bool,
+    // Krok zamówiony klawiszem, wykonywany w następnej klatce.
+    requested: bool,
+}
+
+pub struct DebugPlugin;
+
+impl Plugin for DebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FrameStepping>()
+            .init_resource::<GameSpeed>()
+            .add_systems(First, step_virtual_time.after(TimeSystem))
+            .add_systems(
+                Update,
+                (frame_step_keys, game_speed_keys, apply_game_speed).chain(),
+            )
+            .add_systems(
+                Update,
+                draw_debug_gizmos.run_if(|stepping: Res<FrameStepping>| stepping.enabled),
+            )
+            .register_console_command("speed", "speed <0.1-5>", speed_command);
+    }
+}
+
+fn frame_step_keys(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut stepping: ResMut<FrameStepping>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    if keyboard_input.just_pressed(STEP_TOGGLE_KEY) {
+        stepping.enabled = !stepping.enabled;
+        stepping.requested = false;
+        if stepping.enabled {
+            virtual_time.pause();
+        } else {
+            virtual_time.unpause();
+        }
+        info!(
+            "Tryb krok po kroku: {}",
+            if stepping.enabled { "wł." } else { "wył." }
+        );
+    }
+    if stepping.enabled && keyboard_input.just_pressed(STEP_KEY) {
+        stepping.requested = true;
+    }
+}
+
+fn game_speed_keys(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut speed: ResMut<GameSpeed>,
+    mut toasts: EventWriter<Toast>,
+) {
+    let faster = keyboard_input.just_pressed(FASTER_KEY);
+    if !faster && !keyboard_input.just_pressed(SLOWER_KEY) {
+        return;
+    }
+    let next = speed.stepped(faster);
+    if next != *speed {
+        *speed = next;
+        toasts.send(Toast(format!("Game speed {}x", next.0)));
+    }
+}
+
+fn apply_game_speed(speed: Res<GameSpeed>, mut virtual_time: ResMut<Time<Virtual>>) {
+    if speed.is_changed() {
+        virtual_time.set_relative_speed(speed.0);
+    }
+}
+
+fn speed_command(In(args): In<ConsoleArgs>, mut speed: ResMut<GameSpeed>) -> ConsoleReply {
+    let value: f32 = args
+        .first()
+        .and_then(|arg| arg.parse().ok())
+        .ok_or("usage: speed <0.1-5>")?;
+    speed.0 = value.clamp(GAME_SPEEDS[0], GAME_SPEEDS[GAME_SPEEDS.len() - 1]);
+    Ok(format!("game speed = {}x", speed.0))
+}
+
+// Czas wirtualny stoi, więc na zamówiony krok dokładamy go ręcznie. Dokładamy
+// tyle, żeby po kroku została prawie cała następna porcja – interpolacja
+// rysuje wtedy stan z końca kroku, a nie z jego początku.
+// Tempo gry nie wpływa na krok – zawsze dokładnie jeden.
+fn step_virtual_time(
+    mut stepping: ResMut<FrameStepping>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    fixed_time: Res<Time<Fixed>>,
+) {
+    if !stepping.enabled || !std::mem::take(&mut stepping.requested) {
+        return;
+    }
+    let timestep = fixed_time.timestep();
+    let delta = timestep * 2 - fixed_time.overstep() - Duration::from_micros(1);
+    virtual_time.advance_by(delta);
+}
+
+// Prostokąty kolizji w miejscu, w którym liczy je symulacja.
+fn draw_debug_gizmos(
+    mut gizmos: Gizmos,
+    colliders: Query<(&Transform, &Collider, Option<&Interpolated>)>,
+    movers: Query<(&Transform, &Velocity, Option<&Interpolated>)>,
+) {
+    let simulated = |transform: &Transform, interpolated: Option<&Interpolated>| {
+        Transform::from_translation(interpolated.map_or(transform.translation, |interpolated| {
+            interpolated.simulated(transform)
+        }))
+    };
+
+    for (transform, collider, interpolated) in colliders.iter() {
+        let center = collider.center(&simulated(transform, interpolated));
+        gizmos.rect_2d(center, collider.half_size * 2.0, COLLIDER_COLOR);
+    }
+    for (transform, velocity, interpolated) in movers.iter() {
+        let start = simulated(transform, interpolated).translation.truncate();
+        let end = start + Vec2::new(velocity.dx, velocity.dy) * VELOCITY_ARROW_SECS;
+        if start != end {
+            gizmos.arrow_2d(start, end, VELOCITY_COLOR);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn game_speed_steps_stay_in_range() {
+        assert_eq!(GameSpeed::default().stepped(true), GameSpeed(1.5));
+        assert_eq!(GameSpeed(0.1).stepped(false), GameSpeed(0.1));
+        assert_eq!(GameSpeed(5.0).stepped(true), GameSpeed(5.0));
+        // Tempo spoza listy wraca na najbliższe sąsiednie.
+        assert_eq!(GameSpeed(1.2).stepped(false), GameSpeed(1.0));
+    }
+}
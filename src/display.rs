@@ -0,0 +1,164 @@
+// Wybór monitora dla pełnego ekranu oraz wielkości zależne od rozdzielczości.
+
+use bevy::prelude::*;
+use bevy::window::{Monitor, MonitorSelection, PrimaryWindow, WindowMode};
+
+use crate::buttons::button_text;
+use crate::mini::MiniMode;
+use crate::settings::Settings;
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+// Świat gry ma zawsze co najmniej tyle jednostek w poziomie i pionie.
+pub const WORLD_SIZE: Vec2 = Vec2::new(800.0, 600.0);
+
+// Widoczny obszar świata; na szerszych ekranach kamera pokazuje więcej w poziomie.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct PlayArea {
+    pub size: Vec2,
+}
+
+impl Default for PlayArea {
+    fn default() -> Self {
+        Self { size: WORLD_SIZE }
+    }
+}
+
+impl PlayArea {
+    pub fn half_width(&self) -> f32 {
+        self.size.x / 2.0
+    }
+
+    // Rury pojawiają się tuż za prawą krawędzią ekranu.
+    pub fn pipe_spawn_x(&self) -> f32 {
+        self.half_width() + 100.0
+    }
+}
+
+#[derive(Component)]
+pub struct DisplayButton;
+
+pub struct DisplayPlugin;
+
+impl Plugin for DisplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlayArea>().add_systems(
+            Update,
+            (
+                display_button_system.run_if(in_state(AppState::Menu)),
+                apply_display_mode,
+                update_play_area,
+            ),
+        );
+    }
+}
+
+pub fn display_button_label(settings: &Settings) -> String {
+    if !settings.window.fullscreen {
+        return "Display: Window".to_string();
+    }
+
+    match &settings.window.fullscreen_monitor {
+        Some(name) => format!("Display: {name}"),
+        None => "Display: Fullscreen".to_string(),
+    }
+}
+
+// Kolejno: okno → pełny ekran na każdym z monitorów → okno.
+fn display_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, Option<&Children>),
+        (Changed<Interaction>, With<DisplayButton>),
+    >,
+    mut text_query: Query<&mut Text>,
+    monitors: Query<&Monitor>,
+    mut settings: ResMut<Settings>,
+) {
+    for (interaction, mut bg_color, children) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+
+                let names: Vec<Option<String>> = monitors
+                    .iter()
+                    .map(|monitor| monitor.name.clone())
+                    .collect();
+                let current = names
+                    .iter()
+                    .position(|name| *name == settings.window.fullscreen_monitor);
+
+                let next = match (settings.window.fullscreen, current) {
+                    (false, _) => names.first(),
+                    (true, Some(index)) => names.get(index + 1),
+                    (true, None) => None,
+                };
+                match next {
+                    Some(name) => {
+                        settings.window.fullscreen = true;
+                        settings.window.fullscreen_monitor = name.clone();
+                    }
+                    None => settings.window.fullscreen = false,
+                }
+
+                if let Some(mut text) = button_text(children, &mut text_query) {
+                    text.0 = display_button_label(&settings);
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+// Przenosi okno na wybrany monitor, gdy zmienią się ustawienia albo lista monitorów.
+fn apply_display_mode(
+    settings: Res<Settings>,
+    monitors: Query<(Entity, &Monitor)>,
+    added_monitors: Query<(), Added<Monitor>>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+    mini: Option<Res<MiniMode>>,
+) {
+    // Okno trybu mini ma zawsze stały rozmiar.
+    if mini.is_some() || (!settings.is_changed() && added_monitors.is_empty()) {
+        return;
+    }
+    let Ok(mut window) = window_query.get_single_mut() else {
+        return;
+    };
+
+    let mode = if settings.window.fullscreen {
+        let selection = monitors
+            .iter()
+            .find(|(_, monitor)| monitor.name == settings.window.fullscreen_monitor)
+            .map(|(entity, _)| MonitorSelection::Entity(entity))
+            .unwrap_or(MonitorSelection::Current);
+        WindowMode::BorderlessFullscreen(selection)
+    } else {
+        WindowMode::Windowed
+    };
+
+    if window.mode != mode {
+        window.mode = mode;
+    }
+}
+
+// Po zmianie rozdzielczości przeliczamy widoczny obszar świata.
+fn update_play_area(
+    projection_query: Query<
+        &OrthographicProjection,
+        (With<Camera2d>, Changed<OrthographicProjection>),
+    >,
+    mut play_area: ResMut<PlayArea>,
+) {
+    let Ok(projection) = projection_query.get_single() else {
+        return;
+    };
+
+    let size = projection.area.size().max(WORLD_SIZE);
+    if play_area.size != size {
+        play_area.size = size;
+    }
+}
@@ -0,0 +1,259 @@
+// Efekty obrazu, ustawienie "Visual effects":
+//   Low  – delikatna winieta,
+//   High – do tego bloom na jasnych elementach i błysk aberracji
+//          chromatycznej przy śmierci.
+// Tryb ograniczonego ruchu wyłącza wszystkie efekty, niezależnie od ustawienia.
+
+use bevy::asset::RenderAssetUsages;
+use bevy::core_pipeline::bloom::{Bloom, BloomPrefilter};
+use bevy::core_pipeline::post_process::ChromaticAberration;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use serde::{Deserialize, Serialize};
+
+use crate::buttons::button_text;
+use crate::settings::Settings;
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+// Bloom tylko dla prawie białych pikseli (chmury, napisy, złoty ptak).
+const BLOOM: Bloom = Bloom {
+    intensity: 0.12,
+    prefilter: BloomPrefilter {
+        threshold: 0.7,
+        threshold_softness: 0.4,
+    },
+    ..Bloom::NATURAL
+};
+
+const VIGNETTE_SIZE: u32 = 128;
+// Krycie winiety w samych rogach ekranu.
+const VIGNETTE_STRENGTH: f32 = 0.45;
+
+// Rozszczepienie kolorów tuż po uderzeniu (ułamek szerokości okna) i czas wygasania.
+const HIT_FLASH_INTENSITY: f32 = 0.04;
+const HIT_FLASH_SECS: f32 = 0.4;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VisualEffects {
+    #[default]
+    Off,
+    Low,
+    High,
+}
+
+impl VisualEffects {
+    pub fn label(self) -> &'static str {
+        match self {
+            VisualEffects::Off => "Off",
+            VisualEffects::Low => "Low",
+            VisualEffects::High => "High",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            VisualEffects::Off => VisualEffects::Low,
+            VisualEffects::Low => VisualEffects::High,
+            VisualEffects::High => VisualEffects::Off,
+        }
+    }
+
+    // Poziom faktycznie używany przy danych ustawieniach.
+    pub fn effective(settings: &Settings) -> Self {
+        if settings.accessibility.reduced_motion {
+            VisualEffects::Off
+        } else {
+            settings.visual_effects
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct VisualEffectsButton;
+
+#[derive(Component)]
+struct Vignette;
+
+// Błysk przy śmierci na kamerze; po wygaśnięciu zdejmujemy aberrację.
+#[derive(Component)]
+struct HitFlash {
+    remaining: f32,
+}
+
+pub fn visual_effects_button_label(settings: &Settings) -> String {
+    format!("Effects: {}", settings.visual_effects.label())
+}
+
+pub struct EffectsPlugin;
+
+impl Plugin for EffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                visual_effects_button_system.run_if(in_state(AppState::Menu)),
+                apply_visual_effects,
+                fade_hit_flash,
+            ),
+        )
+        .add_systems(OnEnter(AppState::GameOver), start_hit_flash);
+    }
+}
+
+fn visual_effects_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, Option<&Children>),
+        (Changed<Interaction>, With<VisualEffectsButton>),
+    >,
+    mut text_query: Query<&mut Text>,
+    mut settings: ResMut<Settings>,
+) {
+    for (interaction, mut bg_color, children) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                settings.visual_effects = settings.visual_effects.next();
+                if let Some(mut text) = button_text(children, &mut text_query) {
+                    text.0 = visual_effects_button_label(&settings);
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+// Bloom wymaga kamery HDR, więc przełączamy ją razem z efektem.
+fn apply_visual_effects(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    mut cameras: Query<(Entity, &mut Camera), With<Camera2d>>,
+    vignettes: Query<Entity, With<Vignette>>,
+    mut images: ResMut<Assets<Image>>,
+    mut applied: Local<Option<VisualEffects>>,
+) {
+    let Ok((camera_entity, mut camera)) = cameras.get_single_mut() else {
+        return;
+    };
+    let level = VisualEffects::effective(&settings);
+    if *applied == Some(level) {
+        return;
+    }
+    *applied = Some(level);
+
+    let bloom = level == VisualEffects::High;
+    camera.hdr = bloom;
+    if bloom {
+        commands.entity(camera_entity).insert(BLOOM);
+    } else {
+        commands
+            .entity(camera_entity)
+            .remove::<(Bloom, ChromaticAberration, HitFlash)>();
+    }
+
+    let vignette = level != VisualEffects::Off;
+    if vignette && vignettes.is_empty() {
+        commands.spawn((
+            ImageNode::new(images.add(vignette_image())),
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..Default::default()
+            },
+            // Pod resztą interfejsu, ale nad światem gry.
+            GlobalZIndex(-1),
+            Vignette,
+        ));
+    } else if !vignette {
+        for entity in vignettes.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+// Przezroczysty środek i przyciemnione brzegi, rozciągane na cały ekran.
+fn vignette_image() -> Image {
+    let half = VIGNETTE_SIZE as f32 / 2.0;
+    let mut data = Vec::with_capacity((VIGNETTE_SIZE * VIGNETTE_SIZE * 4) as usize);
+    for y in 0..VIGNETTE_SIZE {
+        for x in 0..VIGNETTE_SIZE {
+            let offset = (Vec2::new(x as f32, y as f32) + 0.5 - half) / half;
+            // 1.0 na środku krawędzi, ~1.41 w rogach.
+            let distance = offset.length() / std::f32::consts::SQRT_2;
+            let t = ((distance - 0.45) / 0.55).clamp(0.0, 1.0);
+            let alpha = t * t * (3.0 - 2.0 * t) * VIGNETTE_STRENGTH;
+            data.extend_from_slice(&[0, 0, 0, (alpha * 255.0) as u8]);
+        }
+    }
+    Image::new(
+        Extent3d {
+            width: VIGNETTE_SIZE,
+            height: VIGNETTE_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+fn start_hit_flash(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    cameras: Query<Entity, With<Camera2d>>,
+) {
+    if VisualEffects::effective(&settings) != VisualEffects::High {
+        return;
+    }
+    for camera in cameras.iter() {
+        commands.entity(camera).insert((
+            ChromaticAberration {
+                intensity: HIT_FLASH_INTENSITY,
+                ..Default::default()
+            },
+            HitFlash {
+                remaining: HIT_FLASH_SECS,
+            },
+        ));
+    }
+}
+
+fn fade_hit_flash(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut cameras: Query<(Entity, &mut HitFlash, &mut ChromaticAberration)>,
+) {
+    for (entity, mut flash, mut aberration) in cameras.iter_mut() {
+        flash.remaining -= time.delta_secs();
+        if flash.remaining <= 0.0 {
+            commands
+                .entity(entity)
+                .remove::<(ChromaticAberration, HitFlash)>();
+            continue;
+        }
+        aberration.intensity = HIT_FLASH_INTENSITY * flash.remaining / HIT_FLASH_SECS;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reduced_motion_turns_effects_off() {
+        let mut settings = Settings {
+            visual_effects: VisualEffects::High,
+            ..Default::default()
+        };
+        assert_eq!(VisualEffects::effective(&settings), VisualEffects::High);
+
+        settings.accessibility.reduced_motion = true;
+        assert_eq!(VisualEffects::effective(&settings), VisualEffects::Off);
+    }
+}
@@ -0,0 +1,235 @@
+// Szybkie emotki w wyścigu sieciowym (śmiech, płacz, GG) pokazywane jako
+// dymki nad ptakami. Emotka to mała wiadomość sieciowa; limit chroni przed
+// zasypaniem przeciwników dymkami – zarówno przy wysyłaniu, jak i przy odbiorze.
+
+use std::collections::{HashMap, VecDeque};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::AppState;
+use crate::lobby::PlayerId;
+use crate::player::Player;
+
+// Najwyżej tyle emotek w oknie `EMOTE_WINDOW_SECS`...
+const EMOTE_BURST: usize = 3;
+const EMOTE_WINDOW_SECS: f64 = 5.0;
+// ...i nie częściej niż co tyle sekund.
+const EMOTE_MIN_GAP_SECS: f64 = 0.75;
+
+const BUBBLE_SECONDS: f32 = 2.0;
+const BUBBLE_OFFSET: Vec3 = Vec3::new(0.0, 32.0, 5.0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Emote {
+    Laugh,
+    Cry,
+    Gg,
+}
+
+impl Emote {
+    pub fn label(self) -> &'static str {
+        match self {
+            Emote::Laugh => "XD",
+            Emote::Cry => ":'(",
+            Emote::Gg => "GG",
+        }
+    }
+
+    // Klawisze emotek w trakcie wyścigu.
+    fn from_key(key: KeyCode) -> Option<Self> {
+        match key {
+            KeyCode::Digit1 => Some(Emote::Laugh),
+            KeyCode::Digit2 => Some(Emote::Cry),
+            KeyCode::Digit3 => Some(Emote::Gg),
+            _ => None,
+        }
+    }
+}
+
+// Wiadomość sieciowa z emotką.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmoteMessage {
+    pub player: PlayerId,
+    pub emote: Emote,
+}
+
+// Nasza emotka do wysłania – odbiera ją transport.
+#[derive(Event)]
+pub struct OutgoingEmote(pub Emote);
+
+// Emotka przeciwnika – wysyła ją transport.
+#[derive(Event)]
+pub struct IncomingEmote(pub EmoteMessage);
+
+// Ptak przeciwnika w wyścigu.
+#[derive(Component)]
+pub struct Racer(pub PlayerId);
+
+#[derive(Debug, Default)]
+pub struct EmoteLimiter {
+    sent: VecDeque<f64>,
+}
+
+impl EmoteLimiter {
+    // Zwraca `true` i zapamiętuje emotkę, jeśli mieści się w limicie.
+    pub fn allow(&mut self, now: f64) -> bool {
+        while self
+            .sent
+            .front()
+            .is_some_and(|&at| now - at >= EMOTE_WINDOW_SECS)
+        {
+            self.sent.pop_front();
+        }
+        let too_soon = self
+            .sent
+            .back()
+            .is_some_and(|&at| now - at < EMOTE_MIN_GAP_SECS);
+        if too_soon || self.sent.len() >= EMOTE_BURST {
+            return false;
+        }
+        self.sent.push_back(now);
+        true
+    }
+}
+
+#[derive(Resource, Default)]
+struct EmoteLimits {
+    local: EmoteLimiter,
+    remote: HashMap<PlayerId, EmoteLimiter>,
+}
+
+#[derive(Component)]
+struct EmoteBubble {
+    remaining: f32,
+}
+
+pub struct EmotePlugin;
+
+impl Plugin for EmotePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OutgoingEmote>()
+            .add_event::<IncomingEmote>()
+            .init_resource::<EmoteLimits>()
+            .add_systems(
+                Update,
+                (send_emotes, show_incoming_emotes, expire_bubbles)
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
+
+fn send_emotes(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time<Real>>,
+    mut limits: ResMut<EmoteLimits>,
+    mut outgoing: EventWriter<OutgoingEmote>,
+    player: Query<Entity, With<Player>>,
+    bubbles: Query<(Entity, &Parent), With<EmoteBubble>>,
+) {
+    let Some(emote) = keyboard_input
+        .get_just_pressed()
+        .find_map(|&key| Emote::from_key(key))
+    else {
+        return;
+    };
+    if !limits.local.allow(time.elapsed_secs_f64()) {
+        return;
+    }
+    outgoing.send(OutgoingEmote(emote));
+    // Własna emotka też pojawia się nad naszym ptakiem.
+    for bird in player.iter() {
+        spawn_bubble(&mut commands, bird, emote, &bubbles);
+    }
+}
+
+fn show_incoming_emotes(
+    mut commands: Commands,
+    mut incoming: EventReader<IncomingEmote>,
+    time: Res<Time<Real>>,
+    mut limits: ResMut<EmoteLimits>,
+    racers: Query<(Entity, &Racer)>,
+    bubbles: Query<(Entity, &Parent), With<EmoteBubble>>,
+) {
+    for IncomingEmote(message) in incoming.read() {
+        // Zmodyfikowany klient mógłby wysyłać emotki bez limitu.
+        let limiter = limits.remote.entry(message.player).or_default();
+        if !limiter.allow(time.elapsed_secs_f64()) {
+            continue;
+        }
+        for (bird, racer) in racers.iter() {
+            if racer.0 == message.player {
+                spawn_bubble(&mut commands, bird, message.emote, &bubbles);
+            }
+        }
+    }
+}
+
+// Nowa emotka zastępuje poprzedni dymek nad tym samym ptakiem.
+fn spawn_bubble(
+    commands: &mut Commands,
+    bird: Entity,
+    emote: Emote,
+    bubbles: &Query<(Entity, &Parent), With<EmoteBubble>>,
+) {
+    for (bubble, parent) in bubbles.iter() {
+        if parent.get() == bird {
+            commands.entity(bubble).despawn_recursive();
+        }
+    }
+    commands.entity(bird).with_children(|parent| {
+        parent
+            .spawn((
+                Sprite {
+                    color: Color::srgba(1.0, 1.0, 1.0, 0.9),
+                    custom_size: Some(Vec2::new(44.0, 24.0)),
+                    ..Default::default()
+                },
+                Transform::from_translation(BUBBLE_OFFSET),
+                EmoteBubble {
+                    remaining: BUBBLE_SECONDS,
+                },
+            ))
+            .with_child((
+                Text2d::new(emote.label()),
+                TextColor(Color::BLACK),
+                TextFont {
+                    font_size: 16.0,
+                    ..Default::default()
+                },
+                Transform::from_xyz(0.0, 0.0, 1.0),
+            ));
+    });
+}
+
+fn expire_bubbles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut bubbles: Query<(Entity, &mut EmoteBubble)>,
+) {
+    for (entity, mut bubble) in bubbles.iter_mut() {
+        bubble.remaining -= time.delta_secs();
+        if bubble.remaining <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emotes_are_rate_limited() {
+        let mut limiter = EmoteLimiter::default();
+
+        assert!(limiter.allow(0.0));
+        assert!(!limiter.allow(0.5));
+        assert!(limiter.allow(1.0));
+        assert!(limiter.allow(2.0));
+        // Trzy w oknie pięciu sekund – czwarta musi poczekać.
+        assert!(!limiter.allow(3.0));
+        assert!(limiter.allow(5.0));
+    }
+}
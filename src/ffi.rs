@@ -0,0 +1,234 @@
+// Bezgłowe środowisko gry dla agentów (np. z Pythona przez `ctypes`), w stylu
+// Gym: `create` → `reset` → `step`… → `destroy`. Jeden `step` to dokładnie
+// jeden krok symulacji; obserwacja ma te same pola co zbiór danych
+// z `dataset.rs`, nagroda to punkty zdobyte w kroku albo -1 za śmierć.
+//
+// Funkcje zwracają 0 przy powodzeniu i -1 przy pustym wskaźniku. Wskaźnik
+// środowiska nie może być używany z kilku wątków naraz.
+//
+//   env = lib.flappy_env_create(0)              # poziom: 0 Easy, 1 Normal, 2 Hard
+//   lib.flappy_env_reset(env, seed, byref(obs))
+//   lib.flappy_env_step(env, 1, byref(result))  # 1 = machnięcie
+//   lib.flappy_env_destroy(env)
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::dataset::Observation;
+use crate::input::FlapBuffer;
+use crate::replay_verify::{headless_app, wait_for_assets};
+use crate::rng::SeedChoice;
+use crate::score::Score;
+use crate::settings::{Difficulty, Settings};
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FlappyObservation {
+    pub bird_y: f32,
+    pub bird_dy: f32,
+    pub pipe1_dx: f32,
+    pub pipe1_gap_y: f32,
+    pub pipe1_gap: f32,
+    pub pipe2_dx: f32,
+    pub pipe2_gap_y: f32,
+    pub pipe2_gap: f32,
+}
+
+impl From<Observation> for FlappyObservation {
+    fn from(observation: Observation) -> Self {
+        let [
+            (pipe1_dx, pipe1_gap_y, pipe1_gap),
+            (pipe2_dx, pipe2_gap_y, pipe2_gap),
+        ] = observation.pipes;
+        Self {
+            bird_y: observation.bird_y,
+            bird_dy: observation.bird_dy,
+            pipe1_dx,
+            pipe1_gap_y,
+            pipe1_gap,
+            pipe2_dx,
+            pipe2_gap_y,
+            pipe2_gap,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FlappyStep {
+    pub observation: FlappyObservation,
+    pub reward: f32,
+    // 1, gdy runda się skończyła – trzeba wywołać `flappy_env_reset`.
+    pub done: u8,
+    pub score: i32,
+}
+
+pub struct FlappyEnv {
+    app: App,
+    done: bool,
+}
+
+impl FlappyEnv {
+    fn new(difficulty: Difficulty) -> Self {
+        let settings = Settings {
+            difficulty,
+            ..Settings::default()
+        };
+        let mut app = headless_app(settings);
+        wait_for_assets(&mut app);
+        Self { app, done: true }
+    }
+
+    fn state(&self) -> AppState {
+        *self.app.world().resource::<State<AppState>>().get()
+    }
+
+    fn set_state(&mut self, state: AppState) {
+        self.app
+            .world_mut()
+            .resource_mut::<NextState<AppState>>()
+            .set(state);
+        self.app.update();
+    }
+
+    fn observation(&mut self) -> FlappyObservation {
+        Observation::of_world(self.app.world_mut())
+            .map(FlappyObservation::from)
+            .unwrap_or_default()
+    }
+
+    // Nowa runda; wejście w nią wykonuje już pierwszy (pusty) krok.
+    fn reset(&mut self, seed: u64) -> FlappyObservation {
+        if self.state() == AppState::Playing {
+            self.set_state(AppState::GameOver);
+        }
+        self.app.insert_resource(SeedChoice(Some(seed)));
+        self.set_state(AppState::Playing);
+        self.done = false;
+        self.observation()
+    }
+
+    fn step(&mut self, flap: bool) -> FlappyStep {
+        if self.done {
+            return FlappyStep {
+                observation: self.observation(),
+                done: 1,
+                score: self.app.world().resource::<Score>().0,
+                ..Default::default()
+            };
+        }
+
+        let before = self.app.world().resource::<Score>().0;
+        if flap {
+            let world = self.app.world_mut();
+            let now = world.resource::<Time<Real>>().elapsed_secs_f64();
+            world.resource_mut::<FlapBuffer>().press(now);
+        }
+        self.app.update();
+
+        let world = self.app.world();
+        let score = world.resource::<Score>().0;
+        self.done = matches!(
+            world.resource::<NextState<AppState>>(),
+            NextState::Pending(AppState::GameOver)
+        ) || *world.resource::<State<AppState>>().get() != AppState::Playing;
+        FlappyStep {
+            observation: self.observation(),
+            reward: if self.done {
+                -1.0
+            } else {
+                (score - before) as f32
+            },
+            done: u8::from(self.done),
+            score,
+        }
+    }
+}
+
+// Tworzy środowisko; poziom trudności spoza zakresu oznacza Normal.
+#[unsafe(no_mangle)]
+pub extern "C" fn flappy_env_create(difficulty: u32) -> *mut FlappyEnv {
+    let difficulty = Difficulty::ALL
+        .get(difficulty as usize)
+        .copied()
+        .unwrap_or_default();
+    Box::into_raw(Box::new(FlappyEnv::new(difficulty)))
+}
+
+/// # Safety
+/// `env` musi pochodzić z `flappy_env_create`, a `observation` wskazywać
+/// zapisywalną `FlappyObservation` albo być pusty.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn flappy_env_reset(
+    env: *mut FlappyEnv,
+    seed: u64,
+    observation: *mut FlappyObservation,
+) -> i32 {
+    let Some(env) = (unsafe { env.as_mut() }) else {
+        return -1;
+    };
+    let initial = env.reset(seed);
+    if let Some(observation) = unsafe { observation.as_mut() } {
+        *observation = initial;
+    }
+    0
+}
+
+/// # Safety
+/// `env` musi pochodzić z `flappy_env_create`, a `result` wskazywać
+/// zapisywalny `FlappyStep` albo być pusty.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn flappy_env_step(
+    env: *mut FlappyEnv,
+    action: u8,
+    result: *mut FlappyStep,
+) -> i32 {
+    let Some(env) = (unsafe { env.as_mut() }) else {
+        return -1;
+    };
+    let step = env.step(action != 0);
+    if let Some(result) = unsafe { result.as_mut() } {
+        *result = step;
+    }
+    0
+}
+
+/// # Safety
+/// `env` musi pochodzić z `flappy_env_create` i nie może być już zwolniony.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn flappy_env_destroy(env: *mut FlappyEnv) {
+    if !env.is_null() {
+        drop(unsafe { Box::from_raw(env) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn environment_runs_until_the_bird_falls() {
+        let env = flappy_env_create(1);
+        let mut observation = FlappyObservation::default();
+        let mut step = FlappyStep::default();
+
+        unsafe {
+            assert_eq!(flappy_env_reset(env, 42, &mut observation), 0);
+            assert_eq!(flappy_env_step(env, 1, &mut step), 0);
+            assert!(step.observation.bird_dy > 0.0);
+
+            let mut steps = 1;
+            while step.done == 0 && steps < 600 {
+                flappy_env_step(env, 0, &mut step);
+                steps += 1;
+            }
+            assert_eq!(step.done, 1);
+            assert_eq!(step.reward, -1.0);
+
+            flappy_env_reset(env, 42, &mut observation);
+            flappy_env_step(env, 0, &mut step);
+            assert_eq!(step.done, 0);
+            flappy_env_destroy(env);
+        }
+    }
+}
@@ -0,0 +1,214 @@
+// Ekran końca gry: wynik, rekord, podsumowanie rundy, przyciski "Restart"
+// i "Exit" oraz opcjonalny automatyczny restart.
+
+use bevy::prelude::*;
+
+use crate::bests::RunRecord;
+use crate::buttons::{ButtonAction, ButtonPressed};
+use crate::player::Player;
+use crate::run_stats::RunStats;
+use crate::score::Score;
+use crate::score_digits::ScoreDigits;
+use crate::settings::Settings;
+use crate::text_style::OutlinedText;
+use crate::{AppState, NORMAL_BUTTON};
+
+#[derive(Component)]
+#[require(StateScoped<AppState>(|| StateScoped(AppState::GameOver)))]
+pub struct GameOverUI;
+
+pub struct GameOverPlugin;
+
+impl Plugin for GameOverPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(AppState::GameOver),
+            (
+                on_enter_game_over.after(crate::bests::record_best),
+                start_auto_restart,
+            ),
+        )
+        .add_systems(OnExit(AppState::GameOver), stop_auto_restart)
+        .add_systems(
+            Update,
+            auto_restart_system
+                .run_if(in_state(AppState::GameOver).and(resource_exists::<AutoRestart>)),
+        )
+        .add_systems(
+            Update,
+            restart_on_press
+                .after(crate::buttons::button_interaction_system)
+                .run_if(in_state(AppState::GameOver)),
+        );
+    }
+}
+
+fn spawn_game_over_ui(
+    mut commands: Commands,
+    score: i32,
+    record: &RunRecord,
+    stats: Option<&RunStats>,
+) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(20.0),
+                ..Default::default()
+            },
+            // Overlay z lekką przezroczystością
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+            GameOverUI,
+        ))
+        .with_children(|parent| {
+            // Tekst "Game Over"
+            parent.spawn((
+                Text::new("Game Over"),
+                OutlinedText::new(Color::srgb(0.151, 0.1, 0.44)),
+                TextFont {
+                    font_size: 60.0,
+                    ..Default::default()
+                },
+            ));
+            // Wynik rundy tymi samymi cyframi co w trakcie gry
+            parent.spawn((Node::default(), ScoreDigits(score)));
+            // Rekord w kategorii rundy
+            parent.spawn((
+                Text::new(if record.new_record {
+                    format!("New record! Best: {}", record.best)
+                } else {
+                    format!("Best: {}", record.best)
+                }),
+                TextColor(Color::WHITE),
+                TextFont {
+                    font_size: 30.0,
+                    ..Default::default()
+                },
+            ));
+            // Podsumowanie rundy
+            if let Some(stats) = stats {
+                parent
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(4.0),
+                        ..Default::default()
+                    })
+                    .with_children(|breakdown| {
+                        for line in stats.breakdown() {
+                            breakdown.spawn((
+                                Text::new(line),
+                                TextColor(Color::WHITE),
+                                TextFont {
+                                    font_size: 22.0,
+                                    ..Default::default()
+                                },
+                            ));
+                        }
+                    });
+            }
+            // Przycisk "Restart"
+            parent
+                .spawn((
+                    Button,
+                    Interaction::default(),
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(80.0),
+                        border: UiRect::all(Val::Px(5.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(NORMAL_BUTTON),
+                    BorderColor(Color::BLACK),
+                    BorderRadius::MAX,
+                    ButtonAction::Restart,
+                ))
+                .with_child((
+                    Text::new("Restart"),
+                    TextColor(Color::WHITE),
+                    TextFont {
+                        font_size: 33.0,
+                        ..Default::default()
+                    },
+                ));
+            parent
+                .spawn((
+                    Button,
+                    Interaction::default(),
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(80.0),
+                        border: UiRect::all(Val::Px(5.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(NORMAL_BUTTON),
+                    BorderColor(Color::BLACK),
+                    BorderRadius::MAX,
+                    ButtonAction::Exit,
+                ))
+                .with_child((
+                    Text::new("Exit"),
+                    TextColor(Color::WHITE),
+                    TextFont {
+                        font_size: 33.0,
+                        ..Default::default()
+                    },
+                ));
+        });
+}
+
+pub fn on_enter_game_over(
+    commands: Commands,
+    score: Res<Score>,
+    record: Res<RunRecord>,
+    stats_query: Query<&RunStats, With<Player>>,
+) {
+    spawn_game_over_ui(commands, score.0, &record, stats_query.get_single().ok());
+}
+
+// Jak długo wynik zostaje na ekranie przed automatycznym restartem.
+const AUTO_RESTART_SECS: f32 = 2.0;
+
+#[derive(Resource)]
+struct AutoRestart(Timer);
+
+fn start_auto_restart(mut commands: Commands, settings: Res<Settings>) {
+    if settings.auto_restart {
+        commands.insert_resource(AutoRestart(Timer::from_seconds(
+            AUTO_RESTART_SECS,
+            TimerMode::Once,
+        )));
+    }
+}
+
+fn auto_restart_system(
+    time: Res<Time>,
+    mut auto_restart: ResMut<AutoRestart>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if auto_restart.0.tick(time.delta()).just_finished() {
+        next_state.set(AppState::Playing);
+    }
+}
+
+// Wyjście z ekranu końca gry przyciskiem też anuluje odliczanie.
+fn stop_auto_restart(mut commands: Commands) {
+    commands.remove_resource::<AutoRestart>();
+}
+
+fn restart_on_press(
+    mut pressed: EventReader<ButtonPressed>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if pressed.read().any(|event| event.0 == ButtonAction::Restart) {
+        next_state.set(AppState::Playing);
+    }
+}
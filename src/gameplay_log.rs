@@ -0,0 +1,201 @@
+// Ustrukturyzowane zdarzenia rozgrywki (`tracing`, cel `gameplay`):
+//   run_started – seed, difficulty, controls
+//   rng_draw    – tick, index, purpose, value (każde losowanie z `RunRng::audit`)
+//   pipe_scored – score, tick, bird_y
+//   player_hit  – cause (obstacle/boundary), score, tick, bird_y, bird_dy
+//   run_ended   – score, pipes_passed, near_misses, duration_secs
+// Flaga `--log-file plik` dopisuje wszystkie logi do pliku jako JSON (linia na
+// zdarzenie), do analizy po fakcie bez debuggera.
+
+use std::fs::OpenOptions;
+use std::sync::Mutex;
+
+use bevy::log::BoxedLayer;
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::adaptive::DeathCause;
+use crate::controls::RunControls;
+use crate::pause::PauseState;
+use crate::physics::{Collider, Velocity, boundary_collision_system, collision_system};
+use crate::player::Player;
+use crate::rng::RunRng;
+use crate::run_stats::RunStats;
+use crate::score::{PointScored, Score, apply_score_events};
+use crate::settings::Settings;
+
+const LOG_FILE_FLAG: &str = "--log-file";
+
+#[derive(Resource, Default)]
+struct RunLog {
+    tick: u32,
+    started_at: f32,
+    // Trafienie już zapisane (do zmiany stanu może minąć jeszcze krok).
+    hit: bool,
+    // Ile wpisów dziennika losowań już zalogowano.
+    logged_draws: usize,
+}
+
+pub struct GameplayLogPlugin;
+
+impl Plugin for GameplayLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunLog>()
+            .add_systems(
+                OnEnter(AppState::Playing),
+                log_run_started.after(crate::rng::reseed_run_rng),
+            )
+            .add_systems(OnEnter(AppState::GameOver), log_run_ended)
+            .add_systems(
+                FixedUpdate,
+                (
+                    log_pipe_scored.after(apply_score_events),
+                    log_rng_draws
+                        .after(crate::pipes::pipe_spawn_system)
+                        .after(crate::hammer::spawn_hammers),
+                    log_player_hit
+                        .after(collision_system)
+                        .after(boundary_collision_system),
+                )
+                    .run_if(in_state(PauseState::Running)),
+            );
+    }
+}
+
+// Dodatkowe warstwy dla `LogPlugin::custom_layer`: ostatnie zdarzenia do
+// raportu awarii i opcjonalnie plik z `--log-file`.
+pub fn log_layers(_app: &mut App) -> Option<BoxedLayer> {
+    let mut layers = vec![crate::crash::event_layer()];
+    layers.extend(log_file_layer());
+    Some(Box::new(layers))
+}
+
+// Logi w JSON do pliku z `--log-file`.
+fn log_file_layer() -> Option<BoxedLayer> {
+    let mut args = std::env::args().skip_while(|arg| arg != LOG_FILE_FLAG);
+    args.next()?;
+    let path = args.next()?;
+    let file = match OpenOptions::new().create(true).append(true).open(&path) {
+        Ok(file) => file,
+        Err(err) => {
+            // Logowanie jeszcze nie działa, więc komunikat idzie prosto na stderr.
+            eprintln!("Nie udało się otworzyć pliku logów {path}: {err}");
+            return None;
+        }
+    };
+    Some(Box::new(
+        tracing_subscriber::fmt::layer()
+            .json()
+            .with_ansi(false)
+            .with_writer(Mutex::new(file)),
+    ))
+}
+
+fn log_run_started(
+    time: Res<Time>,
+    rng: Res<RunRng>,
+    settings: Res<Settings>,
+    controls: Res<RunControls>,
+    mut log: ResMut<RunLog>,
+) {
+    *log = RunLog {
+        tick: 0,
+        started_at: time.elapsed_secs(),
+        hit: false,
+        logged_draws: 0,
+    };
+    info!(
+        target: "gameplay",
+        event = "run_started",
+        seed = rng.seed(),
+        difficulty = ?settings.difficulty,
+        controls = ?controls.0,
+        "run_started"
+    );
+}
+
+fn log_pipe_scored(
+    mut scored: EventReader<PointScored>,
+    score: Res<Score>,
+    player: Query<&Transform, With<Player>>,
+    mut log: ResMut<RunLog>,
+) {
+    log.tick += 1;
+    if scored.read().count() == 0 {
+        return;
+    }
+    let bird_y = player
+        .get_single()
+        .map_or(0.0, |transform| transform.translation.y);
+    info!(
+        target: "gameplay",
+        event = "pipe_scored",
+        score = score.0,
+        tick = log.tick,
+        bird_y,
+        "pipe_scored"
+    );
+}
+
+fn log_rng_draws(rng: Res<RunRng>, mut log: ResMut<RunLog>) {
+    let audit = rng.audit();
+    for (index, draw) in audit.iter().enumerate().skip(log.logged_draws) {
+        info!(
+            target: "gameplay",
+            event = "rng_draw",
+            tick = log.tick,
+            index,
+            purpose = draw.purpose.label(),
+            value = draw.value,
+            "rng_draw"
+        );
+    }
+    log.logged_draws = audit.len();
+}
+
+fn log_player_hit(
+    next_state: Res<NextState<AppState>>,
+    score: Res<Score>,
+    player: Query<(&Transform, &Collider, &Velocity), With<Player>>,
+    mut log: ResMut<RunLog>,
+) {
+    if log.hit || !matches!(*next_state, NextState::Pending(AppState::GameOver)) {
+        return;
+    }
+    log.hit = true;
+    let Ok((transform, collider, velocity)) = player.get_single() else {
+        return;
+    };
+    let bird_y = transform.translation.y;
+    let cause = DeathCause::of(transform, collider).label();
+    info!(
+        target: "gameplay",
+        event = "player_hit",
+        cause,
+        score = score.0,
+        tick = log.tick,
+        bird_y,
+        bird_dy = velocity.dy,
+        "player_hit"
+    );
+}
+
+fn log_run_ended(
+    time: Res<Time>,
+    score: Res<Score>,
+    stats: Query<&RunStats, With<Player>>,
+    log: Res<RunLog>,
+) {
+    let (pipes_passed, near_misses) = stats
+        .get_single()
+        .map_or((0, 0), |stats| (stats.pipes_passed, stats.near_misses));
+    info!(
+        target: "gameplay",
+        event = "run_ended",
+        score = score.0,
+        pipes_passed,
+        near_misses,
+        duration_secs = time.elapsed_secs() - log.started_at,
+        "run_ended"
+    );
+}
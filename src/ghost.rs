@@ -0,0 +1,220 @@
+// Wyścig z duchem: powtórka najlepszej rundy jest odgrywana na bezgłowym App,
+// a jej tor leci obok gracza jako półprzezroczysty ptak. Pod wynikiem widać,
+// ile punktów gracz ma przewagi albo straty.
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::assets::GameAssets;
+use crate::interpolation::Interpolated;
+use crate::pause::PauseState;
+use crate::player::Player;
+use crate::replay::Replay;
+use crate::replay_verify::{VerifyError, simulate};
+use crate::rng::RunRng;
+use crate::score::Score;
+use crate::settings::{Difficulty, Settings};
+use crate::text_style::OutlinedText;
+
+const GHOST_ALPHA: f32 = 0.4;
+
+// Położenie i wynik ducha po jednym kroku symulacji.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GhostFrame {
+    pub y: f32,
+    pub score: i32,
+}
+
+// Trwający wyścig; zostaje na kolejne rundy aż do powrotu do menu.
+#[derive(Resource)]
+pub struct GhostRace {
+    seed: u64,
+    track: Vec<GhostFrame>,
+    // Liczba kroków symulacji bieżącej rundy.
+    frame: usize,
+    // Poziom trudności gracza sprzed wyścigu, przywracany po powrocie do menu.
+    previous_difficulty: Option<Difficulty>,
+}
+
+impl GhostRace {
+    pub fn new(replay: &Replay) -> Result<Self, VerifyError> {
+        Ok(Self {
+            seed: replay.seed,
+            track: ghost_track(replay)?,
+            frame: 0,
+            previous_difficulty: None,
+        })
+    }
+
+    // Wyścig leci na poziomie ducha; po nim gracz wraca do swojego.
+    pub fn restoring_difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.previous_difficulty = Some(difficulty);
+        self
+    }
+
+    // Stan ducha w bieżącym kroku; po rozbiciu się zostaje ostatni.
+    fn current(&self) -> Option<GhostFrame> {
+        self.frame
+            .min(self.track.len())
+            .checked_sub(1)
+            .map(|frame| self.track[frame])
+    }
+
+    fn finished(&self) -> bool {
+        self.frame > self.track.len()
+    }
+}
+
+// Tor ducha: pozycja ptaka i wynik po każdym kroku powtórki.
+pub fn ghost_track(replay: &Replay) -> Result<Vec<GhostFrame>, VerifyError> {
+    let mut track = Vec::with_capacity(replay.frames as usize);
+    simulate(replay, |world| {
+        let y = world
+            .query_filtered::<(&Transform, &Interpolated), With<Player>>()
+            .iter(world)
+            .next()
+            .map(|(transform, interpolated)| interpolated.simulated(transform).y);
+        if let Some(y) = y {
+            track.push(GhostFrame {
+                y,
+                score: world.resource::<Score>().0,
+            });
+        }
+    })?;
+    Ok(track)
+}
+
+#[derive(Component)]
+struct GhostBird;
+
+// Półprzezroczysty ptak w zadanym odcieniu (duch, rywale).
+pub fn ghost_sprite(assets: &GameAssets, tint: Color) -> Sprite {
+    Sprite {
+        image: assets.bird.clone(),
+        color: tint.with_alpha(GHOST_ALPHA),
+        ..Default::default()
+    }
+}
+
+#[derive(Component)]
+struct GhostDeltaText;
+
+pub struct GhostPlugin;
+
+impl Plugin for GhostPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(AppState::Playing),
+            start_ghost
+                .after(crate::rng::reseed_run_rng)
+                .before(crate::replay::start_recording)
+                .run_if(resource_exists::<GhostRace>),
+        )
+        .add_systems(OnEnter(AppState::Menu), end_race)
+        .add_systems(
+            FixedUpdate,
+            advance_ghost.run_if(in_state(PauseState::Running).and(resource_exists::<GhostRace>)),
+        )
+        .add_systems(
+            Update,
+            (move_ghost, update_delta_text).run_if(resource_exists::<GhostRace>),
+        );
+    }
+}
+
+// Każda runda wyścigu leci na ziarnie ducha.
+fn start_ghost(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    mut race: ResMut<GhostRace>,
+    mut rng: ResMut<RunRng>,
+    old: Query<Entity, Or<(With<GhostBird>, With<GhostDeltaText>)>>,
+) {
+    for entity in old.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    race.frame = 0;
+    *rng = RunRng::new(race.seed);
+
+    commands.spawn((
+        ghost_sprite(&assets, Color::WHITE),
+        Transform::from_xyz(0.0, 0.0, 0.9),
+        GhostBird,
+    ));
+    commands.spawn((
+        Text2d::new(""),
+        OutlinedText::new(Color::WHITE),
+        Transform::from_xyz(0.0, 220.0, 10.0),
+        GhostDeltaText,
+    ));
+}
+
+fn end_race(
+    mut commands: Commands,
+    race: Option<Res<GhostRace>>,
+    mut settings: ResMut<Settings>,
+    query: Query<Entity, Or<(With<GhostBird>, With<GhostDeltaText>)>>,
+) {
+    if let Some(difficulty) = race.and_then(|race| race.previous_difficulty) {
+        if settings.difficulty != difficulty {
+            settings.difficulty = difficulty;
+        }
+    }
+    commands.remove_resource::<GhostRace>();
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn advance_ghost(mut race: ResMut<GhostRace>) {
+    race.frame += 1;
+}
+
+// Duch jest rysowany między dwoma krokami toru, tak jak interpolowany gracz.
+fn move_ghost(
+    race: Res<GhostRace>,
+    fixed: Res<Time<Fixed>>,
+    mut query: Query<(&mut Transform, &mut Visibility), With<GhostBird>>,
+) {
+    let Some(current) = race.current() else {
+        return;
+    };
+    let previous = race
+        .frame
+        .checked_sub(2)
+        .and_then(|frame| race.track.get(frame))
+        .map_or(current.y, |frame| frame.y);
+    let finished = race.finished();
+
+    for (mut transform, mut visibility) in query.iter_mut() {
+        transform.translation.y = previous.lerp(current.y, fixed.overstep_fraction());
+        // Po rozbiciu się ducha zostaje tylko licznik.
+        *visibility = if finished {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+    }
+}
+
+fn update_delta_text(
+    race: Res<GhostRace>,
+    score: Res<Score>,
+    mut query: Query<&mut Text2d, With<GhostDeltaText>>,
+) {
+    let ghost_score = race.current().map_or(0, |frame| frame.score);
+    let label = delta_label(score.0 - ghost_score);
+    for mut text in query.iter_mut() {
+        if text.0 != label {
+            text.0 = label.clone();
+        }
+    }
+}
+
+fn delta_label(delta: i32) -> String {
+    match delta {
+        0 => "Ghost: even".to_string(),
+        delta if delta > 0 => format!("Ghost: ahead +{delta}"),
+        delta => format!("Ghost: behind {delta}"),
+    }
+}
@@ -0,0 +1,231 @@
+// Premia za ryzyko: kolejne przerwy pokonane najwyżej `GLIDE_MAX_FLAPS`
+// machnięciami budują serię "glide streak", a co `STREAK_PER_LEVEL` przerw
+// z serii każdy punkt jest wart o jeden więcej (do `MAX_MULTIPLIER`).
+// Machanie bez opamiętania (więcej niż `SPAM_FLAPS` w jednej przerwie)
+// od razu zeruje serię. Stan serii pokazuje pasek w lewym górnym rogu.
+
+use bevy::prelude::*;
+
+use crate::pause::PauseState;
+use crate::player::{Flapped, Player, player_input_system};
+use crate::score::{PointScored, apply_score_events, score_system};
+use crate::text_style::OutlinedText;
+use crate::{AppState, RunStarted};
+
+// Przerwa pokonana najwyżej tyloma machnięciami wydłuża serię.
+const GLIDE_MAX_FLAPS: u32 = 2;
+// Tyle machnięć w jednej przerwie to już "spam" – seria przepada.
+const SPAM_FLAPS: u32 = 4;
+const STREAK_PER_LEVEL: u32 = 3;
+const MAX_MULTIPLIER: i32 = 4;
+const METER_WIDTH_PX: f32 = 140.0;
+const METER_COLOR: Color = Color::srgb(0.55, 0.8, 1.0);
+const METER_BONUS_COLOR: Color = Color::srgb(1.0, 0.8, 0.25);
+
+// Seria bieżącej rundy; siedzi na encji gracza i na `RunStarted` zaczyna od zera.
+#[derive(Component, Default, Debug, Clone, PartialEq, Eq)]
+pub struct GlideStreak {
+    // Ile przerw z rzędu ptak pokonał oszczędnie.
+    pub streak: u32,
+    // Machnięcia od ostatniego punktu.
+    flaps: u32,
+}
+
+impl GlideStreak {
+    pub fn multiplier(&self) -> i32 {
+        (1 + (self.streak / STREAK_PER_LEVEL) as i32).min(MAX_MULTIPLIER)
+    }
+
+    // Postęp do następnego poziomu mnożnika (0..=1); przy maksimum pasek jest pełny.
+    pub fn progress(&self) -> f32 {
+        if self.multiplier() >= MAX_MULTIPLIER {
+            return 1.0;
+        }
+        (self.streak % STREAK_PER_LEVEL) as f32 / STREAK_PER_LEVEL as f32
+    }
+
+    fn flap(&mut self) {
+        self.flaps += 1;
+        if self.flaps > SPAM_FLAPS {
+            self.streak = 0;
+        }
+    }
+
+    fn pass(&mut self) {
+        if self.flaps <= GLIDE_MAX_FLAPS {
+            self.streak += 1;
+        }
+        self.flaps = 0;
+    }
+}
+
+#[derive(Component)]
+#[require(StateScoped<AppState>(|| StateScoped(AppState::Playing)))]
+struct GlideMeter;
+
+#[derive(Component)]
+struct GlideMeterLabel;
+
+#[derive(Component)]
+struct GlideMeterFill;
+
+pub struct GlideStreakPlugin;
+
+impl Plugin for GlideStreakPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            track_glide_streak
+                .after(player_input_system)
+                .after(score_system)
+                .before(apply_score_events)
+                .run_if(in_state(PauseState::Running)),
+        )
+        .add_systems(
+            OnEnter(AppState::Playing),
+            (
+                spawn_glide_meter,
+                reset_glide_streak.after(crate::restart_game),
+            ),
+        )
+        .add_systems(
+            Update,
+            update_glide_meter.run_if(in_state(AppState::Playing)),
+        );
+    }
+}
+
+// Machnięcia liczymy przed punktami z tego samego kroku – należą jeszcze do
+// pokonywanej przerwy.
+fn track_glide_streak(
+    mut flapped: EventReader<Flapped>,
+    mut scored: EventReader<PointScored>,
+    mut query: Query<&mut GlideStreak, With<Player>>,
+) {
+    let flaps = flapped.read().count();
+    let passes = scored.read().count();
+    for mut glide in query.iter_mut() {
+        for _ in 0..flaps {
+            glide.flap();
+        }
+        for _ in 0..passes {
+            glide.pass();
+        }
+    }
+}
+
+fn reset_glide_streak(
+    mut commands: Commands,
+    mut run_started: EventReader<RunStarted>,
+    players: Query<Entity, With<Player>>,
+) {
+    if run_started.read().last().is_none() {
+        return;
+    }
+    for player in players.iter() {
+        commands.entity(player).insert(GlideStreak::default());
+    }
+}
+
+fn spawn_glide_meter(mut commands: Commands, meters: Query<(), With<GlideMeter>>) {
+    if !meters.is_empty() {
+        return;
+    }
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(20.0),
+                top: Val::Px(20.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                ..Default::default()
+            },
+            GlideMeter,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(meter_label(&GlideStreak::default())),
+                TextFont {
+                    font_size: 20.0,
+                    ..Default::default()
+                },
+                OutlinedText::new(Color::WHITE),
+                GlideMeterLabel,
+            ));
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(METER_WIDTH_PX),
+                        height: Val::Px(10.0),
+                        ..Default::default()
+                    },
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+                    BorderRadius::all(Val::Px(5.0)),
+                ))
+                .with_child((
+                    Node {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        ..Default::default()
+                    },
+                    BackgroundColor(METER_COLOR),
+                    BorderRadius::all(Val::Px(5.0)),
+                    GlideMeterFill,
+                ));
+        });
+}
+
+fn meter_label(glide: &GlideStreak) -> String {
+    format!("Glide streak x{}", glide.multiplier())
+}
+
+fn update_glide_meter(
+    player: Query<&GlideStreak, (With<Player>, Changed<GlideStreak>)>,
+    mut labels: Query<&mut Text, With<GlideMeterLabel>>,
+    mut fills: Query<(&mut Node, &mut BackgroundColor), With<GlideMeterFill>>,
+) {
+    let Ok(glide) = player.get_single() else {
+        return;
+    };
+    for mut text in labels.iter_mut() {
+        text.0 = meter_label(glide);
+    }
+    let color = if glide.multiplier() > 1 {
+        METER_BONUS_COLOR
+    } else {
+        METER_COLOR
+    };
+    for (mut node, mut bg_color) in fills.iter_mut() {
+        node.width = Val::Percent(glide.progress() * 100.0);
+        bg_color.0 = color;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thrifty_passes_raise_the_multiplier_and_spam_resets_it() {
+        let mut glide = GlideStreak::default();
+        for _ in 0..STREAK_PER_LEVEL {
+            glide.flap();
+            glide.pass();
+        }
+        assert_eq!(glide.multiplier(), 2);
+
+        // Za dużo machnięć na przelot: seria nie rośnie, ale jeszcze trwa.
+        for _ in 0..SPAM_FLAPS {
+            glide.flap();
+        }
+        glide.pass();
+        assert_eq!(glide.multiplier(), 2);
+
+        for _ in 0..=SPAM_FLAPS {
+            glide.flap();
+        }
+        assert_eq!(glide.multiplier(), 1);
+        assert_eq!(glide.progress(), 0.0);
+    }
+}
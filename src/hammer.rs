@@ -0,0 +1,263 @@
+// Młotek: rzadka znajdźka w przerwie między rurami. Ptak z młotkiem nie ginie
+// przy następnym zderzeniu – przeszkoda rozpada się na odłamki, a młotek
+// znika. Rozbicie dzieje się przed `collision_system`, więc zwykła kolizja
+// już tej przeszkody nie widzi.
+//
+// Znajdźki losujemy z osobnego generatora wyprowadzonego z ziarna rundy (jak
+// rywale AI): to samo ziarno daje te same młotki, a wzory rur się nie zmieniają.
+// Rzuty trafiają do dziennika losowań rundy (`RunRng::audit`).
+// Odłamki to zwykłe sprite'y z `Velocity` i `LifeTime` – bez kolizji, więc nie
+// wpływają na symulację.
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::core::{DrawPurpose, TaggedRng};
+use crate::display::WORLD_SIZE;
+use crate::hazards::Hazard;
+use crate::interpolation::Interpolated;
+use crate::pause::PauseState;
+use crate::physics::{Collider, Gravity, LifeTime, Sensor, Velocity};
+use crate::pipes::Pipe;
+use crate::player::Player;
+use crate::rng::RunRng;
+use crate::score::ScoreZone;
+use crate::toast::Toast;
+use crate::{AppState, RunEntity};
+
+// Szansa na młotek w przerwie każdej nowej przeszkody.
+const HAMMER_CHANCE: f64 = 0.04;
+// Odróżnia generator młotków od generatorów rywali (`seed ^ numer`).
+const HAMMER_SEED_SALT: u64 = 0x4a4d_4552;
+const PICKUP_SIZE: f32 = 22.0;
+const PICKUP_COLOR: Color = Color::srgb(0.95, 0.75, 0.2);
+const PICKUP_SPIN: f32 = 2.0;
+
+const DEBRIS_SECS: f32 = 1.0;
+const DEBRIS_SIZE: f32 = 24.0;
+// Najwięcej odłamków w pionie – rura sięga daleko poza ekran.
+const DEBRIS_MAX_ROWS: usize = 8;
+
+#[derive(Component)]
+#[require(RunEntity)]
+pub struct HammerPickup;
+
+// Ptak niesie młotek – następne zderzenie rozbija przeszkodę.
+#[derive(Component)]
+pub struct Hammer;
+
+#[derive(Component)]
+#[require(RunEntity)]
+pub struct Debris {
+    spin: f32,
+}
+
+impl Debris {
+    pub fn new(spin: f32) -> Self {
+        Self { spin }
+    }
+}
+
+#[derive(Resource)]
+pub struct HammerRng(RunRng);
+
+impl Default for HammerRng {
+    fn default() -> Self {
+        Self(RunRng::new(HAMMER_SEED_SALT))
+    }
+}
+
+pub struct HammerPlugin;
+
+impl Plugin for HammerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HammerRng>()
+            .add_systems(
+                OnEnter(AppState::Playing),
+                reseed_hammer_rng.after(crate::rng::reseed_run_rng),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    spawn_hammers.after(crate::pipes::pipe_spawn_system),
+                    collect_hammers.after(crate::physics::move_system),
+                    smash_hazards
+                        .after(crate::physics::move_system)
+                        .before(crate::physics::collision_system),
+                    debris_system.before(crate::physics::move_system),
+                )
+                    .run_if(in_state(PauseState::Running)),
+            )
+            .add_systems(
+                Update,
+                (spin_pickups, fade_debris).run_if(in_state(PauseState::Running)),
+            );
+    }
+}
+
+fn reseed_hammer_rng(run_rng: Res<RunRng>, mut rng: ResMut<HammerRng>) {
+    rng.0 = RunRng::new(run_rng.seed() ^ HAMMER_SEED_SALT);
+}
+
+// Młotek leci razem z nową strefą punktową, na środku przerwy.
+pub fn spawn_hammers(
+    mut commands: Commands,
+    mut rng: ResMut<HammerRng>,
+    mut run_rng: ResMut<RunRng>,
+    zones: Query<(&Transform, &Velocity, &LifeTime), Added<ScoreZone>>,
+) {
+    for (transform, velocity, lifetime) in zones.iter() {
+        rng.0.tag(DrawPurpose::HammerRoll);
+        let hammer = rng.0.gen_bool(HAMMER_CHANCE);
+        run_rng.absorb_audit(&mut rng.0);
+        if !hammer {
+            continue;
+        }
+        commands.spawn((
+            Sprite::from_color(PICKUP_COLOR, Vec2::splat(PICKUP_SIZE)),
+            Velocity {
+                dx: velocity.dx,
+                dy: 0.0,
+            },
+            Collider {
+                half_size: Vec2::splat(PICKUP_SIZE / 2.0),
+                offset: Vec2::ZERO,
+            },
+            Sensor,
+            HammerPickup,
+            LifeTime(lifetime.0),
+            Interpolated::default(),
+            Transform::from_translation(transform.translation.truncate().extend(0.5)),
+        ));
+    }
+}
+
+fn collect_hammers(
+    mut commands: Commands,
+    player_query: Query<(Entity, &Transform, &Collider), With<Player>>,
+    pickups: Query<(Entity, &Transform, &Collider), With<HammerPickup>>,
+    mut toasts: EventWriter<Toast>,
+) {
+    let Ok((player, player_transform, player_collider)) = player_query.get_single() else {
+        return;
+    };
+
+    for (pickup, transform, collider) in pickups.iter() {
+        if crate::core::aabb_overlap(
+            player_collider.center(player_transform).into(),
+            player_collider.half_size.into(),
+            collider.center(transform).into(),
+            collider.half_size.into(),
+        ) {
+            commands.entity(pickup).despawn();
+            commands.entity(player).insert(Hammer);
+            toasts.send(Toast("Hammer ready!".to_string()));
+        }
+    }
+}
+
+// Ptak z młotkiem rozbija każdą przeszkodę, której dotknie w tym kroku.
+fn smash_hazards(
+    mut commands: Commands,
+    player_query: Query<(Entity, &Transform, &Collider), (With<Player>, With<Hammer>)>,
+    hazards: Query<
+        (
+            Entity,
+            &Transform,
+            &Collider,
+            &Sprite,
+            Option<&Velocity>,
+            Has<Pipe>,
+        ),
+        With<Hazard>,
+    >,
+) {
+    let Ok((player, player_transform, player_collider)) = player_query.get_single() else {
+        return;
+    };
+
+    let mut smashed = false;
+    for (hazard, transform, collider, sprite, velocity, pipe) in hazards.iter() {
+        if !crate::core::aabb_overlap(
+            player_collider.center(player_transform).into(),
+            player_collider.half_size.into(),
+            collider.center(transform).into(),
+            collider.half_size.into(),
+        ) {
+            continue;
+        }
+        // Rury mają teksturę; odłamki barwimy jak ściany tunelu.
+        let color = if pipe {
+            crate::pipes::tunnel_wall_color(sprite.color)
+        } else {
+            sprite.color
+        };
+        let drift = velocity.map_or(0.0, |velocity| velocity.dx);
+        spawn_debris(
+            &mut commands,
+            collider.center(transform),
+            collider.half_size,
+            color,
+            drift,
+        );
+        commands.entity(hazard).despawn_recursive();
+        smashed = true;
+    }
+    if smashed {
+        commands.entity(player).remove::<Hammer>();
+    }
+}
+
+// Widoczną część przeszkody dzielimy na kawałki rozlatujące się na boki.
+fn spawn_debris(commands: &mut Commands, center: Vec2, half_size: Vec2, color: Color, drift: f32) {
+    let bottom = (center.y - half_size.y).max(-WORLD_SIZE.y / 2.0);
+    let top = (center.y + half_size.y).min(WORLD_SIZE.y / 2.0);
+    if top <= bottom {
+        return;
+    }
+    let rows = (((top - bottom) / DEBRIS_SIZE).ceil() as usize).clamp(1, DEBRIS_MAX_ROWS);
+    let row_height = (top - bottom) / rows as f32;
+    let mut rng = rand::thread_rng();
+
+    for row in 0..rows {
+        for side in [-1.0, 1.0] {
+            let x = center.x + side * half_size.x / 2.0;
+            let y = bottom + row_height * (row as f32 + 0.5);
+            commands.spawn((
+                Sprite::from_color(color, Vec2::new(half_size.x, row_height.min(DEBRIS_SIZE))),
+                Velocity {
+                    dx: drift + side * rng.gen_range(40.0..160.0),
+                    dy: rng.gen_range(60.0..260.0),
+                },
+                Debris::new(rng.gen_range(-8.0..8.0)),
+                LifeTime(DEBRIS_SECS),
+                Transform::from_xyz(x, y, 0.4),
+            ));
+        }
+    }
+}
+
+fn debris_system(
+    time: Res<Time>,
+    gravity: Res<Gravity>,
+    mut debris: Query<(&mut Velocity, &mut Transform, &Debris)>,
+) {
+    let delta = time.delta_secs();
+    for (mut velocity, mut transform, debris) in debris.iter_mut() {
+        velocity.dy += gravity.0 * delta;
+        transform.rotate_z(debris.spin * delta);
+    }
+}
+
+fn fade_debris(mut debris: Query<(&LifeTime, &mut Sprite), With<Debris>>) {
+    for (lifetime, mut sprite) in debris.iter_mut() {
+        let alpha = (lifetime.0 / DEBRIS_SECS).clamp(0.0, 1.0);
+        sprite.color.set_alpha(alpha);
+    }
+}
+
+fn spin_pickups(time: Res<Time>, mut pickups: Query<&mut Transform, With<HammerPickup>>) {
+    for mut transform in pickups.iter_mut() {
+        transform.rotate_z(PICKUP_SPIN * time.delta_secs());
+    }
+}
@@ -0,0 +1,204 @@
+// Wyrównanie szans w wyścigach: każdy ptak może mieć własny handicap – szerszą
+// przerwę w rurach i słabszą grawitację – żeby np. rodzic z dzieckiem mogli
+// ścigać się na serio. Handicap jest komponentem ptaka, a nie zmianą
+// globalnych `Gravity` czy parametrów rur, więc każdy ptak ma swoją fizykę.
+//
+// W pokojach sieciowych handicapy ustawia się w lobby, a przeciwników
+// przewidujemy z ich własnym handicapem (`race_sync`). W wyścigu z rywalami
+// AI handicap gracza wybiera przycisk w menu; w zwykłej grze go nie ma.
+
+use std::ops::RangeInclusive;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::buttons::button_text;
+use crate::core::PipeParams;
+use crate::player::Player;
+use crate::royale::RoyaleRun;
+use crate::settings::Settings;
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+// Granice dla wartości z sieci albo ręcznie poprawionego pliku ustawień.
+const GAP_PERCENT_RANGE: RangeInclusive<u8> = 50..=200;
+const GRAVITY_PERCENT_RANGE: RangeInclusive<u8> = 25..=200;
+
+// Wysokość przerwy w rurach i siła grawitacji w procentach zwykłych.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Handicap {
+    pub gap_percent: u8,
+    pub gravity_percent: u8,
+}
+
+impl Default for Handicap {
+    fn default() -> Self {
+        Self {
+            gap_percent: 100,
+            gravity_percent: 100,
+        }
+    }
+}
+
+// Gotowe ustawienia przełączane przyciskiem w menu.
+const PRESETS: [(&str, Handicap); 3] = [
+    (
+        "off",
+        Handicap {
+            gap_percent: 100,
+            gravity_percent: 100,
+        },
+    ),
+    (
+        "light",
+        Handicap {
+            gap_percent: 125,
+            gravity_percent: 85,
+        },
+    ),
+    (
+        "strong",
+        Handicap {
+            gap_percent: 150,
+            gravity_percent: 70,
+        },
+    ),
+];
+
+impl Handicap {
+    pub fn gap_scale(self) -> f32 {
+        f32::from(self.gap_percent) / 100.0
+    }
+
+    pub fn gravity_scale(self) -> f32 {
+        f32::from(self.gravity_percent) / 100.0
+    }
+
+    pub fn clamped(self) -> Self {
+        Self {
+            gap_percent: self
+                .gap_percent
+                .clamp(*GAP_PERCENT_RANGE.start(), *GAP_PERCENT_RANGE.end()),
+            gravity_percent: self
+                .gravity_percent
+                .clamp(*GRAVITY_PERCENT_RANGE.start(), *GRAVITY_PERCENT_RANGE.end()),
+        }
+    }
+
+    // Następne gotowe ustawienie; własne wartości (z lobby) wracają do "off".
+    pub fn next(self) -> Self {
+        let index = PRESETS.iter().position(|(_, preset)| *preset == self);
+        index.map_or(PRESETS[0].1, |index| PRESETS[(index + 1) % PRESETS.len()].1)
+    }
+
+    fn label(self) -> &'static str {
+        PRESETS
+            .iter()
+            .find(|(_, preset)| *preset == self)
+            .map_or("custom", |(name, _)| name)
+    }
+}
+
+// Parametry rur dla ptaka z handicapem.
+pub fn widen_gap(mut params: PipeParams, handicap: Option<&Handicap>) -> PipeParams {
+    if let Some(handicap) = handicap {
+        params.gap *= handicap.gap_scale();
+    }
+    params
+}
+
+#[derive(Component)]
+pub struct HandicapButton;
+
+pub fn handicap_button_label(settings: &Settings) -> String {
+    format!("Handicap: {}", settings.ai.handicap.label())
+}
+
+pub struct HandicapPlugin;
+
+impl Plugin for HandicapPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(AppState::Playing),
+            apply_handicap.after(crate::restart_game),
+        )
+        .add_systems(
+            Update,
+            handicap_button_system.run_if(in_state(AppState::Menu)),
+        );
+    }
+}
+
+// Handicap działa tylko w wyścigu – w zwykłej grze liczy się uczciwy wynik.
+fn apply_handicap(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    royale: Option<Res<RoyaleRun>>,
+    player: Query<Entity, With<Player>>,
+) {
+    let versus = settings.ai.rivals > 0 || royale.is_some();
+    let handicap = settings.ai.handicap.clamped();
+    if !versus || handicap == Handicap::default() {
+        return;
+    }
+    for entity in player.iter() {
+        commands.entity(entity).insert(handicap);
+    }
+}
+
+fn handicap_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, Option<&Children>),
+        (Changed<Interaction>, With<HandicapButton>),
+    >,
+    mut text_query: Query<&mut Text>,
+    mut settings: ResMut<Settings>,
+) {
+    for (interaction, mut bg_color, children) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                settings.ai.handicap = settings.ai.handicap.next();
+                if let Some(mut text) = button_text(children, &mut text_query) {
+                    text.0 = handicap_button_label(&settings);
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_cycle_and_custom_values_reset() {
+        let light = Handicap::default().next();
+        assert_eq!(light.label(), "light");
+        assert_eq!(light.next().next(), Handicap::default());
+
+        let custom = Handicap {
+            gap_percent: 110,
+            gravity_percent: 100,
+        };
+        assert_eq!(custom.label(), "custom");
+        assert_eq!(custom.next(), Handicap::default());
+    }
+
+    #[test]
+    fn extreme_values_are_clamped() {
+        let handicap = Handicap {
+            gap_percent: 255,
+            gravity_percent: 0,
+        }
+        .clamped();
+        assert_eq!(handicap.gap_scale(), 2.0);
+        assert_eq!(handicap.gravity_scale(), 0.25);
+    }
+}
@@ -0,0 +1,69 @@
+// Wibracje na urządzeniach, które je obsługują. Gra wysyła tylko zdarzenie
+// `Haptic`, a o sposobie jego realizacji decyduje backend danej platformy.
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::player::Flapped;
+use crate::settings::Settings;
+
+// Krótki impuls przy machnięciu, długi przy śmierci.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Haptic {
+    Short,
+    Long,
+}
+
+impl Haptic {
+    fn duration_ms(self) -> u32 {
+        match self {
+            Haptic::Short => 15,
+            Haptic::Long => 250,
+        }
+    }
+}
+
+pub struct HapticsPlugin;
+
+impl Plugin for HapticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Haptic>()
+            .add_systems(Update, (flap_haptics, play_haptics).chain())
+            .add_systems(OnEnter(AppState::GameOver), death_haptics);
+    }
+}
+
+fn flap_haptics(mut flapped: EventReader<Flapped>, mut haptics: EventWriter<Haptic>) {
+    for _ in flapped.read() {
+        haptics.send(Haptic::Short);
+    }
+}
+
+fn death_haptics(mut haptics: EventWriter<Haptic>) {
+    haptics.send(Haptic::Long);
+}
+
+fn play_haptics(mut haptics: EventReader<Haptic>, settings: Res<Settings>) {
+    for haptic in haptics.read() {
+        if settings.haptics {
+            backend::vibrate(haptic.duration_ms());
+        }
+    }
+}
+
+// Przeglądarka: Vibration API (`navigator.vibrate`).
+#[cfg(target_arch = "wasm32")]
+mod backend {
+    pub fn vibrate(duration_ms: u32) {
+        if let Some(window) = web_sys::window() {
+            let _ = window.navigator().vibrate_with_duration(duration_ms);
+        }
+    }
+}
+
+// Pozostałe platformy (w tym natywne Android/iOS, dopóki nie mają własnego
+// backendu) po prostu ignorują wibracje.
+#[cfg(not(target_arch = "wasm32"))]
+mod backend {
+    pub fn vibrate(_duration_ms: u32) {}
+}
@@ -0,0 +1,63 @@
+// Przeszkody inne niż rury. Wszystko z `Hazard` przechodzi przez ten sam
+// `collision_system` co rury.
+
+use bevy::prelude::*;
+
+use crate::RunEntity;
+use crate::interpolation::Interpolated;
+use crate::physics::{Collider, LifeTime, Velocity};
+use crate::pipes::PipeEnd;
+
+// Wysokość listwy z kolcami – wystarczy, żeby wymusić lot środkiem ekranu.
+const SPIKE_STRIP_SIZE: Vec2 = Vec2::new(80.0, 70.0);
+const SPIKE_SIZE: f32 = 14.0;
+
+// Znacznik: zetknięcie kończy grę.
+#[derive(Component)]
+#[require(RunEntity)]
+pub struct Hazard;
+
+#[derive(Component)]
+pub struct Spikes;
+
+// Listwa kolców przy suficie (`PipeEnd::Top`) albo podłodze (`PipeEnd::Bottom`).
+pub fn spawn_spike_strip(
+    commands: &mut Commands,
+    side: PipeEnd,
+    x: f32,
+    world_half_height: f32,
+    speed: f32,
+    lifetime: f32,
+) {
+    let direction = -side.body_direction();
+    let y = -direction * (world_half_height - SPIKE_STRIP_SIZE.y / 2.0);
+    let color = Color::srgb(0.55, 0.55, 0.6);
+
+    commands
+        .spawn((
+            Sprite::from_color(color, SPIKE_STRIP_SIZE - Vec2::new(0.0, SPIKE_SIZE)),
+            Velocity { dx: speed, dy: 0.0 },
+            Collider {
+                half_size: SPIKE_STRIP_SIZE / 2.0,
+                offset: Vec2::ZERO,
+            },
+            Hazard,
+            Spikes,
+            LifeTime(lifetime),
+            Interpolated::default(),
+            Transform::from_xyz(x, y, 0.0),
+        ))
+        .with_children(|parent| {
+            // Rząd "zębów" (kwadraty obrócone o 45°) na krawędzi od strony przerwy.
+            let tip_y = direction * (SPIKE_STRIP_SIZE.y - SPIKE_SIZE) / 2.0;
+            let count = (SPIKE_STRIP_SIZE.x / SPIKE_SIZE) as i32;
+            for i in 0..count {
+                let tooth_x = -SPIKE_STRIP_SIZE.x / 2.0 + SPIKE_SIZE * (i as f32 + 0.5);
+                parent.spawn((
+                    Sprite::from_color(color, Vec2::splat(SPIKE_SIZE / std::f32::consts::SQRT_2)),
+                    Transform::from_xyz(tooth_x, tip_y, -0.1)
+                        .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+                ));
+            }
+        });
+}
@@ -0,0 +1,152 @@
+// Podpowiedzi sterowania ("Space to flap", "A to flap", "Tap to flap") na
+// ekranach "Get Ready" i pauzy. Treść zależy od urządzenia, którego gracz
+// ostatnio użył (`LastInputDevice` z warstwy wejścia), i zmienia się od razu,
+// gdy gracz sięgnie po inne.
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::input::{InputDevice, LastInputDevice};
+use crate::player::{HoverIdle, Player};
+use crate::settings::Settings;
+use crate::text_style::OutlinedText;
+
+const HINT_COLOR: Color = Color::srgb(0.95, 0.95, 0.85);
+
+// Napis z podpowiedzią; tekst uzupełnia `update_button_hints`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[require(Text, TextFont(hint_font), OutlinedText(hint_style))]
+pub enum ButtonHint {
+    Flap,
+    Resume,
+}
+
+impl ButtonHint {
+    pub fn text(self, device: InputDevice, settings: &Settings) -> String {
+        let button = match (self, device) {
+            (_, InputDevice::Touch) => "Tap".to_string(),
+            (ButtonHint::Flap, InputDevice::Keyboard) => key_label(settings.keybinds.flap),
+            (ButtonHint::Flap, InputDevice::Gamepad) => "A".to_string(),
+            (ButtonHint::Resume, InputDevice::Keyboard) => "Esc".to_string(),
+            (ButtonHint::Resume, InputDevice::Gamepad) => "Start".to_string(),
+        };
+        let action = match self {
+            ButtonHint::Flap => "flap",
+            ButtonHint::Resume => "resume",
+        };
+        format!("{button} to {action}")
+    }
+}
+
+// Podpowiedź nad ptakiem unoszącym się przed pierwszym machnięciem.
+#[derive(Component)]
+#[require(StateScoped<AppState>(|| StateScoped(AppState::Playing)))]
+struct GetReadyHint;
+
+pub struct HintsPlugin;
+
+impl Plugin for HintsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                show_get_ready_hint.run_if(in_state(AppState::Playing)),
+                update_button_hints,
+            )
+                .chain(),
+        );
+    }
+}
+
+fn hint_font() -> TextFont {
+    TextFont {
+        font_size: 26.0,
+        ..Default::default()
+    }
+}
+
+fn hint_style() -> OutlinedText {
+    OutlinedText::new(HINT_COLOR)
+}
+
+// "KeyW" -> "W", "Digit1" -> "1"; pozostałe nazwy klawiszy zostają bez zmian.
+fn key_label(key: KeyCode) -> String {
+    let name = format!("{key:?}");
+    name.strip_prefix("Key")
+        .or_else(|| name.strip_prefix("Digit"))
+        .unwrap_or(&name)
+        .to_string()
+}
+
+// Podpowiedź jest widoczna, dopóki ptak czeka na pierwsze machnięcie.
+fn show_get_ready_hint(
+    mut commands: Commands,
+    waiting: Query<(), (With<Player>, With<HoverIdle>)>,
+    hints: Query<Entity, With<GetReadyHint>>,
+) {
+    if waiting.is_empty() {
+        for entity in hints.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+    if !hints.is_empty() {
+        return;
+    }
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                top: Val::Percent(62.0),
+                justify_content: JustifyContent::Center,
+                ..Default::default()
+            },
+            GetReadyHint,
+        ))
+        .with_child(ButtonHint::Flap);
+}
+
+fn update_button_hints(
+    device: Res<LastInputDevice>,
+    settings: Res<Settings>,
+    mut hints: Query<(Ref<ButtonHint>, &mut Text)>,
+) {
+    let refresh = device.is_changed() || settings.is_changed();
+    for (hint, mut text) in hints.iter_mut() {
+        if !refresh && !hint.is_added() {
+            continue;
+        }
+        let label = hint.text(device.0, &settings);
+        if text.0 != label {
+            text.0 = label;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hints_follow_the_last_used_device() {
+        let mut settings = Settings::default();
+        let flap = ButtonHint::Flap;
+        assert_eq!(flap.text(InputDevice::Keyboard, &settings), "Space to flap");
+        assert_eq!(flap.text(InputDevice::Gamepad, &settings), "A to flap");
+        assert_eq!(flap.text(InputDevice::Touch, &settings), "Tap to flap");
+
+        let resume = ButtonHint::Resume;
+        assert_eq!(
+            resume.text(InputDevice::Keyboard, &settings),
+            "Esc to resume"
+        );
+        assert_eq!(
+            resume.text(InputDevice::Gamepad, &settings),
+            "Start to resume"
+        );
+
+        settings.keybinds.flap = KeyCode::KeyW;
+        assert_eq!(flap.text(InputDevice::Keyboard, &settings), "W to flap");
+    }
+}
@@ -0,0 +1,268 @@
+// Warstwa wejścia: zbiera machnięcia z klawiatury, padów i ekranu dotykowego
+// w jeden zasób, pamięta ostatnio używane urządzenie (podpowiedzi na ekranie)
+// oraz obsługuje podłączanie/odłączanie kontrolerów w trakcie gry.
+
+use bevy::input::InputSystem;
+use bevy::input::gamepad::{GamepadConnection, GamepadConnectionEvent};
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::pause::PauseState;
+use crate::settings::Settings;
+use crate::toast::Toast;
+
+// Stan akcji "machnij" w bieżącej klatce, niezależnie od urządzenia.
+#[derive(Resource)]
+pub struct FlapInput {
+    pub just_pressed: bool,
+    pub pressed: bool,
+    // Jak długo przycisk jest już przytrzymany (sekundy, czas rzeczywisty).
+    pub held_for: f32,
+    // Ustawione przy puszczeniu przycisku: czas, przez jaki był trzymany.
+    // Czeka, aż odbierze je krok symulacji.
+    pub released_after: Option<f32>,
+    // Mnożnik siły ostatniego machnięcia (1.0 bez analogowego spustu).
+    pub strength: f32,
+}
+
+impl Default for FlapInput {
+    fn default() -> Self {
+        Self {
+            just_pressed: false,
+            pressed: false,
+            held_for: 0.0,
+            released_after: None,
+            strength: 1.0,
+        }
+    }
+}
+
+// Maksymalny wiek zbuforowanego machnięcia (sekundy).
+pub const FLAP_BUFFER_WINDOW: f64 = 0.1;
+
+// Ostatnie naciśnięcie "machnij" – zużywane dopiero przez symulację, dzięki czemu
+// naciśnięcie tuż przed startem (odliczanie, zmiana stanu) nie ginie.
+#[derive(Resource, Default)]
+pub struct FlapBuffer {
+    pressed_at: Option<f64>,
+}
+
+impl FlapBuffer {
+    pub fn press(&mut self, now: f64) {
+        self.pressed_at = Some(now);
+    }
+
+    // Zwraca `true` i czyści bufor, jeśli naciśnięcie jest wystarczająco świeże.
+    pub fn take(&mut self, now: f64) -> bool {
+        match self.pressed_at.take() {
+            Some(at) => now - at <= FLAP_BUFFER_WINDOW,
+            None => false,
+        }
+    }
+}
+
+// Kierunki i przyciski A/B z klawiatury (strzałki, A, B) albo pada (krzyżak,
+// South, East) – wspólne dla obu urządzeń, np. do kodów w menu.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuInput {
+    Up,
+    Down,
+    Left,
+    Right,
+    A,
+    B,
+}
+
+const MENU_KEYS: [(KeyCode, MenuInput); 6] = [
+    (KeyCode::ArrowUp, MenuInput::Up),
+    (KeyCode::ArrowDown, MenuInput::Down),
+    (KeyCode::ArrowLeft, MenuInput::Left),
+    (KeyCode::ArrowRight, MenuInput::Right),
+    (KeyCode::KeyA, MenuInput::A),
+    (KeyCode::KeyB, MenuInput::B),
+];
+
+const MENU_BUTTONS: [(GamepadButton, MenuInput); 6] = [
+    (GamepadButton::DPadUp, MenuInput::Up),
+    (GamepadButton::DPadDown, MenuInput::Down),
+    (GamepadButton::DPadLeft, MenuInput::Left),
+    (GamepadButton::DPadRight, MenuInput::Right),
+    (GamepadButton::South, MenuInput::A),
+    (GamepadButton::East, MenuInput::B),
+];
+
+// Kontroler, którym ostatnio grano.
+#[derive(Resource, Default)]
+pub struct ActiveGamepad(pub Option<Entity>);
+
+// Rodzaj urządzenia, którego gracz ostatnio użył.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InputDevice {
+    #[default]
+    Keyboard,
+    Gamepad,
+    Touch,
+}
+
+#[derive(Resource, Default)]
+pub struct LastInputDevice(pub InputDevice);
+
+// Nazwy podłączonych kontrolerów (zdarzenie rozłączenia nie niesie nazwy).
+#[derive(Resource, Default)]
+struct GamepadNames(HashMap<Entity, String>);
+
+pub struct GameInputPlugin;
+
+impl Plugin for GameInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FlapInput>()
+            .init_resource::<FlapBuffer>()
+            .init_resource::<ActiveGamepad>()
+            .init_resource::<LastInputDevice>()
+            .init_resource::<GamepadNames>()
+            .add_event::<MenuInput>()
+            .add_systems(
+                PreUpdate,
+                (
+                    gamepad_connection_system,
+                    track_input_device,
+                    read_flap_input,
+                    read_menu_input,
+                )
+                    .chain()
+                    .after(InputSystem),
+            );
+    }
+}
+
+// Zmieniamy zasób tylko przy faktycznej zmianie urządzenia, żeby podpowiedzi
+// nie przebudowywały się co klatkę.
+fn track_input_device(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    touches: Option<Res<Touches>>,
+    mut last: ResMut<LastInputDevice>,
+) {
+    let device = if touches.is_some_and(|touches| touches.any_just_pressed()) {
+        InputDevice::Touch
+    } else if gamepads
+        .iter()
+        .any(|gamepad| gamepad.get_just_pressed().next().is_some())
+    {
+        InputDevice::Gamepad
+    } else if keyboard_input.get_just_pressed().next().is_some() {
+        InputDevice::Keyboard
+    } else {
+        return;
+    };
+    if last.0 != device {
+        last.0 = device;
+    }
+}
+
+fn read_flap_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    touches: Option<Res<Touches>>,
+    settings: Res<Settings>,
+    mut active: ResMut<ActiveGamepad>,
+    mut flap: ResMut<FlapInput>,
+    mut buffer: ResMut<FlapBuffer>,
+    time: Res<Time<Real>>,
+) {
+    let was_pressed = flap.pressed;
+    flap.just_pressed = keyboard_input.just_pressed(settings.keybinds.flap);
+    flap.pressed = keyboard_input.pressed(settings.keybinds.flap);
+    let mut strength = 1.0;
+
+    // Dotknięcie ekranu w dowolnym miejscu to machnięcie.
+    if let Some(touches) = touches {
+        flap.just_pressed |= touches.any_just_pressed();
+        flap.pressed |= touches.iter().next().is_some();
+    }
+
+    for (entity, gamepad) in gamepads.iter() {
+        // Naciśnięcie czegokolwiek na padzie czyni go aktywnym.
+        if gamepad.get_just_pressed().next().is_some() {
+            active.0 = Some(entity);
+        }
+        flap.just_pressed |= gamepad.just_pressed(GamepadButton::South);
+        flap.pressed |= gamepad.pressed(GamepadButton::South);
+
+        // Spust trzymany w trakcie naciśnięcia South ustala siłę machnięcia.
+        if settings.analog_flap && gamepad.just_pressed(GamepadButton::South) {
+            let pressure = gamepad.get(GamepadButton::RightTrigger2).unwrap_or(0.0);
+            strength = crate::core::analog_flap_scale(pressure);
+        }
+    }
+
+    if flap.just_pressed {
+        flap.strength = strength;
+        buffer.press(time.elapsed_secs_f64());
+        flap.held_for = 0.0;
+    }
+
+    if flap.pressed {
+        flap.held_for += time.delta_secs();
+    } else if was_pressed {
+        flap.released_after = Some(flap.held_for);
+    }
+}
+
+fn read_menu_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut menu_input: EventWriter<MenuInput>,
+) {
+    for (key, input) in MENU_KEYS {
+        if keyboard_input.just_pressed(key) {
+            menu_input.send(input);
+        }
+    }
+    for gamepad in gamepads.iter() {
+        for (button, input) in MENU_BUTTONS {
+            if gamepad.just_pressed(button) {
+                menu_input.send(input);
+            }
+        }
+    }
+}
+
+fn gamepad_connection_system(
+    mut events: EventReader<GamepadConnectionEvent>,
+    mut names: ResMut<GamepadNames>,
+    mut active: ResMut<ActiveGamepad>,
+    mut toasts: EventWriter<Toast>,
+    pause_state: Option<Res<State<PauseState>>>,
+    mut next_pause: ResMut<NextState<PauseState>>,
+) {
+    for event in events.read() {
+        match &event.connection {
+            GamepadConnection::Connected { name, .. } => {
+                names.0.insert(event.gamepad, name.clone());
+                if active.0.is_none() {
+                    active.0 = Some(event.gamepad);
+                }
+                toasts.send(Toast(format!("Controller connected: {name}")));
+            }
+            GamepadConnection::Disconnected => {
+                let name = names
+                    .0
+                    .remove(&event.gamepad)
+                    .unwrap_or_else(|| "controller".to_string());
+                toasts.send(Toast(format!("Controller disconnected: {name}")));
+
+                if active.0 == Some(event.gamepad) {
+                    active.0 = None;
+                    // Gracz stracił kontrolę nad ptakiem – zatrzymujemy rundę.
+                    if pause_state
+                        .as_deref()
+                        .is_some_and(|state| *state.get() == PauseState::Running)
+                    {
+                        next_pause.set(PauseState::Paused);
+                    }
+                }
+            }
+        }
+    }
+}
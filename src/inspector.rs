@@ -0,0 +1,72 @@
+// Inspektor świata (cecha `inspector`): F2 otwiera okno z encjami
+// i komponentami oraz z najczęściej zmienianymi zasobami gry – wszystko
+// można edytować na żywo.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_inspector_egui::bevy_egui::{EguiContext, EguiPlugin};
+use bevy_inspector_egui::{DefaultInspectorConfigPlugin, bevy_inspector, egui};
+
+use crate::physics::Gravity;
+use crate::score::Score;
+use crate::settings::{Difficulty, Settings};
+
+const INSPECTOR_KEY: KeyCode = KeyCode::F2;
+
+#[derive(Resource, Default)]
+struct InspectorOpen(bool);
+
+pub struct InspectorPlugin;
+
+impl Plugin for InspectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((EguiPlugin, DefaultInspectorConfigPlugin))
+            .register_type::<Score>()
+            .register_type::<Gravity>()
+            .register_type::<Difficulty>()
+            .init_resource::<InspectorOpen>()
+            .add_systems(Update, toggle_inspector)
+            .add_systems(
+                Update,
+                inspector_ui.run_if(|open: Res<InspectorOpen>| open.0),
+            );
+    }
+}
+
+fn toggle_inspector(keyboard_input: Res<ButtonInput<KeyCode>>, mut open: ResMut<InspectorOpen>) {
+    if keyboard_input.just_pressed(INSPECTOR_KEY) {
+        open.0 = !open.0;
+    }
+}
+
+fn inspector_ui(world: &mut World) {
+    let Ok(egui_context) = world
+        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
+        .get_single(world)
+    else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+
+    egui::Window::new("Inspector").show(egui_context.get_mut(), |ui| {
+        egui::ScrollArea::both().show(ui, |ui| {
+            ui.heading("Score");
+            bevy_inspector::ui_for_resource::<Score>(world, ui);
+            ui.heading("Gravity");
+            bevy_inspector::ui_for_resource::<Gravity>(world, ui);
+            ui.heading("Difficulty");
+            world.resource_scope(|world, mut settings: Mut<Settings>| {
+                let mut difficulty = settings.difficulty;
+                bevy_inspector::ui_for_value(&mut difficulty, ui, world);
+                // Porównanie, żeby nie zapisywać ustawień w każdej klatce.
+                if settings.difficulty != difficulty {
+                    settings.difficulty = difficulty;
+                }
+            });
+
+            ui.separator();
+            ui.heading("Entities");
+            bevy_inspector::ui_for_world_entities(world, ui);
+        });
+    });
+}
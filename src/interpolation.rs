@@ -0,0 +1,87 @@
+// Wygładzanie ruchu między krokami symulacji: fizyka liczy pozycje w `FixedUpdate`,
+// a na ekranie rysujemy pozycję pośrednią, zależną od tego, jaka część
+// następnego kroku już upłynęła. Dzięki temu na monitorach 144 Hz ruch jest
+// płynny nawet przy logice 60 Hz.
+
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+
+// Encje, których translacja jest interpolowana (ptak, rury, strefy punktów).
+#[derive(Component, Default)]
+pub struct Interpolated {
+    // Pozycje z początku i końca ostatniego kroku symulacji.
+    previous: Vec3,
+    current: Vec3,
+    // Ostatnio narysowana pozycja – inna wartość w `Transform` oznacza teleport.
+    rendered: Vec3,
+    // Ustawiane po pierwszym kroku symulacji od spawnu.
+    ready: bool,
+}
+
+impl Interpolated {
+    // Pozycja z ostatniego kroku symulacji (a nie ta narysowana na ekranie).
+    pub fn simulated(&self, transform: &Transform) -> Vec3 {
+        if self.ready {
+            self.current
+        } else {
+            transform.translation
+        }
+    }
+}
+
+pub struct InterpolationPlugin;
+
+impl Plugin for InterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(FixedFirst, restore_simulated)
+            .add_systems(FixedLast, record_simulated)
+            .add_systems(
+                PostUpdate,
+                blend_rendered.before(TransformSystem::TransformPropagate),
+            );
+    }
+}
+
+// Przed krokiem symulacji przywracamy pozycję "fizyczną" zamiast narysowanej.
+fn restore_simulated(mut query: Query<(&mut Transform, &mut Interpolated)>) {
+    for (mut transform, mut interpolated) in query.iter_mut() {
+        if !interpolated.ready {
+            continue;
+        }
+
+        if transform.translation == interpolated.rendered {
+            transform.translation = interpolated.current;
+        } else {
+            // Ktoś przestawił encję poza symulacją – bez interpolacji z poprzedniego miejsca.
+            interpolated.current = transform.translation;
+        }
+        interpolated.previous = interpolated.current;
+    }
+}
+
+fn record_simulated(mut query: Query<(&Transform, &mut Interpolated)>) {
+    for (transform, mut interpolated) in query.iter_mut() {
+        if !interpolated.ready {
+            interpolated.previous = transform.translation;
+            interpolated.ready = true;
+        }
+        interpolated.current = transform.translation;
+        interpolated.rendered = transform.translation;
+    }
+}
+
+pub fn blend_rendered(
+    fixed_time: Res<Time<Fixed>>,
+    mut query: Query<(&mut Transform, &mut Interpolated)>,
+) {
+    let t = fixed_time.overstep_fraction();
+    for (mut transform, mut interpolated) in query.iter_mut() {
+        if !interpolated.ready {
+            continue;
+        }
+
+        let translation = interpolated.previous.lerp(interpolated.current, t);
+        transform.translation = translation;
+        interpolated.rendered = translation;
+    }
+}
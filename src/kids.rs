@@ -0,0 +1,211 @@
+// Tryb dla dzieci, włączany profilem typu "kid" (`profiles.rs`):
+//   - ogromne przerwy i wolne rury (`DifficultyCurve::KIDS`), bez kolców i tuneli,
+//   - zamiast końca gry ptak odbija się od rur, podłogi i sufitu i chwilę
+//     mruga jak po straconym życiu,
+//   - co 5 punktów wybuch konfetti,
+//   - wyjście i ustawienia trzeba przytrzymać (`ParentalLock`), żeby
+//     przypadkowe kliknięcie nie zamknęło gry ani nie zmieniło ustawień.
+
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use rand::Rng;
+
+use crate::blink::Blink;
+use crate::core::DifficultyCurve;
+use crate::physics::Velocity;
+use crate::player::Player;
+use crate::score::Score;
+use crate::settings::Settings;
+use crate::{NORMAL_BUTTON, PRESSED_BUTTON};
+
+// Jak długo trzeba trzymać zablokowany przycisk (sekundy).
+const LONG_PRESS_SECS: f32 = 2.0;
+// Prędkość pionowa po odbiciu.
+const BOUNCE_SPEED: f32 = 220.0;
+const BOUNCE_BLINK_SECS: f32 = 1.0;
+const BOUNCE_BLINK_PERIOD: f32 = 0.2;
+const CONFETTI_EVERY: i32 = 5;
+const CONFETTI_PIECES: usize = 40;
+const CONFETTI_SECS: f32 = 1.5;
+const CONFETTI_GRAVITY: f32 = -400.0;
+const CONFETTI_COLORS: [Color; 5] = [
+    Color::srgb(1.0, 0.3, 0.3),
+    Color::srgb(1.0, 0.85, 0.2),
+    Color::srgb(0.3, 0.85, 0.4),
+    Color::srgb(0.3, 0.6, 1.0),
+    Color::srgb(0.85, 0.4, 1.0),
+];
+
+// Obecny, gdy gra dziecięcy profil.
+#[derive(Resource)]
+pub struct KidsMode;
+
+// Przycisk, który w trybie dla dzieci działa dopiero po przytrzymaniu.
+#[derive(Component, Default)]
+pub struct ParentalLock {
+    held: f32,
+    unlocked: bool,
+}
+
+// Zderzenie w trybie dla dzieci: ptak odbija się w górę (1.0) albo w dół (-1.0).
+#[derive(Event)]
+pub struct KidBounce {
+    pub direction: f32,
+}
+
+#[derive(Component)]
+struct Confetti {
+    velocity: Vec2,
+    spin: f32,
+    age: f32,
+}
+
+pub struct KidsPlugin;
+
+impl Plugin for KidsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<KidBounce>()
+            .add_systems(
+                PreUpdate,
+                hold_to_unlock
+                    .after(UiSystem::Focus)
+                    .run_if(resource_exists::<KidsMode>),
+            )
+            .add_systems(
+                FixedUpdate,
+                bounce_system
+                    .after(crate::physics::collision_system)
+                    .after(crate::physics::boundary_collision_system),
+            )
+            .add_systems(
+                Update,
+                (
+                    celebrate_score.run_if(resource_exists::<KidsMode>),
+                    animate_confetti,
+                ),
+            );
+    }
+}
+
+// Krzywa trudności bieżącej rundy.
+pub fn run_curve(settings: &Settings, kids: bool) -> DifficultyCurve {
+    if kids {
+        DifficultyCurve::KIDS
+    } else {
+        settings.difficulty.curve()
+    }
+}
+
+// Naciśnięcie zablokowanego przycisku zamieniamy na najechanie, dopóki nie
+// minie `LONG_PRESS_SECS`; tło przycisku pokazuje postęp.
+fn hold_to_unlock(
+    mouse: Option<Res<ButtonInput<MouseButton>>>,
+    touches: Option<Res<Touches>>,
+    time: Res<Time<Real>>,
+    mut buttons: Query<(&mut Interaction, &mut ParentalLock, &mut BackgroundColor)>,
+) {
+    let held = mouse.is_some_and(|mouse| mouse.pressed(MouseButton::Left))
+        || touches.is_some_and(|touches| touches.iter().next().is_some());
+
+    for (mut interaction, mut lock, mut bg_color) in buttons.iter_mut() {
+        if *interaction == Interaction::None || !held {
+            lock.held = 0.0;
+            lock.unlocked = false;
+            continue;
+        }
+        if lock.unlocked {
+            continue;
+        }
+        // Przytrzymanie liczy się dopiero od naciśnięcia na samym przycisku.
+        if *interaction == Interaction::Hovered && lock.held == 0.0 {
+            continue;
+        }
+
+        lock.held += time.delta_secs();
+        if lock.held >= LONG_PRESS_SECS {
+            lock.unlocked = true;
+            *interaction = Interaction::Pressed;
+        } else {
+            if *interaction == Interaction::Pressed {
+                *interaction = Interaction::Hovered;
+            }
+            bg_color.0 = NORMAL_BUTTON.mix(&PRESSED_BUTTON, lock.held / LONG_PRESS_SECS);
+        }
+    }
+}
+
+fn bounce_system(
+    mut commands: Commands,
+    mut bounces: EventReader<KidBounce>,
+    mut player: Query<(Entity, &mut Velocity, Has<Blink>), With<Player>>,
+) {
+    let Some(bounce) = bounces.read().last() else {
+        return;
+    };
+    for (entity, mut velocity, blinking) in player.iter_mut() {
+        velocity.dy = bounce.direction * BOUNCE_SPEED;
+        // Nowe mruganie dopiero po poprzednim, żeby nie zapamiętało
+        // przygaszonego koloru jako bazowego.
+        if !blinking {
+            commands
+                .entity(entity)
+                .insert(Blink::flicker(BOUNCE_BLINK_SECS, BOUNCE_BLINK_PERIOD));
+        }
+    }
+}
+
+fn celebrate_score(
+    mut commands: Commands,
+    score: Res<Score>,
+    settings: Res<Settings>,
+    player: Query<&Transform, With<Player>>,
+    mut last_score: Local<i32>,
+) {
+    // Z mnożnikiem serii wynik może przeskoczyć wielokrotność, więc patrzymy na
+    // przekroczenie progu, a nie na równość.
+    let crossed = score.0 / CONFETTI_EVERY > *last_score / CONFETTI_EVERY;
+    *last_score = score.0;
+    if !crossed || settings.accessibility.reduced_motion {
+        return;
+    }
+    let Ok(origin) = player.get_single() else {
+        return;
+    };
+
+    let mut rng = rand::thread_rng();
+    for i in 0..CONFETTI_PIECES {
+        let angle = rng.gen_range(0.3..std::f32::consts::PI - 0.3);
+        let speed = rng.gen_range(150.0..350.0);
+        commands.spawn((
+            Sprite::from_color(
+                CONFETTI_COLORS[i % CONFETTI_COLORS.len()],
+                Vec2::new(6.0, 10.0),
+            ),
+            Transform::from_translation(origin.translation.truncate().extend(5.0)),
+            Confetti {
+                velocity: Vec2::from_angle(angle) * speed,
+                spin: rng.gen_range(-10.0..10.0),
+                age: 0.0,
+            },
+        ));
+    }
+}
+
+fn animate_confetti(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut pieces: Query<(Entity, &mut Confetti, &mut Transform, &mut Sprite)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut piece, mut transform, mut sprite) in pieces.iter_mut() {
+        piece.age += dt;
+        if piece.age >= CONFETTI_SECS {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        piece.velocity.y += CONFETTI_GRAVITY * dt;
+        transform.translation += (piece.velocity * dt).extend(0.0);
+        transform.rotate_z(piece.spin * dt);
+        sprite.color.set_alpha(1.0 - piece.age / CONFETTI_SECS);
+    }
+}
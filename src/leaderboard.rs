@@ -0,0 +1,292 @@
+// Lokalna tablica wyników otwierana z menu. Zakładki filtrują rundy według
+// poziomu trudności; każda pozycja pokazuje też asysty i ziarno rundy, a rundy
+// z powtórką można przelecieć jeszcze raz w wyścigu z duchem.
+
+use bevy::prelude::*;
+
+use crate::bests::{BestKey, GameMode, PersonalBests};
+use crate::ghost::GhostRace;
+use crate::menu::Menu;
+use crate::replay::Replay;
+use crate::seed_display::seed_label;
+use crate::settings::{Difficulty, Settings};
+use crate::text_style::OutlinedText;
+use crate::toast::Toast;
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+// Ile pozycji mieści się na panelu.
+const VISIBLE_ENTRIES: usize = 10;
+
+#[derive(Component)]
+pub struct LeaderboardButton;
+
+#[derive(Component)]
+struct LeaderboardPanel;
+
+#[derive(Component)]
+struct DifficultyTab(Difficulty);
+
+#[derive(Component)]
+struct CloseLeaderboardButton;
+
+// Zakodowana powtórka rundy, z którą chcemy się ścigać.
+#[derive(Component)]
+struct RaceButton(Vec<u8>);
+
+#[derive(Resource)]
+struct LeaderboardView {
+    open: bool,
+    filter: BestKey,
+}
+
+pub struct LeaderboardPlugin;
+
+impl Plugin for LeaderboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(LeaderboardView {
+            open: false,
+            filter: BestKey {
+                mode: GameMode::Classic,
+                difficulty: Difficulty::Normal,
+            },
+        })
+        .add_systems(OnEnter(AppState::Menu), reset_view)
+        .add_systems(
+            Update,
+            (
+                leaderboard_buttons_system,
+                race_button_system,
+                rebuild_panel.run_if(resource_changed::<LeaderboardView>),
+            )
+                .chain()
+                .run_if(in_state(AppState::Menu)),
+        );
+    }
+}
+
+// Panel otwiera się na zakładce poziomu, na którym gracz aktualnie gra.
+fn reset_view(settings: Res<Settings>, mut view: ResMut<LeaderboardView>) {
+    view.open = false;
+    view.filter.difficulty = settings.difficulty;
+}
+
+fn leaderboard_buttons_system(
+    mut interaction_query: Query<
+        (
+            &Interaction,
+            &mut BackgroundColor,
+            Has<LeaderboardButton>,
+            Option<&DifficultyTab>,
+            Has<CloseLeaderboardButton>,
+        ),
+        (
+            Changed<Interaction>,
+            Or<(
+                With<LeaderboardButton>,
+                With<DifficultyTab>,
+                With<CloseLeaderboardButton>,
+            )>,
+        ),
+    >,
+    mut view: ResMut<LeaderboardView>,
+) {
+    for (interaction, mut bg_color, open_button, tab, close_button) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                if open_button {
+                    view.open = true;
+                } else if let Some(tab) = tab {
+                    view.filter.difficulty = tab.0;
+                } else if close_button {
+                    view.open = false;
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+// Wyścig startuje od razu, na poziomie trudności i ziarnie ducha. Poziom
+// gracza wraca po wyścigu (`GhostRace::restoring_difficulty`).
+fn race_button_system(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &RaceButton),
+        Changed<Interaction>,
+    >,
+    mut settings: ResMut<Settings>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut toasts: EventWriter<Toast>,
+) {
+    for (interaction, mut bg_color, race) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                let ghost = Replay::decode(&race.0)
+                    .map_err(|err| err.to_string())
+                    .and_then(|replay| {
+                        let ghost = GhostRace::new(&replay).map_err(|err| err.to_string())?;
+                        Ok((replay.difficulty, ghost))
+                    });
+                match ghost {
+                    Ok((difficulty, ghost)) => {
+                        let previous = settings.difficulty;
+                        if previous != difficulty {
+                            settings.difficulty = difficulty;
+                        }
+                        commands.insert_resource(ghost.restoring_difficulty(previous));
+                        next_state.set(AppState::Playing);
+                    }
+                    Err(err) => {
+                        toasts.send(Toast(format!("Can't race this run: {err}")));
+                    }
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+fn rebuild_panel(
+    mut commands: Commands,
+    view: Res<LeaderboardView>,
+    bests: Res<PersonalBests>,
+    panels: Query<Entity, With<LeaderboardPanel>>,
+) {
+    for entity in panels.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    if !view.open {
+        return;
+    }
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(10.0),
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.9)),
+            GlobalZIndex(1),
+            LeaderboardPanel,
+            // Znika razem z menu przy starcie rundy.
+            Menu,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Leaderboard"),
+                OutlinedText::new(Color::WHITE),
+                TextFont {
+                    font_size: 40.0,
+                    ..Default::default()
+                },
+            ));
+
+            // Zakładki poziomów trudności
+            parent
+                .spawn(Node {
+                    column_gap: Val::Px(10.0),
+                    ..Default::default()
+                })
+                .with_children(|tabs| {
+                    for difficulty in Difficulty::ALL {
+                        let selected = difficulty == view.filter.difficulty;
+                        spawn_small_button(
+                            tabs,
+                            difficulty.label(),
+                            if selected {
+                                PRESSED_BUTTON
+                            } else {
+                                NORMAL_BUTTON
+                            },
+                            DifficultyTab(difficulty),
+                        );
+                    }
+                });
+
+            let entries: Vec<_> = bests
+                .leaderboard(view.filter)
+                .take(VISIBLE_ENTRIES)
+                .collect();
+            if entries.is_empty() {
+                parent.spawn((Text::new("No runs yet"), TextColor(Color::WHITE)));
+            }
+            for (rank, entry) in entries.iter().enumerate() {
+                let assists = if entry.assists { "  (assists)" } else { "" };
+                let mutators = if entry.mutators.is_empty() {
+                    String::new()
+                } else {
+                    format!("  [{}]", entry.mutators.label())
+                };
+                parent
+                    .spawn(Node {
+                        column_gap: Val::Px(10.0),
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    })
+                    .with_children(|row| {
+                        row.spawn((
+                            Text::new(format!(
+                                "{}. {}{assists}{mutators}  seed {}",
+                                rank + 1,
+                                entry.score,
+                                seed_label(entry.seed)
+                            )),
+                            TextColor(Color::WHITE),
+                            TextFont {
+                                font_size: 20.0,
+                                ..Default::default()
+                            },
+                        ));
+                        if !entry.replay.is_empty() {
+                            spawn_small_button(
+                                row,
+                                "Race",
+                                NORMAL_BUTTON,
+                                RaceButton(entry.replay.clone()),
+                            );
+                        }
+                    });
+            }
+
+            spawn_small_button(parent, "Close", NORMAL_BUTTON, CloseLeaderboardButton);
+        });
+}
+
+fn spawn_small_button(parent: &mut ChildBuilder, label: &str, color: Color, marker: impl Bundle) {
+    parent
+        .spawn((
+            Button,
+            Interaction::default(),
+            Node {
+                width: Val::Px(120.0),
+                height: Val::Px(50.0),
+                border: UiRect::all(Val::Px(3.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(color),
+            BorderColor(Color::BLACK),
+            BorderRadius::MAX,
+            marker,
+        ))
+        .with_child((Text::new(label), TextColor(Color::WHITE)));
+}
@@ -0,0 +1,530 @@
+use bevy::prelude::*;
+use bevy::render::camera::ScalingMode;
+use bevy::window::{MonitorSelection, PresentMode, WindowMode, WindowPosition, WindowResolution};
+
+use crate::adaptive::AdaptivePlugin;
+use crate::ai_race::AiRacePlugin;
+use crate::ambient::AmbientPlugin;
+use crate::assets::{GameAssets, GameAssetsPlugin};
+use crate::audio::SoundPlugin;
+use crate::bests::{BestsPersistencePlugin, BestsPlugin, PersonalBests};
+use crate::biome::BiomePlugin;
+use crate::blink::BlinkPlugin;
+use crate::bubble::BubblePlugin;
+use crate::celebration::CelebrationPlugin;
+use crate::challenge::ChallengePlugin;
+use crate::cheats::CheatsPlugin;
+use crate::controls::ControlsPlugin;
+use crate::coop::CoopPlugin;
+use crate::crash::CrashReportPlugin;
+use crate::dataset::{DatasetExport, DatasetPlugin};
+use crate::display::{DisplayPlugin, PlayArea, WORLD_SIZE};
+use crate::effects::EffectsPlugin;
+use crate::gameplay_log::GameplayLogPlugin;
+use crate::ghost::GhostPlugin;
+use crate::glide_streak::GlideStreakPlugin;
+use crate::hammer::HammerPlugin;
+use crate::handicap::HandicapPlugin;
+use crate::haptics::HapticsPlugin;
+use crate::hints::HintsPlugin;
+use crate::input::GameInputPlugin;
+use crate::interpolation::InterpolationPlugin;
+use crate::kids::{KidsMode, KidsPlugin};
+use crate::leaderboard::LeaderboardPlugin;
+use crate::milestone::MilestonePlugin;
+use crate::mini::{MiniMode, MiniModePlugin};
+use crate::mirror::MirrorPlugin;
+use crate::mutators::MutatorsPlugin;
+use crate::pause::PausePlugin;
+use crate::pixel_art::PixelArtPlugin;
+use crate::player::spawn_player;
+use crate::power::PowerPlugin;
+use crate::practice::PracticePlugin;
+use crate::procedural::ProceduralArt;
+use crate::profiles::{ActiveProfile, PickProfileOnStart, ProfileList, ProfilesPlugin};
+use crate::replay::ReplayPlugin;
+use crate::rng::RunRngPlugin;
+use crate::royale::RoyalePlugin;
+use crate::run_save::RunSavePlugin;
+use crate::run_stats::RunStatsPlugin;
+use crate::scheduler::SchedulerPlugin;
+use crate::score::ScoreText;
+use crate::score_digits::{ScoreDigits, ScoreDigitsPlugin};
+use crate::seed_display::SeedDisplayPlugin;
+use crate::seed_entry::SeedEntryPlugin;
+use crate::settings::{Settings, SettingsPersistencePlugin};
+use crate::setup_wizard::{FirstLaunch, SetupWizardPlugin};
+use crate::spectate::SpectatePlugin;
+use crate::stamina::StaminaPlugin;
+use crate::submission::{SubmissionOutboxPlugin, SubmissionPlugin};
+use crate::text_style::TextStylePlugin;
+use crate::theme::{ActiveTheme, Theme, ThemePlugin};
+use crate::title::TitlePlugin;
+use crate::toast::ToastPlugin;
+use crate::tournament::{TournamentPlugin, TournamentSchedule};
+use crate::tower::TowerPlugin;
+use crate::tween::TweenPlugin;
+use crate::window_geometry::WindowGeometryPlugin;
+use crate::zones::ZonesPlugin;
+
+pub use crate::buttons::{ButtonAction, ButtonPressed, ButtonsPlugin};
+pub use crate::game_over::GameOverPlugin;
+pub use crate::menu::MenuPlugin;
+pub use crate::physics::PhysicsPlugin;
+pub use crate::pipes::PipesPlugin;
+pub use crate::player::PlayerPlugin;
+pub use crate::score::ScorePlugin;
+
+mod adaptive;
+mod ai_race;
+mod ambient;
+mod assets;
+mod audio;
+mod bests;
+mod biome;
+mod blink;
+mod bubble;
+mod buttons;
+mod celebration;
+mod challenge;
+mod cheats;
+mod clipboard;
+#[cfg(debug_assertions)]
+mod console;
+mod controls;
+mod coop;
+mod core;
+mod crash;
+mod crt;
+mod dataset;
+#[cfg(debug_assertions)]
+mod debug;
+mod display;
+mod effects;
+#[cfg(feature = "online")]
+mod emotes;
+pub mod ffi;
+mod game_over;
+mod gameplay_log;
+mod ghost;
+mod glide_streak;
+mod hammer;
+mod handicap;
+mod haptics;
+mod hazards;
+mod hints;
+mod input;
+#[cfg(feature = "inspector")]
+mod inspector;
+mod interpolation;
+mod kids;
+mod leaderboard;
+// Protokół gry sieciowej; bez transportu, dopóki nie ma serwera.
+#[cfg(feature = "online")]
+mod lobby;
+mod menu;
+mod milestone;
+mod mini;
+mod mirror;
+mod music;
+mod mutators;
+mod patterns;
+mod pause;
+mod physics;
+mod pipes;
+mod pixel_art;
+mod player;
+mod power;
+mod practice;
+mod procedural;
+mod profiles;
+#[cfg(feature = "online")]
+mod race_sync;
+#[cfg(feature = "remote")]
+mod remote;
+mod replay;
+mod replay_verify;
+mod rng;
+mod royale;
+mod run_save;
+mod run_stats;
+mod scheduler;
+mod score;
+mod score_digits;
+mod seed_display;
+mod seed_entry;
+mod settings;
+mod setup_wizard;
+mod share_card;
+mod sky;
+mod spectate;
+mod stamina;
+mod submission;
+#[cfg(test)]
+mod test_support;
+#[cfg(test)]
+mod tests;
+mod text_style;
+mod theme;
+mod title;
+mod toast;
+mod tournament;
+mod tower;
+mod tween;
+mod window_geometry;
+mod zones;
+
+const NORMAL_BUTTON: Color = Color::srgb(0.34, 0.34, 0.34);
+const HOVERED_BUTTON: Color = Color::srgb(0.44, 0.44, 0.44);
+const PRESSED_BUTTON: Color = Color::srgb(0.24, 0.24, 0.24);
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, States)]
+pub enum AppState {
+    // Wczytywanie obrazków przed pokazaniem menu.
+    #[default]
+    Loading,
+    // Kreator pierwszego uruchomienia (`setup_wizard.rs`), przed pierwszym menu.
+    Setup,
+    Menu,
+    Playing,
+    GameOver,
+}
+
+// Encja należąca do jednej rundy (ptak, przeszkody, strefy) – sprzątana przez
+// `despawn_run_entities` przed startem następnej. Kamera, tło i wynik zostają.
+#[derive(Component, Default)]
+struct RunEntity;
+
+// Wysyłany przez `restart_game` na starcie każdej rundy. Stan rundy (wynik,
+// statystyki, serie, odnowienia) zerują systemy, do których należy.
+#[derive(Event)]
+struct RunStarted;
+
+#[derive(Component)]
+struct Background;
+
+//
+// SYSTEMY
+//
+
+fn despawn_run_entities(mut commands: Commands, run_entities: Query<Entity, With<RunEntity>>) {
+    for entity in run_entities.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// Scena za menu i na starcie rundy powstaje tak samo: tło w motywie i ptak
+// unoszący się w miejscu.
+fn reset_scene(
+    commands: &mut Commands,
+    assets: &GameAssets,
+    theme: &Theme,
+    play_area: &PlayArea,
+    backgrounds: &Query<Entity, (With<Background>, Without<RunEntity>)>,
+) {
+    // Tło mogło odjechać przy zmianie biomu albo zamienić się w niebo z shadera.
+    for background in backgrounds.iter() {
+        commands.entity(background).insert((
+            Sprite {
+                image: assets.theme(theme).background.clone(),
+                color: theme.background_tint(),
+                custom_size: Some(play_area.size),
+                ..Default::default()
+            },
+            Transform::from_xyz(0.0, 0.0, -1.0),
+        ));
+    }
+
+    spawn_player(commands, assets);
+}
+
+// Nowa runda na stałej scenie z `setup`: świeża scena w motywie rundy,
+// widoczny licznik i ogłoszenie `RunStarted`.
+fn restart_game(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    theme: Res<ActiveTheme>,
+    play_area: Res<PlayArea>,
+    backgrounds: Query<Entity, (With<Background>, Without<RunEntity>)>,
+    mut score_text: Query<&mut Visibility, With<ScoreText>>,
+    mut run_started: EventWriter<RunStarted>,
+) {
+    reset_scene(&mut commands, &assets, &theme.0, &play_area, &backgrounds);
+    for mut visibility in score_text.iter_mut() {
+        *visibility = Visibility::Inherited;
+    }
+    run_started.send(RunStarted);
+}
+
+// System inicjalizacyjny – stała scena (kamera, wynik, tło). Ptaka wstawia
+// dopiero `setup_menu_scene` albo `restart_game`.
+fn setup(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    // mut next_state: ResMut<NextState<AppState>>,
+) {
+    // Kamera zawsze pokazuje cały świat 800x600, na innych proporcjach ekranu – więcej w poziomie.
+    commands.spawn((
+        Camera2d,
+        OrthographicProjection {
+            scaling_mode: ScalingMode::AutoMin {
+                min_width: WORLD_SIZE.x,
+                min_height: WORLD_SIZE.y,
+            },
+            ..OrthographicProjection::default_2d()
+        },
+    ));
+
+    commands.spawn((
+        ScoreDigits(0),
+        Transform::from_xyz(0.0, 250.0, 10.0),
+        Visibility::default(),
+        ScoreText,
+    ));
+
+    //Background
+    commands.spawn((
+        Sprite {
+            image: assets.theme(&Theme::Day).background.clone(),
+            custom_size: Some(WORLD_SIZE),
+            ..Default::default()
+        },
+        Transform::from_xyz(0.0, 0.0, -1.0),
+        GlobalTransform::default(),
+        Background,
+    ));
+
+    // next_state.set(AppState::Playing);
+}
+
+//
+// PLUGIN
+//
+
+// Krok `FixedUpdate` według ustawień gracza.
+fn apply_tick_rate(settings: Res<Settings>, mut fixed: ResMut<Time<Fixed>>) {
+    let timestep = settings.tick_duration();
+    if fixed.timestep() != timestep {
+        fixed.set_timestep(timestep);
+    }
+}
+
+// Cała logika gry (stany, zasoby, systemy) – bez okna i renderowania. Da się ją
+// osadzić w innej aplikacji Bevy albo uruchomić w bezgłowym App
+// (`replay_verify::headless_app`); aplikacja-gospodarz dostarcza pluginy Bevy
+// (`DefaultPlugins` albo `MinimalPlugins` ze stanami i zasobami).
+pub struct FlappyBirdPlugin;
+
+impl Plugin for FlappyBirdPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<AppState>()
+            // Encje z `StateScoped` znikają przy wyjściu ze swojego stanu.
+            .enable_state_scoped_entities::<AppState>()
+            .init_resource::<Settings>()
+            .init_resource::<ActiveProfile>()
+            .add_event::<RunStarted>()
+            .add_plugins((
+                TweenPlugin,
+                ThemePlugin,
+                SoundPlugin,
+                DisplayPlugin,
+                PausePlugin,
+                ControlsPlugin,
+                GameInputPlugin,
+                HintsPlugin,
+                ToastPlugin,
+                TextStylePlugin,
+                TitlePlugin,
+                SetupWizardPlugin,
+                HapticsPlugin,
+                InterpolationPlugin,
+                GameAssetsPlugin,
+            ))
+            // Oprawa wizualna świata.
+            .add_plugins((
+                PixelArtPlugin,
+                BiomePlugin,
+                AmbientPlugin,
+                ZonesPlugin,
+                EffectsPlugin,
+                ScoreDigitsPlugin,
+                CelebrationPlugin,
+                BlinkPlugin,
+            ))
+            .add_plugins((
+                RunRngPlugin,
+                RunSavePlugin,
+                RunStatsPlugin,
+                BestsPlugin,
+                LeaderboardPlugin,
+                SeedDisplayPlugin,
+                SeedEntryPlugin,
+                ChallengePlugin,
+                ReplayPlugin,
+                SubmissionPlugin,
+                GhostPlugin,
+                AiRacePlugin,
+                CheatsPlugin,
+                DatasetPlugin,
+                GameplayLogPlugin,
+            ))
+            .add_plugins((
+                KidsPlugin,
+                GlideStreakPlugin,
+                TournamentPlugin,
+                MirrorPlugin,
+                TowerPlugin,
+                CoopPlugin,
+                RoyalePlugin,
+                HandicapPlugin,
+                SpectatePlugin,
+                HammerPlugin,
+                BubblePlugin,
+                MilestonePlugin,
+                AdaptivePlugin,
+                PracticePlugin,
+                StaminaPlugin,
+            ))
+            .add_plugins((MutatorsPlugin, SchedulerPlugin))
+            // Rdzeń rozgrywki. Kolejność rejestracji systemów kroku stałego
+            // zostaje ta sama co przed podziałem na moduły – od niej zależy
+            // kolejność systemów bez jawnego porządku, a więc i powtórki.
+            .add_plugins((
+                PlayerPlugin,
+                PhysicsPlugin,
+                PipesPlugin,
+                ScorePlugin,
+                ButtonsPlugin,
+                MenuPlugin,
+                GameOverPlugin,
+            ))
+            .add_systems(Startup, setup.after(crate::assets::load_game_assets))
+            .add_systems(Update, apply_tick_rate.run_if(resource_changed::<Settings>))
+            .add_systems(
+                OnEnter(AppState::Playing),
+                (despawn_run_entities, restart_game)
+                    .chain()
+                    .after(crate::theme::pick_run_theme),
+            );
+
+        #[cfg(feature = "online")]
+        app.add_plugins(crate::emotes::EmotePlugin);
+    }
+}
+
+//
+// MAIN
+//
+
+// Okno gry zbudowane z zapisanych ustawień.
+fn main_window(settings: &Settings) -> Window {
+    Window {
+        title: "Flappy Bird".to_string(),
+        resolution: WindowResolution::new(settings.window.width, settings.window.height),
+        position: settings
+            .window
+            .position
+            .map(WindowPosition::At)
+            .unwrap_or(WindowPosition::Automatic),
+        mode: if settings.window.fullscreen {
+            // Konkretny monitor wybiera `DisplayPlugin`, gdy lista monitorów będzie znana.
+            WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+        } else {
+            WindowMode::Windowed
+        },
+        present_mode: if settings.window.vsync {
+            PresentMode::AutoVsync
+        } else {
+            PresentMode::AutoNoVsync
+        },
+        ..Default::default()
+    }
+}
+
+// Start gry z oknem; `main.rs` tylko to wywołuje.
+pub fn run() {
+    crate::crash::install_panic_hook();
+    if let Some(path) = crate::replay_verify::requested() {
+        std::process::exit(crate::replay_verify::verify_file(&path));
+    }
+    if let Some(path) = crate::submission::requested() {
+        std::process::exit(crate::submission::check_file(&path));
+    }
+
+    // Ustawienia czytamy przed utworzeniem okna, żeby od razu miało właściwy tryb i rozmiar.
+    let first_launch = !Settings::exists();
+    let mut settings = Settings::load();
+    let profiles = ProfileList::load(&settings);
+    let profile = profiles.active();
+    settings.cosmetics = crate::profiles::load_unlocks(&profile);
+    let mini = MiniMode::requested();
+
+    let window = if mini {
+        MiniMode::window()
+    } else {
+        main_window(&settings)
+    };
+
+    let mut app = App::new();
+    if let Some(export) = DatasetExport::requested() {
+        app.insert_resource(export);
+    }
+    if let Some(procedural) = ProceduralArt::requested() {
+        app.insert_resource(procedural);
+    }
+    if first_launch {
+        app.insert_resource(FirstLaunch);
+    }
+    if profiles.names.len() > 1 {
+        app.insert_resource(PickProfileOnStart);
+    }
+    if profiles.is_kid(&profile) {
+        app.insert_resource(KidsMode);
+    }
+    app.add_plugins(
+        DefaultPlugins
+            .set(WindowPlugin {
+                primary_window: Some(window),
+                ..Default::default()
+            })
+            .set(bevy::log::LogPlugin {
+                custom_layer: crate::gameplay_log::log_layers,
+                ..Default::default()
+            }),
+    )
+    .insert_resource(settings)
+    .insert_resource(PersonalBests::load(&profile))
+    .insert_resource(profiles)
+    .insert_resource(profile)
+    .insert_resource(TournamentSchedule::load())
+    .add_plugins((
+        FlappyBirdPlugin,
+        SettingsPersistencePlugin,
+        BestsPersistencePlugin,
+        SubmissionOutboxPlugin,
+        CrashReportPlugin,
+        ProfilesPlugin,
+        PowerPlugin,
+        crate::sky::SkyPlugin,
+        crate::milestone::MilestoneSoundPlugin,
+        crate::audio::SfxPlaybackPlugin,
+        crate::music::MusicPlugin,
+        crate::crt::CrtPlugin,
+        crate::share_card::ShareCardPlugin,
+    ));
+    #[cfg(debug_assertions)]
+    app.add_plugins((crate::debug::DebugPlugin, crate::console::ConsolePlugin));
+    #[cfg(feature = "inspector")]
+    app.add_plugins(crate::inspector::InspectorPlugin);
+    #[cfg(feature = "remote")]
+    app.add_plugins(crate::remote::RemoteControlPlugin);
+
+    if mini {
+        app.add_plugins(MiniModePlugin);
+    } else {
+        // Geometria małego okna nie powinna nadpisywać zapamiętanej geometrii zwykłego.
+        app.add_plugins(WindowGeometryPlugin);
+    }
+
+    app.run();
+}
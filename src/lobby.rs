@@ -0,0 +1,668 @@
+// Prywatne pokoje wyścigowe. Gospodarz zakłada pokój i dostaje sześcioznakowy
+// kod, znajomi dołączają po kodzie, gospodarz wybiera ziarno, poziom
+// trudności i handicapy graczy, a start dostają wszyscy naraz – z tym samym
+// opóźnieniem.
+//
+// Moduł opisuje tylko protokół: wiadomości klienta i serwera, maszynę stanów
+// pokoju po stronie serwera i widok lobby po stronie klienta. Transport
+// (WebSocket, WebRTC) jedynie przenosi te wiadomości.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::handicap::Handicap;
+use crate::settings::Difficulty;
+
+pub const ROOM_CODE_LEN: usize = 6;
+pub const MAX_PLAYERS: usize = 4;
+
+// Bez 0/O i 1/I, żeby kod dało się bezbłędnie przepisać ze słuchu.
+const ROOM_CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+// Odliczanie przed startem – wiadomość `Start` musi zdążyć dotrzeć do wszystkich.
+pub const START_DELAY_MS: u32 = 3000;
+
+pub type PlayerId = u32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RoomCode([u8; ROOM_CODE_LEN]);
+
+impl RoomCode {
+    pub fn random(rng: &mut impl Rng) -> Self {
+        let mut code = [0; ROOM_CODE_LEN];
+        for byte in &mut code {
+            *byte = ROOM_CODE_ALPHABET[rng.gen_range(0..ROOM_CODE_ALPHABET.len())];
+        }
+        Self(code)
+    }
+
+    // Kod wpisany przez gracza: wielkość liter i spacje nie mają znaczenia.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut code = [0; ROOM_CODE_LEN];
+        let mut chars = text
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase());
+        for byte in &mut code {
+            let c = chars.next()?;
+            if !c.is_ascii() || !ROOM_CODE_ALPHABET.contains(&(c as u8)) {
+                return None;
+            }
+            *byte = c as u8;
+        }
+        chars.next().is_none().then_some(Self(code))
+    }
+}
+
+impl fmt::Display for RoomCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for &byte in &self.0 {
+            write!(f, "{}", byte as char)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaceConfig {
+    pub seed: u64,
+    pub difficulty: Difficulty,
+    // Zasady battle royale: zwężające się przerwy, wygrywa ostatni w powietrzu.
+    #[serde(default)]
+    pub royale: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LobbyPlayer {
+    pub id: PlayerId,
+    pub name: String,
+    pub ready: bool,
+    #[serde(default)]
+    pub handicap: Handicap,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClientMessage {
+    CreateRoom { name: String },
+    JoinRoom { code: RoomCode, name: String },
+    // Tylko gospodarz.
+    Configure(RaceConfig),
+    Ready(bool),
+    // Gospodarz ustawia handicap każdemu, pozostali tylko sobie.
+    SetHandicap { id: PlayerId, handicap: Handicap },
+    // Tylko gospodarz, gdy wszyscy są gotowi.
+    Start,
+    Leave,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ServerMessage {
+    Joined {
+        code: RoomCode,
+        you: PlayerId,
+        host: PlayerId,
+        players: Vec<LobbyPlayer>,
+        config: RaceConfig,
+    },
+    PlayerJoined(LobbyPlayer),
+    PlayerLeft {
+        id: PlayerId,
+        // Po wyjściu gospodarza pokój przejmuje najstarszy gracz.
+        host: PlayerId,
+    },
+    ReadyChanged {
+        id: PlayerId,
+        ready: bool,
+    },
+    ConfigChanged(RaceConfig),
+    HandicapChanged {
+        id: PlayerId,
+        handicap: Handicap,
+    },
+    // Wszyscy startują `delay_ms` po otrzymaniu wiadomości.
+    Start {
+        config: RaceConfig,
+        delay_ms: u32,
+    },
+    Error(LobbyError),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LobbyError {
+    RoomNotFound,
+    RoomFull,
+    AlreadyInRoom,
+    NotInRoom,
+    NotHost,
+    NotEveryoneReady,
+    RaceInProgress,
+}
+
+impl fmt::Display for LobbyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            LobbyError::RoomNotFound => "No room with that code",
+            LobbyError::RoomFull => "The room is full",
+            LobbyError::AlreadyInRoom => "You are already in a room",
+            LobbyError::NotInRoom => "You are not in a room",
+            LobbyError::NotHost => "Only the host can do that",
+            LobbyError::NotEveryoneReady => "Not everyone is ready",
+            LobbyError::RaceInProgress => "The race has already started",
+        };
+        f.write_str(message)
+    }
+}
+
+// Stan pokoju po stronie serwera.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoomPhase {
+    Waiting,
+    Racing,
+}
+
+#[derive(Debug)]
+struct Room {
+    host: PlayerId,
+    // W kolejności dołączania.
+    players: Vec<LobbyPlayer>,
+    config: RaceConfig,
+    phase: RoomPhase,
+}
+
+impl Room {
+    fn ids(&self) -> Vec<PlayerId> {
+        self.players.iter().map(|player| player.id).collect()
+    }
+}
+
+// Wiadomość do wysłania konkretnemu graczowi.
+pub type Outgoing = (PlayerId, ServerMessage);
+
+// Serwer pokojów: przyjmuje wiadomości klientów i zwraca, co komu wysłać.
+#[derive(Debug, Default)]
+pub struct RoomServer {
+    rooms: HashMap<RoomCode, Room>,
+    player_rooms: HashMap<PlayerId, RoomCode>,
+}
+
+impl RoomServer {
+    pub fn phase(&self, code: RoomCode) -> Option<RoomPhase> {
+        self.rooms.get(&code).map(|room| room.phase)
+    }
+
+    pub fn handle(
+        &mut self,
+        from: PlayerId,
+        message: ClientMessage,
+        rng: &mut impl Rng,
+    ) -> Vec<Outgoing> {
+        match self.try_handle(from, message, rng) {
+            Ok(outgoing) => outgoing,
+            Err(err) => vec![(from, ServerMessage::Error(err))],
+        }
+    }
+
+    fn try_handle(
+        &mut self,
+        from: PlayerId,
+        message: ClientMessage,
+        rng: &mut impl Rng,
+    ) -> Result<Vec<Outgoing>, LobbyError> {
+        if matches!(
+            message,
+            ClientMessage::CreateRoom { .. } | ClientMessage::JoinRoom { .. }
+        ) && self.player_rooms.contains_key(&from)
+        {
+            return Err(LobbyError::AlreadyInRoom);
+        }
+
+        match message {
+            ClientMessage::CreateRoom { name } => {
+                let code = loop {
+                    let code = RoomCode::random(rng);
+                    if !self.rooms.contains_key(&code) {
+                        break code;
+                    }
+                };
+                let room = Room {
+                    host: from,
+                    players: vec![LobbyPlayer {
+                        id: from,
+                        name,
+                        ready: false,
+                        handicap: Handicap::default(),
+                    }],
+                    config: RaceConfig {
+                        seed: rng.next_u64(),
+                        difficulty: Difficulty::default(),
+                        royale: false,
+                    },
+                    phase: RoomPhase::Waiting,
+                };
+                let joined = joined_message(code, from, &room);
+                self.rooms.insert(code, room);
+                self.player_rooms.insert(from, code);
+                Ok(vec![(from, joined)])
+            }
+            ClientMessage::JoinRoom { code, name } => {
+                let room = self.rooms.get_mut(&code).ok_or(LobbyError::RoomNotFound)?;
+                if room.phase == RoomPhase::Racing {
+                    return Err(LobbyError::RaceInProgress);
+                }
+                if room.players.len() >= MAX_PLAYERS {
+                    return Err(LobbyError::RoomFull);
+                }
+                let player = LobbyPlayer {
+                    id: from,
+                    name,
+                    ready: false,
+                    handicap: Handicap::default(),
+                };
+                let mut outgoing: Vec<_> = room
+                    .ids()
+                    .into_iter()
+                    .map(|id| (id, ServerMessage::PlayerJoined(player.clone())))
+                    .collect();
+                room.players.push(player);
+                outgoing.push((from, joined_message(code, from, room)));
+                self.player_rooms.insert(from, code);
+                Ok(outgoing)
+            }
+            ClientMessage::Configure(config) => {
+                let room = self.room_of(from)?;
+                if room.host != from {
+                    return Err(LobbyError::NotHost);
+                }
+                if room.phase == RoomPhase::Racing {
+                    return Err(LobbyError::RaceInProgress);
+                }
+                room.config = config;
+                // Nowe warunki – każdy musi jeszcze raz potwierdzić gotowość.
+                for player in &mut room.players {
+                    player.ready = false;
+                }
+                Ok(broadcast(room, ServerMessage::ConfigChanged(config)))
+            }
+            ClientMessage::Ready(ready) => {
+                let room = self.room_of(from)?;
+                if room.phase == RoomPhase::Racing {
+                    return Err(LobbyError::RaceInProgress);
+                }
+                if let Some(player) = room.players.iter_mut().find(|player| player.id == from) {
+                    player.ready = ready;
+                }
+                Ok(broadcast(
+                    room,
+                    ServerMessage::ReadyChanged { id: from, ready },
+                ))
+            }
+            ClientMessage::SetHandicap { id, handicap } => {
+                let room = self.room_of(from)?;
+                if room.host != from && id != from {
+                    return Err(LobbyError::NotHost);
+                }
+                if room.phase == RoomPhase::Racing {
+                    return Err(LobbyError::RaceInProgress);
+                }
+                let handicap = handicap.clamped();
+                let player = room
+                    .players
+                    .iter_mut()
+                    .find(|player| player.id == id)
+                    .ok_or(LobbyError::NotInRoom)?;
+                player.handicap = handicap;
+                // Jak przy zmianie warunków – gotowość trzeba potwierdzić od nowa.
+                for player in &mut room.players {
+                    player.ready = false;
+                }
+                Ok(broadcast(
+                    room,
+                    ServerMessage::HandicapChanged { id, handicap },
+                ))
+            }
+            ClientMessage::Start => {
+                let room = self.room_of(from)?;
+                if room.host != from {
+                    return Err(LobbyError::NotHost);
+                }
+                if room.phase == RoomPhase::Racing {
+                    return Err(LobbyError::RaceInProgress);
+                }
+                // Gospodarz startem potwierdza własną gotowość.
+                if room
+                    .players
+                    .iter()
+                    .any(|player| player.id != from && !player.ready)
+                {
+                    return Err(LobbyError::NotEveryoneReady);
+                }
+                room.phase = RoomPhase::Racing;
+                Ok(broadcast(
+                    room,
+                    ServerMessage::Start {
+                        config: room.config,
+                        delay_ms: START_DELAY_MS,
+                    },
+                ))
+            }
+            ClientMessage::Leave => Ok(self.leave(from)),
+        }
+    }
+
+    // Gracz wychodzi (albo zerwało mu połączenie).
+    pub fn leave(&mut self, id: PlayerId) -> Vec<Outgoing> {
+        let Some(code) = self.player_rooms.remove(&id) else {
+            return Vec::new();
+        };
+        let Some(room) = self.rooms.get_mut(&code) else {
+            return Vec::new();
+        };
+        room.players.retain(|player| player.id != id);
+        let Some(first) = room.players.first() else {
+            self.rooms.remove(&code);
+            return Vec::new();
+        };
+        if room.host == id {
+            room.host = first.id;
+        }
+        broadcast(
+            room,
+            ServerMessage::PlayerLeft {
+                id,
+                host: room.host,
+            },
+        )
+    }
+
+    fn room_of(&mut self, id: PlayerId) -> Result<&mut Room, LobbyError> {
+        self.player_rooms
+            .get(&id)
+            .and_then(|code| self.rooms.get_mut(code))
+            .ok_or(LobbyError::NotInRoom)
+    }
+}
+
+fn joined_message(code: RoomCode, you: PlayerId, room: &Room) -> ServerMessage {
+    ServerMessage::Joined {
+        code,
+        you,
+        host: room.host,
+        players: room.players.clone(),
+        config: room.config,
+    }
+}
+
+fn broadcast(room: &Room, message: ServerMessage) -> Vec<Outgoing> {
+    room.ids()
+        .into_iter()
+        .map(|id| (id, message.clone()))
+        .collect()
+}
+
+// Widok lobby po stronie klienta, budowany z wiadomości serwera.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum LobbyState {
+    #[default]
+    Idle,
+    InRoom(RoomView),
+    // Start ogłoszony; runda rusza po `delay_ms`.
+    Starting {
+        config: RaceConfig,
+        delay_ms: u32,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoomView {
+    pub code: RoomCode,
+    pub you: PlayerId,
+    pub host: PlayerId,
+    pub players: Vec<LobbyPlayer>,
+    pub config: RaceConfig,
+}
+
+impl RoomView {
+    pub fn is_host(&self) -> bool {
+        self.you == self.host
+    }
+}
+
+impl LobbyState {
+    // Aktualizuje widok; błąd serwera wraca do wyświetlenia graczowi.
+    pub fn apply(&mut self, message: ServerMessage) -> Result<(), LobbyError> {
+        match message {
+            ServerMessage::Error(err) => return Err(err),
+            ServerMessage::Joined {
+                code,
+                you,
+                host,
+                players,
+                config,
+            } => {
+                *self = LobbyState::InRoom(RoomView {
+                    code,
+                    you,
+                    host,
+                    players,
+                    config,
+                });
+            }
+            ServerMessage::Start { config, delay_ms } => {
+                *self = LobbyState::Starting { config, delay_ms };
+            }
+            message => {
+                let LobbyState::InRoom(room) = self else {
+                    return Ok(());
+                };
+                match message {
+                    ServerMessage::PlayerJoined(player) => room.players.push(player),
+                    ServerMessage::PlayerLeft { id, host } => {
+                        room.players.retain(|player| player.id != id);
+                        room.host = host;
+                    }
+                    ServerMessage::ReadyChanged { id, ready } => {
+                        if let Some(player) = room.players.iter_mut().find(|player| player.id == id)
+                        {
+                            player.ready = ready;
+                        }
+                    }
+                    ServerMessage::ConfigChanged(config) => {
+                        room.config = config;
+                        for player in &mut room.players {
+                            player.ready = false;
+                        }
+                    }
+                    ServerMessage::HandicapChanged { id, handicap } => {
+                        for player in &mut room.players {
+                            if player.id == id {
+                                player.handicap = handicap;
+                            }
+                            player.ready = false;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use super::*;
+
+    fn deliver(clients: &mut HashMap<PlayerId, LobbyState>, outgoing: Vec<Outgoing>) {
+        for (id, message) in outgoing {
+            clients
+                .entry(id)
+                .or_default()
+                .apply(message)
+                .expect("server rejected a message");
+        }
+    }
+
+    #[test]
+    fn room_codes_parse_loosely() {
+        let code = RoomCode::random(&mut StdRng::seed_from_u64(1));
+        let typed = code.to_string().to_lowercase();
+
+        assert_eq!(RoomCode::parse(&typed), Some(code));
+        assert_eq!(RoomCode::parse("abc 234"), RoomCode::parse("ABC234"));
+        assert_eq!(RoomCode::parse("ABC10O"), None);
+        assert_eq!(RoomCode::parse("ABC23"), None);
+    }
+
+    #[test]
+    fn everyone_starts_with_the_host_config() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut server = RoomServer::default();
+        let mut clients = HashMap::new();
+
+        let created = server.handle(
+            1,
+            ClientMessage::CreateRoom {
+                name: "Host".into(),
+            },
+            &mut rng,
+        );
+        deliver(&mut clients, created);
+        let LobbyState::InRoom(room) = &clients[&1] else {
+            panic!("host is not in a room");
+        };
+        let code = room.code;
+
+        let joined = server.handle(
+            2,
+            ClientMessage::JoinRoom {
+                code,
+                name: "Guest".into(),
+            },
+            &mut rng,
+        );
+        deliver(&mut clients, joined);
+        let config = RaceConfig {
+            seed: 0xfeed,
+            difficulty: Difficulty::Hard,
+            royale: true,
+        };
+        let configured = server.handle(1, ClientMessage::Configure(config), &mut rng);
+        deliver(&mut clients, configured);
+
+        assert_eq!(
+            server.handle(1, ClientMessage::Start, &mut rng),
+            vec![(1, ServerMessage::Error(LobbyError::NotEveryoneReady))]
+        );
+        let ready = server.handle(2, ClientMessage::Ready(true), &mut rng);
+        deliver(&mut clients, ready);
+        let started = server.handle(1, ClientMessage::Start, &mut rng);
+        deliver(&mut clients, started);
+
+        let expected = LobbyState::Starting {
+            config,
+            delay_ms: START_DELAY_MS,
+        };
+        assert_eq!(clients[&1], expected);
+        assert_eq!(clients[&2], expected);
+        assert_eq!(server.phase(code), Some(RoomPhase::Racing));
+    }
+
+    #[test]
+    fn handicaps_are_set_per_player() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut server = RoomServer::default();
+        let mut clients = HashMap::new();
+
+        let created = server.handle(
+            1,
+            ClientMessage::CreateRoom {
+                name: "Parent".into(),
+            },
+            &mut rng,
+        );
+        deliver(&mut clients, created);
+        let LobbyState::InRoom(room) = &clients[&1] else {
+            panic!("host is not in a room");
+        };
+        let code = room.code;
+        let joined = server.handle(
+            2,
+            ClientMessage::JoinRoom {
+                code,
+                name: "Kid".into(),
+            },
+            &mut rng,
+        );
+        deliver(&mut clients, joined);
+
+        let handicap = Handicap {
+            gap_percent: 150,
+            gravity_percent: 70,
+        };
+        assert_eq!(
+            server.handle(2, ClientMessage::SetHandicap { id: 1, handicap }, &mut rng),
+            vec![(2, ServerMessage::Error(LobbyError::NotHost))]
+        );
+        let changed = server.handle(1, ClientMessage::SetHandicap { id: 2, handicap }, &mut rng);
+        deliver(&mut clients, changed);
+
+        for id in [1, 2] {
+            let LobbyState::InRoom(room) = &clients[&id] else {
+                panic!("player {id} is not in a room");
+            };
+            let handicaps: Vec<_> = room.players.iter().map(|player| player.handicap).collect();
+            assert_eq!(handicaps, [Handicap::default(), handicap]);
+        }
+    }
+
+    #[test]
+    fn host_leaving_hands_the_room_over() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut server = RoomServer::default();
+        let mut clients = HashMap::new();
+
+        let created = server.handle(1, ClientMessage::CreateRoom { name: "A".into() }, &mut rng);
+        deliver(&mut clients, created);
+        let LobbyState::InRoom(room) = &clients[&1] else {
+            panic!("host is not in a room");
+        };
+        let code = room.code;
+        for id in 2..=4 {
+            let joined = server.handle(
+                id,
+                ClientMessage::JoinRoom {
+                    code,
+                    name: format!("P{id}"),
+                },
+                &mut rng,
+            );
+            deliver(&mut clients, joined);
+        }
+        assert_eq!(
+            server.handle(
+                5,
+                ClientMessage::JoinRoom {
+                    code,
+                    name: "Late".into()
+                },
+                &mut rng
+            ),
+            vec![(5, ServerMessage::Error(LobbyError::RoomFull))]
+        );
+
+        let left = server.handle(1, ClientMessage::Leave, &mut rng);
+        deliver(&mut clients, left);
+
+        let LobbyState::InRoom(room) = &clients[&2] else {
+            panic!("guest is not in a room");
+        };
+        assert!(room.is_host());
+        assert_eq!(room.players.len(), 3);
+    }
+}
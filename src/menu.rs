@@ -0,0 +1,408 @@
+// Menu główne na żywej scenie gry. Przyciski trybów i ustawień dokładają
+// moduły, do których należą (`.after(crate::menu::setup_menu)`).
+
+use bevy::prelude::*;
+
+use crate::adaptive::AdaptiveButton;
+use crate::ai_race::{RivalSkillButton, RivalsButton};
+use crate::assets::GameAssets;
+use crate::buttons::{ButtonAction, ButtonPressed};
+use crate::controls::ControlsButton;
+use crate::crt::CrtButton;
+use crate::display::{DisplayButton, PlayArea};
+use crate::effects::VisualEffectsButton;
+use crate::handicap::HandicapButton;
+use crate::kids::ParentalLock;
+use crate::leaderboard::LeaderboardButton;
+use crate::profiles::ActiveProfile;
+use crate::run_save::ResumeRunButton;
+use crate::score::ScoreText;
+use crate::settings::Settings;
+use crate::theme::{ActiveTheme, ThemeButton};
+use crate::{AppState, Background, NORMAL_BUTTON, RunEntity, despawn_run_entities, reset_scene};
+
+// Cały interfejs menu; znika sam przy wyjściu z menu (`StateScoped`).
+#[derive(Component)]
+#[require(StateScoped<AppState>(|| StateScoped(AppState::Menu)))]
+pub struct Menu;
+
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(AppState::Menu),
+            ((despawn_run_entities, setup_menu_scene).chain(), setup_menu),
+        )
+        .add_systems(
+            Update,
+            start_on_press
+                .after(crate::buttons::button_interaction_system)
+                .run_if(in_state(AppState::Menu)),
+        );
+    }
+}
+
+fn start_on_press(
+    mut pressed: EventReader<ButtonPressed>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if pressed
+        .read()
+        .any(|event| event.0 == ButtonAction::StartGame)
+    {
+        next_state.set(AppState::Playing);
+    }
+}
+
+pub fn setup_menu(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    assets: Res<GameAssets>,
+    profile: Res<ActiveProfile>,
+) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(20.0),
+                ..Default::default()
+            },
+            // Przyciemnione tło gry zamiast jednolitego szarego panelu
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.35)),
+            Menu,
+        ))
+        .with_children(|parent| {
+            crate::title::spawn_title_logo(parent, &assets, &settings);
+            // Przycisk "Start Game"
+            parent
+                .spawn((
+                    Button,
+                    Interaction::default(),
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(80.0),
+                        border: UiRect::all(Val::Px(5.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(NORMAL_BUTTON),
+                    BorderColor(Color::BLACK),
+                    BorderRadius::MAX,
+                    ButtonAction::StartGame,
+                ))
+                .with_child((Text::new("Start Game"), TextColor(Color::WHITE)));
+            // Przycisk "Resume run" – widoczny tylko, gdy profil ma zapisaną rundę
+            parent
+                .spawn((
+                    Button,
+                    Interaction::default(),
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(80.0),
+                        border: UiRect::all(Val::Px(5.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        display: crate::run_save::resume_button_display(&profile),
+                        ..Default::default()
+                    },
+                    BackgroundColor(NORMAL_BUTTON),
+                    BorderColor(Color::BLACK),
+                    BorderRadius::MAX,
+                    ResumeRunButton,
+                ))
+                .with_child((Text::new("Resume run"), TextColor(Color::WHITE)));
+            // Przycisk wyboru motywu
+            parent
+                .spawn((
+                    Button,
+                    Interaction::default(),
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(80.0),
+                        border: UiRect::all(Val::Px(5.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(NORMAL_BUTTON),
+                    BorderColor(Color::BLACK),
+                    BorderRadius::MAX,
+                    ThemeButton,
+                    ParentalLock::default(),
+                ))
+                .with_child((
+                    Text::new(crate::theme::theme_button_label(&settings.theme)),
+                    TextColor(Color::WHITE),
+                ));
+            // Przycisk wyboru monitora
+            parent
+                .spawn((
+                    Button,
+                    Interaction::default(),
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(80.0),
+                        border: UiRect::all(Val::Px(5.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(NORMAL_BUTTON),
+                    BorderColor(Color::BLACK),
+                    BorderRadius::MAX,
+                    DisplayButton,
+                    ParentalLock::default(),
+                ))
+                .with_child((
+                    Text::new(crate::display::display_button_label(&settings)),
+                    TextColor(Color::WHITE),
+                ));
+            // Przycisk wyboru sterowania
+            parent
+                .spawn((
+                    Button,
+                    Interaction::default(),
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(80.0),
+                        border: UiRect::all(Val::Px(5.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(NORMAL_BUTTON),
+                    BorderColor(Color::BLACK),
+                    BorderRadius::MAX,
+                    ControlsButton,
+                    ParentalLock::default(),
+                ))
+                .with_child((
+                    Text::new(crate::controls::controls_button_label(&settings)),
+                    TextColor(Color::WHITE),
+                ));
+            // Efekty obrazu, filtr CRT i adaptacyjna trudność w jednym rzędzie
+            parent
+                .spawn(Node {
+                    column_gap: Val::Px(10.0),
+                    ..Default::default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Button,
+                        Interaction::default(),
+                        Node {
+                            width: Val::Px(95.0),
+                            height: Val::Px(80.0),
+                            border: UiRect::all(Val::Px(5.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        BackgroundColor(NORMAL_BUTTON),
+                        BorderColor(Color::BLACK),
+                        BorderRadius::MAX,
+                        VisualEffectsButton,
+                        ParentalLock::default(),
+                    ))
+                    .with_child((
+                        Text::new(crate::effects::visual_effects_button_label(&settings)),
+                        TextColor(Color::WHITE),
+                        TextFont {
+                            font_size: 14.0,
+                            ..Default::default()
+                        },
+                    ));
+                    row.spawn((
+                        Button,
+                        Interaction::default(),
+                        Node {
+                            width: Val::Px(95.0),
+                            height: Val::Px(80.0),
+                            border: UiRect::all(Val::Px(5.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        BackgroundColor(NORMAL_BUTTON),
+                        BorderColor(Color::BLACK),
+                        BorderRadius::MAX,
+                        CrtButton,
+                        ParentalLock::default(),
+                    ))
+                    .with_child((
+                        Text::new(crate::crt::crt_button_label(&settings)),
+                        TextColor(Color::WHITE),
+                        TextFont {
+                            font_size: 14.0,
+                            ..Default::default()
+                        },
+                    ));
+                    row.spawn((
+                        Button,
+                        Interaction::default(),
+                        Node {
+                            width: Val::Px(95.0),
+                            height: Val::Px(80.0),
+                            border: UiRect::all(Val::Px(5.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        BackgroundColor(NORMAL_BUTTON),
+                        BorderColor(Color::BLACK),
+                        BorderRadius::MAX,
+                        AdaptiveButton,
+                        ParentalLock::default(),
+                    ))
+                    .with_child((
+                        Text::new(crate::adaptive::adaptive_button_label(&settings)),
+                        TextColor(Color::WHITE),
+                        TextFont {
+                            font_size: 14.0,
+                            ..Default::default()
+                        },
+                    ));
+                });
+            // Rywale AI: liczba, poziom i handicap gracza w jednym rzędzie
+            parent
+                .spawn(Node {
+                    column_gap: Val::Px(10.0),
+                    ..Default::default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Button,
+                        Interaction::default(),
+                        Node {
+                            width: Val::Px(95.0),
+                            height: Val::Px(80.0),
+                            border: UiRect::all(Val::Px(5.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        BackgroundColor(NORMAL_BUTTON),
+                        BorderColor(Color::BLACK),
+                        BorderRadius::MAX,
+                        RivalsButton,
+                    ))
+                    .with_child((
+                        Text::new(crate::ai_race::rivals_button_label(&settings)),
+                        TextColor(Color::WHITE),
+                        TextFont {
+                            font_size: 14.0,
+                            ..Default::default()
+                        },
+                    ));
+                    row.spawn((
+                        Button,
+                        Interaction::default(),
+                        Node {
+                            width: Val::Px(95.0),
+                            height: Val::Px(80.0),
+                            border: UiRect::all(Val::Px(5.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        BackgroundColor(NORMAL_BUTTON),
+                        BorderColor(Color::BLACK),
+                        BorderRadius::MAX,
+                        RivalSkillButton,
+                    ))
+                    .with_child((
+                        Text::new(crate::ai_race::rival_skill_button_label(&settings)),
+                        TextColor(Color::WHITE),
+                        TextFont {
+                            font_size: 14.0,
+                            ..Default::default()
+                        },
+                    ));
+                    row.spawn((
+                        Button,
+                        Interaction::default(),
+                        Node {
+                            width: Val::Px(95.0),
+                            height: Val::Px(80.0),
+                            border: UiRect::all(Val::Px(5.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        BackgroundColor(NORMAL_BUTTON),
+                        BorderColor(Color::BLACK),
+                        BorderRadius::MAX,
+                        HandicapButton,
+                    ))
+                    .with_child((
+                        Text::new(crate::handicap::handicap_button_label(&settings)),
+                        TextColor(Color::WHITE),
+                        TextFont {
+                            font_size: 14.0,
+                            ..Default::default()
+                        },
+                    ));
+                });
+            // Przycisk tablicy wyników
+            parent
+                .spawn((
+                    Button,
+                    Interaction::default(),
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(80.0),
+                        border: UiRect::all(Val::Px(5.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(NORMAL_BUTTON),
+                    BorderColor(Color::BLACK),
+                    BorderRadius::MAX,
+                    LeaderboardButton,
+                ))
+                .with_child((Text::new("Leaderboard"), TextColor(Color::WHITE)));
+            // Przycisk "Exit"
+            parent
+                .spawn((
+                    Button,
+                    Interaction::default(),
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(80.0),
+                        border: UiRect::all(Val::Px(5.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(Color::srgb(0.34, 0.34, 0.34)),
+                    BorderColor(Color::BLACK),
+                    BorderRadius::MAX,
+                    ButtonAction::Exit,
+                    ParentalLock::default(),
+                ))
+                .with_child((Text::new("Exit"), TextColor(Color::WHITE)));
+        });
+}
+
+// Menu leży na żywej scenie gry, przyciemnionej nakładką z `setup_menu`.
+// Licznik wyniku chowamy do następnej rundy.
+fn setup_menu_scene(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    theme: Res<ActiveTheme>,
+    play_area: Res<PlayArea>,
+    backgrounds: Query<Entity, (With<Background>, Without<RunEntity>)>,
+    mut score_text: Query<&mut Visibility, With<ScoreText>>,
+) {
+    reset_scene(&mut commands, &assets, &theme.0, &play_area, &backgrounds);
+    for mut visibility in score_text.iter_mut() {
+        *visibility = Visibility::Hidden;
+    }
+}
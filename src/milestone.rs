@@ -0,0 +1,312 @@
+// Kamienie milowe: co `MILESTONE_EVERY` punktów krótka chwila chwały –
+// zwolnione tempo, złoty błysk licznika i fanfara. `detect_milestones` słucha
+// zdarzeń punktowych i ogłasza `MilestoneReached`; na to zdarzenie reagują
+// osobne systemy efektów.
+//
+// Zwolnione tempo zmienia tylko szybkość czasu wirtualnego – krok symulacji
+// zostaje ten sam, więc powtórki i wyniki się nie zmieniają.
+
+use bevy::audio::{AddAudioSource, Decodable, Source, Volume};
+use bevy::math::curve::EaseFunction;
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::pause::PauseState;
+use crate::score::{PointScored, Score, ScoreText};
+use crate::settings::Settings;
+use crate::tween::ScaleTween;
+
+pub const MILESTONE_EVERY: i32 = 25;
+
+// Zwolnione tempo trwa tyle sekund czasu rzeczywistego.
+const SLOW_MOTION_SECS: f32 = 0.3;
+const SLOW_MOTION_SPEED: f32 = 0.35;
+const GLOW_SECS: f32 = 0.8;
+const GLOW_COLOR: Color = Color::srgb(1.0, 0.8, 0.15);
+const POP_SCALE: f32 = 1.4;
+
+// Fanfara: rosnące arpeggio C-dur (częstotliwość w Hz, długość w sekundach).
+const FANFARE_NOTES: [(f32, f32); 4] = [
+    (523.25, 0.09),
+    (659.25, 0.09),
+    (783.99, 0.09),
+    (1046.5, 0.3),
+];
+const FANFARE_SAMPLE_RATE: u32 = 44_100;
+
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MilestoneReached;
+
+// Czy wynik przekroczył kolejną wielokrotność `MILESTONE_EVERY` (mnożnik serii
+// może przeskoczyć próg, więc nie wystarczy sprawdzić reszty z dzielenia).
+pub fn crossed_milestone(before: i32, after: i32) -> bool {
+    after > before && after.div_euclid(MILESTONE_EVERY) > before.max(0).div_euclid(MILESTONE_EVERY)
+}
+
+// Trwające zwolnione tempo i szybkość czasu sprzed niego.
+#[derive(Resource, Debug)]
+pub struct SlowMotion {
+    remaining: f32,
+    previous: f32,
+}
+
+// Złoty błysk licznika wyniku.
+#[derive(Component)]
+struct ScoreGlow {
+    elapsed: f32,
+}
+
+pub struct MilestonePlugin;
+
+impl Plugin for MilestonePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<MilestoneReached>()
+            .add_systems(
+                FixedUpdate,
+                detect_milestones
+                    .after(crate::score::apply_score_events)
+                    .run_if(in_state(PauseState::Running)),
+            )
+            .add_systems(
+                Update,
+                (
+                    start_slow_motion,
+                    end_slow_motion
+                        .after(start_slow_motion)
+                        .run_if(resource_exists::<SlowMotion>),
+                    glow_score,
+                    fade_score_glow.after(crate::score_digits::compose_score_digits),
+                ),
+            )
+            .add_systems(
+                OnExit(AppState::Playing),
+                restore_time_speed.run_if(resource_exists::<SlowMotion>),
+            );
+    }
+}
+
+fn detect_milestones(
+    mut scored: EventReader<PointScored>,
+    score: Res<Score>,
+    mut last: Local<i32>,
+    mut reached: EventWriter<MilestoneReached>,
+) {
+    let scored_now = scored.read().count() > 0;
+    if scored_now && crossed_milestone(*last, score.0) {
+        reached.send(MilestoneReached);
+    }
+    *last = score.0;
+}
+
+fn start_slow_motion(
+    mut commands: Commands,
+    mut reached: EventReader<MilestoneReached>,
+    slow_motion: Option<ResMut<SlowMotion>>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    if reached.read().last().is_none() {
+        return;
+    }
+    match slow_motion {
+        Some(mut slow_motion) => slow_motion.remaining = SLOW_MOTION_SECS,
+        None => commands.insert_resource(SlowMotion {
+            remaining: SLOW_MOTION_SECS,
+            previous: time.relative_speed(),
+        }),
+    }
+    time.set_relative_speed(SLOW_MOTION_SPEED);
+}
+
+fn end_slow_motion(
+    mut commands: Commands,
+    real: Res<Time<Real>>,
+    mut slow_motion: ResMut<SlowMotion>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    slow_motion.remaining -= real.delta_secs();
+    if slow_motion.remaining <= 0.0 {
+        time.set_relative_speed(slow_motion.previous);
+        commands.remove_resource::<SlowMotion>();
+    }
+}
+
+// Śmierć w trakcie zwolnionego tempa nie może go przenieść na ekran końca gry.
+pub fn restore_time_speed(
+    mut commands: Commands,
+    slow_motion: Res<SlowMotion>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    time.set_relative_speed(slow_motion.previous);
+    commands.remove_resource::<SlowMotion>();
+}
+
+fn glow_score(
+    mut commands: Commands,
+    mut reached: EventReader<MilestoneReached>,
+    settings: Res<Settings>,
+    score_text: Query<Entity, With<ScoreText>>,
+) {
+    if reached.read().last().is_none() {
+        return;
+    }
+    for entity in score_text.iter() {
+        let mut widget = commands.entity(entity);
+        widget.insert(ScoreGlow { elapsed: 0.0 });
+        if !settings.accessibility.reduced_motion {
+            widget.insert(ScaleTween::new(
+                Vec3::splat(POP_SCALE),
+                Vec3::ONE,
+                GLOW_SECS / 2.0,
+                EaseFunction::BackOut,
+            ));
+        }
+    }
+}
+
+// Cyfry przebudowują się przy każdej zmianie wyniku, więc barwimy je co klatkę
+// aż do końca błysku, zamiast raz dodać `Blink`.
+fn fade_score_glow(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut glowing: Query<(Entity, &mut ScoreGlow, &Children)>,
+    mut digits: Query<&mut Sprite>,
+) {
+    for (entity, mut glow, children) in glowing.iter_mut() {
+        glow.elapsed += time.delta_secs();
+        let t = (glow.elapsed / GLOW_SECS).min(1.0);
+        let color = GLOW_COLOR.mix(&Color::WHITE, t);
+        let mut iter = digits.iter_many_mut(children);
+        while let Some(mut sprite) = iter.fetch_next() {
+            sprite.color = color;
+        }
+        if t >= 1.0 {
+            commands.entity(entity).remove::<ScoreGlow>();
+        }
+    }
+}
+
+// Fanfara generowana w kodzie – gra nie ma plików dźwiękowych. Rejestrowana
+// tylko w oknie gry (jak niebo w `sky.rs`); bezgłowe środowiska nie mają
+// wyjścia audio.
+pub struct MilestoneSoundPlugin;
+
+impl Plugin for MilestoneSoundPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_audio_source::<Fanfare>()
+            .add_systems(Startup, create_fanfare)
+            .add_systems(Update, play_fanfare);
+    }
+}
+
+#[derive(Asset, TypePath)]
+struct Fanfare;
+
+#[derive(Resource)]
+struct FanfareHandle(Handle<Fanfare>);
+
+struct FanfareDecoder {
+    sample: u32,
+    total: u32,
+}
+
+impl FanfareDecoder {
+    fn new() -> Self {
+        let secs: f32 = FANFARE_NOTES.iter().map(|(_, length)| length).sum();
+        Self {
+            sample: 0,
+            total: (secs * FANFARE_SAMPLE_RATE as f32) as u32,
+        }
+    }
+}
+
+impl Iterator for FanfareDecoder {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if self.sample >= self.total {
+            return None;
+        }
+        let t = self.sample as f32 / FANFARE_SAMPLE_RATE as f32;
+        self.sample += 1;
+
+        // Nuta grana w chwili `t` i czas od jej początku.
+        let mut start = 0.0;
+        for (frequency, length) in FANFARE_NOTES {
+            if t < start + length {
+                let local = t - start;
+                let envelope = (1.0 - local / length).powi(2);
+                let tone = (std::f32::consts::TAU * frequency * t).sin();
+                return Some(0.3 * envelope * tone.signum() * tone.abs().sqrt());
+            }
+            start += length;
+        }
+        Some(0.0)
+    }
+}
+
+impl Source for FanfareDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        FANFARE_SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<std::time::Duration> {
+        None
+    }
+}
+
+impl Decodable for Fanfare {
+    type DecoderItem = f32;
+    type Decoder = FanfareDecoder;
+
+    fn decoder(&self) -> Self::Decoder {
+        FanfareDecoder::new()
+    }
+}
+
+fn create_fanfare(mut commands: Commands, mut fanfares: ResMut<Assets<Fanfare>>) {
+    commands.insert_resource(FanfareHandle(fanfares.add(Fanfare)));
+}
+
+fn play_fanfare(
+    mut commands: Commands,
+    mut reached: EventReader<MilestoneReached>,
+    fanfare: Res<FanfareHandle>,
+    settings: Res<Settings>,
+) {
+    if reached.read().last().is_none() {
+        return;
+    }
+    commands.spawn((
+        AudioPlayer(fanfare.0.clone()),
+        PlaybackSettings::DESPAWN.with_volume(Volume::new(settings.volume.sfx)),
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn milestones_fire_once_per_threshold_even_when_skipped_over() {
+        assert!(!crossed_milestone(0, 24));
+        assert!(crossed_milestone(24, 25));
+        assert!(!crossed_milestone(25, 26));
+        assert!(crossed_milestone(48, 52));
+        assert!(!crossed_milestone(30, 0));
+    }
+
+    #[test]
+    fn fanfare_is_short_and_ends() {
+        let samples = FanfareDecoder::new().count();
+        assert!(samples > 0);
+        assert!(samples < FANFARE_SAMPLE_RATE as usize);
+    }
+}
@@ -0,0 +1,67 @@
+// Tryb `--mini`: małe, przezroczyste okno bez ramki, zawsze na wierzchu –
+// można grać "nad" innymi aplikacjami.
+
+use bevy::prelude::*;
+use bevy::window::{CompositeAlphaMode, PrimaryWindow, WindowLevel, WindowResolution};
+
+use crate::Background;
+
+pub const MINI_FLAG: &str = "--mini";
+
+// Skala okna względem świata gry 800x600.
+const MINI_SCALE: f32 = 0.5;
+
+#[derive(Resource)]
+pub struct MiniMode;
+
+impl MiniMode {
+    pub fn requested() -> bool {
+        std::env::args().any(|arg| arg == MINI_FLAG)
+    }
+
+    // Okno trybu mini zamiast zwykłego okna gry.
+    pub fn window() -> Window {
+        Window {
+            title: "Flappy Bird".to_string(),
+            resolution: WindowResolution::new(800.0 * MINI_SCALE, 600.0 * MINI_SCALE),
+            transparent: true,
+            decorations: false,
+            window_level: WindowLevel::AlwaysOnTop,
+            composite_alpha_mode: CompositeAlphaMode::PostMultiplied,
+            ..Default::default()
+        }
+    }
+}
+
+pub struct MiniModePlugin;
+
+impl Plugin for MiniModePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(MiniMode)
+            .insert_resource(ClearColor(Color::NONE))
+            // Kompaktowy interfejs: przyciski i napisy w połowie rozmiaru.
+            .insert_resource(UiScale(MINI_SCALE))
+            .add_systems(Update, (hide_background, drag_window));
+    }
+}
+
+// Tło zasłoniłoby pulpit – w trybie mini widać tylko ptaka i rury.
+fn hide_background(mut query: Query<&mut Visibility, Added<Background>>) {
+    for mut visibility in query.iter_mut() {
+        *visibility = Visibility::Hidden;
+    }
+}
+
+// Okno bez ramki przesuwamy, przeciągając je prawym przyciskiem myszy.
+fn drag_window(
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if !mouse.just_pressed(MouseButton::Right) {
+        return;
+    }
+
+    if let Ok(mut window) = window_query.get_single_mut() {
+        window.start_drag_move();
+    }
+}
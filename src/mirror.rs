@@ -0,0 +1,131 @@
+// Tryb chaosu "Mirror": kolejne fale przeszkód na przemian nadlatują z prawej
+// i z lewej krawędzi ekranu. Ptak i tak stoi w środkowej kolumnie, a strefy
+// punktowe i czas życia rur nie zależą od kierunku lotu, więc wystarczy, że
+// `PipeSpawner` pyta `MirrorRun` o stronę następnej fali.
+//
+// Przycisk w lewym górnym rogu menu startuje rundę; rundy zapisujemy jako
+// tryb `GameMode::Mirror`. Powtórek tych rund nie nagrywamy – weryfikacja
+// odtwarza zwykłe fale z prawej.
+
+use bevy::prelude::*;
+
+use crate::bests::{GameMode, RunMode};
+use crate::menu::Menu;
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+// Obecny, gdy gracz wybrał tryb lustrzany; znika po powrocie do menu.
+#[derive(Resource, Default)]
+pub struct MirrorRun {
+    waves: u32,
+}
+
+impl MirrorRun {
+    // Strona następnej fali: 1.0 to prawa krawędź, -1.0 lewa.
+    pub fn next_side(&mut self) -> f32 {
+        let side = if self.waves % 2 == 0 { 1.0 } else { -1.0 };
+        self.waves += 1;
+        side
+    }
+}
+
+#[derive(Component)]
+struct MirrorButton;
+
+pub struct MirrorPlugin;
+
+impl Plugin for MirrorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(AppState::Menu),
+            (
+                end_mirror_run,
+                spawn_mirror_button.after(crate::menu::setup_menu),
+            ),
+        )
+        .add_systems(
+            Update,
+            mirror_button_system.run_if(in_state(AppState::Menu)),
+        )
+        .add_systems(
+            OnEnter(AppState::Playing),
+            start_mirror_run.run_if(resource_exists::<MirrorRun>),
+        );
+    }
+}
+
+// Każda runda zaczyna od fali z prawej, jak w zwykłej grze.
+fn start_mirror_run(mut mirror: ResMut<MirrorRun>, mut mode: ResMut<RunMode>) {
+    *mirror = MirrorRun::default();
+    mode.0 = GameMode::Mirror;
+}
+
+fn end_mirror_run(
+    mut commands: Commands,
+    mirror: Option<Res<MirrorRun>>,
+    mut mode: ResMut<RunMode>,
+) {
+    if mirror.is_none() {
+        return;
+    }
+    commands.remove_resource::<MirrorRun>();
+    mode.0 = GameMode::Classic;
+}
+
+fn spawn_mirror_button(mut commands: Commands) {
+    commands
+        .spawn((
+            Button,
+            Interaction::default(),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(20.0),
+                top: Val::Px(20.0),
+                padding: UiRect::axes(Val::Px(16.0), Val::Px(10.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(NORMAL_BUTTON),
+            BorderRadius::all(Val::Px(8.0)),
+            MirrorButton,
+            Menu,
+        ))
+        .with_child((Text::new("Mirror mode"), TextColor(Color::WHITE)));
+}
+
+fn mirror_button_system(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<MirrorButton>),
+    >,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, mut bg_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                commands.init_resource::<MirrorRun>();
+                next_state.set(AppState::Playing);
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn waves_alternate_starting_from_the_right() {
+        let mut mirror = MirrorRun::default();
+        let sides: Vec<f32> = (0..4).map(|_| mirror.next_side()).collect();
+        assert_eq!(sides, [1.0, -1.0, 1.0, -1.0]);
+    }
+}
@@ -0,0 +1,251 @@
+// Muzyka w tle: osobny zapętlony utwór dla menu, rundy i ekranu końca gry.
+// Przy zmianie stanu stary utwór wycisza się, a nowy narasta (krótkie
+// przenikanie zamiast trzasku urwanego dźwięku). Na pauzie i na ekranie końca
+// gry muzyka gra ciszej.
+//
+// Tylko w oknie gry (jak efekty w `audio.rs`); bezgłowe środowiska nie mają
+// wyjścia audio.
+
+use bevy::audio::Volume;
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::audio::{audio_path, load_failed};
+use crate::pause::PauseState;
+use crate::procedural::ProceduralArt;
+use crate::settings::Settings;
+use crate::toast::Toast;
+
+// Czas przenikania utworów w sekundach.
+const CROSSFADE_SECS: f32 = 0.6;
+// Głośność przyciszonej muzyki względem pełnej.
+const DUCKED_VOLUME: f32 = 0.35;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MusicTrack {
+    Menu,
+    Playing,
+    GameOver,
+}
+
+impl MusicTrack {
+    const ALL: [MusicTrack; 3] = [MusicTrack::Menu, MusicTrack::Playing, MusicTrack::GameOver];
+
+    fn path(self) -> &'static str {
+        match self {
+            MusicTrack::Menu => "audio/music/menu.ogg",
+            MusicTrack::Playing => "audio/music/playing.ogg",
+            MusicTrack::GameOver => "audio/music/game_over.ogg",
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct MusicController {
+    // Utwór, który ma grać; pozostałe wyciszają się i znikają.
+    current: Option<MusicTrack>,
+    tracks: Vec<(MusicTrack, Handle<AudioSource>)>,
+    // Stopień przyciszenia: 0 – pełna głośność, 1 – `DUCKED_VOLUME`.
+    ducking: f32,
+}
+
+impl MusicController {
+    pub fn play(&mut self, track: MusicTrack) {
+        self.current = Some(track);
+    }
+
+    pub fn stop(&mut self) {
+        self.current = None;
+    }
+
+    fn handle(&self, track: MusicTrack) -> Option<&Handle<AudioSource>> {
+        self.tracks
+            .iter()
+            .find(|(entry, _)| *entry == track)
+            .map(|(_, handle)| handle)
+    }
+}
+
+// Grający utwór i jego bieżąca głośność względna (0..=1).
+#[derive(Component)]
+pub struct MusicVoice {
+    track: MusicTrack,
+    level: f32,
+}
+
+pub struct MusicPlugin;
+
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MusicController>()
+            .add_systems(Startup, load_music)
+            .add_systems(OnEnter(AppState::Menu), play_menu_music)
+            .add_systems(OnEnter(AppState::Playing), play_run_music)
+            .add_systems(OnEnter(AppState::GameOver), play_game_over_music)
+            .add_systems(OnExit(AppState::Menu), stop_music)
+            .add_systems(OnExit(AppState::Playing), stop_music)
+            .add_systems(OnExit(AppState::GameOver), stop_music)
+            .add_systems(
+                Update,
+                (drop_failed_tracks, spawn_current_track, crossfade_system)
+                    .chain()
+                    .after(crate::audio::AudioSet::Mute),
+            );
+    }
+}
+
+fn load_music(
+    asset_server: Res<AssetServer>,
+    procedural: Option<Res<ProceduralArt>>,
+    mut music: ResMut<MusicController>,
+) {
+    // `--no-assets` działa z gołego repozytorium – wtedy gramy bez muzyki.
+    if procedural.is_some() {
+        return;
+    }
+    music.tracks = MusicTrack::ALL
+        .into_iter()
+        .map(|track| (track, asset_server.load(track.path())))
+        .collect();
+}
+
+// Utwór, który się nie wczytał, milczy – jak efekty w `audio.rs`.
+fn drop_failed_tracks(
+    asset_server: Res<AssetServer>,
+    mut music: ResMut<MusicController>,
+    mut toasts: EventWriter<Toast>,
+) {
+    let failed = |handle: &Handle<AudioSource>| load_failed(&asset_server, handle);
+    if !music.tracks.iter().any(|(_, handle)| failed(handle)) {
+        return;
+    }
+    let mut missing = Vec::new();
+    music.tracks.retain(|(_, handle)| {
+        let keep = !failed(handle);
+        if !keep {
+            missing.push(audio_path(&asset_server, handle));
+        }
+        keep
+    });
+    for path in &missing {
+        warn!("Brak utworu {path}, gramy bez niego");
+    }
+    toasts.send(Toast(format!("Missing files: {}", missing.join(", "))));
+}
+
+fn play_menu_music(mut music: ResMut<MusicController>) {
+    music.play(MusicTrack::Menu);
+}
+
+fn play_run_music(mut music: ResMut<MusicController>) {
+    music.play(MusicTrack::Playing);
+}
+
+fn play_game_over_music(mut music: ResMut<MusicController>) {
+    music.play(MusicTrack::GameOver);
+}
+
+fn stop_music(mut music: ResMut<MusicController>) {
+    music.stop();
+}
+
+// Bieżący utwór zaczyna od ciszy; wyciszający się ten sam utwór po prostu
+// wraca do pełnej głośności.
+fn spawn_current_track(
+    mut commands: Commands,
+    music: Res<MusicController>,
+    voices: Query<&MusicVoice>,
+) {
+    let Some(track) = music.current else {
+        return;
+    };
+    if voices.iter().any(|voice| voice.track == track) {
+        return;
+    }
+    let Some(handle) = music.handle(track) else {
+        return;
+    };
+    commands.spawn((
+        AudioPlayer(handle.clone()),
+        PlaybackSettings::LOOP.with_volume(Volume::new(0.0)),
+        MusicVoice { track, level: 0.0 },
+    ));
+}
+
+// Przenikanie i przyciszanie liczymy w czasie rzeczywistym – pauza i zwolnione
+// tempo ich nie wstrzymują.
+fn crossfade_system(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    settings: Res<Settings>,
+    app_state: Res<State<AppState>>,
+    pause: Option<Res<State<PauseState>>>,
+    mut music: ResMut<MusicController>,
+    mut voices: Query<(Entity, &mut MusicVoice, Option<&AudioSink>)>,
+) {
+    let step = time.delta_secs() / CROSSFADE_SECS;
+    let ducked = is_ducked(app_state.get(), pause.as_deref().map(State::get));
+    music.ducking = fade_towards(music.ducking, if ducked { 1.0 } else { 0.0 }, step);
+
+    let full = if settings.muted {
+        0.0
+    } else {
+        settings.volume.master * settings.volume.music
+    };
+    let volume = full * (1.0 - music.ducking * (1.0 - DUCKED_VOLUME));
+    for (entity, mut voice, sink) in voices.iter_mut() {
+        let target = if music.current == Some(voice.track) {
+            1.0
+        } else {
+            0.0
+        };
+        voice.level = fade_towards(voice.level, target, step);
+        if target == 0.0 && voice.level == 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        if let Some(sink) = sink {
+            sink.set_volume(voice.level * volume);
+        }
+    }
+}
+
+fn is_ducked(app_state: &AppState, pause: Option<&PauseState>) -> bool {
+    *app_state == AppState::GameOver || pause == Some(&PauseState::Paused)
+}
+
+fn fade_towards(level: f32, target: f32, step: f32) -> f32 {
+    if level < target {
+        (level + step).min(target)
+    } else {
+        (level - step).max(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crossfade_reaches_the_target_without_overshooting() {
+        let step = 1.0 / 60.0 / CROSSFADE_SECS;
+        let mut rising = 0.0;
+        let mut falling = 1.0;
+        let frames = (CROSSFADE_SECS * 60.0).ceil() as usize + 1;
+        for _ in 0..frames {
+            rising = fade_towards(rising, 1.0, step);
+            falling = fade_towards(falling, 0.0, step);
+        }
+        assert_eq!(rising, 1.0);
+        assert_eq!(falling, 0.0);
+        assert_eq!(fade_towards(0.5, 0.5, step), 0.5);
+    }
+
+    #[test]
+    fn music_ducks_on_pause_and_game_over_only() {
+        assert!(is_ducked(&AppState::GameOver, None));
+        assert!(is_ducked(&AppState::Playing, Some(&PauseState::Paused)));
+        assert!(!is_ducked(&AppState::Playing, Some(&PauseState::Resuming)));
+        assert!(!is_ducked(&AppState::Menu, None));
+    }
+}
@@ -0,0 +1,469 @@
+// Własna gra: ekran "Custom game" z przełącznikami mutatorów, które zmieniają
+// zasady rundy – duży ptak, ciasne przerwy, wiatr, odwrócone sterowanie,
+// podwójna prędkość i brak sufitu. Wybrany zestaw trafia do `MutatedRun`
+// i zostaje do powrotu do menu; wpis na tablicy wyników zapamiętuje zestaw
+// (`LeaderboardEntry::mutators`), żeby zmienione rundy były podpisane.
+//
+// Powtórka nie zapisuje mutatorów, więc takich rund nie nagrywamy.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::buttons::button_text;
+use crate::core::{GRAVITY, PipeParams};
+use crate::menu::Menu;
+use crate::pause::PauseState;
+use crate::physics::{Collider, Gravity, Velocity};
+use crate::player::{HoverIdle, Player};
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+const BIRD_SIZE: Vec2 = Vec2::new(34.0, 24.0);
+const BIG_BIRD_SCALE: f32 = 1.5;
+const DOUBLE_SPEED: f32 = 2.0;
+
+// Kolejność jest częścią zapisu tablicy wyników (bit mutatora w `Mutators`) –
+// nowe mutatory dopisujemy na końcu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mutator {
+    BigBird,
+    TinyGaps,
+    Wind,
+    InvertedControls,
+    DoubleSpeed,
+    NoCeiling,
+}
+
+impl Mutator {
+    pub const ALL: [Mutator; 6] = [
+        Mutator::BigBird,
+        Mutator::TinyGaps,
+        Mutator::Wind,
+        Mutator::InvertedControls,
+        Mutator::DoubleSpeed,
+        Mutator::NoCeiling,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Mutator::BigBird => "Big bird",
+            Mutator::TinyGaps => "Tiny gaps",
+            Mutator::Wind => "Wind",
+            Mutator::InvertedControls => "Inverted controls",
+            Mutator::DoubleSpeed => "Double speed",
+            Mutator::NoCeiling => "No ceiling",
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+// Zestaw mutatorów rundy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Mutators(u8);
+
+impl Mutators {
+    pub fn contains(self, mutator: Mutator) -> bool {
+        self.0 & mutator.bit() != 0
+    }
+
+    pub fn toggle(&mut self, mutator: Mutator) {
+        self.0 ^= mutator.bit();
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    // Podpis na tablicy wyników, np. "Big bird + Wind".
+    pub fn label(self) -> String {
+        Mutator::ALL
+            .iter()
+            .filter(|mutator| self.contains(**mutator))
+            .map(|mutator| mutator.label())
+            .collect::<Vec<_>>()
+            .join(" + ")
+    }
+}
+
+impl FromIterator<Mutator> for Mutators {
+    fn from_iter<I: IntoIterator<Item = Mutator>>(iter: I) -> Self {
+        let mut mutators = Mutators::default();
+        for mutator in iter {
+            mutators.0 |= mutator.bit();
+        }
+        mutators
+    }
+}
+
+// Obecny, gdy gracz wystartował własną grę; znika po powrocie do menu.
+#[derive(Resource, Debug, Clone)]
+pub struct MutatedRun {
+    pub mutators: Mutators,
+    wind_clock: f32,
+}
+
+impl MutatedRun {
+    pub fn new(mutators: Mutators) -> Self {
+        Self {
+            mutators,
+            wind_clock: 0.0,
+        }
+    }
+
+    pub fn has(&self, mutator: Mutator) -> bool {
+        self.mutators.contains(mutator)
+    }
+}
+
+// Parametry rur z mutatorem ciasnych przerw.
+pub fn adjust(mut params: PipeParams, mutated: Option<&MutatedRun>) -> PipeParams {
+    if mutated.is_some_and(|mutated| mutated.has(Mutator::TinyGaps)) {
+        params.gap *= crate::tournament::TINY_GAP_SCALE;
+    }
+    params
+}
+
+// Czy wylot poza krawędź na wysokości `y` jest dozwolony. Sufit to krawędź,
+// od której odciąga grawitacja – przy odwróconym sterowaniu dolna.
+pub fn past_open_ceiling(y: f32, mutated: Option<&MutatedRun>) -> bool {
+    let Some(mutated) = mutated.filter(|mutated| mutated.has(Mutator::NoCeiling)) else {
+        return false;
+    };
+    if mutated.has(Mutator::InvertedControls) {
+        y < 0.0
+    } else {
+        y > 0.0
+    }
+}
+
+// Zestaw wybrany na ekranie własnej gry (pamiętany do końca sesji).
+#[derive(Resource, Default)]
+struct CustomGame(Mutators);
+
+#[derive(Component)]
+struct CustomGameButton;
+
+#[derive(Component)]
+struct CustomGamePanel;
+
+#[derive(Component)]
+struct MutatorToggle(Mutator);
+
+#[derive(Component)]
+enum PanelButton {
+    Start,
+    Close,
+}
+
+pub struct MutatorsPlugin;
+
+impl Plugin for MutatorsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CustomGame>()
+            .add_systems(
+                OnEnter(AppState::Menu),
+                (
+                    end_mutated_run,
+                    spawn_custom_game_button.after(crate::menu::setup_menu),
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    custom_game_button_system,
+                    mutator_toggle_system,
+                    panel_button_system,
+                )
+                    .run_if(in_state(AppState::Menu)),
+            )
+            .add_systems(
+                OnEnter(AppState::Playing),
+                start_mutated_run
+                    .after(crate::restart_game)
+                    .before(crate::replay::start_recording)
+                    .run_if(resource_exists::<MutatedRun>),
+            )
+            .add_systems(
+                OnExit(AppState::Playing),
+                restore_time_speed
+                    .after(crate::milestone::restore_time_speed)
+                    .run_if(resource_exists::<MutatedRun>),
+            )
+            .add_systems(
+                FixedUpdate,
+                apply_wind
+                    .before(crate::physics::gravity_system)
+                    .run_if(in_state(PauseState::Running))
+                    .run_if(resource_exists::<MutatedRun>),
+            );
+    }
+}
+
+// Każda runda własnej gry zaczyna z tymi samymi zasadami.
+fn start_mutated_run(
+    mut mutated: ResMut<MutatedRun>,
+    mut gravity: ResMut<Gravity>,
+    mut time: ResMut<Time<Virtual>>,
+    mut player: Query<(&mut Sprite, &mut Collider), With<Player>>,
+) {
+    mutated.wind_clock = 0.0;
+    // Odwrócona grawitacja odwraca też machnięcie (`player_input_system`).
+    gravity.0 = if mutated.has(Mutator::InvertedControls) {
+        -GRAVITY
+    } else {
+        GRAVITY
+    };
+    if mutated.has(Mutator::DoubleSpeed) {
+        time.set_relative_speed(DOUBLE_SPEED);
+    }
+    if mutated.has(Mutator::BigBird) {
+        for (mut sprite, mut collider) in player.iter_mut() {
+            sprite.custom_size = Some(BIRD_SIZE * BIG_BIRD_SCALE);
+            collider.half_size *= BIG_BIRD_SCALE;
+        }
+    }
+}
+
+// Ekran końca gry i menu chodzą w zwykłym tempie.
+fn restore_time_speed(mutated: Res<MutatedRun>, mut time: ResMut<Time<Virtual>>) {
+    if mutated.has(Mutator::DoubleSpeed) {
+        time.set_relative_speed(1.0);
+    }
+}
+
+fn end_mutated_run(
+    mut commands: Commands,
+    mutated: Option<Res<MutatedRun>>,
+    mut gravity: ResMut<Gravity>,
+) {
+    if mutated.is_none() {
+        return;
+    }
+    commands.remove_resource::<MutatedRun>();
+    gravity.0 = GRAVITY;
+}
+
+fn apply_wind(
+    time: Res<Time>,
+    mut mutated: ResMut<MutatedRun>,
+    mut player: Query<&mut Velocity, (With<Player>, Without<HoverIdle>)>,
+) {
+    if !mutated.has(Mutator::Wind) {
+        return;
+    }
+    let Ok(mut velocity) = player.get_single_mut() else {
+        return;
+    };
+    let dt = time.delta_secs();
+    mutated.wind_clock += dt;
+    velocity.dy += crate::tournament::wind_accel(mutated.wind_clock) * dt;
+}
+
+fn spawn_custom_game_button(mut commands: Commands) {
+    commands
+        .spawn((
+            Button,
+            Interaction::default(),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(20.0),
+                top: Val::Px(270.0),
+                padding: UiRect::axes(Val::Px(16.0), Val::Px(10.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(NORMAL_BUTTON),
+            BorderRadius::all(Val::Px(8.0)),
+            CustomGameButton,
+            Menu,
+        ))
+        .with_child((Text::new("Custom game"), TextColor(Color::WHITE)));
+}
+
+fn toggle_label(mutator: Mutator, mutators: Mutators) -> String {
+    let state = if mutators.contains(mutator) {
+        "On"
+    } else {
+        "Off"
+    };
+    format!("{}: {state}", mutator.label())
+}
+
+fn spawn_panel_button(parent: &mut ChildBuilder, label: String, marker: impl Bundle) {
+    parent
+        .spawn((
+            Button,
+            Interaction::default(),
+            Node {
+                width: Val::Px(260.0),
+                padding: UiRect::axes(Val::Px(16.0), Val::Px(8.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(NORMAL_BUTTON),
+            BorderRadius::all(Val::Px(8.0)),
+            marker,
+        ))
+        .with_child((Text::new(label), TextColor(Color::WHITE)));
+}
+
+fn spawn_custom_game_panel(commands: &mut Commands, mutators: Mutators) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.9)),
+            GlobalZIndex(1),
+            CustomGamePanel,
+            // Znika razem z menu przy starcie rundy.
+            Menu,
+        ))
+        .with_children(|parent| {
+            parent.spawn((Text::new("Custom game"), TextColor(Color::WHITE)));
+            for mutator in Mutator::ALL {
+                spawn_panel_button(
+                    parent,
+                    toggle_label(mutator, mutators),
+                    MutatorToggle(mutator),
+                );
+            }
+            spawn_panel_button(parent, "Start".to_string(), PanelButton::Start);
+            spawn_panel_button(parent, "Close".to_string(), PanelButton::Close);
+        });
+}
+
+fn custom_game_button_system(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<CustomGameButton>),
+    >,
+    custom: Res<CustomGame>,
+    panels: Query<(), With<CustomGamePanel>>,
+) {
+    for (interaction, mut bg_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                if panels.is_empty() {
+                    spawn_custom_game_panel(&mut commands, custom.0);
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+fn mutator_toggle_system(
+    mut interaction_query: Query<
+        (
+            &Interaction,
+            &mut BackgroundColor,
+            Option<&Children>,
+            &MutatorToggle,
+        ),
+        Changed<Interaction>,
+    >,
+    mut text_query: Query<&mut Text>,
+    mut custom: ResMut<CustomGame>,
+) {
+    for (interaction, mut bg_color, children, toggle) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                custom.0.toggle(toggle.0);
+                if let Some(mut text) = button_text(children, &mut text_query) {
+                    text.0 = toggle_label(toggle.0, custom.0);
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+fn panel_button_system(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &PanelButton),
+        Changed<Interaction>,
+    >,
+    custom: Res<CustomGame>,
+    panels: Query<Entity, With<CustomGamePanel>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, mut bg_color, button) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                match button {
+                    // Bez mutatorów to zwykła runda.
+                    PanelButton::Start => {
+                        if !custom.0.is_empty() {
+                            commands.insert_resource(MutatedRun::new(custom.0));
+                        }
+                        next_state.set(AppState::Playing);
+                    }
+                    PanelButton::Close => {
+                        for panel in panels.iter() {
+                            commands.entity(panel).despawn_recursive();
+                        }
+                    }
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mutator_sets_toggle_and_label_in_a_fixed_order() {
+        let mut mutators = Mutators::default();
+        assert!(mutators.is_empty());
+        mutators.toggle(Mutator::Wind);
+        mutators.toggle(Mutator::BigBird);
+        assert_eq!(mutators.label(), "Big bird + Wind");
+        mutators.toggle(Mutator::Wind);
+        assert_eq!(mutators, [Mutator::BigBird].into_iter().collect());
+    }
+
+    #[test]
+    fn mutators_round_trip_through_ron() {
+        let mutators: Mutators = [Mutator::TinyGaps, Mutator::NoCeiling]
+            .into_iter()
+            .collect();
+        let text = ron::to_string(&mutators).unwrap();
+        assert_eq!(ron::from_str::<Mutators>(&text).unwrap(), mutators);
+    }
+}
@@ -0,0 +1,86 @@
+// Wzory przeszkód inne niż pojedyncza para rur. Tunel to długi, wąski korytarz
+// z krętą przerwą, złożony z wielu krótkich segmentów ścian.
+
+use bevy::prelude::*;
+
+use crate::core::PipeParams;
+use crate::hazards::Hazard;
+use crate::interpolation::Interpolated;
+use crate::physics::{Collider, LifeTime, Velocity};
+use crate::score::spawn_score_zone;
+
+// Szerokość i liczba segmentów tunelu.
+const SEGMENT_WIDTH: f32 = 40.0;
+const TUNNEL_SEGMENTS: usize = 14;
+// Tunel wybacza trochę więcej niż zwykła przerwa – ściany są długie.
+const TUNNEL_EXTRA_GAP: f32 = 30.0;
+const WALL_HEIGHT: f32 = 600.0;
+
+#[derive(Component)]
+pub struct TunnelWall;
+
+// Tunel wjeżdżający na ekran krawędzią `x`; reszta segmentów ciągnie się za nią,
+// przeciwnie do kierunku lotu. Zwraca czas, po którym jego koniec minie punkt startu.
+pub fn spawn_tunnel(
+    commands: &mut Commands,
+    x: f32,
+    start_center: f32,
+    params: PipeParams,
+    color: Color,
+) -> f32 {
+    let gap = params.gap + TUNNEL_EXTRA_GAP;
+    let behind = -params.speed.signum();
+    let centers = crate::core::tunnel_centers(start_center, TUNNEL_SEGMENTS, -120.0..=120.0);
+
+    for (i, center_y) in centers.iter().copied().enumerate() {
+        let segment_x = x + behind * SEGMENT_WIDTH * (i as f32 + 0.5);
+        let lifetime = crate::core::pipe_lifetime(segment_x, params.speed);
+        let (top_mouth, bottom_mouth) = crate::core::pipe_mouths(center_y, gap);
+
+        for (mouth_y, direction) in [(top_mouth, 1.0), (bottom_mouth, -1.0)] {
+            spawn_tunnel_wall(
+                commands,
+                Vec2::new(segment_x, mouth_y + direction * WALL_HEIGHT / 2.0),
+                params.speed,
+                lifetime,
+                color,
+            );
+        }
+    }
+
+    // Jeden punkt za przelot całego tunelu – strefa na wyjściu.
+    let exit_x = x + behind * SEGMENT_WIDTH * TUNNEL_SEGMENTS as f32;
+    let exit_center = *centers.last().unwrap_or(&start_center);
+    spawn_score_zone(
+        commands,
+        Vec2::new(exit_x, exit_center),
+        gap,
+        params.speed,
+        crate::core::pipe_lifetime(exit_x, params.speed),
+    );
+
+    SEGMENT_WIDTH * TUNNEL_SEGMENTS as f32 / params.speed.abs().max(1.0)
+}
+
+// Pojedynczy segment ściany tunelu wyśrodkowany w `position`.
+pub fn spawn_tunnel_wall(
+    commands: &mut Commands,
+    position: Vec2,
+    speed: f32,
+    lifetime: f32,
+    color: Color,
+) {
+    commands.spawn((
+        Sprite::from_color(color, Vec2::new(SEGMENT_WIDTH, WALL_HEIGHT)),
+        Velocity { dx: speed, dy: 0.0 },
+        Collider {
+            half_size: Vec2::new(SEGMENT_WIDTH / 2.0, WALL_HEIGHT / 2.0 - 5.0),
+            offset: Vec2::ZERO,
+        },
+        Hazard,
+        TunnelWall,
+        LifeTime(lifetime),
+        Interpolated::default(),
+        Transform::from_xyz(position.x, position.y, 0.0),
+    ));
+}
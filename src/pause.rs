@@ -0,0 +1,129 @@
+// Pauza w trakcie rozgrywki (Escape / Start na padzie) z krótkim odliczaniem
+// przed wznowieniem. Na ekranie dotykowym pauzę zdejmuje dotknięcie.
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::hints::ButtonHint;
+use crate::text_style::OutlinedText;
+
+// Podstan istniejący tylko w `AppState::Playing`.
+#[derive(SubStates, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[source(AppState = AppState::Playing)]
+pub enum PauseState {
+    #[default]
+    Running,
+    Paused,
+    // Odliczanie po zdjęciu pauzy, zanim symulacja ruszy.
+    Resuming,
+}
+
+const RESUME_SECONDS: f32 = 1.0;
+
+#[derive(Component)]
+struct PauseOverlay;
+
+#[derive(Resource)]
+struct ResumeCountdown(Timer);
+
+pub struct PausePlugin;
+
+impl Plugin for PausePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_sub_state::<PauseState>()
+            .add_systems(
+                Update,
+                toggle_pause_system.run_if(in_state(AppState::Playing)),
+            )
+            .add_systems(OnEnter(PauseState::Paused), spawn_pause_overlay)
+            .add_systems(OnExit(PauseState::Paused), despawn_pause_overlay)
+            .add_systems(OnEnter(PauseState::Resuming), start_resume_countdown)
+            .add_systems(
+                Update,
+                resume_countdown_system.run_if(in_state(PauseState::Resuming)),
+            )
+            .add_systems(OnExit(PauseState::Resuming), despawn_pause_overlay);
+    }
+}
+
+fn toggle_pause_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    touches: Option<Res<Touches>>,
+    state: Res<State<PauseState>>,
+    mut next_state: ResMut<NextState<PauseState>>,
+) {
+    let tapped = *state.get() == PauseState::Paused
+        && touches.is_some_and(|touches| touches.any_just_pressed());
+    let pressed = tapped
+        || keyboard_input.just_pressed(KeyCode::Escape)
+        || gamepads
+            .iter()
+            .any(|gamepad| gamepad.just_pressed(GamepadButton::Start));
+    if !pressed {
+        return;
+    }
+
+    next_state.set(match state.get() {
+        PauseState::Running | PauseState::Resuming => PauseState::Paused,
+        PauseState::Paused => PauseState::Resuming,
+    });
+}
+
+fn spawn_pause_overlay(commands: Commands) {
+    spawn_overlay(commands, "Paused", ButtonHint::Resume);
+}
+
+fn start_resume_countdown(mut commands: Commands) {
+    commands.insert_resource(ResumeCountdown(Timer::from_seconds(
+        RESUME_SECONDS,
+        TimerMode::Once,
+    )));
+    spawn_overlay(commands, "Get Ready", ButtonHint::Flap);
+}
+
+// Odliczanie w czasie rzeczywistym – czas gry stoi w miejscu.
+fn resume_countdown_system(
+    mut countdown: ResMut<ResumeCountdown>,
+    time: Res<Time<Real>>,
+    mut next_state: ResMut<NextState<PauseState>>,
+) {
+    if countdown.0.tick(time.delta()).finished() {
+        next_state.set(PauseState::Running);
+    }
+}
+
+// Napis na środku ekranu i pod nim podpowiedź dla ostatnio używanego urządzenia.
+fn spawn_overlay(mut commands: Commands, label: &str, hint: ButtonHint) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            PauseOverlay,
+        ))
+        .with_children(|overlay| {
+            overlay.spawn((
+                Text::new(label),
+                OutlinedText::new(Color::WHITE),
+                TextFont {
+                    font_size: 60.0,
+                    ..Default::default()
+                },
+            ));
+            overlay.spawn(hint);
+        });
+}
+
+fn despawn_pause_overlay(mut commands: Commands, query: Query<Entity, With<PauseOverlay>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
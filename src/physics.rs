@@ -0,0 +1,277 @@
+// Fizyka świata: ruch, grawitacja, czas życia encji oraz kolizje ptaka
+// z przeszkodami i granicami planszy. Wszystko w kroku stałym.
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::display::WORLD_SIZE;
+use crate::handicap::Handicap;
+use crate::hazards::Hazard;
+use crate::kids::{KidBounce, KidsMode};
+use crate::mutators::MutatedRun;
+use crate::pause::PauseState;
+use crate::pipes::Pipe;
+use crate::player::{HoverIdle, Player};
+use crate::settings::Settings;
+use crate::zones::{InLowGravity, LOW_GRAVITY_SCALE, LowGravityZone};
+
+#[derive(Component)]
+pub struct Velocity {
+    pub dx: f32,
+    pub dy: f32,
+}
+
+#[derive(Component)]
+pub struct LifeTime(pub f32);
+
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
+pub struct Gravity(pub f32);
+
+#[derive(Component)]
+pub struct Collider {
+    pub half_size: Vec2,
+    // Przesunięcie środka prostokąta względem `Transform` (np. rury zaczepione w wylocie).
+    pub offset: Vec2,
+}
+
+impl Collider {
+    pub fn center(&self, transform: &Transform) -> Vec2 {
+        transform.translation.truncate() + self.offset
+    }
+}
+
+// Znacznik collidera, który niczego nie blokuje, a jedynie wykrywa nachodzenie.
+#[derive(Component)]
+pub struct Sensor;
+
+pub struct PhysicsPlugin;
+
+impl Plugin for PhysicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Gravity(crate::core::GRAVITY))
+            .add_systems(
+                FixedUpdate,
+                (
+                    gravity_system,
+                    move_system,
+                    collision_system.after(move_system),
+                    boundary_collision_system.after(move_system),
+                    lifetime_system,
+                    low_gravity_overlap_system
+                        .after(move_system)
+                        .before(gravity_system),
+                )
+                    .run_if(in_state(PauseState::Running)),
+            );
+    }
+}
+
+// System ruchu: aktualizuje Transform na podstawie Velocity
+pub fn move_system(mut query: Query<(&mut Transform, &Velocity)>, time: Res<Time>) {
+    for (mut transform, vel) in query.iter_mut() {
+        transform.translation.x += vel.dx * time.delta_secs();
+        transform.translation.y += vel.dy * time.delta_secs();
+    }
+}
+
+// System obsługi czasu życia – zmniejsza LifeTime o upływ czasu i usuwa encję, gdy czas osiągnie 0.
+fn lifetime_system(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut LifeTime)>,
+    time: Res<Time>,
+) {
+    for (entity, mut lifetime) in query.iter_mut() {
+        lifetime.0 -= time.delta_secs();
+        if lifetime.0 <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+// Oznacza ptaka, który znajduje się w strefie niskiej grawitacji.
+fn low_gravity_overlap_system(
+    mut commands: Commands,
+    player_query: Query<(Entity, &Transform, &Collider, Has<InLowGravity>), With<Player>>,
+    zone_query: Query<(&Transform, &Collider), (With<LowGravityZone>, With<Sensor>)>,
+) {
+    let Ok((player, player_transform, player_collider, was_inside)) = player_query.get_single()
+    else {
+        return;
+    };
+
+    let inside = zone_query.iter().any(|(zone_transform, zone_collider)| {
+        crate::core::aabb_overlap(
+            player_collider.center(player_transform).into(),
+            player_collider.half_size.into(),
+            zone_collider.center(zone_transform).into(),
+            zone_collider.half_size.into(),
+        )
+    });
+
+    if inside && !was_inside {
+        commands.entity(player).insert(InLowGravity);
+    } else if !inside && was_inside {
+        commands.entity(player).remove::<InLowGravity>();
+    }
+}
+
+// Maksymalny czas otarcia, który wybacza asysta "leniency".
+const GRAZE_GRACE_SECS: f32 = 0.05;
+
+// System wykrywający kolizje ptaka z rurami i przeszkodami – zderzenie kończy
+// rundę (w trybie dla dzieci ptak się odbija), a z asystą "leniency" płytkie
+// otarcie trwające krócej niż `GRAZE_GRACE_SECS` jest wybaczane.
+pub fn collision_system(
+    player_query: Query<(Entity, &Transform, &Velocity), (With<Player>, Without<Hazard>)>,
+    hazard_query: Query<(Entity, Option<&Pipe>, &Transform, Option<&Velocity>), With<Hazard>>,
+    collider_query: Query<&Collider>,
+    settings: Res<Settings>,
+    kids: Option<Res<KidsMode>>,
+    mut bounces: EventWriter<KidBounce>,
+    time: Res<Time>,
+    // Jak długo (w sekundach) ptak ociera się o rurę bez przerwy.
+    mut graze_time: Local<f32>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let Ok((player_entity, player_transform, player_velocity)) = player_query.get_single() else {
+        return;
+    };
+
+    let Ok(player_collider) = collider_query.get(player_entity) else {
+        return;
+    };
+
+    let dt = time.delta_secs();
+    let mut grazing = false;
+
+    for (pipe_entity, pipe, pipe_transform, pipe_velocity) in hazard_query.iter() {
+        let Ok(pipe_collider) = collider_query.get(pipe_entity) else {
+            continue;
+        };
+
+        let Some(depth) = crate::core::penetration(
+            player_collider.center(player_transform).into(),
+            player_collider.half_size.into(),
+            pipe_collider.center(pipe_transform).into(),
+            pipe_collider.half_size.into(),
+        ) else {
+            continue;
+        };
+
+        // Dziecko nie przegrywa – ptak odbija się od przeszkody.
+        if kids.is_some() {
+            let away = player_transform.translation.y - pipe_collider.center(pipe_transform).y;
+            bounces.send(KidBounce {
+                direction: if away >= 0.0 { 1.0 } else { -1.0 },
+            });
+            return;
+        }
+
+        let assists = &settings.assists;
+        if !assists.leniency || depth[0].min(depth[1]) > assists.leniency_px {
+            match pipe {
+                Some(pipe) => debug!("Zderzenie z rurą ({:?})", pipe.end),
+                None => debug!("Zderzenie z przeszkodą"),
+            }
+            next_state.set(AppState::GameOver);
+            return;
+        }
+
+        // Ile z tej klatki ptak faktycznie spędził w kontakcie: cofamy ruch
+        // względny z tej klatki i szukamy momentu zetknięcia.
+        let pipe_velocity = pipe_velocity.map_or(Vec2::ZERO, |v| Vec2::new(v.dx, v.dy));
+        let relative_velocity = Vec2::new(player_velocity.dx, player_velocity.dy) - pipe_velocity;
+        let end = player_collider.center(player_transform) - pipe_collider.center(pipe_transform);
+        let start = end - relative_velocity * dt;
+        let combined_half = player_collider.half_size + pipe_collider.half_size;
+        let entry =
+            crate::core::sweep_entry(start.into(), end.into(), combined_half.into()).unwrap_or(0.0);
+
+        grazing = true;
+        *graze_time += (1.0 - entry) * dt;
+    }
+
+    if !grazing {
+        *graze_time = 0.0;
+    } else if *graze_time > GRAZE_GRACE_SECS {
+        next_state.set(AppState::GameOver);
+    }
+}
+
+pub fn boundary_collision_system(
+    player_query: Query<(&Transform, &Collider, &Velocity), With<Player>>,
+    settings: Res<Settings>,
+    kids: Option<Res<KidsMode>>,
+    mutated: Option<Res<MutatedRun>>,
+    mut bounces: EventWriter<KidBounce>,
+    time: Res<Time>,
+    mut graze_time: Local<f32>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let Ok((transform, collider, velocity)) = player_query.get_single() else {
+        return;
+    };
+
+    // Granice świata gry (600 jednostek w pionie niezależnie od rozdzielczości)
+    let top_boundary = WORLD_SIZE.y / 2.0;
+    let bottom_boundary = -WORLD_SIZE.y / 2.0;
+
+    // Sprawdź kolizje z granicami
+    if !crate::core::out_of_bounds(
+        transform.translation.y,
+        collider.half_size.y,
+        top_boundary,
+        bottom_boundary,
+    ) {
+        *graze_time = 0.0;
+        return;
+    }
+
+    if crate::mutators::past_open_ceiling(transform.translation.y, mutated.as_deref()) {
+        *graze_time = 0.0;
+        return;
+    }
+
+    if kids.is_some() {
+        bounces.send(KidBounce {
+            direction: if transform.translation.y > 0.0 {
+                -1.0
+            } else {
+                1.0
+            },
+        });
+        return;
+    }
+
+    // Asysta dotyczy tylko muśnięcia sufitu – podłoga zawsze kończy grę.
+    let ceiling_depth = transform.translation.y + collider.half_size.y - top_boundary;
+    let assists = &settings.assists;
+    if !assists.leniency || ceiling_depth <= 0.0 || ceiling_depth > assists.leniency_px {
+        next_state.set(AppState::GameOver);
+        return;
+    }
+
+    // Czas kontaktu w tej klatce: od momentu przekroczenia sufitu.
+    let dt = time.delta_secs();
+    *graze_time += (ceiling_depth / velocity.dy.abs().max(f32::EPSILON)).min(dt);
+    if *graze_time > GRAZE_GRACE_SECS {
+        next_state.set(AppState::GameOver);
+    }
+}
+
+pub fn gravity_system(
+    time: Res<Time>,
+    mut query: Query<
+        (&mut Velocity, Has<InLowGravity>, Option<&Handicap>),
+        (With<Player>, Without<HoverIdle>),
+    >,
+    gravity: Res<Gravity>,
+) {
+    let delta = time.delta_secs();
+    for (mut velocity, low_gravity, handicap) in &mut query {
+        let zone_scale = if low_gravity { LOW_GRAVITY_SCALE } else { 1.0 };
+        let scale = zone_scale * handicap.map_or(1.0, |handicap| handicap.gravity_scale());
+        velocity.dy += gravity.0 * scale * delta;
+    }
+}
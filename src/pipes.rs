@@ -0,0 +1,328 @@
+// Spawnowanie pojedynczych rur. Rura jest zaczepiona (anchor) w swoim wylocie,
+// więc `Transform` wskazuje krawędź przerwy, a ciało rury rozciąga się od niej
+// w stronę krawędzi ekranu.
+//
+// Grafika rury to czapka (górne `PIPE_CAP_PX` pikseli) i ciało pod nią. Rysujemy
+// ją jako 9-slice: czapka zostaje w oryginalnej skali, a ciało jest kafelkowane,
+// więc rura może mieć dowolną długość bez rozciągania tekstury. Paczki motywów
+// powinny trzymać się tego samego układu.
+//
+// Tu powstają też całe wzory przeszkód (para rur, tunel, kolce, strefa niskiej
+// grawitacji) w rytmie wyzwalacza `PipeSpawn` z harmonogramu rundy.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::sprite::{Anchor, BorderRect, SliceScaleMode, TextureSlicer};
+use serde::{Deserialize, Serialize};
+
+use crate::adaptive::AdaptiveRun;
+use crate::assets::GameAssets;
+use crate::biome::CurrentBiome;
+use crate::core::{Extra, Pattern, PatternOdds, PatternPlan, PipeParams};
+use crate::display::{PlayArea, WORLD_SIZE};
+use crate::handicap::Handicap;
+use crate::hazards::{Hazard, spawn_spike_strip};
+use crate::interpolation::Interpolated;
+use crate::kids::KidsMode;
+use crate::mirror::MirrorRun;
+use crate::mutators::MutatedRun;
+use crate::patterns::spawn_tunnel;
+use crate::pause::PauseState;
+use crate::physics::{Collider, LifeTime, Velocity};
+use crate::player::{Player, bird_released};
+use crate::rng::RunRng;
+use crate::royale::RoyaleRun;
+use crate::scheduler::{Scheduler, TriggerId};
+use crate::score::{Score, spawn_score_zone};
+use crate::settings::{Difficulty, Settings};
+use crate::theme::{ActiveTheme, Theme};
+use crate::tournament::WeeklyRun;
+use crate::zones::spawn_low_gravity_zone;
+
+// Szerokość tekstury rury i wysokość jej czapki (piksele).
+const PIPE_TEXTURE_WIDTH: f32 = 52.0;
+const PIPE_CAP_PX: f32 = 24.0;
+
+// Z której strony ekranu wyrasta rura.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PipeEnd {
+    Top,
+    Bottom,
+}
+
+impl PipeEnd {
+    // Punkt zaczepienia sprite'a: wylot rury.
+    fn anchor(self) -> Anchor {
+        match self {
+            PipeEnd::Top => Anchor::BottomCenter,
+            PipeEnd::Bottom => Anchor::TopCenter,
+        }
+    }
+
+    // Kierunek od wylotu do podstawy rury.
+    pub fn body_direction(self) -> f32 {
+        match self {
+            PipeEnd::Top => 1.0,
+            PipeEnd::Bottom => -1.0,
+        }
+    }
+}
+
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Pipe {
+    pub end: PipeEnd,
+}
+
+// Wszystko, czego potrzeba do postawienia jednej rury.
+pub struct PipeSpec {
+    pub end: PipeEnd,
+    pub x: f32,
+    // Wysokość wylotu (krawędzi przerwy).
+    pub mouth_y: f32,
+    // Szerokość kolizji i długość rury od wylotu do podstawy.
+    pub size: Vec2,
+    pub speed: f32,
+    pub lifetime: f32,
+    pub tint: Color,
+}
+
+pub fn spawn_pipe(commands: &mut Commands, image: Handle<Image>, spec: PipeSpec) -> Entity {
+    commands
+        .spawn((
+            Sprite {
+                image,
+                color: spec.tint,
+                custom_size: Some(Vec2::new(PIPE_TEXTURE_WIDTH, spec.size.y)),
+                image_mode: SpriteImageMode::Sliced(pipe_slicer()),
+                // Grafika ma czapkę u góry – górna rura jest jej lustrzanym odbiciem.
+                flip_y: spec.end == PipeEnd::Top,
+                anchor: spec.end.anchor(),
+                ..Default::default()
+            },
+            Velocity {
+                dx: spec.speed,
+                dy: 0.0,
+            },
+            Collider {
+                half_size: spec.size / 2.0 - 5.0,
+                offset: Vec2::new(0.0, spec.end.body_direction() * spec.size.y / 2.0),
+            },
+            Pipe { end: spec.end },
+            Hazard,
+            LifeTime(spec.lifetime),
+            Interpolated::default(),
+            Transform::from_xyz(spec.x, spec.mouth_y, 0.0),
+        ))
+        .id()
+}
+
+fn pipe_slicer() -> TextureSlicer {
+    TextureSlicer {
+        border: BorderRect {
+            top: PIPE_CAP_PX,
+            ..Default::default()
+        },
+        center_scale_mode: SliceScaleMode::Tile { stretch_value: 1.0 },
+        sides_scale_mode: SliceScaleMode::Tile { stretch_value: 1.0 },
+        max_corner_scale: 1.0,
+    }
+}
+
+// Odstęp między kolejnymi wzorami przeszkód (sekundy).
+pub const PIPE_INTERVAL_SECS: f32 = 2.0;
+
+// Szerokość kolizji i długość pojedynczej rury.
+pub const PIPE_SIZE: Vec2 = Vec2::new(50.0, 600.0);
+
+// Szansa na strefę niskiej grawitacji między parami rur.
+const LOW_GRAVITY_ZONE_CHANCE: f64 = 0.15;
+
+// Tunel zamiast pary rur – dopiero gdy gracz złapie rytm.
+const TUNNEL_CHANCE: f64 = 0.1;
+const TUNNEL_MIN_SCORE: i32 = 5;
+
+pub struct PipesPlugin;
+
+impl Plugin for PipesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            pipe_spawn_system
+                .run_if(bird_released)
+                .run_if(in_state(PauseState::Running)),
+        );
+    }
+}
+
+// Zasoby potrzebne do wylosowania i postawienia kolejnej pary rur.
+#[derive(SystemParam)]
+pub struct PipeSpawner<'w, 's> {
+    assets: Res<'w, GameAssets>,
+    score: Res<'w, Score>,
+    theme: Res<'w, ActiveTheme>,
+    biome: Res<'w, CurrentBiome>,
+    settings: Res<'w, Settings>,
+    kids: Option<Res<'w, KidsMode>>,
+    weekly: Option<Res<'w, WeeklyRun>>,
+    mirror: Option<ResMut<'w, MirrorRun>>,
+    royale: Option<Res<'w, RoyaleRun>>,
+    // Handicap gracza w wyścigu poszerza przerwy na jego ekranie.
+    handicap: Query<'w, 's, &'static Handicap, With<Player>>,
+    adaptive: Option<Res<'w, AdaptiveRun>>,
+    mutated: Option<Res<'w, MutatedRun>>,
+    play_area: Res<'w, PlayArea>,
+    // Losowanie z ziarna rundy, żeby zapisaną rundę dało się dokładnie odtworzyć.
+    rng: ResMut<'w, RunRng>,
+}
+
+// Szanse na wzory przeszkód przy danym poziomie trudności.
+pub fn pattern_odds(difficulty: Difficulty) -> PatternOdds {
+    PatternOdds {
+        tunnel_min_score: TUNNEL_MIN_SCORE,
+        tunnel: TUNNEL_CHANCE,
+        spikes: difficulty.spike_chance(),
+        low_gravity: LOW_GRAVITY_ZONE_CHANCE,
+    }
+}
+
+impl PipeSpawner<'_, '_> {
+    // Generowanie przeszkód; zwraca czas do następnego wzoru.
+    fn spawn(&mut self, commands: &mut Commands) -> f32 {
+        let mut odds = pattern_odds(self.settings.difficulty);
+        if self.kids.is_some() {
+            // Dzieciom oszczędzamy kolców i ciasnych tuneli.
+            odds.tunnel = 0.0;
+            odds.spikes = 0.0;
+        }
+        let plan = crate::core::plan_pattern(&mut *self.rng, self.score.0, &odds);
+        self.spawn_planned(commands, plan)
+    }
+
+    // Stawia podany wzór (również na żądanie z konsoli deweloperskiej).
+    pub fn spawn_planned(&mut self, commands: &mut Commands, plan: PatternPlan) -> f32 {
+        let center_y = plan.center_y;
+        let params = crate::tournament::apply_rules(
+            crate::kids::run_curve(&self.settings, self.kids.is_some()).params_at(self.score.0),
+            self.weekly.as_deref(),
+        );
+        let params = crate::royale::shrink_gap(params, self.royale.as_deref());
+        let params = crate::handicap::widen_gap(params, self.handicap.get_single().ok());
+        let params = crate::adaptive::adjust(params, self.adaptive.as_deref());
+        let params = crate::mutators::adjust(params, self.mutated.as_deref());
+        // W trybie lustrzanym co druga fala nadlatuje z lewej.
+        let side = self.mirror.as_deref_mut().map_or(1.0, MirrorRun::next_side);
+        let params = PipeParams {
+            speed: params.speed * side,
+            ..params
+        };
+        let spawn_x = side * self.play_area.pipe_spawn_x();
+
+        let Pattern::Pipes(extra) = plan.pattern else {
+            let length_secs = spawn_tunnel(
+                commands,
+                spawn_x,
+                center_y,
+                params,
+                tunnel_wall_color(self.biome.0.pipe_tint()),
+            );
+            return length_secs + PIPE_INTERVAL_SECS;
+        };
+
+        spawn_pipe_pair(
+            commands,
+            &self.assets,
+            &self.theme.0,
+            self.biome.0.pipe_tint(),
+            spawn_x,
+            center_y,
+            params,
+        );
+
+        // W połowie drogi do następnej pary (rury pojawiają się co 2 s)
+        // czasem pojawiają się kolce albo strefa niskiej grawitacji.
+        let x = spawn_x - params.speed;
+        let lifetime = crate::core::pipe_lifetime(x, params.speed);
+        match extra {
+            Extra::Spikes { top } => {
+                let side = if top { PipeEnd::Top } else { PipeEnd::Bottom };
+                spawn_spike_strip(
+                    commands,
+                    side,
+                    x,
+                    WORLD_SIZE.y / 2.0,
+                    params.speed,
+                    lifetime,
+                );
+            }
+            Extra::LowGravity => {
+                spawn_low_gravity_zone(commands, x, center_y, params.speed, lifetime);
+            }
+            Extra::None => {}
+        }
+
+        PIPE_INTERVAL_SECS
+    }
+}
+
+// Ściany tunelu w kolorze rur, zabarwione jak rury bieżącego biomu.
+pub fn tunnel_wall_color(tint: Color) -> Color {
+    let tint = tint.to_srgba();
+    Color::srgb(0.33 * tint.red, 0.55 * tint.green, 0.2 * tint.blue)
+}
+
+// Para rur (górna i dolna) z przerwą wyśrodkowaną na `center_y`.
+pub fn spawn_pipe_pair(
+    commands: &mut Commands,
+    assets: &GameAssets,
+    theme: &Theme,
+    tint: Color,
+    x: f32,
+    center_y: f32,
+    params: PipeParams,
+) {
+    let pipe_speed = params.speed;
+    let lifetime = crate::core::pipe_lifetime(x, pipe_speed);
+
+    let image = assets.theme(theme).pipe.clone();
+    let (top_mouth_y, bottom_mouth_y) = crate::core::pipe_mouths(center_y, params.gap);
+
+    for (end, mouth_y) in [
+        (PipeEnd::Top, top_mouth_y),
+        (PipeEnd::Bottom, bottom_mouth_y),
+    ] {
+        spawn_pipe(
+            commands,
+            image.clone(),
+            PipeSpec {
+                end,
+                x,
+                mouth_y,
+                size: PIPE_SIZE,
+                speed: pipe_speed,
+                lifetime,
+                tint,
+            },
+        );
+    }
+
+    // Strefa punktowa wypełniająca przerwę
+    spawn_score_zone(
+        commands,
+        Vec2::new(x, center_y),
+        params.gap,
+        pipe_speed,
+        lifetime,
+    );
+}
+
+// Odstęp do następnego wzoru zależy od właśnie postawionego (tunel jest dłuższy).
+pub fn pipe_spawn_system(
+    mut commands: Commands,
+    mut scheduler: ResMut<Scheduler>,
+    mut spawner: PipeSpawner,
+) {
+    if scheduler.take(TriggerId::PipeSpawn) {
+        let next = spawner.spawn(&mut commands);
+        scheduler.set_interval(TriggerId::PipeSpawn, next);
+    }
+}
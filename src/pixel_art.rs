@@ -0,0 +1,109 @@
+// Tryb "pixel perfect": próbkowanie najbliższym sąsiadem, całkowita skala kamery
+// i przyciąganie narysowanych pozycji do siatki pikseli.
+
+use bevy::image::ImageSampler;
+use bevy::prelude::*;
+use bevy::render::camera::ScalingMode;
+use bevy::transform::TransformSystem;
+use bevy::window::PrimaryWindow;
+
+use crate::assets::GameAssets;
+use crate::display::WORLD_SIZE;
+use crate::settings::Settings;
+
+pub struct PixelArtPlugin;
+
+impl Plugin for PixelArtPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (apply_sampling, apply_camera_scale))
+            .add_systems(
+                PostUpdate,
+                snap_to_pixels
+                    .after(TransformSystem::TransformPropagate)
+                    .run_if(|settings: Res<Settings>| settings.pixel_perfect),
+            );
+    }
+}
+
+// Sampler ustawiamy tylko obrazkom gry – atlasy fontów zostają bez zmian.
+fn apply_sampling(
+    settings: Res<Settings>,
+    assets: Option<Res<GameAssets>>,
+    mut events: EventReader<AssetEvent<Image>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let Some(assets) = assets else {
+        return;
+    };
+
+    let loaded: Vec<AssetId<Image>> = events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::LoadedWithDependencies { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+    if !settings.is_changed() && loaded.is_empty() {
+        return;
+    }
+
+    let sampler = if settings.pixel_perfect {
+        ImageSampler::nearest()
+    } else {
+        ImageSampler::Default
+    };
+    for id in assets.ids() {
+        if let Some(image) = images.get_mut(id.typed::<Image>()) {
+            image.sampler = sampler.clone();
+        }
+    }
+}
+
+// Największa całkowita skala, przy której cały świat mieści się w oknie.
+fn apply_camera_scale(
+    settings: Res<Settings>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut projection_query: Query<&mut OrthographicProjection, With<Camera2d>>,
+    mut applied: Local<Option<Option<f32>>>,
+) {
+    let (Ok(window), Ok(mut projection)) =
+        (window_query.get_single(), projection_query.get_single_mut())
+    else {
+        return;
+    };
+
+    let factor = (window.width() / WORLD_SIZE.x)
+        .min(window.height() / WORLD_SIZE.y)
+        .floor();
+
+    // Skala "piksele na jednostkę świata" albo `None` dla zwykłego skalowania.
+    let pixel_scale = (settings.pixel_perfect && factor >= 1.0).then_some(factor);
+    if *applied == Some(pixel_scale) {
+        return;
+    }
+    *applied = Some(pixel_scale);
+
+    match pixel_scale {
+        Some(factor) => {
+            projection.scaling_mode = ScalingMode::WindowSize;
+            projection.scale = 1.0 / factor;
+        }
+        None => {
+            projection.scaling_mode = ScalingMode::AutoMin {
+                min_width: WORLD_SIZE.x,
+                min_height: WORLD_SIZE.y,
+            };
+            projection.scale = 1.0;
+        }
+    }
+}
+
+// Zaokrąglamy tylko pozycję rysowaną – `Transform` (i symulacja) zostają dokładne.
+fn snap_to_pixels(mut query: Query<&mut GlobalTransform, With<Sprite>>) {
+    for mut global in query.iter_mut() {
+        let mut affine = global.affine();
+        affine.translation.x = affine.translation.x.round();
+        affine.translation.y = affine.translation.y.round();
+        *global = GlobalTransform::from(affine);
+    }
+}
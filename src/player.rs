@@ -0,0 +1,179 @@
+// Ptak: machnięcia z warstwy wejścia (`input.rs`), unoszenie się w fazie
+// "Get Ready" i sprężyste zmiany skali przy machnięciu i nurkowaniu.
+
+use std::f32::consts::PI;
+
+use bevy::math::curve::{Curve, EaseFunction, EasingCurve};
+use bevy::prelude::*;
+
+use crate::assets::GameAssets;
+use crate::controls::{ControlScheme, RunControls};
+use crate::input::{FlapBuffer, FlapInput};
+use crate::interpolation::Interpolated;
+use crate::pause::PauseState;
+use crate::physics::{Collider, Gravity, Velocity};
+use crate::settings::Settings;
+use crate::tween::ScaleTween;
+use crate::{AppState, RunEntity};
+
+#[derive(Component)]
+#[require(RunEntity)]
+pub struct Player;
+
+// Faza "Get Ready": ptak unosi się w miejscu aż do pierwszego machnięcia.
+#[derive(Component)]
+pub struct HoverIdle {
+    base_y: f32,
+    elapsed: f32,
+}
+
+impl HoverIdle {
+    pub fn at(base_y: f32) -> Self {
+        Self {
+            base_y,
+            elapsed: 0.0,
+        }
+    }
+}
+
+// Wysyłany przy każdym machnięciu skrzydłami.
+#[derive(Event)]
+pub struct Flapped;
+
+pub struct PlayerPlugin;
+
+impl Plugin for PlayerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Flapped>()
+            // Ptak za menu unosi się jak w fazie "Get Ready".
+            .add_systems(
+                FixedUpdate,
+                hover_idle_system.run_if(in_state(AppState::Menu)),
+            )
+            .add_systems(
+                FixedUpdate,
+                (player_input_system, hover_idle_system).run_if(in_state(PauseState::Running)),
+            )
+            .add_systems(
+                Update,
+                (flap_squash_system, dive_stretch_system).run_if(in_state(PauseState::Running)),
+            );
+    }
+}
+
+// System obsługi wejścia – dla gracza.
+// Machnięcia zbiera warstwa wejścia; tu zużywamy bufor, więc naciśnięcie
+// sprzed chwili (np. z końca odliczania po pauzie) też się liczy.
+pub fn player_input_system(
+    mut commands: Commands,
+    mut flap_buffer: ResMut<FlapBuffer>,
+    mut flap_input: ResMut<FlapInput>,
+    controls: Res<RunControls>,
+    gravity: Res<Gravity>,
+    time: Res<Time<Real>>,
+    mut query: Query<(Entity, &mut Velocity), With<Player>>,
+    mut flapped: EventWriter<Flapped>,
+) {
+    let buffered = flap_buffer.take(time.elapsed_secs_f64());
+    let released_after = flap_input.released_after.take();
+    let impulse = match controls.0 {
+        // W trybie ładowania machnięcie następuje dopiero po puszczeniu przycisku.
+        ControlScheme::Charge => released_after.map(crate::core::charge_impulse),
+        ControlScheme::Classic | ControlScheme::Glide | ControlScheme::Dash => {
+            buffered.then_some(crate::core::FLAP_IMPULSE)
+        }
+    };
+
+    if let Some(impulse) = impulse {
+        for (entity, mut vel) in query.iter_mut() {
+            // Machnięcie zawsze działa przeciw grawitacji (także odwróconej).
+            vel.dy = -gravity.0.signum() * impulse * flap_input.strength;
+            // Pierwsze machnięcie kończy fazę "Get Ready".
+            commands.entity(entity).remove::<HoverIdle>();
+        }
+        flapped.send(Flapped);
+    }
+}
+
+// Sinusoidalne unoszenie się ptaka przed pierwszym machnięciem.
+fn hover_idle_system(
+    mut query: Query<(&mut Transform, &mut HoverIdle)>,
+    time: Res<Time>,
+    settings: Res<Settings>,
+) {
+    let amplitude = if settings.accessibility.reduced_motion {
+        0.0
+    } else {
+        6.0
+    };
+
+    for (mut transform, mut hover) in query.iter_mut() {
+        hover.elapsed += time.delta_secs();
+        transform.translation.y = hover.base_y + (hover.elapsed * 2.0 * PI * 0.8).sin() * amplitude;
+    }
+}
+
+// Warunek: ptak wystartował (faza "Get Ready" zakończona).
+pub fn bird_released(query: Query<(), (With<Player>, With<HoverIdle>)>) -> bool {
+    query.is_empty()
+}
+
+// Spłaszczenie ptaka przy machnięciu, sprężyście wracające do normalnej skali.
+fn flap_squash_system(
+    mut commands: Commands,
+    mut flapped: EventReader<Flapped>,
+    player_query: Query<Entity, With<Player>>,
+    settings: Res<Settings>,
+) {
+    if flapped.read().last().is_none() || settings.accessibility.reduced_motion {
+        return;
+    }
+
+    for entity in player_query.iter() {
+        commands.entity(entity).insert(ScaleTween::new(
+            Vec3::new(1.25, 0.75, 1.0),
+            Vec3::ONE,
+            0.25,
+            EaseFunction::BackOut,
+        ));
+    }
+}
+
+// Lekkie rozciągnięcie w pionie podczas nurkowania, rosnące z prędkością spadania.
+fn dive_stretch_system(
+    mut query: Query<(&Velocity, &mut Transform), (With<Player>, Without<ScaleTween>)>,
+    settings: Res<Settings>,
+) {
+    if settings.accessibility.reduced_motion {
+        return;
+    }
+
+    let stretch = EasingCurve::new(
+        Vec3::ONE,
+        Vec3::new(0.9, 1.12, 1.0),
+        EaseFunction::QuadraticIn,
+    );
+
+    for (velocity, mut transform) in query.iter_mut() {
+        let t = ((-velocity.dy - 100.0) / 300.0).clamp(0.0, 1.0);
+        transform.scale = stretch.sample_clamped(t);
+    }
+}
+
+pub fn spawn_player(commands: &mut Commands, assets: &GameAssets) {
+    commands.spawn((
+        Sprite {
+            image: assets.bird.clone(),
+            ..Default::default()
+        },
+        Velocity { dx: 0.0, dy: 0.0 },
+        Collider {
+            half_size: Vec2::new(16.0, 16.0),
+            offset: Vec2::ZERO,
+        },
+        Player,
+        HoverIdle::at(0.0),
+        Interpolated::default(),
+        Transform::from_xyz(0.0, 0.0, 1.0),
+    ));
+}
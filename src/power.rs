@@ -0,0 +1,98 @@
+// Tempo odświeżania: poza rozgrywką klatki rysujemy tylko na zdarzenia.
+// Tryb oszczędzania baterii dodaje limit 30 FPS w grze i usypia też pauzę.
+// Efekty ozdobne sprawdzają `Settings::low_power`.
+//
+// W przeglądarce nie wolno usypiać wątku, więc tam limit ustawia samo
+// `WinitSettings` (reaktywne odświeżanie co klatkę limitu).
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::utils::Instant;
+use bevy::winit::{UpdateMode, WinitSettings};
+
+use crate::AppState;
+use crate::pause::PauseState;
+use crate::settings::Settings;
+
+const LOW_POWER_FRAME: Duration = Duration::from_nanos(1_000_000_000 / 30);
+
+// Jak długo menu może czekać bez zdarzeń na kolejną klatkę (tosty, animacje przycisków).
+const MENU_WAIT: Duration = Duration::from_millis(250);
+const LOW_POWER_MENU_WAIT: Duration = Duration::from_secs(1);
+
+pub struct PowerPlugin;
+
+impl Plugin for PowerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, apply_update_mode)
+            .add_systems(Last, frame_limiter);
+    }
+}
+
+// Na ekranie końca gry nic się nie rusza bez udziału gracza, więc nie ma
+// sensu palić całego rdzenia na rysowanie tych samych klatek. W menu unosi
+// się ptak – stoi w miejscu tylko przy ograniczonym ruchu.
+fn apply_update_mode(
+    settings: Res<Settings>,
+    app_state: Res<State<AppState>>,
+    pause_state: Option<Res<State<PauseState>>>,
+    mut winit: ResMut<WinitSettings>,
+) {
+    let paused = pause_state.is_some_and(|state| *state.get() == PauseState::Paused);
+    let menu_animated = !settings.accessibility.reduced_motion;
+
+    let mode = match (app_state.get(), settings.low_power) {
+        (AppState::Playing, true) if paused => UpdateMode::reactive_low_power(LOW_POWER_MENU_WAIT),
+        (AppState::Playing, true) if !backend::CAN_SLEEP => {
+            UpdateMode::reactive_low_power(LOW_POWER_FRAME)
+        }
+        (AppState::Loading | AppState::Playing, _) => UpdateMode::Continuous,
+        (AppState::Menu, false) if menu_animated => UpdateMode::Continuous,
+        (AppState::Setup | AppState::Menu | AppState::GameOver, true) => {
+            UpdateMode::reactive_low_power(LOW_POWER_MENU_WAIT)
+        }
+        (AppState::Setup | AppState::Menu | AppState::GameOver, false) => {
+            UpdateMode::reactive(MENU_WAIT)
+        }
+    };
+
+    if winit.focused_mode != mode {
+        winit.focused_mode = mode;
+    }
+}
+
+fn frame_limiter(settings: Res<Settings>, mut last_frame: Local<Option<Instant>>) {
+    if !settings.low_power || !backend::CAN_SLEEP {
+        *last_frame = None;
+        return;
+    }
+    if let Some(last) = *last_frame {
+        let elapsed = last.elapsed();
+        if elapsed < LOW_POWER_FRAME {
+            backend::sleep(LOW_POWER_FRAME - elapsed);
+        }
+    }
+    *last_frame = Some(Instant::now());
+}
+
+// Przeglądarka: wątek główny nie może spać.
+#[cfg(target_arch = "wasm32")]
+mod backend {
+    use std::time::Duration;
+
+    pub const CAN_SLEEP: bool = false;
+
+    pub fn sleep(_duration: Duration) {}
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod backend {
+    use std::time::Duration;
+
+    pub const CAN_SLEEP: bool = true;
+
+    pub fn sleep(duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
@@ -0,0 +1,419 @@
+// Trening na ziarnie: przycisk "Study" obok pola ziarna otwiera przewijaną
+// minimapę całej sekwencji przeszkód wybranego ziarna (a bez wybranego ziarna –
+// ziarna bieżącego tygodnia turnieju, z jego zasadami). Rura to słupek z
+// przerwą na właściwej wysokości; tunel ma ciemniejsze ściany, a kolce
+// i strefy niskiej grawitacji są zaznaczone obok.
+//
+// Znajomość trasy to przewaga, więc rundy na obejrzanym ziarnie są treningiem:
+// nie trafiają do zgłoszeń na tablicę wyników (`submission.rs`). Obejrzane
+// ziarna pamiętamy do końca sesji.
+
+use std::collections::HashSet;
+
+use bevy::ecs::system::SystemParam;
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+
+use crate::core::{Extra, Pattern};
+use crate::display::WORLD_SIZE;
+use crate::menu::Menu;
+use crate::pipes::pattern_odds;
+use crate::rng::{SeedChoice, preview_patterns};
+use crate::seed_display::seed_label;
+use crate::settings::Settings;
+use crate::toast::Toast;
+use crate::tournament::{TournamentSchedule, WeeklyRun, current_week};
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+// Ile przeszkód pokazuje minimapa.
+const PREVIEW_OBSTACLES: usize = 80;
+const MAP_HEIGHT: f32 = 180.0;
+const COLUMN_WIDTH: f32 = 18.0;
+// Pasek obok rury na kolce albo strefę niskiej grawitacji.
+const MARK_WIDTH: f32 = 10.0;
+const SPIKE_HEIGHT: f32 = 8.0;
+// Numer co tyle przeszkód.
+const LABEL_EVERY: usize = 5;
+const LINE_SCROLL: f32 = 40.0;
+const KEY_SCROLL_SPEED: f32 = 500.0;
+
+const PIPE_COLOR: Color = Color::srgb(0.35, 0.75, 0.25);
+const TUNNEL_COLOR: Color = Color::srgb(0.25, 0.4, 0.15);
+const SPIKE_COLOR: Color = Color::srgb(0.9, 0.2, 0.2);
+const LOW_GRAVITY_COLOR: Color = Color::srgba(0.4, 0.6, 1.0, 0.5);
+
+// Ziarna obejrzane na minimapie w tej sesji.
+#[derive(Resource, Default)]
+pub struct StudiedSeeds(HashSet<u64>);
+
+impl StudiedSeeds {
+    pub fn contains(&self, seed: u64) -> bool {
+        self.0.contains(&seed)
+    }
+}
+
+#[derive(Component)]
+pub struct PracticeButton;
+
+#[derive(Component)]
+struct PracticeCloseButton;
+
+#[derive(Component)]
+struct PracticeMap;
+
+// Przewijany pasek z przeszkodami.
+#[derive(Component)]
+struct MapStrip;
+
+pub struct PracticePlugin;
+
+impl Plugin for PracticePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StudiedSeeds>().add_systems(
+            Update,
+            (practice_buttons_system, close_on_escape, scroll_map).run_if(in_state(AppState::Menu)),
+        );
+    }
+}
+
+// Przycisk obok pola ziarna (`seed_entry.rs`).
+pub fn spawn_practice_button(row: &mut ChildBuilder) {
+    row.spawn((
+        Button,
+        Interaction::default(),
+        Node {
+            height: Val::Px(40.0),
+            padding: UiRect::horizontal(Val::Px(14.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..Default::default()
+        },
+        BackgroundColor(NORMAL_BUTTON),
+        BorderRadius::all(Val::Px(8.0)),
+        PracticeButton,
+    ))
+    .with_child((Text::new("Study"), TextColor(Color::WHITE)));
+}
+
+// Ziarno do obejrzenia: wybrane w polu ziarna albo ziarno bieżącego tygodnia.
+#[derive(SystemParam)]
+struct StudyTarget<'w> {
+    choice: Res<'w, SeedChoice>,
+    schedule: Res<'w, TournamentSchedule>,
+}
+
+impl StudyTarget<'_> {
+    fn get(&self) -> Option<(u64, Option<WeeklyRun>)> {
+        match self.choice.0 {
+            Some(seed) => Some((seed, None)),
+            None => current_week(&self.schedule)
+                .map(|(week, _)| (week.seed, Some(WeeklyRun::new(week)))),
+        }
+    }
+}
+
+fn practice_buttons_system(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, Has<PracticeButton>),
+        (
+            Changed<Interaction>,
+            Or<(With<PracticeButton>, With<PracticeCloseButton>)>,
+        ),
+    >,
+    target: StudyTarget,
+    settings: Res<Settings>,
+    maps: Query<Entity, With<PracticeMap>>,
+    mut studied: ResMut<StudiedSeeds>,
+    mut toasts: EventWriter<Toast>,
+) {
+    for (interaction, mut bg_color, open) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                for map in maps.iter() {
+                    commands.entity(map).despawn_recursive();
+                }
+                if !open || !maps.is_empty() {
+                    continue;
+                }
+                let Some((seed, weekly)) = target.get() else {
+                    toasts.send(Toast("Enter a seed to study".to_string()));
+                    continue;
+                };
+                if studied.0.insert(seed) {
+                    toasts.send(Toast(
+                        "Practice seed: runs on it won't be submitted".to_string(),
+                    ));
+                }
+                spawn_practice_map(&mut commands, seed, weekly.as_ref(), &settings);
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+fn spawn_practice_map(
+    commands: &mut Commands,
+    seed: u64,
+    weekly: Option<&WeeklyRun>,
+    settings: &Settings,
+) {
+    let curve = crate::kids::run_curve(settings, false);
+    let plans = preview_patterns(seed, &pattern_odds(settings.difficulty), PREVIEW_OBSTACLES);
+    let title = match weekly {
+        Some(weekly) => format!(
+            "{} – seed {}",
+            weekly.week.modifier.label(),
+            seed_label(seed)
+        ),
+        None => format!("Seed {}", seed_label(seed)),
+    };
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(5.0),
+                right: Val::Percent(5.0),
+                bottom: Val::Px(20.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+            BorderRadius::all(Val::Px(8.0)),
+            // Nad resztą menu.
+            GlobalZIndex(1),
+            PracticeMap,
+            Menu,
+        ))
+        .with_children(|parent| {
+            parent.spawn((Text::new(title), TextColor(Color::WHITE)));
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        overflow: Overflow::scroll_x(),
+                        column_gap: Val::Px(2.0),
+                        ..Default::default()
+                    },
+                    BackgroundColor(Color::srgb(0.45, 0.7, 0.9)),
+                    ScrollPosition::default(),
+                    MapStrip,
+                ))
+                .with_children(|strip| {
+                    for (index, plan) in plans.iter().enumerate() {
+                        let score = index as i32;
+                        let params = crate::tournament::apply_rules(curve.params_at(score), weekly);
+                        spawn_obstacle(strip, index + 1, plan.center_y, params.gap, plan.pattern);
+                    }
+                });
+            parent.spawn((
+                Text::new("Scroll with the mouse wheel or ← →   ·   Esc closes"),
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                TextFont {
+                    font_size: 14.0,
+                    ..Default::default()
+                },
+            ));
+            parent
+                .spawn((
+                    Button,
+                    Interaction::default(),
+                    Node {
+                        padding: UiRect::axes(Val::Px(16.0), Val::Px(8.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(NORMAL_BUTTON),
+                    BorderRadius::all(Val::Px(8.0)),
+                    PracticeCloseButton,
+                ))
+                .with_child((Text::new("Close"), TextColor(Color::WHITE)));
+        });
+}
+
+// Jedna przeszkoda: numer, słupek z przerwą i pasek na dodatek.
+fn spawn_obstacle(
+    strip: &mut ChildBuilder,
+    number: usize,
+    center_y: f32,
+    gap: f32,
+    pattern: Pattern,
+) {
+    let (top, bottom) = pipe_heights(center_y, gap);
+    let (color, extra) = match pattern {
+        Pattern::Tunnel => (TUNNEL_COLOR, Extra::None),
+        Pattern::Pipes(extra) => (PIPE_COLOR, extra),
+    };
+    let label = if number % LABEL_EVERY == 0 {
+        number.to_string()
+    } else {
+        String::new()
+    };
+
+    strip
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            flex_shrink: 0.0,
+            ..Default::default()
+        })
+        .with_children(|slot| {
+            slot.spawn((
+                Text::new(label),
+                TextColor(Color::BLACK),
+                TextFont {
+                    font_size: 12.0,
+                    ..Default::default()
+                },
+                Node {
+                    height: Val::Px(14.0),
+                    ..Default::default()
+                },
+            ));
+            slot.spawn(Node {
+                height: Val::Px(MAP_HEIGHT),
+                ..Default::default()
+            })
+            .with_children(|map| {
+                map.spawn(Node {
+                    width: Val::Px(COLUMN_WIDTH),
+                    height: Val::Percent(100.0),
+                    ..Default::default()
+                })
+                .with_children(|column| {
+                    column.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            top: Val::Px(0.0),
+                            width: Val::Percent(100.0),
+                            height: Val::Px(top),
+                            ..Default::default()
+                        },
+                        BackgroundColor(color),
+                    ));
+                    column.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            bottom: Val::Px(0.0),
+                            width: Val::Percent(100.0),
+                            height: Val::Px(bottom),
+                            ..Default::default()
+                        },
+                        BackgroundColor(color),
+                    ));
+                });
+                map.spawn(Node {
+                    width: Val::Px(MARK_WIDTH),
+                    height: Val::Percent(100.0),
+                    ..Default::default()
+                })
+                .with_children(|mark| match extra {
+                    Extra::Spikes { top } => {
+                        mark.spawn((
+                            Node {
+                                position_type: PositionType::Absolute,
+                                top: if top { Val::Px(0.0) } else { Val::Auto },
+                                bottom: if top { Val::Auto } else { Val::Px(0.0) },
+                                width: Val::Percent(100.0),
+                                height: Val::Px(SPIKE_HEIGHT),
+                                ..Default::default()
+                            },
+                            BackgroundColor(SPIKE_COLOR),
+                        ));
+                    }
+                    Extra::LowGravity => {
+                        mark.spawn((
+                            Node {
+                                width: Val::Percent(100.0),
+                                height: Val::Percent(100.0),
+                                ..Default::default()
+                            },
+                            BackgroundColor(LOW_GRAVITY_COLOR),
+                        ));
+                    }
+                    Extra::None => {}
+                });
+            });
+        });
+}
+
+// Wysokość górnej i dolnej rury na minimapie, w pikselach od krawędzi mapy.
+fn pipe_heights(center_y: f32, gap: f32) -> (f32, f32) {
+    let scale = MAP_HEIGHT / WORLD_SIZE.y;
+    let (top_mouth, bottom_mouth) = crate::core::pipe_mouths(center_y, gap);
+    let top = (WORLD_SIZE.y / 2.0 - top_mouth) * scale;
+    let bottom = (bottom_mouth + WORLD_SIZE.y / 2.0) * scale;
+    (top.clamp(0.0, MAP_HEIGHT), bottom.clamp(0.0, MAP_HEIGHT))
+}
+
+fn close_on_escape(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    maps: Query<Entity, With<PracticeMap>>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::Escape) {
+        return;
+    }
+    for map in maps.iter() {
+        commands.entity(map).despawn_recursive();
+    }
+}
+
+fn scroll_map(
+    time: Res<Time>,
+    mut wheel: EventReader<MouseWheel>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut strips: Query<&mut ScrollPosition, With<MapStrip>>,
+) {
+    let mut delta: f32 = wheel
+        .read()
+        .map(|event| {
+            // Kółko w pionie też przewija w bok – mapa jest pozioma.
+            let amount = event.x - event.y;
+            match event.unit {
+                MouseScrollUnit::Line => amount * LINE_SCROLL,
+                MouseScrollUnit::Pixel => amount,
+            }
+        })
+        .sum();
+    if keyboard_input.pressed(KeyCode::ArrowRight) {
+        delta += KEY_SCROLL_SPEED * time.delta_secs();
+    }
+    if keyboard_input.pressed(KeyCode::ArrowLeft) {
+        delta -= KEY_SCROLL_SPEED * time.delta_secs();
+    }
+    if delta == 0.0 {
+        return;
+    }
+    for mut scroll in strips.iter_mut() {
+        scroll.offset_x = (scroll.offset_x + delta).max(0.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimap_pipes_leave_the_gap_at_its_height() {
+        let scale = MAP_HEIGHT / WORLD_SIZE.y;
+        let (top, bottom) = pipe_heights(0.0, 200.0);
+        assert_eq!(top, bottom);
+        assert!((MAP_HEIGHT - top - bottom - 200.0 * scale).abs() < 0.01);
+
+        let (top, bottom) = pipe_heights(100.0, 200.0);
+        assert!(top < bottom);
+        assert_eq!(pipe_heights(0.0, 2.0 * WORLD_SIZE.y), (0.0, 0.0));
+    }
+}
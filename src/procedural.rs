@@ -0,0 +1,241 @@
+// Tryb bez plików graficznych (`--no-assets`): ptak, rury i tło są rysowane
+// w kodzie, piksel po pikselu, w rozmiarach oryginalnych obrazków. Gra działa
+// wtedy z gołego klona repozytorium, bez paczek ze sprite'ami.
+
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use crate::theme::Theme;
+
+const NO_ASSETS_FLAG: &str = "--no-assets";
+
+// Obecność zasobu włącza rysowanie grafiki w kodzie zamiast wczytywania plików.
+#[derive(Resource)]
+pub struct ProceduralArt;
+
+impl ProceduralArt {
+    pub fn requested() -> Option<Self> {
+        std::env::args()
+            .any(|arg| arg == NO_ASSETS_FLAG)
+            .then_some(Self)
+    }
+}
+
+type Rgba = [u8; 4];
+
+const CLEAR: Rgba = [0, 0, 0, 0];
+const OUTLINE: Rgba = [40, 30, 30, 255];
+
+// Obrazek z funkcji koloru piksela (y rośnie w dół, jak w plikach PNG).
+fn paint(width: u32, height: u32, pixel: impl Fn(f32, f32) -> Rgba) -> Image {
+    let mut data = Vec::with_capacity((width * height * 4) as usize);
+    for y in 0..height {
+        for x in 0..width {
+            data.extend_from_slice(&pixel(x as f32 + 0.5, y as f32 + 0.5));
+        }
+    }
+    Image::new(
+        Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+fn mix(a: Rgba, b: Rgba, t: f32) -> Rgba {
+    let t = t.clamp(0.0, 1.0);
+    std::array::from_fn(|i| (a[i] as f32 + (b[i] as f32 - a[i] as f32) * t).round() as u8)
+}
+
+// Wartość > 1 poza elipsą o środku `center` i półosiach `radius`.
+fn ellipse(x: f32, y: f32, center: Vec2, radius: Vec2) -> f32 {
+    ((x - center.x) / radius.x).powi(2) + ((y - center.y) / radius.y).powi(2)
+}
+
+// Ptak 34×24 patrzący w prawo.
+pub fn bird_image() -> Image {
+    bird_frame(0.0)
+}
+
+// Klatka machania: skrzydło uniesione o `wing_lift` pikseli (ujemne – opuszczone).
+pub fn bird_frame(wing_lift: f32) -> Image {
+    let body = Vec2::new(15.0, 12.0);
+    paint(34, 24, |x, y| {
+        let eye = ellipse(x, y, Vec2::new(22.0, 8.0), Vec2::splat(4.5));
+        let pupil = ellipse(x, y, Vec2::new(23.5, 8.5), Vec2::splat(1.8));
+        let beak = ellipse(x, y, Vec2::new(27.0, 14.0), Vec2::new(6.0, 3.0));
+        let wing = ellipse(x, y, Vec2::new(9.0, 13.0 - wing_lift), Vec2::new(6.0, 4.0));
+        let shape = ellipse(x, y, body, Vec2::new(14.0, 11.0));
+
+        if pupil <= 1.0 {
+            OUTLINE
+        } else if eye <= 1.0 {
+            [255, 255, 255, 255]
+        } else if beak <= 1.0 && x > 22.0 {
+            [245, 110, 40, 255]
+        } else if wing <= 1.0 {
+            [255, 245, 190, 255]
+        } else if shape <= 0.8 {
+            mix([250, 200, 40, 255], [230, 150, 30, 255], y / 24.0)
+        } else if shape <= 1.0 {
+            OUTLINE
+        } else {
+            CLEAR
+        }
+    })
+}
+
+// Bańka 64×64: przejrzyste wnętrze, jaśniejszy brzeg i odblask u góry.
+pub fn bubble_image() -> Image {
+    let center = Vec2::splat(32.0);
+    paint(64, 64, |x, y| {
+        let shape = ellipse(x, y, center, Vec2::splat(31.0));
+        let shine = ellipse(x, y, Vec2::new(22.0, 18.0), Vec2::new(7.0, 4.0));
+
+        if shape > 1.0 {
+            CLEAR
+        } else if shine <= 1.0 {
+            [255, 255, 255, 220]
+        } else if shape > 0.8 {
+            [220, 245, 255, 200]
+        } else {
+            mix([200, 235, 255, 30], [200, 235, 255, 90], shape / 0.8)
+        }
+    })
+}
+
+// Rura 52×320 z kołnierzem u góry obrazka (wylot).
+pub fn pipe_image(theme: &Theme) -> Image {
+    let (light, dark) = match theme {
+        Theme::Sunset => ([230, 90, 70, 255], [150, 40, 35, 255]),
+        _ => ([150, 220, 80, 255], [70, 130, 40, 255]),
+    };
+    const LIP_HEIGHT: f32 = 24.0;
+    const BODY_INSET: f32 = 3.0;
+    paint(52, 320, |x, y| {
+        let lip = y < LIP_HEIGHT;
+        let (left, right) = if lip {
+            (0.0, 52.0)
+        } else {
+            (BODY_INSET, 52.0 - BODY_INSET)
+        };
+        if x < left || x > right {
+            return CLEAR;
+        }
+        let edge = x - left < 2.0 || right - x < 2.0 || (lip && (y < 2.0 || LIP_HEIGHT - y < 2.0));
+        if edge {
+            return OUTLINE;
+        }
+        // Walec oświetlony z lewej.
+        let across = (x - left) / (right - left);
+        let shade = ((across - 0.3).abs() * 1.6).min(1.0);
+        mix(light, dark, shade)
+    })
+}
+
+// Tło 288×512: niebo z gradientem, chmury i pagórki u dołu; nocą gwiazdy.
+pub fn background_image(theme: &Theme) -> Image {
+    let night = matches!(theme, Theme::Night);
+    let (top, horizon) = if night {
+        ([10, 20, 55, 255], [40, 60, 110, 255])
+    } else {
+        ([80, 180, 220, 255], [190, 235, 245, 255])
+    };
+    let hills = if night {
+        [30, 70, 50, 255]
+    } else {
+        [95, 190, 90, 255]
+    };
+    let clouds = [
+        (Vec2::new(60.0, 330.0), Vec2::new(40.0, 14.0)),
+        (Vec2::new(190.0, 300.0), Vec2::new(55.0, 16.0)),
+        (Vec2::new(250.0, 350.0), Vec2::new(35.0, 12.0)),
+    ];
+
+    paint(288, 512, |x, y| {
+        let hill_top = 430.0 - 18.0 * (x / 288.0 * std::f32::consts::TAU * 2.0).sin();
+        if y > hill_top {
+            return mix(hills, OUTLINE, (y - hill_top) / 200.0);
+        }
+        if !night
+            && clouds
+                .iter()
+                .any(|&(center, radius)| ellipse(x, y, center, radius) <= 1.0)
+        {
+            return [250, 252, 255, 255];
+        }
+        // Rzadkie, stałe gwiazdy z prostego skrótu pozycji.
+        let hash = (x as u32).wrapping_mul(73_856_093) ^ (y as u32).wrapping_mul(19_349_663);
+        if night && y < 380.0 && hash % 997 == 0 {
+            return [255, 255, 230, 255];
+        }
+        mix(top, horizon, y / 430.0)
+    })
+}
+
+// Cyfra 24×36 jak na wyświetlaczu siedmiosegmentowym: białe segmenty z obwódką.
+pub fn digit_image(digit: usize) -> Image {
+    // Segmenty: góra, prawy górny, prawy dolny, dół, lewy dolny, lewy górny, środek.
+    const SEGMENTS: [[bool; 7]; 10] = [
+        [true, true, true, true, true, true, false],
+        [false, true, true, false, false, false, false],
+        [true, true, false, true, true, false, true],
+        [true, true, true, true, false, false, true],
+        [false, true, true, false, false, true, true],
+        [true, false, true, true, false, true, true],
+        [true, false, true, true, true, true, true],
+        [true, true, true, false, false, false, false],
+        [true, true, true, true, true, true, true],
+        [true, true, true, true, false, true, true],
+    ];
+    // Prostokąty segmentów (x0, y0, x1, y1) w tej samej kolejności.
+    const RECTS: [[f32; 4]; 7] = [
+        [4.0, 2.0, 20.0, 7.0],
+        [17.0, 4.0, 22.0, 18.0],
+        [17.0, 18.0, 22.0, 32.0],
+        [4.0, 29.0, 20.0, 34.0],
+        [2.0, 18.0, 7.0, 32.0],
+        [2.0, 4.0, 7.0, 18.0],
+        [4.0, 15.5, 20.0, 20.5],
+    ];
+    let lit = SEGMENTS[digit % 10];
+    paint(24, 36, |x, y| {
+        // Odległość od najbliższego zapalonego segmentu (0 wewnątrz).
+        let distance = RECTS
+            .iter()
+            .zip(lit)
+            .filter(|(_, on)| *on)
+            .map(|([x0, y0, x1, y1], _)| {
+                let dx = (x0 - x).max(x - x1).max(0.0);
+                let dy = (y0 - y).max(y - y1).max(0.0);
+                dx.max(dy)
+            })
+            .fold(f32::INFINITY, f32::min);
+        if distance <= 0.0 {
+            [255, 255, 255, 255]
+        } else if distance <= 2.0 {
+            [0, 0, 0, 255]
+        } else {
+            CLEAR
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_images_match_the_sprite_sizes() {
+        assert_eq!(bird_image().size(), UVec2::new(34, 24));
+        assert_eq!(pipe_image(&Theme::Day).size(), UVec2::new(52, 320));
+        assert_eq!(background_image(&Theme::Night).size(), UVec2::new(288, 512));
+        assert_eq!(digit_image(8).size(), UVec2::new(24, 36));
+    }
+}
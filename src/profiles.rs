@@ -0,0 +1,620 @@
+// Profile graczy dzielących jeden komputer. Każdy profil ma własny katalog
+// `profiles/<nazwa>/` z rekordami i tablicą wyników (`bests.ron`), zapisaną
+// rundą (`run.ron`) i odblokowanymi dodatkami (`unlocks.ron`); lista profili
+// i ostatnio wybrany leżą w `profiles.ron`. Ustawienia (okno, dźwięk,
+// sterowanie) zostają wspólne.
+//
+// Przy starcie z kilkoma profilami menu zaczyna od pytania "Who's playing?",
+// a przycisk "Profile: …" w rogu menu otwiera ten sam ekran z dodawaniem i
+// usuwaniem profili. Profil dodany przyciskiem "Add kid" gra w trybie dla
+// dzieci. Tylko gra z oknem dodaje ten plugin – testy nie dotykają plików.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use serde::{Deserialize, Serialize};
+
+use crate::bests::PersonalBests;
+use crate::buttons::button_text;
+use crate::cheats::Cosmetics;
+use crate::kids::{KidsMode, ParentalLock};
+use crate::menu::Menu;
+use crate::settings::Settings;
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+const PROFILES_PATH: &str = "profiles.ron";
+const PROFILES_DIR: &str = "profiles";
+const UNLOCKS_FILE: &str = "unlocks.ron";
+// Pliki sprzed profili, przenoszone do pierwszego profilu.
+const LEGACY_FILES: [&str; 2] = ["bests.ron", "run.ron"];
+const DEFAULT_PROFILE: &str = "Player";
+const MAX_NAME_LEN: usize = 16;
+
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProfileList {
+    pub names: Vec<String>,
+    pub last: Option<String>,
+    // Profile dziecięce – grają w trybie dla dzieci (`kids.rs`).
+    pub kids: Vec<String>,
+}
+
+// Profil, którego dane są teraz wczytane. Bez pliku profili (testy) to
+// domyślny "Player".
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct ActiveProfile(pub String);
+
+impl Default for ActiveProfile {
+    fn default() -> Self {
+        Self(DEFAULT_PROFILE.to_string())
+    }
+}
+
+impl ActiveProfile {
+    pub fn dir(&self) -> PathBuf {
+        Path::new(PROFILES_DIR).join(&self.0)
+    }
+
+    // Ścieżka pliku w katalogu profilu.
+    pub fn path(&self, file: &str) -> PathBuf {
+        self.dir().join(file)
+    }
+}
+
+// Przy starcie z kilkoma profilami pierwsze menu pyta, kto gra.
+#[derive(Resource)]
+pub struct PickProfileOnStart;
+
+impl ProfileList {
+    // Wczytuje listę profili; przy pierwszym starcie z profilami przenosi
+    // dotychczasowe pliki i odblokowania z ustawień do profilu "Player".
+    pub fn load(settings: &Settings) -> Self {
+        let mut list = match fs::read_to_string(PROFILES_PATH) {
+            Ok(contents) => ron::from_str(&contents).unwrap_or_else(|err| {
+                warn!("Nie udało się odczytać {PROFILES_PATH}: {err}");
+                ProfileList::default()
+            }),
+            Err(_) => ProfileList::migrate(settings),
+        };
+        if list.names.is_empty() {
+            list.names.push(DEFAULT_PROFILE.to_string());
+        }
+        list
+    }
+
+    fn migrate(settings: &Settings) -> Self {
+        let profile = ActiveProfile::default();
+        if let Err(err) = fs::create_dir_all(profile.dir()) {
+            warn!("Nie udało się utworzyć katalogu profilu: {err}");
+        }
+        for file in LEGACY_FILES {
+            if Path::new(file).exists() {
+                if let Err(err) = fs::rename(file, profile.path(file)) {
+                    warn!("Nie udało się przenieść {file} do profilu: {err}");
+                }
+            }
+        }
+        save_unlocks(&profile, &settings.cosmetics);
+
+        let list = ProfileList {
+            names: vec![profile.0.clone()],
+            last: Some(profile.0),
+            kids: Vec::new(),
+        };
+        list.save();
+        list
+    }
+
+    pub fn save(&self) {
+        let contents = match ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()) {
+            Ok(contents) => contents,
+            Err(err) => {
+                warn!("Nie udało się zserializować listy profili: {err}");
+                return;
+            }
+        };
+
+        if let Err(err) = fs::write(PROFILES_PATH, contents) {
+            warn!("Nie udało się zapisać {PROFILES_PATH}: {err}");
+        }
+    }
+
+    // Ostatnio wybrany profil albo pierwszy z listy.
+    pub fn active(&self) -> ActiveProfile {
+        let name = self
+            .last
+            .as_ref()
+            .filter(|last| self.names.contains(last))
+            .or(self.names.first());
+        name.map(|name| ActiveProfile(name.clone()))
+            .unwrap_or_default()
+    }
+
+    pub fn is_kid(&self, profile: &ActiveProfile) -> bool {
+        self.kids.contains(&profile.0)
+    }
+
+    // Sprawdza nazwę nowego profilu i zwraca ją bez zbędnych spacji.
+    fn validate_name(&self, name: &str) -> Result<String, &'static str> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Type a name first");
+        }
+        if self
+            .names
+            .iter()
+            .any(|other| other.eq_ignore_ascii_case(name))
+        {
+            return Err("That name is taken");
+        }
+        Ok(name.to_string())
+    }
+}
+
+// Nazwa jest też nazwą katalogu, więc dopuszczamy tylko bezpieczne znaki.
+fn allowed_in_name(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, ' ' | '-' | '_')
+}
+
+pub fn load_unlocks(profile: &ActiveProfile) -> Cosmetics {
+    let path = profile.path(UNLOCKS_FILE);
+    let Ok(contents) = fs::read_to_string(&path) else {
+        return Cosmetics::default();
+    };
+    ron::from_str(&contents).unwrap_or_else(|err| {
+        warn!("Nie udało się odczytać {}: {err}", path.display());
+        Cosmetics::default()
+    })
+}
+
+fn save_unlocks(profile: &ActiveProfile, cosmetics: &Cosmetics) {
+    let path = profile.path(UNLOCKS_FILE);
+    let contents = match ron::ser::to_string_pretty(cosmetics, ron::ser::PrettyConfig::default()) {
+        Ok(contents) => contents,
+        Err(err) => {
+            warn!("Nie udało się zserializować odblokowań: {err}");
+            return;
+        }
+    };
+    if let Err(err) = fs::write(&path, contents) {
+        warn!("Nie udało się zapisać {}: {err}", path.display());
+    }
+}
+
+#[derive(Component)]
+struct ProfileButton;
+
+#[derive(Component)]
+struct ProfileScreenUi;
+
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+enum ProfileScreenButton {
+    Select(String),
+    Delete(String),
+    Add,
+    AddKid,
+    Close,
+}
+
+// Otwarty ekran profili: wpisywana nazwa nowego profilu, profil czekający na
+// potwierdzenie usunięcia i komunikat o błędzie.
+#[derive(Resource, Default)]
+struct ProfileScreen {
+    typed: String,
+    confirm_delete: Option<String>,
+    error: Option<&'static str>,
+}
+
+pub fn profile_button_label(profile: &ActiveProfile) -> String {
+    format!("Profile: {}", profile.0)
+}
+
+pub struct ProfilesPlugin;
+
+impl Plugin for ProfilesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ProfileList>()
+            .add_systems(
+                OnEnter(AppState::Menu),
+                (spawn_profile_button, open_picker_on_start)
+                    .chain()
+                    .after(crate::menu::setup_menu),
+            )
+            .add_systems(OnExit(AppState::Menu), close_profile_screen)
+            .add_systems(
+                Update,
+                (
+                    profile_button_system,
+                    profile_screen_buttons,
+                    profile_name_typing,
+                    spawn_profile_screen.run_if(resource_changed_or_removed::<ProfileScreen>),
+                    update_profile_button.run_if(resource_changed::<ActiveProfile>),
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Menu)),
+            )
+            .add_systems(
+                Update,
+                load_active_profile.run_if(
+                    resource_changed::<ActiveProfile>.and(not(resource_added::<ActiveProfile>)),
+                ),
+            )
+            .add_systems(
+                Last,
+                save_unlocks_on_change.run_if(resource_changed::<Settings>),
+            );
+    }
+}
+
+fn spawn_profile_button(mut commands: Commands, profile: Res<ActiveProfile>) {
+    commands
+        .spawn((
+            Button,
+            Interaction::default(),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(20.0),
+                bottom: Val::Px(20.0),
+                padding: UiRect::axes(Val::Px(16.0), Val::Px(10.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(NORMAL_BUTTON),
+            BorderRadius::all(Val::Px(8.0)),
+            ProfileButton,
+            ParentalLock::default(),
+            Menu,
+        ))
+        .with_child((
+            Text::new(profile_button_label(&profile)),
+            TextColor(Color::WHITE),
+        ));
+}
+
+fn open_picker_on_start(mut commands: Commands, pick: Option<Res<PickProfileOnStart>>) {
+    if pick.is_some() {
+        commands.remove_resource::<PickProfileOnStart>();
+        commands.init_resource::<ProfileScreen>();
+    }
+}
+
+fn close_profile_screen(mut commands: Commands, ui: Query<Entity, With<ProfileScreenUi>>) {
+    for entity in ui.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.remove_resource::<ProfileScreen>();
+}
+
+fn profile_button_system(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<ProfileButton>),
+    >,
+) {
+    for (interaction, mut bg_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                commands.insert_resource(ProfileScreen::default());
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+fn update_profile_button(
+    profile: Res<ActiveProfile>,
+    buttons: Query<Option<&Children>, With<ProfileButton>>,
+    mut texts: Query<&mut Text>,
+) {
+    for children in buttons.iter() {
+        if let Some(mut text) = button_text(children, &mut texts) {
+            text.0 = profile_button_label(&profile);
+        }
+    }
+}
+
+// Ekran jest budowany od nowa przy każdej zmianie jego stanu.
+fn spawn_profile_screen(
+    mut commands: Commands,
+    screen: Option<Res<ProfileScreen>>,
+    list: Res<ProfileList>,
+    profile: Res<ActiveProfile>,
+    ui: Query<Entity, With<ProfileScreenUi>>,
+) {
+    for entity in ui.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    let Some(screen) = screen else {
+        return;
+    };
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(10.0),
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+            FocusPolicy::Block,
+            GlobalZIndex(40),
+            ProfileScreenUi,
+            Menu,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Who's playing?"),
+                TextColor(Color::WHITE),
+                TextFont {
+                    font_size: 40.0,
+                    ..Default::default()
+                },
+            ));
+
+            for name in &list.names {
+                parent
+                    .spawn(Node {
+                        column_gap: Val::Px(8.0),
+                        ..Default::default()
+                    })
+                    .with_children(|row| {
+                        let mut label = name.clone();
+                        if list.kids.contains(name) {
+                            label.push_str(" (kid)");
+                        }
+                        if *name == profile.0 {
+                            label = format!("> {label} <");
+                        }
+                        spawn_button(
+                            row,
+                            &label,
+                            240.0,
+                            ProfileScreenButton::Select(name.clone()),
+                        );
+                        // Aktywnego profilu nie usuwamy – najpierw trzeba wybrać inny.
+                        if *name != profile.0 {
+                            let delete = if screen.confirm_delete.as_ref() == Some(name) {
+                                "Sure?"
+                            } else {
+                                "Delete"
+                            };
+                            spawn_button(
+                                row,
+                                delete,
+                                100.0,
+                                ProfileScreenButton::Delete(name.clone()),
+                            );
+                        }
+                    });
+            }
+
+            parent
+                .spawn(Node {
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    margin: UiRect::top(Val::Px(12.0)),
+                    ..Default::default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new(format!("New: {}_", screen.typed)),
+                        TextColor(Color::WHITE),
+                    ));
+                    spawn_button(row, "Add", 100.0, ProfileScreenButton::Add);
+                    spawn_button(row, "Add kid", 120.0, ProfileScreenButton::AddKid);
+                });
+            if let Some(error) = screen.error {
+                parent.spawn((Text::new(error), TextColor(Color::srgb(1.0, 0.5, 0.5))));
+            }
+            spawn_button(parent, "Close", 120.0, ProfileScreenButton::Close);
+        });
+}
+
+fn spawn_button(parent: &mut ChildBuilder, label: &str, width: f32, action: ProfileScreenButton) {
+    parent
+        .spawn((
+            Button,
+            Interaction::default(),
+            Node {
+                width: Val::Px(width),
+                height: Val::Px(44.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(NORMAL_BUTTON),
+            BorderRadius::all(Val::Px(8.0)),
+            action,
+        ))
+        .with_child((Text::new(label), TextColor(Color::WHITE)));
+}
+
+fn profile_screen_buttons(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &ProfileScreenButton),
+        Changed<Interaction>,
+    >,
+    screen: Option<ResMut<ProfileScreen>>,
+    mut list: ResMut<ProfileList>,
+    mut profile: ResMut<ActiveProfile>,
+) {
+    let Some(mut screen) = screen else {
+        return;
+    };
+    for (interaction, mut bg_color, button) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                match button {
+                    ProfileScreenButton::Select(name) => {
+                        if profile.0 != *name {
+                            profile.0.clone_from(name);
+                        }
+                        list.last = Some(name.clone());
+                        list.save();
+                        commands.remove_resource::<ProfileScreen>();
+                    }
+                    ProfileScreenButton::Delete(name) => {
+                        if screen.confirm_delete.as_ref() != Some(name) {
+                            screen.confirm_delete = Some(name.clone());
+                            continue;
+                        }
+                        screen.confirm_delete = None;
+                        list.names.retain(|other| other != name);
+                        list.kids.retain(|other| other != name);
+                        list.save();
+                        let dir = ActiveProfile(name.clone()).dir();
+                        if let Err(err) = fs::remove_dir_all(&dir) {
+                            warn!("Nie udało się usunąć {}: {err}", dir.display());
+                        }
+                    }
+                    ProfileScreenButton::Add => add_profile(&mut screen, &mut list, false),
+                    ProfileScreenButton::AddKid => add_profile(&mut screen, &mut list, true),
+                    ProfileScreenButton::Close => {
+                        commands.remove_resource::<ProfileScreen>();
+                    }
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+fn add_profile(screen: &mut ProfileScreen, list: &mut ProfileList, kid: bool) {
+    match list.validate_name(&screen.typed) {
+        Ok(name) => {
+            let dir = ActiveProfile(name.clone()).dir();
+            if let Err(err) = fs::create_dir_all(&dir) {
+                warn!("Nie udało się utworzyć {}: {err}", dir.display());
+                return;
+            }
+            if kid {
+                list.kids.push(name.clone());
+            }
+            list.names.push(name);
+            list.save();
+            screen.typed.clear();
+            screen.error = None;
+        }
+        Err(error) => screen.error = Some(error),
+    }
+}
+
+fn profile_name_typing(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    screen: Option<ResMut<ProfileScreen>>,
+    mut list: ResMut<ProfileList>,
+) {
+    let Some(mut screen) = screen else {
+        keyboard_events.clear();
+        return;
+    };
+
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Backspace => {
+                screen.typed.pop();
+            }
+            Key::Enter => add_profile(&mut screen, &mut list, false),
+            Key::Space if screen.typed.len() < MAX_NAME_LEN => screen.typed.push(' '),
+            Key::Character(text) => {
+                for c in text.chars().filter(|c| allowed_in_name(*c)) {
+                    if screen.typed.len() < MAX_NAME_LEN {
+                        screen.typed.push(c);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// Po zmianie profilu wczytujemy jego rekordy i odblokowania oraz włączamy
+// albo wyłączamy tryb dla dzieci.
+fn load_active_profile(
+    mut commands: Commands,
+    profile: Res<ActiveProfile>,
+    list: Res<ProfileList>,
+    mut bests: ResMut<PersonalBests>,
+    mut settings: ResMut<Settings>,
+) {
+    *bests = PersonalBests::load(&profile);
+    settings.cosmetics = load_unlocks(&profile);
+    if list.is_kid(&profile) {
+        commands.insert_resource(KidsMode);
+    } else {
+        commands.remove_resource::<KidsMode>();
+    }
+}
+
+fn save_unlocks_on_change(
+    profile: Res<ActiveProfile>,
+    settings: Res<Settings>,
+    mut saved: Local<Option<Cosmetics>>,
+) {
+    if saved.as_ref() == Some(&settings.cosmetics) {
+        return;
+    }
+    // Pierwsze wywołanie tylko zapamiętuje stan wczytany przy starcie.
+    if saved.is_some() {
+        save_unlocks(&profile, &settings.cosmetics);
+    }
+    *saved = Some(settings.cosmetics.clone());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_profile_names_must_be_unique() {
+        let list = ProfileList {
+            names: vec!["Ola".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(list.validate_name("  Tomek "), Ok("Tomek".to_string()));
+        assert!(list.validate_name("ola").is_err());
+        assert!(list.validate_name("   ").is_err());
+        assert!(!allowed_in_name('/'));
+    }
+
+    #[test]
+    fn last_profile_is_active_when_it_still_exists() {
+        let mut list = ProfileList {
+            names: vec!["Ola".to_string(), "Tomek".to_string()],
+            last: Some("Tomek".to_string()),
+            kids: vec!["Tomek".to_string()],
+        };
+        assert_eq!(list.active(), ActiveProfile("Tomek".to_string()));
+        assert!(list.is_kid(&list.active()));
+
+        list.last = Some("Kasia".to_string());
+        assert_eq!(list.active(), ActiveProfile("Ola".to_string()));
+    }
+}
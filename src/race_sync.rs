@@ -0,0 +1,315 @@
+// Synchronizacja ptaków przeciwników w wyścigu sieciowym.
+//
+// Każdy klient co kilka kroków wysyła swoje machnięcia i stan ptaka z ostatniego
+// kroku. Ptaki przeciwników liczymy lokalnie tą samą fizyką co własnego, krok
+// w krok z naszą symulacją, przewidując "brak machnięcia" tam, gdzie wejście
+// jeszcze nie dotarło. Gdy dotrze, cofamy się do potwierdzonego stanu
+// i przeliczamy kroki do bieżącego (rollback). Opóźnienia sieci zmieniają więc
+// tylko to, jak często poprawiamy przewidywanie, a nie tempo ruchu – ptaki nie
+// skaczą w rytm pakietów. Drobne poprawki wygładzamy na ekranie.
+
+use std::collections::{BTreeSet, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{FLAP_IMPULSE, GRAVITY};
+use crate::handicap::Handicap;
+use crate::lobby::PlayerId;
+
+// Co ile kroków wysyłamy paczkę wejścia.
+pub const INPUT_BATCH_FRAMES: u32 = 3;
+
+// Jaką część różnicy między pozycją narysowaną a przewidzianą odrabiamy w klatce.
+const DISPLAY_SMOOTHING: f32 = 0.3;
+
+// Stan pionowego ruchu ptaka po danym kroku. Ptaki lecą w jednej kolumnie,
+// więc pozycja pozioma nie jest potrzebna.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BirdState {
+    pub y: f32,
+    pub dy: f32,
+    // Faza "Get Ready" – ptak wisi do pierwszego machnięcia.
+    pub hovering: bool,
+    pub alive: bool,
+}
+
+impl Default for BirdState {
+    fn default() -> Self {
+        Self {
+            y: 0.0,
+            dy: 0.0,
+            hovering: true,
+            alive: true,
+        }
+    }
+}
+
+impl BirdState {
+    // Jeden krok symulacji, w tej samej kolejności co systemy `FixedUpdate`:
+    // wejście, grawitacja, ruch.
+    pub fn step(&mut self, flap: bool, dt: f32) {
+        self.step_with(flap, dt, Handicap::default());
+    }
+
+    // Krok ptaka z handicapem – każdy gracz w pokoju może mieć inny.
+    pub fn step_with(&mut self, flap: bool, dt: f32, handicap: Handicap) {
+        if !self.alive {
+            return;
+        }
+        if flap {
+            self.dy = FLAP_IMPULSE;
+            self.hovering = false;
+        }
+        if !self.hovering {
+            self.dy += GRAVITY * handicap.gravity_scale() * dt;
+            self.y += self.dy * dt;
+        }
+    }
+}
+
+// Paczka wejścia jednego gracza.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InputBatch {
+    pub player: PlayerId,
+    // Ostatni krok objęty paczką.
+    pub frame: u32,
+    // Kroki z machnięciem od poprzedniej paczki.
+    pub flaps: Vec<u32>,
+    // Stan ptaka nadawcy po kroku `frame` – źródło prawdy (np. strefy niskiej
+    // grawitacji, których tu nie liczymy, albo śmierć).
+    pub state: BirdState,
+}
+
+// Zbieranie własnego wejścia do wysłania.
+#[derive(Debug, Default)]
+pub struct LocalInputs {
+    pending: Vec<u32>,
+}
+
+impl LocalInputs {
+    // Zapisuje krok i zwraca paczkę, gdy przyszła pora ją wysłać.
+    pub fn record(
+        &mut self,
+        player: PlayerId,
+        frame: u32,
+        flapped: bool,
+        state: BirdState,
+    ) -> Option<InputBatch> {
+        if flapped {
+            self.pending.push(frame);
+        }
+        // Śmierć wysyłamy od razu, żeby przeciwnicy nie lecieli dalej "duchem".
+        let due = (frame + 1) % INPUT_BATCH_FRAMES == 0 || !state.alive;
+        due.then(|| InputBatch {
+            player,
+            frame,
+            flaps: std::mem::take(&mut self.pending),
+            state,
+        })
+    }
+}
+
+// Ptak przeciwnika: potwierdzony stan z sieci plus przewidywanie do bieżącego kroku.
+#[derive(Debug, Clone)]
+pub struct RemoteBird {
+    // Stan po kroku `confirmed_frame` (`None` – przed pierwszym krokiem).
+    confirmed: BirdState,
+    confirmed_frame: Option<u32>,
+    // Machnięcia znane, ale jeszcze niepotwierdzone stanem.
+    flaps: BTreeSet<u32>,
+    predicted: BirdState,
+    // Ostatni przeliczony krok.
+    frame: Option<u32>,
+    // Pozycja rysowana na ekranie.
+    display_y: f32,
+    // Handicap ustawiony w lobby.
+    handicap: Handicap,
+}
+
+impl Default for RemoteBird {
+    fn default() -> Self {
+        Self {
+            confirmed: BirdState::default(),
+            confirmed_frame: None,
+            flaps: BTreeSet::new(),
+            predicted: BirdState::default(),
+            frame: None,
+            display_y: 0.0,
+            handicap: Handicap::default(),
+        }
+    }
+}
+
+impl RemoteBird {
+    pub fn predicted(&self) -> BirdState {
+        self.predicted
+    }
+
+    pub fn display_y(&self) -> f32 {
+        self.display_y
+    }
+
+    // Lokalna symulacja przeszła krok `frame`.
+    pub fn advance_to(&mut self, frame: u32, dt: f32) {
+        while self.frame.is_none_or(|last| last < frame) {
+            let next = self.frame.map_or(0, |last| last + 1);
+            self.predicted
+                .step_with(self.flaps.contains(&next), dt, self.handicap);
+            self.frame = Some(next);
+        }
+    }
+
+    // Paczka z sieci: przyjmujemy stan nadawcy i przeliczamy przewidywanie od niego.
+    pub fn apply(&mut self, batch: &InputBatch, dt: f32) {
+        // Stara paczka, która przyszła po nowszej.
+        if self
+            .confirmed_frame
+            .is_some_and(|confirmed| confirmed >= batch.frame)
+        {
+            return;
+        }
+        self.flaps.extend(batch.flaps.iter().copied());
+        self.flaps.retain(|&flap| flap > batch.frame);
+        self.confirmed = batch.state;
+        self.confirmed_frame = Some(batch.frame);
+
+        // Rollback: od potwierdzonego stanu do bieżącego kroku.
+        let current = self.frame;
+        self.predicted = self.confirmed;
+        self.frame = self.confirmed_frame;
+        if let Some(current) = current {
+            self.advance_to(current, dt);
+        }
+    }
+
+    // Wygładzenie na ekranie; wołane raz na klatkę.
+    pub fn smooth_display(&mut self) {
+        self.display_y += (self.predicted.y - self.display_y) * DISPLAY_SMOOTHING;
+    }
+}
+
+// Wszyscy przeciwnicy w wyścigu.
+#[derive(Debug, Default)]
+pub struct RaceSync {
+    birds: HashMap<PlayerId, RemoteBird>,
+}
+
+impl RaceSync {
+    pub fn receive(&mut self, batch: &InputBatch, dt: f32) {
+        self.birds.entry(batch.player).or_default().apply(batch, dt);
+    }
+
+    // Handicap gracza z lobby; wołane przed startem wyścigu.
+    pub fn set_handicap(&mut self, player: PlayerId, handicap: Handicap) {
+        self.birds.entry(player).or_default().handicap = handicap.clamped();
+    }
+
+    pub fn advance_to(&mut self, frame: u32, dt: f32) {
+        for bird in self.birds.values_mut() {
+            bird.advance_to(frame, dt);
+        }
+    }
+
+    pub fn smooth_display(&mut self) {
+        for bird in self.birds.values_mut() {
+            bird.smooth_display();
+        }
+    }
+
+    pub fn bird(&self, player: PlayerId) -> Option<&RemoteBird> {
+        self.birds.get(&player)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DT: f32 = 1.0 / 60.0;
+
+    // Lot nadawcy: machnięcie co 25 kroków.
+    fn sender(frames: u32) -> (Vec<InputBatch>, BirdState) {
+        let mut state = BirdState::default();
+        let mut inputs = LocalInputs::default();
+        let mut batches = Vec::new();
+        for frame in 0..frames {
+            let flap = frame % 25 == 0;
+            state.step(flap, DT);
+            batches.extend(inputs.record(7, frame, flap, state));
+        }
+        (batches, state)
+    }
+
+    #[test]
+    fn late_inputs_are_rolled_back_into_place() {
+        let (batches, sender_state) = sender(120);
+        let mut sync = RaceSync::default();
+
+        // Paczki docierają z opóźnieniem 10 kroków, a jedna zamienia się kolejnością.
+        let mut delivered = batches.clone();
+        delivered.swap(4, 5);
+        let mut next = 0;
+        for frame in 0..130 {
+            sync.advance_to(frame, DT);
+            while next < delivered.len() && delivered[next].frame + 10 <= frame {
+                sync.receive(&delivered[next], DT);
+                next += 1;
+            }
+        }
+        for batch in &batches {
+            sync.receive(batch, DT);
+        }
+
+        let mut expected = sender_state;
+        for _ in 120..130 {
+            expected.step(false, DT);
+        }
+        let predicted = sync.bird(7).unwrap().predicted();
+        assert!((predicted.y - expected.y).abs() < 1e-3);
+        assert!((predicted.dy - expected.dy).abs() < 1e-3);
+    }
+
+    #[test]
+    fn prediction_moves_at_simulation_pace_between_packets() {
+        let (batches, _) = sender(30);
+        let mut sync = RaceSync::default();
+        sync.receive(&batches[0], DT);
+        sync.advance_to(2, DT);
+        let before = sync.bird(7).unwrap().predicted();
+
+        // Bez nowych paczek ptak dalej leci, zamiast stać w miejscu.
+        sync.advance_to(20, DT);
+        let after = sync.bird(7).unwrap().predicted();
+        assert_ne!(before.y, after.y);
+        assert!(after.dy < before.dy);
+    }
+
+    #[test]
+    fn handicapped_bird_is_predicted_with_its_own_gravity() {
+        let (batches, _) = sender(3);
+        let mut sync = RaceSync::default();
+        sync.set_handicap(
+            8,
+            Handicap {
+                gap_percent: 100,
+                gravity_percent: 50,
+            },
+        );
+        for batch in &batches {
+            sync.receive(batch, DT);
+            sync.receive(
+                &InputBatch {
+                    player: 8,
+                    ..batch.clone()
+                },
+                DT,
+            );
+        }
+        sync.advance_to(30, DT);
+
+        let normal = sync.bird(7).unwrap().predicted();
+        let handicapped = sync.bird(8).unwrap().predicted();
+        assert!(handicapped.y > normal.y);
+        assert!(handicapped.dy > normal.dy);
+    }
+}
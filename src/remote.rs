@@ -0,0 +1,265 @@
+// Zdalne sterowanie grą przez TCP – dla zewnętrznych programów (pokazy,
+// zawody botów). Funkcja deweloperska: cecha `remote` i flaga
+// `--remote-control [adres]` (domyślnie 127.0.0.1:7878).
+//
+// Protokół tekstowy, jedna wiadomość na linię.
+// Serwer → klient:
+//   tick,<n>,<bird_y>,<bird_dy>,<pipe1_dx>,<pipe1_gap_y>,<pipe1_gap>,
+//        <pipe2_dx>,<pipe2_gap_y>,<pipe2_gap>,<score>
+//     – po każdym kroku rundy; pola jak w zbiorze danych z `dataset.rs`
+//   over,<score> – koniec rundy
+// Klient → serwer:
+//   flap  – machnięcie (jak naciśnięcie klawisza)
+//   start – nowa runda z menu albo z ekranu końca gry
+
+use std::io::{ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::dataset::Observation;
+use crate::input::FlapBuffer;
+use crate::pause::PauseState;
+use crate::physics::{Collider, Velocity, boundary_collision_system, collision_system};
+use crate::player::Player;
+use crate::score::{Score, ScoreZone, apply_score_events};
+
+const REMOTE_FLAG: &str = "--remote-control";
+const DEFAULT_ADDR: &str = "127.0.0.1:7878";
+
+// Klient, który tyle nie odebrał, jest rozłączany.
+const MAX_PENDING_BYTES: usize = 64 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteCommand {
+    Flap,
+    Start,
+}
+
+impl RemoteCommand {
+    pub fn parse(line: &str) -> Option<Self> {
+        match line.trim() {
+            "flap" => Some(RemoteCommand::Flap),
+            "start" => Some(RemoteCommand::Start),
+            _ => None,
+        }
+    }
+}
+
+// Adres z `--remote-control`, jeśli gra została z nim uruchomiona.
+pub fn requested() -> Option<SocketAddr> {
+    let mut args = std::env::args().skip_while(|arg| arg != REMOTE_FLAG);
+    args.next()?;
+    let addr = args
+        .next()
+        .filter(|arg| !arg.starts_with("--"))
+        .unwrap_or_else(|| DEFAULT_ADDR.to_string());
+    match addr.parse() {
+        Ok(addr) => Some(addr),
+        Err(err) => {
+            warn!("Niepoprawny adres zdalnego sterowania {addr}: {err}");
+            None
+        }
+    }
+}
+
+struct RemoteClient {
+    stream: TcpStream,
+    // Odebrane bajty bez zakończonej linii.
+    incoming: Vec<u8>,
+    // Bajty czekające na wysłanie.
+    outgoing: Vec<u8>,
+}
+
+impl RemoteClient {
+    // Zwraca `false`, gdy połączenie trzeba zamknąć.
+    fn read_commands(&mut self, commands: &mut Vec<RemoteCommand>) -> bool {
+        let mut buf = [0; 512];
+        loop {
+            match self.stream.read(&mut buf) {
+                Ok(0) => return false,
+                Ok(read) => self.incoming.extend_from_slice(&buf[..read]),
+                Err(err) if err.kind() == ErrorKind::WouldBlock => break,
+                Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                Err(_) => return false,
+            }
+        }
+        while let Some(end) = self.incoming.iter().position(|&byte| byte == b'\n') {
+            let line: Vec<u8> = self.incoming.drain(..=end).collect();
+            commands.extend(RemoteCommand::parse(&String::from_utf8_lossy(&line)));
+        }
+        self.incoming.len() <= MAX_PENDING_BYTES
+    }
+
+    fn flush(&mut self) -> bool {
+        while !self.outgoing.is_empty() {
+            match self.stream.write(&self.outgoing) {
+                Ok(0) => return false,
+                Ok(written) => {
+                    self.outgoing.drain(..written);
+                }
+                Err(err) if err.kind() == ErrorKind::WouldBlock => break,
+                Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                Err(_) => return false,
+            }
+        }
+        self.outgoing.len() <= MAX_PENDING_BYTES
+    }
+}
+
+#[derive(Resource)]
+pub struct RemoteControl {
+    listener: TcpListener,
+    clients: Vec<RemoteClient>,
+    tick: u32,
+}
+
+impl RemoteControl {
+    pub fn bind(addr: SocketAddr) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        Ok(Self {
+            listener,
+            clients: Vec::new(),
+            tick: 0,
+        })
+    }
+
+    fn broadcast(&mut self, line: &str) {
+        for client in &mut self.clients {
+            client.outgoing.extend_from_slice(line.as_bytes());
+            client.outgoing.push(b'\n');
+        }
+    }
+}
+
+pub struct RemoteControlPlugin;
+
+impl Plugin for RemoteControlPlugin {
+    fn build(&self, app: &mut App) {
+        let Some(addr) = requested() else {
+            return;
+        };
+        match RemoteControl::bind(addr) {
+            Ok(remote) => {
+                info!("Zdalne sterowanie nasłuchuje na {addr}");
+                app.insert_resource(remote);
+            }
+            Err(err) => {
+                warn!("Nie udało się uruchomić zdalnego sterowania na {addr}: {err}");
+                return;
+            }
+        }
+
+        app.add_systems(PreUpdate, (accept_clients, apply_commands).chain())
+            .add_systems(OnEnter(AppState::Playing), reset_tick)
+            .add_systems(OnEnter(AppState::GameOver), send_game_over)
+            .add_systems(
+                FixedUpdate,
+                send_observation
+                    .after(apply_score_events)
+                    .after(collision_system)
+                    .after(boundary_collision_system)
+                    .run_if(in_state(PauseState::Running)),
+            )
+            .add_systems(Last, flush_clients);
+    }
+}
+
+fn accept_clients(mut remote: ResMut<RemoteControl>) {
+    loop {
+        match remote.listener.accept() {
+            Ok((stream, addr)) => {
+                if let Err(err) = stream.set_nonblocking(true) {
+                    warn!("Nie udało się przyjąć klienta {addr}: {err}");
+                    continue;
+                }
+                // Obserwacje są małe i częste – nie czekamy na ich sklejenie.
+                let _ = stream.set_nodelay(true);
+                info!("Zdalny klient połączony: {addr}");
+                remote.clients.push(RemoteClient {
+                    stream,
+                    incoming: Vec::new(),
+                    outgoing: Vec::new(),
+                });
+            }
+            Err(err) if err.kind() == ErrorKind::WouldBlock => break,
+            Err(err) => {
+                warn!("Błąd zdalnego sterowania: {err}");
+                break;
+            }
+        }
+    }
+}
+
+fn apply_commands(
+    mut remote: ResMut<RemoteControl>,
+    time: Res<Time<Real>>,
+    state: Res<State<AppState>>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut flap_buffer: ResMut<FlapBuffer>,
+) {
+    let mut commands = Vec::new();
+    remote
+        .clients
+        .retain_mut(|client| client.read_commands(&mut commands));
+
+    for command in commands {
+        match command {
+            RemoteCommand::Flap => flap_buffer.press(time.elapsed_secs_f64()),
+            RemoteCommand::Start => {
+                if matches!(state.get(), AppState::Menu | AppState::GameOver) {
+                    next_state.set(AppState::Playing);
+                }
+            }
+        }
+    }
+}
+
+fn reset_tick(mut remote: ResMut<RemoteControl>) {
+    remote.tick = 0;
+}
+
+// Stan świata po kroku.
+fn send_observation(
+    player: Query<(&Transform, &Velocity), With<Player>>,
+    zones: Query<(&Transform, &Collider), With<ScoreZone>>,
+    score: Res<Score>,
+    mut remote: ResMut<RemoteControl>,
+) {
+    let Ok(bird) = player.get_single() else {
+        return;
+    };
+    let observation = Observation::new(bird, zones.iter());
+    let [(dx1, y1, gap1), (dx2, y2, gap2)] = observation.pipes;
+    let line = format!(
+        "tick,{},{:.2},{:.2},{dx1:.2},{y1:.2},{gap1:.2},{dx2:.2},{y2:.2},{gap2:.2},{}",
+        remote.tick, observation.bird_y, observation.bird_dy, score.0,
+    );
+    remote.tick += 1;
+    remote.broadcast(&line);
+}
+
+fn send_game_over(score: Res<Score>, mut remote: ResMut<RemoteControl>) {
+    remote.broadcast(&format!("over,{}", score.0));
+}
+
+fn flush_clients(mut remote: ResMut<RemoteControl>) {
+    remote.clients.retain_mut(RemoteClient::flush);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commands_are_parsed_per_line() {
+        assert_eq!(RemoteCommand::parse("flap\n"), Some(RemoteCommand::Flap));
+        assert_eq!(
+            RemoteCommand::parse("start\r\n"),
+            Some(RemoteCommand::Start)
+        );
+        assert_eq!(RemoteCommand::parse("jump"), None);
+    }
+}
@@ -0,0 +1,521 @@
+// Zapis przebiegu rundy (powtórka) w zwartym formacie binarnym, na tyle małym,
+// że da się go dołączyć do wpisu na tablicy wyników.
+//
+// Runda jest w pełni wyznaczona przez ziarno, ustawienia wpływające na fizykę
+// i numery kroków symulacji, w których ptak machnął skrzydłami. Numery kroków
+// zapisujemy jako różnice kodowane kodem Rice'a – przy typowym tempie gry
+// pięciominutowa runda zajmuje kilkaset bajtów.
+//
+// Układ (little endian):
+//   "FBRP", wersja: u8, ziarno: u64, tick_rate: u16, trudność: u8,
+//   sterowanie: u8, flagi: u8, wynik: u32, kroki: u32, machnięcia: u32,
+//   k: u8, strumień bitów, suma kontrolna FNV-1a: u32.
+
+use std::fmt;
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::adaptive::AdaptiveRun;
+use crate::controls::{ControlScheme, RunControls};
+use crate::coop::CoopRun;
+use crate::input::FlapBuffer;
+use crate::mirror::MirrorRun;
+use crate::mutators::MutatedRun;
+use crate::pause::PauseState;
+use crate::player::{Flapped, player_input_system};
+use crate::rng::RunRng;
+use crate::royale::RoyaleRun;
+use crate::run_save::PendingResume;
+use crate::score::Score;
+use crate::settings::{Difficulty, Settings};
+use crate::spectate::Spectating;
+use crate::stamina::StaminaRun;
+use crate::tournament::WeeklyRun;
+
+const MAGIC: &[u8; 4] = b"FBRP";
+const VERSION: u8 = 1;
+const HEADER_LEN: usize = 31;
+const CHECKSUM_LEN: usize = 4;
+
+const FLAG_REDUCED_MOTION: u8 = 1 << 0;
+const FLAG_LENIENCY: u8 = 1 << 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replay {
+    pub seed: u64,
+    pub tick_rate: u32,
+    pub difficulty: Difficulty,
+    pub controls: ControlScheme,
+    // Ruch ptaka w fazie "Get Ready" zależy od tego ustawienia.
+    pub reduced_motion: bool,
+    pub leniency: bool,
+    // Wynik deklarowany przez gracza.
+    pub score: u32,
+    // Liczba kroków symulacji w rundzie.
+    pub frames: u32,
+    // Kroki, w których ptak machnął (rosnąco).
+    pub flaps: Vec<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayError {
+    TooShort,
+    BadMagic,
+    UnsupportedVersion(u8),
+    BadChecksum,
+    Corrupt,
+    // Częstotliwość kroku nie mieści się w polu nagłówka (u16).
+    TickRateOutOfRange(u32),
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::TooShort => write!(f, "powtórka jest za krótka"),
+            ReplayError::BadMagic => write!(f, "to nie jest plik powtórki"),
+            ReplayError::UnsupportedVersion(version) => {
+                write!(f, "nieobsługiwana wersja powtórki: {version}")
+            }
+            ReplayError::BadChecksum => write!(f, "niezgodna suma kontrolna"),
+            ReplayError::Corrupt => write!(f, "uszkodzone dane powtórki"),
+            ReplayError::TickRateOutOfRange(tick_rate) => {
+                write!(
+                    f,
+                    "częstotliwość kroku {tick_rate} Hz nie mieści się w powtórce"
+                )
+            }
+        }
+    }
+}
+
+impl Replay {
+    // Machnięcie w sterowaniu innym niż klasyczne zależy też od czasu
+    // przytrzymania, którego powtórka nie przechowuje.
+    pub fn supports(controls: ControlScheme, settings: &Settings) -> bool {
+        controls == ControlScheme::Classic && !settings.analog_flap
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>, ReplayError> {
+        let tick_rate = u16::try_from(self.tick_rate)
+            .map_err(|_| ReplayError::TickRateOutOfRange(self.tick_rate))?;
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.flaps.len() + CHECKSUM_LEN);
+        let k = rice_parameter(&self.flaps, self.frames);
+
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&self.seed.to_le_bytes());
+        bytes.extend_from_slice(&tick_rate.to_le_bytes());
+        bytes.push(difficulty_code(self.difficulty));
+        bytes.push(controls_code(self.controls));
+        let mut flags = 0;
+        if self.reduced_motion {
+            flags |= FLAG_REDUCED_MOTION;
+        }
+        if self.leniency {
+            flags |= FLAG_LENIENCY;
+        }
+        bytes.push(flags);
+        bytes.extend_from_slice(&self.score.to_le_bytes());
+        bytes.extend_from_slice(&self.frames.to_le_bytes());
+        bytes.extend_from_slice(&(self.flaps.len() as u32).to_le_bytes());
+        bytes.push(k);
+
+        let mut writer = BitWriter::default();
+        let mut previous = 0;
+        for &frame in &self.flaps {
+            writer.write_rice(frame - previous, k);
+            previous = frame;
+        }
+        bytes.extend(writer.finish());
+
+        let checksum = fnv1a(&bytes);
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+        Ok(bytes)
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, ReplayError> {
+        if bytes.len() < HEADER_LEN + CHECKSUM_LEN {
+            return Err(ReplayError::TooShort);
+        }
+        if &bytes[..4] != MAGIC {
+            return Err(ReplayError::BadMagic);
+        }
+        if bytes[4] != VERSION {
+            return Err(ReplayError::UnsupportedVersion(bytes[4]));
+        }
+        let (body, checksum) = bytes.split_at(bytes.len() - CHECKSUM_LEN);
+        if fnv1a(body).to_le_bytes() != checksum {
+            return Err(ReplayError::BadChecksum);
+        }
+
+        let u32_at = |at: usize| u32::from_le_bytes(body[at..at + 4].try_into().unwrap());
+        let seed = u64::from_le_bytes(body[5..13].try_into().unwrap());
+        let tick_rate = u16::from_le_bytes([body[13], body[14]]) as u32;
+        let difficulty = difficulty_from_code(body[15]).ok_or(ReplayError::Corrupt)?;
+        let controls = controls_from_code(body[16]).ok_or(ReplayError::Corrupt)?;
+        let flags = body[17];
+        let score = u32_at(18);
+        let frames = u32_at(22);
+        let flap_count = u32_at(26) as usize;
+        let k = body[30];
+        if k > 31 {
+            return Err(ReplayError::Corrupt);
+        }
+
+        // Każde machnięcie zajmuje co najmniej `k + 1` bitów; liczba z nagłówka
+        // (chroniona tylko sumą kontrolną) nie może obiecać więcej, niż jest danych.
+        let bits = (body.len() - HEADER_LEN) * 8;
+        if flap_count > bits / (k as usize + 1) {
+            return Err(ReplayError::Corrupt);
+        }
+
+        let mut reader = BitReader::new(&body[HEADER_LEN..]);
+        let mut flaps = Vec::with_capacity(flap_count);
+        let mut previous: u32 = 0;
+        for _ in 0..flap_count {
+            let delta = reader.read_rice(k).ok_or(ReplayError::Corrupt)?;
+            previous = previous.checked_add(delta).ok_or(ReplayError::Corrupt)?;
+            if previous > frames {
+                return Err(ReplayError::Corrupt);
+            }
+            flaps.push(previous);
+        }
+
+        Ok(Replay {
+            seed,
+            tick_rate,
+            difficulty,
+            controls,
+            reduced_motion: flags & FLAG_REDUCED_MOTION != 0,
+            leniency: flags & FLAG_LENIENCY != 0,
+            score,
+            frames,
+            flaps,
+        })
+    }
+}
+
+fn difficulty_code(difficulty: Difficulty) -> u8 {
+    match difficulty {
+        Difficulty::Easy => 0,
+        Difficulty::Normal => 1,
+        Difficulty::Hard => 2,
+    }
+}
+
+fn difficulty_from_code(code: u8) -> Option<Difficulty> {
+    Difficulty::ALL.get(code as usize).copied()
+}
+
+fn controls_code(controls: ControlScheme) -> u8 {
+    match controls {
+        ControlScheme::Classic => 0,
+        ControlScheme::Glide => 1,
+        ControlScheme::Charge => 2,
+        ControlScheme::Dash => 3,
+    }
+}
+
+fn controls_from_code(code: u8) -> Option<ControlScheme> {
+    match code {
+        0 => Some(ControlScheme::Classic),
+        1 => Some(ControlScheme::Glide),
+        2 => Some(ControlScheme::Charge),
+        3 => Some(ControlScheme::Dash),
+        _ => None,
+    }
+}
+
+// Parametr kodu Rice'a dopasowany do średniego odstępu między machnięciami.
+fn rice_parameter(flaps: &[u32], frames: u32) -> u8 {
+    if flaps.is_empty() {
+        return 0;
+    }
+    let mean = (frames / flaps.len() as u32).max(1);
+    (31 - mean.leading_zeros()) as u8
+}
+
+fn fnv1a(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c_9dc5, |hash, &byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    })
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    used: u8,
+}
+
+impl BitWriter {
+    fn write_bit(&mut self, bit: bool) {
+        if self.used == 0 {
+            self.bytes.push(0);
+        }
+        if bit {
+            *self.bytes.last_mut().unwrap() |= 1 << self.used;
+        }
+        self.used = (self.used + 1) % 8;
+    }
+
+    // Iloraz unarnie (jedynki zakończone zerem), reszta na `k` bitach.
+    fn write_rice(&mut self, value: u32, k: u8) {
+        for _ in 0..(value >> k) {
+            self.write_bit(true);
+        }
+        self.write_bit(false);
+        for i in 0..k {
+            self.write_bit((value >> i) & 1 == 1);
+        }
+    }
+
+    fn finish(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    fn read_bit(&mut self) -> Option<bool> {
+        let byte = self.bytes.get(self.position / 8)?;
+        let bit = (byte >> (self.position % 8)) & 1 == 1;
+        self.position += 1;
+        Some(bit)
+    }
+
+    fn read_rice(&mut self, k: u8) -> Option<u32> {
+        let mut quotient: u32 = 0;
+        while self.read_bit()? {
+            quotient = quotient.checked_add(1)?;
+        }
+        let mut remainder = 0;
+        for i in 0..k {
+            if self.read_bit()? {
+                remainder |= 1 << i;
+            }
+        }
+        quotient.checked_mul(1 << k)?.checked_add(remainder)
+    }
+}
+
+// Nagrywanie bieżącej rundy. Runda wznowiona z zapisu nie ma pełnej historii,
+// więc nie dostaje powtórki.
+#[derive(Resource, Default)]
+pub struct ReplayRecorder {
+    recording: Option<Replay>,
+}
+
+impl ReplayRecorder {
+    // Numer bieżącego kroku symulacji, jeśli runda jest nagrywana.
+    pub fn frame(&self) -> Option<u32> {
+        self.recording.as_ref().map(|replay| replay.frames)
+    }
+
+    // Nagrywana runda do tej pory (np. do transmisji dla widza).
+    pub fn recording(&self) -> Option<&Replay> {
+        self.recording.as_ref()
+    }
+}
+
+// Powtórka ostatniej zakończonej rundy.
+#[derive(Resource, Default)]
+pub struct LastReplay(pub Option<Replay>);
+
+// Odtwarzanie powtórki zamiast wejścia gracza (weryfikacja wyników).
+#[derive(Resource)]
+pub struct ReplayPlayback {
+    flaps: Vec<u32>,
+    next: usize,
+}
+
+impl ReplayPlayback {
+    pub fn new(replay: &Replay) -> Self {
+        Self {
+            flaps: replay.flaps.clone(),
+            next: 0,
+        }
+    }
+}
+
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ReplayRecorder>()
+            .init_resource::<LastReplay>()
+            .add_systems(
+                OnEnter(AppState::Playing),
+                start_recording
+                    .after(crate::rng::reseed_run_rng)
+                    .after(crate::controls::lock_run_controls),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    play_back_flaps
+                        .before(player_input_system)
+                        .run_if(resource_exists::<ReplayPlayback>),
+                    record_frame.after(player_input_system),
+                )
+                    .run_if(in_state(PauseState::Running)),
+            )
+            .add_systems(
+                OnEnter(AppState::GameOver),
+                finish_recording.before(crate::bests::record_best),
+            );
+    }
+}
+
+pub fn start_recording(
+    settings: Res<Settings>,
+    controls: Res<RunControls>,
+    rng: Res<RunRng>,
+    pending_resume: Option<Res<PendingResume>>,
+    weekly: Option<Res<WeeklyRun>>,
+    mirror: Option<Res<MirrorRun>>,
+    coop: Option<Res<CoopRun>>,
+    royale: Option<Res<RoyaleRun>>,
+    spectating: Option<Res<Spectating>>,
+    adaptive: Option<Res<AdaptiveRun>>,
+    stamina: Option<Res<StaminaRun>>,
+    mutated: Option<Res<MutatedRun>>,
+    mut recorder: ResMut<ReplayRecorder>,
+) {
+    // Zasad tygodnia turnieju, fal z lewej, drugiego ptaka, zwężania przerw,
+    // adaptacyjnej trudności, zmęczenia ani mutatorów powtórka nie zapisuje, więc nie
+    // dałoby się jej sprawdzić. Oglądana runda znajomego nie jest rundą widza.
+    let supported = Replay::supports(controls.0, &settings)
+        && weekly.is_none()
+        && mirror.is_none()
+        && coop.is_none()
+        && royale.is_none()
+        && spectating.is_none()
+        && adaptive.is_none()
+        && stamina.is_none()
+        && mutated.is_none();
+    recorder.recording = (pending_resume.is_none() && supported).then(|| Replay {
+        seed: rng.seed(),
+        tick_rate: settings.tick_rate,
+        difficulty: settings.difficulty,
+        controls: controls.0,
+        reduced_motion: settings.accessibility.reduced_motion,
+        leniency: settings.assists.leniency,
+        score: 0,
+        frames: 0,
+        flaps: Vec::new(),
+    });
+}
+
+pub fn record_frame(mut flapped: EventReader<Flapped>, mut recorder: ResMut<ReplayRecorder>) {
+    let flapped = flapped.read().last().is_some();
+    let Some(replay) = recorder.recording.as_mut() else {
+        return;
+    };
+    if flapped {
+        replay.flaps.push(replay.frames);
+    }
+    replay.frames += 1;
+}
+
+// Wciska "machnij" w krokach zapisanych w powtórce. Krok liczy nagrywarka,
+// więc odtwarzanie i zapis zawsze używają tej samej numeracji.
+fn play_back_flaps(
+    recorder: Res<ReplayRecorder>,
+    time: Res<Time<Real>>,
+    mut playback: ResMut<ReplayPlayback>,
+    mut buffer: ResMut<FlapBuffer>,
+) {
+    let Some(frame) = recorder.frame() else {
+        return;
+    };
+    if playback.flaps.get(playback.next) == Some(&frame) {
+        playback.next += 1;
+        buffer.press(time.elapsed_secs_f64());
+    }
+}
+
+pub fn finish_recording(
+    score: Res<Score>,
+    mut recorder: ResMut<ReplayRecorder>,
+    mut last: ResMut<LastReplay>,
+) {
+    last.0 = recorder.recording.take().map(|mut replay| {
+        replay.score = score.0.max(0) as u32;
+        replay
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_replay() -> Replay {
+        // Pięć minut przy 60 Hz, machnięcie mniej więcej co pół sekundy.
+        let frames = 5 * 60 * 60;
+        let flaps = (0..frames)
+            .filter(|frame| frame % 31 == 0 || frame % 47 == 0)
+            .collect();
+        Replay {
+            seed: 0xdead_beef,
+            tick_rate: 60,
+            difficulty: Difficulty::Hard,
+            controls: ControlScheme::Classic,
+            reduced_motion: true,
+            leniency: false,
+            score: 140,
+            frames,
+            flaps,
+        }
+    }
+
+    #[test]
+    fn replay_round_trips() {
+        let replay = sample_replay();
+        assert_eq!(Replay::decode(&replay.encode().unwrap()), Ok(replay));
+    }
+
+    #[test]
+    fn five_minute_run_fits_in_a_few_hundred_bytes() {
+        let bytes = sample_replay().encode().unwrap();
+        assert!(bytes.len() < 1024, "{} bytes", bytes.len());
+    }
+
+    #[test]
+    fn tampered_replay_is_rejected() {
+        let mut bytes = sample_replay().encode().unwrap();
+        bytes[18] ^= 1;
+        assert_eq!(Replay::decode(&bytes), Err(ReplayError::BadChecksum));
+        assert_eq!(Replay::decode(&bytes[..10]), Err(ReplayError::TooShort));
+    }
+
+    #[test]
+    fn header_cannot_claim_more_flaps_than_the_data_holds() {
+        let mut bytes = sample_replay().encode().unwrap();
+        let body = bytes.len() - CHECKSUM_LEN;
+        bytes[26..30].copy_from_slice(&u32::MAX.to_le_bytes());
+        let checksum = fnv1a(&bytes[..body]);
+        bytes[body..].copy_from_slice(&checksum.to_le_bytes());
+        assert_eq!(Replay::decode(&bytes), Err(ReplayError::Corrupt));
+    }
+
+    #[test]
+    fn tick_rate_outside_the_header_field_is_an_error() {
+        let replay = Replay {
+            tick_rate: 70_000,
+            ..sample_replay()
+        };
+        assert_eq!(
+            replay.encode(),
+            Err(ReplayError::TickRateOutOfRange(70_000))
+        );
+    }
+}
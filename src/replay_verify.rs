@@ -0,0 +1,197 @@
+// Weryfikacja wyników: powtórka jest odgrywana na bezgłowym App z tymi samymi
+// ustawieniami i ziarnem, a wynik symulacji musi zgadzać się z deklarowanym.
+// Wpis trafia na wspólną tablicę wyników dopiero po pomyślnej weryfikacji.
+
+use std::fmt;
+use std::fs;
+
+use bevy::input::gamepad::GamepadConnectionEvent;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::controls::ControlScheme;
+use crate::replay::{LastReplay, Replay, ReplayPlayback};
+use crate::rng::SeedChoice;
+use crate::score::Score;
+use crate::settings::{Settings, TICK_RATES};
+use crate::{AppState, FlappyBirdPlugin};
+
+// `flappy-bird --verify-replay plik` sprawdza powtórkę bez otwierania okna.
+const VERIFY_FLAG: &str = "--verify-replay";
+
+// Ile kroków po zapisanym końcu rundy czekamy na śmierć ptaka.
+const END_GRACE_SECS: u32 = 1;
+// O ile kroków koniec odegranej rundy może się różnić od zapisanego.
+const END_TOLERANCE_FRAMES: u32 = 2;
+// Najdłuższa runda, jaką zgadzamy się odgrywać (dwie godziny).
+const MAX_RUN_SECS: u32 = 2 * 60 * 60;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerifyError {
+    // Powtórka z ustawieniami, których nie umiemy odtworzyć.
+    Unsupported,
+    // Deklarowana długość rundy przekracza `MAX_RUN_SECS`.
+    TooLong,
+    // Po odegraniu wszystkich kroków runda wciąż trwa.
+    DidNotEnd,
+    // Ptak zginął w innym kroku, niż twierdzi nagłówek.
+    EndMismatch { claimed: u32, actual: u32 },
+    ScoreMismatch { claimed: u32, actual: i32 },
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyError::Unsupported => write!(f, "nieobsługiwane ustawienia powtórki"),
+            VerifyError::TooLong => write!(f, "powtórka jest za długa"),
+            VerifyError::DidNotEnd => write!(f, "runda z powtórki się nie kończy"),
+            VerifyError::EndMismatch { claimed, actual } => {
+                write!(
+                    f,
+                    "deklarowany koniec w kroku {claimed}, a symulacja w {actual}"
+                )
+            }
+            VerifyError::ScoreMismatch { claimed, actual } => {
+                write!(f, "deklarowany wynik {claimed}, a symulacja daje {actual}")
+            }
+        }
+    }
+}
+
+// App bez okna, grafiki i dźwięku, z czasem przesuwanym ręcznie o jeden krok
+// symulacji na klatkę.
+pub fn headless_app(settings: Settings) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), StatesPlugin))
+        .init_asset::<Image>()
+        .init_resource::<ButtonInput<KeyCode>>()
+        .init_resource::<GlobalVolume>()
+        .add_event::<GamepadConnectionEvent>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(settings.tick_duration()))
+        .insert_resource(settings)
+        .add_plugins(FlappyBirdPlugin);
+    app
+}
+
+// Pierwsza klatka uruchamia Startup; potem czekamy, aż obrazki się wczytają
+// (bez loadera PNG – aż wczytywanie się nie powiedzie).
+pub fn wait_for_assets(app: &mut App) {
+    app.update();
+    for _ in 0..600 {
+        if *app.world().resource::<State<AppState>>().get() != AppState::Loading {
+            break;
+        }
+        app.update();
+    }
+}
+
+// Odgrywa powtórkę i zwraca wynik symulacji, jeśli zgadza się z deklarowanym.
+pub fn verify(replay: &Replay) -> Result<i32, VerifyError> {
+    let actual = simulate(replay, |_| {})?;
+    if actual == replay.score as i32 {
+        Ok(actual)
+    } else {
+        Err(VerifyError::ScoreMismatch {
+            claimed: replay.score,
+            actual,
+        })
+    }
+}
+
+// Odgrywa powtórkę do końca rundy i zwraca wynik. `observe` dostaje świat po
+// każdym kroku symulacji.
+pub fn simulate(replay: &Replay, observe: impl FnMut(&mut World)) -> Result<i32, VerifyError> {
+    let app = play(replay, observe)?;
+    Ok(app.world().resource::<Score>().0)
+}
+
+// Dziennik losowań odegranej powtórki – do porównania z dziennikiem rundy na
+// żywo (`rng::first_divergence`), gdy powtórka się rozjeżdża. Plik powtórki
+// nie zawiera dziennika z rundy na żywo, więc korzystają z tego tylko testy.
+#[cfg(test)]
+pub fn draw_audit(replay: &Replay) -> Result<Vec<crate::rng::DrawRecord>, VerifyError> {
+    use crate::rng::RunRng;
+
+    let app = play(replay, |_| {})?;
+    Ok(app.world().resource::<RunRng>().audit().to_vec())
+}
+
+// Odgrywa powtórkę i zwraca App zatrzymany na końcu rundy.
+fn play(replay: &Replay, mut observe: impl FnMut(&mut World)) -> Result<App, VerifyError> {
+    if replay.controls != ControlScheme::Classic || !TICK_RATES.contains(&replay.tick_rate) {
+        return Err(VerifyError::Unsupported);
+    }
+    if replay.frames > replay.tick_rate.saturating_mul(MAX_RUN_SECS) {
+        return Err(VerifyError::TooLong);
+    }
+
+    let mut settings = Settings {
+        difficulty: replay.difficulty,
+        controls: replay.controls,
+        tick_rate: replay.tick_rate,
+        ..Settings::default()
+    };
+    settings.accessibility.reduced_motion = replay.reduced_motion;
+    settings.assists.leniency = replay.leniency;
+
+    let mut app = headless_app(settings);
+    wait_for_assets(&mut app);
+    app.insert_resource(SeedChoice(Some(replay.seed)))
+        .insert_resource(ReplayPlayback::new(replay));
+    app.world_mut()
+        .resource_mut::<NextState<AppState>>()
+        .set(AppState::Playing);
+
+    let limit = replay
+        .frames
+        .saturating_add(replay.tick_rate.saturating_mul(END_GRACE_SECS));
+    for _ in 0..=limit {
+        app.update();
+        if *app.world().resource::<State<AppState>>().get() == AppState::GameOver {
+            return check_end(&app, replay).map(|()| app);
+        }
+        observe(app.world_mut());
+    }
+    Err(VerifyError::DidNotEnd)
+}
+
+// Odegrana runda nagrywa się tak samo jak na żywo, więc jej długość da się
+// porównać z nagłówkiem krok w krok.
+fn check_end(app: &App, replay: &Replay) -> Result<(), VerifyError> {
+    let Some(actual) = app.world().resource::<LastReplay>().0.as_ref() else {
+        return Err(VerifyError::Unsupported);
+    };
+    if actual.frames.abs_diff(replay.frames) > END_TOLERANCE_FRAMES {
+        return Err(VerifyError::EndMismatch {
+            claimed: replay.frames,
+            actual: actual.frames,
+        });
+    }
+    Ok(())
+}
+
+// Ścieżka powtórki do sprawdzenia, jeśli gra została uruchomiona z `--verify-replay`.
+pub fn requested() -> Option<String> {
+    let mut args = std::env::args().skip_while(|arg| arg != VERIFY_FLAG);
+    args.next()?;
+    args.next()
+}
+
+// Weryfikacja z linii poleceń; zwraca kod wyjścia procesu.
+pub fn verify_file(path: &str) -> i32 {
+    let replay = match fs::read(path) {
+        Ok(bytes) => Replay::decode(&bytes).map_err(|err| err.to_string()),
+        Err(err) => Err(err.to_string()),
+    };
+    match replay.and_then(|replay| verify(&replay).map_err(|err| err.to_string())) {
+        Ok(score) => {
+            println!("{path}: OK, wynik {score}");
+            0
+        }
+        Err(err) => {
+            eprintln!("{path}: {err}");
+            1
+        }
+    }
+}
@@ -0,0 +1,225 @@
+// Deterministyczny generator losowości rundy. Pamięta ziarno i liczbę
+// pobranych słów, więc zapisany stan da się odtworzyć co do bitu.
+//
+// Każde pobrane słowo trafia też do dziennika losowań razem z celem podanym
+// przez `TaggedRng::tag`. Dziennik rundy na żywo i dziennik odtworzonej
+// powtórki powinny być identyczne; pierwsza różnica wskazuje rozjazd.
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+use crate::AppState;
+use crate::core::{DrawPurpose, PatternOdds, PatternPlan, TaggedRng};
+
+// Jedno pobrane słowo: po co i jaka wartość.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DrawRecord {
+    pub purpose: DrawPurpose,
+    pub value: u64,
+}
+
+#[derive(Resource)]
+pub struct RunRng {
+    seed: u64,
+    draws: u64,
+    rng: StdRng,
+    purpose: DrawPurpose,
+    audit: Vec<DrawRecord>,
+}
+
+impl RunRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            draws: 0,
+            rng: StdRng::seed_from_u64(seed),
+            purpose: DrawPurpose::Other,
+            audit: Vec::new(),
+        }
+    }
+
+    // Generator w tym samym miejscu sekwencji co zapisany. Dziennik wznowionej
+    // rundy zaczyna się od miejsca wznowienia.
+    pub fn restore(seed: u64, draws: u64) -> Self {
+        let mut run_rng = Self::new(seed);
+        for _ in 0..draws {
+            run_rng.next_u64();
+        }
+        run_rng.audit.clear();
+        run_rng
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn draws(&self) -> u64 {
+        self.draws
+    }
+
+    // Dziennik losowań od początku rundy, w kolejności pobrania.
+    pub fn audit(&self) -> &[DrawRecord] {
+        &self.audit
+    }
+
+    // Przejmuje dziennik generatora pochodnego (np. znajdziek), żeby wszystkie
+    // losowania rundy były w jednym miejscu i w kolejności, w jakiej zaszły.
+    pub fn absorb_audit(&mut self, other: &mut RunRng) {
+        self.audit.append(&mut other.audit);
+    }
+}
+
+impl TaggedRng for RunRng {
+    fn tag(&mut self, purpose: DrawPurpose) {
+        self.purpose = purpose;
+    }
+}
+
+impl Default for RunRng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+// Wszystko idzie przez `next_u64`, dzięki czemu pozycję opisuje jedna liczba.
+impl RngCore for RunRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.draws += 1;
+        let value = self.rng.next_u64();
+        self.audit.push(DrawRecord {
+            purpose: self.purpose,
+            value,
+        });
+        value
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+// Ziarno wybrane przez gracza; `None` oznacza losowe ziarno dla każdej rundy.
+#[derive(Resource, Default)]
+pub struct SeedChoice(pub Option<u64>);
+
+pub struct RunRngPlugin;
+
+impl Plugin for RunRngPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunRng>()
+            .init_resource::<SeedChoice>()
+            .add_systems(OnEnter(AppState::Playing), reseed_run_rng);
+    }
+}
+
+// Każda nowa runda dostaje świeże (albo wybrane przez gracza) ziarno.
+pub fn reseed_run_rng(choice: Res<SeedChoice>, mut run_rng: ResMut<RunRng>) {
+    *run_rng = RunRng::new(choice.0.unwrap_or_else(rand::random));
+}
+
+// Ziarno w zapisie szesnastkowym (jak na ekranie końca gry), opcjonalnie z `0x`.
+pub fn parse_seed(text: &str) -> Option<u64> {
+    let text = text.trim();
+    let digits = text.strip_prefix("0x").unwrap_or(text);
+    if digits.is_empty() || digits.len() > 16 {
+        return None;
+    }
+    u64::from_str_radix(digits, 16).ok()
+}
+
+// Pierwsze `count` wzorów przeszkód dla danego ziarna. Wynik rośnie tu o jeden
+// na wzór, więc dla kilku pierwszych rur (przed progiem tuneli) podgląd zgadza
+// się z rundą; dalej tunel liczy się jak jedna rura.
+pub fn preview_patterns(seed: u64, odds: &PatternOdds, count: usize) -> Vec<PatternPlan> {
+    let mut rng = RunRng::new(seed);
+    (0..count as i32)
+        .map(|score| crate::core::plan_pattern(&mut rng, score, odds))
+        .collect()
+}
+
+// Środki przerw pierwszych `count` wzorów przeszkód dla danego ziarna.
+pub fn preview_gap_centers(seed: u64, odds: &PatternOdds, count: usize) -> Vec<f32> {
+    preview_patterns(seed, odds, count)
+        .iter()
+        .map(|plan| plan.center_y)
+        .collect()
+}
+
+// Pozycja pierwszego losowania, w którym dzienniki się różnią (również gdy
+// jeden z nich jest krótszy); `None`, gdy są identyczne.
+#[cfg(test)]
+pub fn first_divergence(live: &[DrawRecord], replayed: &[DrawRecord]) -> Option<usize> {
+    live.iter()
+        .zip(replayed)
+        .position(|(a, b)| a != b)
+        .or_else(|| (live.len() != replayed.len()).then_some(live.len().min(replayed.len())))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn restored_rng_continues_the_same_sequence() {
+        let mut original = RunRng::new(42);
+        for _ in 0..5 {
+            original.gen_range(-130.0f32..=130.0);
+            original.gen_bool(0.3);
+        }
+
+        let mut restored = RunRng::restore(original.seed(), original.draws());
+        assert_eq!(restored.next_u64(), original.next_u64());
+    }
+
+    #[test]
+    fn audit_tags_draws_and_finds_divergence() {
+        let odds = PatternOdds {
+            tunnel_min_score: 0,
+            tunnel: 0.5,
+            spikes: 0.5,
+            low_gravity: 0.5,
+        };
+        let mut live = RunRng::new(7);
+        crate::core::plan_pattern(&mut live, 0, &odds);
+        assert_eq!(live.audit().len() as u64, live.draws());
+        assert_eq!(live.audit()[0].purpose, DrawPurpose::GapCenter);
+        assert_eq!(live.audit()[1].purpose, DrawPurpose::TunnelRoll);
+
+        let mut replayed = RunRng::new(7);
+        crate::core::plan_pattern(&mut replayed, 0, &odds);
+        assert_eq!(first_divergence(live.audit(), replayed.audit()), None);
+
+        replayed.next_u64();
+        assert_eq!(
+            first_divergence(live.audit(), replayed.audit()),
+            Some(live.audit().len())
+        );
+        let mut other = RunRng::new(8);
+        crate::core::plan_pattern(&mut other, 0, &odds);
+        assert_eq!(first_divergence(live.audit(), other.audit()), Some(0));
+    }
+
+    #[test]
+    fn seeds_parse_from_hex() {
+        assert_eq!(parse_seed("00000000000000ff"), Some(255));
+        assert_eq!(parse_seed(" 0xFF "), Some(255));
+        assert_eq!(parse_seed(""), None);
+        assert_eq!(parse_seed("xyz"), None);
+        assert_eq!(parse_seed("10000000000000000"), None);
+    }
+}
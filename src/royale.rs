@@ -0,0 +1,311 @@
+// Battle royale: gracz i `ROYALE_RIVALS` rywali komputerowych lecą przez te
+// same rury (to samo ziarno rundy), a przerwy w rurach co `SHRINK_EVERY_SECS`
+// sekund robią się węższe. Wygrywa ostatni ptak w powietrzu – gdy odpadnie
+// ostatni rywal, runda kończy się zwycięstwem gracza.
+//
+// Kto odpadł i na którym miejscu, pokazuje kill-feed po prawej stronie
+// ekranu. Wynikiem rundy są punkty za miejsce, a nie liczba rur. Rundy
+// zapisujemy jako tryb `GameMode::Royale`; powtórek nie nagrywamy. Pokoje
+// sieciowe mogą wybrać te same zasady (`RaceConfig::royale`).
+
+use bevy::prelude::*;
+
+use crate::ai_race::RivalOut;
+use crate::bests::{GameMode, RunMode};
+use crate::core::PipeParams;
+use crate::menu::Menu;
+use crate::pause::PauseState;
+use crate::score::Score;
+use crate::text_style::OutlinedText;
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON, RunEntity};
+
+pub const ROYALE_RIVALS: u8 = 7;
+
+const SHRINK_EVERY_SECS: f32 = 10.0;
+// O ile zwęża się przerwa przy każdym kroku i poniżej czego już nie.
+const SHRINK_STEP: f32 = 0.08;
+const MIN_GAP_SCALE: f32 = 0.5;
+// Punkty za każdego ptaka, którego gracz przetrwał, i premia za wygraną.
+const POINTS_PER_PLACE: i32 = 10;
+const WIN_BONUS: i32 = 30;
+const FEED_LINES: usize = 6;
+
+// Obecny, gdy gracz wybrał battle royale; znika po powrocie do menu.
+#[derive(Resource, Debug, Default)]
+pub struct RoyaleRun {
+    // Czas od pierwszego machnięcia.
+    elapsed: f32,
+    // Liczba ptaków na starcie, łącznie z graczem.
+    field: u32,
+    // Ilu rywali już odpadło.
+    out: u32,
+    feed: Vec<String>,
+}
+
+impl RoyaleRun {
+    fn new(field: u32) -> Self {
+        Self {
+            field,
+            ..Default::default()
+        }
+    }
+
+    fn shrinks(&self) -> u32 {
+        (self.elapsed / SHRINK_EVERY_SECS) as u32
+    }
+
+    // Skala przerwy w rurach w bieżącej chwili rundy.
+    pub fn gap_scale(&self) -> f32 {
+        (1.0 - SHRINK_STEP * self.shrinks() as f32).max(MIN_GAP_SCALE)
+    }
+
+    // Miejsce, które zajmie ptak odpadający teraz.
+    fn current_place(&self) -> u32 {
+        self.field.saturating_sub(self.out).max(1)
+    }
+
+    // Odnotowuje odpadnięcie rywala i zwraca jego miejsce.
+    fn eliminate(&mut self) -> u32 {
+        let place = self.current_place();
+        self.out += 1;
+        place
+    }
+
+    fn rivals_left(&self) -> u32 {
+        self.field.saturating_sub(1 + self.out)
+    }
+
+    fn push_feed(&mut self, line: String) {
+        self.feed.push(line);
+        if self.feed.len() > FEED_LINES {
+            self.feed.remove(0);
+        }
+    }
+}
+
+// Punkty za miejsce: po `POINTS_PER_PLACE` za każdego pokonanego ptaka,
+// zwycięzca dostaje jeszcze premię.
+pub fn placement_points(place: u32, field: u32) -> i32 {
+    let beaten = field.saturating_sub(place) as i32;
+    let bonus = if place == 1 { WIN_BONUS } else { 0 };
+    beaten * POINTS_PER_PLACE + bonus
+}
+
+// Parametry rur po zwężeniu przerwy.
+pub fn shrink_gap(mut params: PipeParams, royale: Option<&RoyaleRun>) -> PipeParams {
+    if let Some(royale) = royale {
+        params.gap *= royale.gap_scale();
+    }
+    params
+}
+
+#[derive(Component)]
+struct RoyaleButton;
+
+#[derive(Component)]
+#[require(RunEntity)]
+struct KillFeed;
+
+pub struct RoyalePlugin;
+
+impl Plugin for RoyalePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(AppState::Menu),
+            (
+                end_royale_run,
+                spawn_royale_button.after(crate::menu::setup_menu),
+            ),
+        )
+        .add_systems(
+            Update,
+            royale_button_system.run_if(in_state(AppState::Menu)),
+        )
+        .add_systems(
+            OnEnter(AppState::Playing),
+            start_royale_run
+                .after(crate::restart_game)
+                .run_if(resource_exists::<RoyaleRun>),
+        )
+        .add_systems(
+            FixedUpdate,
+            (
+                shrink_gaps.run_if(crate::player::bird_released),
+                eliminate_rivals.after(crate::ai_race::RivalsFlight),
+            )
+                .run_if(in_state(PauseState::Running).and(resource_exists::<RoyaleRun>)),
+        )
+        .add_systems(
+            OnEnter(AppState::GameOver),
+            award_placement
+                .before(crate::bests::record_best)
+                .run_if(resource_exists::<RoyaleRun>),
+        )
+        .add_systems(
+            Update,
+            update_kill_feed.run_if(resource_exists_and_changed::<RoyaleRun>),
+        );
+    }
+}
+
+fn start_royale_run(
+    mut commands: Commands,
+    mut royale: ResMut<RoyaleRun>,
+    mut mode: ResMut<RunMode>,
+) {
+    *royale = RoyaleRun::new(u32::from(ROYALE_RIVALS) + 1);
+    mode.0 = GameMode::Royale;
+    commands.spawn((
+        Text::new(""),
+        OutlinedText::new(Color::WHITE),
+        TextFont {
+            font_size: 18.0,
+            ..Default::default()
+        },
+        TextLayout::new_with_justify(JustifyText::Right),
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(12.0),
+            top: Val::Px(80.0),
+            ..Default::default()
+        },
+        KillFeed,
+    ));
+}
+
+fn end_royale_run(
+    mut commands: Commands,
+    royale: Option<Res<RoyaleRun>>,
+    mut mode: ResMut<RunMode>,
+) {
+    if royale.is_none() {
+        return;
+    }
+    commands.remove_resource::<RoyaleRun>();
+    mode.0 = GameMode::Classic;
+}
+
+fn shrink_gaps(time: Res<Time>, mut royale: ResMut<RoyaleRun>) {
+    let before = royale.shrinks();
+    royale.elapsed += time.delta_secs();
+    if royale.shrinks() > before && royale.gap_scale() < 1.0 {
+        royale.push_feed("The gap shrinks!".to_string());
+    }
+}
+
+// Gdy odpadnie ostatni rywal, gracz wygrywa i runda się kończy.
+fn eliminate_rivals(
+    mut out: EventReader<RivalOut>,
+    mut royale: ResMut<RoyaleRun>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for rival in out.read() {
+        let place = royale.eliminate();
+        royale.push_feed(format!("AI {} is out  #{place}", rival.number));
+    }
+    if royale.rivals_left() == 0 {
+        next_state.set(AppState::GameOver);
+    }
+}
+
+// Wynik rundy to punkty za miejsce gracza.
+fn award_placement(mut royale: ResMut<RoyaleRun>, mut score: ResMut<Score>) {
+    let place = royale.current_place();
+    let line = if place == 1 {
+        "You win!".to_string()
+    } else {
+        format!("You are out  #{place}")
+    };
+    royale.push_feed(line);
+    score.0 = placement_points(place, royale.field);
+}
+
+fn update_kill_feed(royale: Res<RoyaleRun>, mut feed: Query<&mut Text, With<KillFeed>>) {
+    let alive = royale.rivals_left() + 1;
+    let mut label = format!("Birds left: {alive}");
+    for line in &royale.feed {
+        label.push('\n');
+        label.push_str(line);
+    }
+    for mut text in feed.iter_mut() {
+        if text.0 != label {
+            text.0 = label.clone();
+        }
+    }
+}
+
+fn spawn_royale_button(mut commands: Commands) {
+    commands
+        .spawn((
+            Button,
+            Interaction::default(),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(20.0),
+                top: Val::Px(170.0),
+                padding: UiRect::axes(Val::Px(16.0), Val::Px(10.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(NORMAL_BUTTON),
+            BorderRadius::all(Val::Px(8.0)),
+            RoyaleButton,
+            Menu,
+        ))
+        .with_child((Text::new("Battle royale"), TextColor(Color::WHITE)));
+}
+
+fn royale_button_system(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<RoyaleButton>),
+    >,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, mut bg_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                commands.init_resource::<RoyaleRun>();
+                next_state.set(AppState::Playing);
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gap_shrinks_every_ten_seconds_down_to_a_floor() {
+        let mut royale = RoyaleRun::new(8);
+        assert_eq!(royale.gap_scale(), 1.0);
+        royale.elapsed = 9.9;
+        assert_eq!(royale.gap_scale(), 1.0);
+        royale.elapsed = 10.0;
+        assert!(royale.gap_scale() < 1.0);
+        royale.elapsed = 600.0;
+        assert_eq!(royale.gap_scale(), MIN_GAP_SCALE);
+    }
+
+    #[test]
+    fn places_count_down_as_birds_drop_out() {
+        let mut royale = RoyaleRun::new(3);
+        assert_eq!(royale.eliminate(), 3);
+        assert_eq!(royale.eliminate(), 2);
+        assert_eq!(royale.rivals_left(), 0);
+        assert_eq!(royale.current_place(), 1);
+
+        assert_eq!(placement_points(1, 8), 7 * POINTS_PER_PLACE + WIN_BONUS);
+        assert_eq!(placement_points(8, 8), 0);
+    }
+}
@@ -0,0 +1,553 @@
+// Zapis rundy w trakcie gry: z pauzy można wyjść do menu ("Save & Quit"),
+// a przycisk "Resume run" w menu odtwarza rundę dokładnie w tym miejscu –
+// ptaka, wynik, wszystkie przeszkody i stan generatora losowości, więc
+// kolejne rury będą te same, które pojawiłyby się bez przerwy.
+
+use std::fs;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::assets::GameAssets;
+use crate::bests::{GameMode, RunMode};
+use crate::biome::{Biome, CurrentBiome};
+use crate::controls::{ControlScheme, RunControls};
+use crate::display::WORLD_SIZE;
+use crate::hazards::{Spikes, spawn_spike_strip};
+use crate::interpolation::Interpolated;
+use crate::kids::ParentalLock;
+use crate::patterns::{TunnelWall, spawn_tunnel_wall};
+use crate::pause::PauseState;
+use crate::physics::{Collider, LifeTime, Velocity};
+use crate::pipes::PIPE_SIZE;
+use crate::pipes::{Pipe, PipeEnd, PipeSpec, spawn_pipe};
+use crate::player::{HoverIdle, Player};
+use crate::profiles::ActiveProfile;
+use crate::rng::RunRng;
+use crate::scheduler::{Scheduler, TriggerId};
+use crate::score::{Score, ScoreZone, Scoreable, spawn_score_zone};
+use crate::settings::{Difficulty, Settings};
+use crate::theme::{ActiveTheme, Theme};
+use crate::zones::{LowGravityZone, spawn_low_gravity_zone};
+use crate::{AppState, Background, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+// Zapis rundy leży w katalogu profilu.
+const RUN_SAVE_FILE: &str = "run.ron";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedRun {
+    seed: u64,
+    draws: u64,
+    score: i32,
+    theme: Theme,
+    controls: ControlScheme,
+    // Poziom i tryb, w których runda się zaczęła – od nich zależą kolejne
+    // przeszkody i kategoria rekordu. Starsze zapisy ich nie mają.
+    #[serde(default)]
+    difficulty: Difficulty,
+    #[serde(default)]
+    mode: GameMode,
+    bird: SavedBird,
+    // Postęp odliczania do następnego wzoru przeszkód (sekundy).
+    spawn_interval: f32,
+    spawn_elapsed: f32,
+    obstacles: Vec<SavedObstacle>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SavedBird {
+    position: Vec2,
+    dx: f32,
+    dy: f32,
+    // Ptak jeszcze unosi się w fazie "Get Ready".
+    hovering: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum SavedObstacle {
+    Pipe {
+        end: PipeEnd,
+        x: f32,
+        mouth_y: f32,
+        speed: f32,
+        lifetime: f32,
+        tint: Color,
+    },
+    ScoreZone {
+        position: Vec2,
+        gap: f32,
+        speed: f32,
+        lifetime: f32,
+        passed: bool,
+    },
+    Spikes {
+        side: PipeEnd,
+        x: f32,
+        speed: f32,
+        lifetime: f32,
+    },
+    LowGravityZone {
+        position: Vec2,
+        speed: f32,
+        lifetime: f32,
+    },
+    TunnelWall {
+        position: Vec2,
+        speed: f32,
+        lifetime: f32,
+        color: Color,
+    },
+}
+
+// Runda do odtworzenia przy najbliższym wejściu w `AppState::Playing`.
+#[derive(Resource)]
+pub struct PendingResume(pub SavedRun);
+
+#[derive(Component)]
+pub struct ResumeRunButton;
+
+#[derive(Component)]
+struct SaveAndQuitButton;
+
+// Pasek pod napisem "Paused" z przyciskiem zapisu.
+#[derive(Component)]
+struct SaveButtonBar;
+
+#[derive(Event)]
+struct SaveRunRequested;
+
+pub struct RunSavePlugin;
+
+impl Plugin for RunSavePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SaveRunRequested>()
+            .add_systems(
+                OnEnter(AppState::Playing),
+                (
+                    apply_resumed_settings
+                        .after(crate::theme::pick_run_theme)
+                        .after(crate::rng::reseed_run_rng)
+                        .after(crate::controls::lock_run_controls)
+                        .before(crate::restart_game),
+                    // Wznowiona runda nie ma początku, więc nie może powstać z niej
+                    // powtórka – nagrywanie musi zobaczyć jeszcze `PendingResume`.
+                    restore_run
+                        .after(crate::score::reset_score)
+                        .after(crate::replay::start_recording),
+                )
+                    .run_if(resource_exists::<PendingResume>),
+            )
+            .add_systems(
+                Update,
+                (
+                    resume_button_system,
+                    show_resume_button.run_if(resource_changed::<ActiveProfile>),
+                )
+                    .run_if(in_state(AppState::Menu)),
+            )
+            .add_systems(OnEnter(PauseState::Paused), spawn_save_button)
+            .add_systems(OnExit(PauseState::Paused), despawn_save_button)
+            .add_systems(
+                Update,
+                (
+                    save_button_system,
+                    save_and_quit.run_if(on_event::<SaveRunRequested>),
+                )
+                    .chain()
+                    .run_if(in_state(PauseState::Paused)),
+            );
+    }
+}
+
+pub fn has_saved_run(profile: &ActiveProfile) -> bool {
+    profile.path(RUN_SAVE_FILE).exists()
+}
+
+// Przycisk "Resume run" jest w menu zawsze, ale widać go tylko wtedy, gdy
+// bieżący profil ma zapisaną rundę.
+pub fn resume_button_display(profile: &ActiveProfile) -> Display {
+    if has_saved_run(profile) {
+        Display::Flex
+    } else {
+        Display::None
+    }
+}
+
+fn show_resume_button(
+    profile: Res<ActiveProfile>,
+    mut buttons: Query<&mut Node, With<ResumeRunButton>>,
+) {
+    for mut node in buttons.iter_mut() {
+        node.display = resume_button_display(&profile);
+    }
+}
+
+// Zrzut bieżącej rundy. Pozycje pochodzą z ostatniego kroku symulacji,
+// a nie z interpolowanego obrazu.
+pub fn capture_run(world: &mut World) -> SavedRun {
+    let (seed, draws) = {
+        let rng = world.resource::<RunRng>();
+        (rng.seed(), rng.draws())
+    };
+    let (spawn_interval, spawn_elapsed) = world
+        .resource::<Scheduler>()
+        .progress(TriggerId::PipeSpawn)
+        .unwrap_or_default();
+
+    let bird = world
+        .query_filtered::<(&Transform, &Interpolated, &Velocity, Has<HoverIdle>), With<Player>>()
+        .get_single(world)
+        .map(|(transform, interpolated, velocity, hovering)| SavedBird {
+            position: interpolated.simulated(transform).truncate(),
+            dx: velocity.dx,
+            dy: velocity.dy,
+            hovering,
+        })
+        .unwrap_or(SavedBird {
+            position: Vec2::ZERO,
+            dx: 0.0,
+            dy: 0.0,
+            hovering: true,
+        });
+
+    let mut obstacles = Vec::new();
+    let mut query = world.query_filtered::<(
+        &Transform,
+        &Interpolated,
+        &Velocity,
+        &LifeTime,
+        Option<&Sprite>,
+        Option<&Pipe>,
+        Option<&Collider>,
+        Option<&Scoreable>,
+        (
+            Has<ScoreZone>,
+            Has<Spikes>,
+            Has<LowGravityZone>,
+            Has<TunnelWall>,
+        ),
+    ), Without<Player>>();
+    for (
+        transform,
+        interpolated,
+        velocity,
+        lifetime,
+        sprite,
+        pipe,
+        collider,
+        scoreable,
+        (score_zone, spikes, low_gravity, tunnel_wall),
+    ) in query.iter(world)
+    {
+        let position = interpolated.simulated(transform).truncate();
+        let (speed, lifetime) = (velocity.dx, lifetime.0);
+        let color = sprite.map_or(Color::WHITE, |sprite| sprite.color);
+
+        let obstacle = if let Some(pipe) = pipe {
+            SavedObstacle::Pipe {
+                end: pipe.end,
+                x: position.x,
+                mouth_y: position.y,
+                speed,
+                lifetime,
+                tint: color,
+            }
+        } else if score_zone {
+            SavedObstacle::ScoreZone {
+                position,
+                gap: collider.map_or(0.0, |collider| collider.half_size.y * 2.0),
+                speed,
+                lifetime,
+                passed: scoreable.is_some_and(|scoreable| scoreable.passed),
+            }
+        } else if spikes {
+            SavedObstacle::Spikes {
+                side: if position.y > 0.0 {
+                    PipeEnd::Top
+                } else {
+                    PipeEnd::Bottom
+                },
+                x: position.x,
+                speed,
+                lifetime,
+            }
+        } else if low_gravity {
+            SavedObstacle::LowGravityZone {
+                position,
+                speed,
+                lifetime,
+            }
+        } else if tunnel_wall {
+            SavedObstacle::TunnelWall {
+                position,
+                speed,
+                lifetime,
+                color,
+            }
+        } else {
+            continue;
+        };
+        obstacles.push(obstacle);
+    }
+
+    SavedRun {
+        seed,
+        draws,
+        score: world.resource::<Score>().0,
+        theme: world.resource::<ActiveTheme>().0.clone(),
+        controls: world.resource::<RunControls>().0,
+        difficulty: world.resource::<Settings>().difficulty,
+        mode: world.resource::<RunMode>().0,
+        bird,
+        spawn_interval,
+        spawn_elapsed,
+        obstacles,
+    }
+}
+
+// Motyw, sterowanie, poziom trudności i generator muszą być ustawione, zanim
+// `restart_game` postawi tło i zanim cokolwiek zacznie losować. Runda kończy
+// się na poziomie, na którym się zaczęła, nawet jeśli gracz go potem zmienił.
+fn apply_resumed_settings(
+    pending: Res<PendingResume>,
+    mut theme: ResMut<ActiveTheme>,
+    mut controls: ResMut<RunControls>,
+    mut settings: ResMut<Settings>,
+    mut mode: ResMut<RunMode>,
+    mut rng: ResMut<RunRng>,
+) {
+    let saved = &pending.0;
+    theme.0 = saved.theme.clone();
+    controls.0 = saved.controls;
+    if settings.difficulty != saved.difficulty {
+        settings.difficulty = saved.difficulty;
+    }
+    mode.0 = saved.mode;
+    *rng = RunRng::restore(saved.seed, saved.draws);
+}
+
+// Odtwarza stan rundy na świeżo przygotowanej planszy.
+fn restore_run(world: &mut World) {
+    let Some(PendingResume(saved)) = world.remove_resource::<PendingResume>() else {
+        return;
+    };
+
+    world.resource_mut::<Score>().0 = saved.score;
+    world.resource_mut::<Scheduler>().resume(
+        TriggerId::PipeSpawn,
+        saved.spawn_interval,
+        saved.spawn_elapsed,
+    );
+
+    // Biom od razu właściwy dla wyniku – bez przewijania tła po wznowieniu.
+    let biome = Biome::at_score(saved.score);
+    world.resource_mut::<CurrentBiome>().0 = biome;
+    let assets = world.resource::<GameAssets>().clone();
+    let (background, tint) = biome.background(&assets, &saved.theme);
+    let mut backgrounds = world.query_filtered::<&mut Sprite, With<Background>>();
+    for mut sprite in backgrounds.iter_mut(world) {
+        sprite.image = background.clone();
+        sprite.color = tint;
+    }
+
+    let mut players =
+        world.query_filtered::<(Entity, &mut Transform, &mut Velocity), With<Player>>();
+    let mut released = None;
+    for (entity, mut transform, mut velocity) in players.iter_mut(world) {
+        transform.translation.x = saved.bird.position.x;
+        transform.translation.y = saved.bird.position.y;
+        velocity.dx = saved.bird.dx;
+        velocity.dy = saved.bird.dy;
+        if !saved.bird.hovering {
+            released = Some(entity);
+        }
+    }
+    if let Some(player) = released {
+        world.entity_mut(player).remove::<HoverIdle>();
+    }
+
+    let pipe_image = assets.theme(&saved.theme).pipe.clone();
+    let mut commands = world.commands();
+    for obstacle in saved.obstacles {
+        match obstacle {
+            SavedObstacle::Pipe {
+                end,
+                x,
+                mouth_y,
+                speed,
+                lifetime,
+                tint,
+            } => {
+                spawn_pipe(
+                    &mut commands,
+                    pipe_image.clone(),
+                    PipeSpec {
+                        end,
+                        x,
+                        mouth_y,
+                        size: PIPE_SIZE,
+                        speed,
+                        lifetime,
+                        tint,
+                    },
+                );
+            }
+            SavedObstacle::ScoreZone {
+                position,
+                gap,
+                speed,
+                lifetime,
+                passed,
+            } => {
+                let zone = spawn_score_zone(&mut commands, position, gap, speed, lifetime);
+                commands.entity(zone).insert(Scoreable { passed });
+            }
+            SavedObstacle::Spikes {
+                side,
+                x,
+                speed,
+                lifetime,
+            } => spawn_spike_strip(&mut commands, side, x, WORLD_SIZE.y / 2.0, speed, lifetime),
+            SavedObstacle::LowGravityZone {
+                position,
+                speed,
+                lifetime,
+            } => spawn_low_gravity_zone(&mut commands, position.x, position.y, speed, lifetime),
+            SavedObstacle::TunnelWall {
+                position,
+                speed,
+                lifetime,
+                color,
+            } => spawn_tunnel_wall(&mut commands, position, speed, lifetime, color),
+        }
+    }
+    world.flush();
+}
+
+fn spawn_save_button(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                bottom: Val::Px(80.0),
+                justify_content: JustifyContent::Center,
+                ..Default::default()
+            },
+            SaveButtonBar,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Button,
+                    Interaction::default(),
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(80.0),
+                        border: UiRect::all(Val::Px(5.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(NORMAL_BUTTON),
+                    BorderColor(Color::BLACK),
+                    BorderRadius::MAX,
+                    SaveAndQuitButton,
+                    ParentalLock::default(),
+                ))
+                .with_child((Text::new("Save & Quit"), TextColor(Color::WHITE)));
+        });
+}
+
+fn despawn_save_button(mut commands: Commands, query: Query<Entity, With<SaveButtonBar>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn save_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<SaveAndQuitButton>),
+    >,
+    mut requests: EventWriter<SaveRunRequested>,
+) {
+    for (interaction, mut bg_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                requests.send(SaveRunRequested);
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+fn save_and_quit(world: &mut World) {
+    let saved = capture_run(world);
+    let path = world.resource::<ActiveProfile>().path(RUN_SAVE_FILE);
+    match ron::ser::to_string_pretty(&saved, ron::ser::PrettyConfig::default()) {
+        Ok(contents) => {
+            if let Err(err) = fs::write(&path, contents) {
+                warn!("Nie udało się zapisać {}: {err}", path.display());
+                return;
+            }
+        }
+        Err(err) => {
+            warn!("Nie udało się zserializować rundy: {err}");
+            return;
+        }
+    }
+
+    world
+        .resource_mut::<NextState<AppState>>()
+        .set(AppState::Menu);
+}
+
+// Zapis jest jednorazowy: wczytana runda znika z dysku.
+fn load_saved_run(profile: &ActiveProfile) -> Option<SavedRun> {
+    let path = profile.path(RUN_SAVE_FILE);
+    let contents = fs::read_to_string(&path).ok()?;
+    if let Err(err) = fs::remove_file(&path) {
+        warn!("Nie udało się usunąć {}: {err}", path.display());
+    }
+    ron::from_str(&contents)
+        .inspect_err(|err| warn!("Nie udało się odczytać {}: {err}", path.display()))
+        .ok()
+}
+
+fn resume_button_system(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<ResumeRunButton>),
+    >,
+    profile: Res<ActiveProfile>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut next_pause_state: ResMut<NextState<PauseState>>,
+) {
+    for (interaction, mut bg_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                if let Some(saved) = load_saved_run(&profile) {
+                    commands.insert_resource(PendingResume(saved));
+                    // Runda rusza od odliczania, a nie od razu po wczytaniu.
+                    next_pause_state.set(PauseState::Resuming);
+                }
+                next_state.set(AppState::Playing);
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
@@ -0,0 +1,115 @@
+// Statystyki bieżącej rundy pokazywane na ekranie końca gry. Komponent siedzi
+// na encji gracza; świeży dostaje każdy nowy ptak na `RunStarted`.
+
+use bevy::prelude::*;
+
+use crate::pause::PauseState;
+use crate::physics::Collider;
+use crate::player::{Flapped, HoverIdle, Player, player_input_system};
+use crate::score::{PointScored, ScoreZone, apply_score_events};
+use crate::{AppState, RunStarted};
+
+// Przelot bliżej niż tyle pikseli od krawędzi przerwy liczy się jako "o włos".
+const NEAR_MISS_PX: f32 = 8.0;
+
+#[derive(Component, Default, Debug, Clone)]
+pub struct RunStats {
+    pub pipes_passed: u32,
+    pub near_misses: u32,
+    // Najdłuższy lot bez machnięcia (sekundy).
+    pub longest_glide: f32,
+    current_glide: f32,
+    // Seria przelotów "o włos" z rzędu.
+    pub max_combo: u32,
+    combo: u32,
+}
+
+impl RunStats {
+    // Wiersze podsumowania na ekranie końca gry.
+    pub fn breakdown(&self) -> [String; 4] {
+        [
+            format!("Pipes passed: {}", self.pipes_passed),
+            format!("Near misses: {}", self.near_misses),
+            format!("Longest glide: {:.1} s", self.longest_glide),
+            format!("Max combo: {}", self.max_combo),
+        ]
+    }
+}
+
+pub struct RunStatsPlugin;
+
+impl Plugin for RunStatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (
+                track_glide.after(player_input_system),
+                track_passes.after(apply_score_events),
+            )
+                .run_if(in_state(PauseState::Running)),
+        )
+        .add_systems(
+            OnEnter(AppState::Playing),
+            reset_run_stats.after(crate::restart_game),
+        );
+    }
+}
+
+fn reset_run_stats(
+    mut commands: Commands,
+    mut run_started: EventReader<RunStarted>,
+    players: Query<Entity, With<Player>>,
+) {
+    if run_started.read().last().is_none() {
+        return;
+    }
+    for player in players.iter() {
+        commands.entity(player).insert(RunStats::default());
+    }
+}
+
+fn track_glide(
+    time: Res<Time>,
+    mut flapped: EventReader<Flapped>,
+    mut query: Query<&mut RunStats, (With<Player>, Without<HoverIdle>)>,
+) {
+    let flapped = flapped.read().last().is_some();
+    for mut stats in query.iter_mut() {
+        if flapped {
+            stats.current_glide = 0.0;
+        } else {
+            stats.current_glide += time.delta_secs();
+            stats.longest_glide = stats.longest_glide.max(stats.current_glide);
+        }
+    }
+}
+
+fn track_passes(
+    mut scored: EventReader<PointScored>,
+    mut player_query: Query<(&Transform, &Collider, &mut RunStats), With<Player>>,
+    zone_query: Query<(&Transform, &Collider), With<ScoreZone>>,
+) {
+    let Ok((player_transform, player_collider, mut stats)) = player_query.get_single_mut() else {
+        return;
+    };
+
+    for event in scored.read() {
+        stats.pipes_passed += 1;
+
+        let Ok((zone_transform, zone_collider)) = zone_query.get(event.zone) else {
+            continue;
+        };
+        let offset = (player_collider.center(player_transform).y
+            - zone_collider.center(zone_transform).y)
+            .abs();
+        let clearance = zone_collider.half_size.y - offset - player_collider.half_size.y;
+
+        if clearance < NEAR_MISS_PX {
+            stats.near_misses += 1;
+            stats.combo += 1;
+            stats.max_combo = stats.max_combo.max(stats.combo);
+        } else {
+            stats.combo = 0;
+        }
+    }
+}
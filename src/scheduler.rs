@@ -0,0 +1,241 @@
+// Harmonogram rundy: wspólne miejsce na wszystko, co ma się wydarzyć "co ileś
+// sekund" albo "co tyle punktów". Zegar liczy czas
+// symulacji od wypuszczenia ptaka i staje razem z pauzą, więc wyzwalacze są tak
+// samo deterministyczne jak reszta kroku stałego.
+//
+// Systemy rejestrują wyzwalacze przy starcie rundy i odbierają je przez
+// `Scheduler::take`. Odpalony, a jeszcze nieodebrany wyzwalacz czeka – dzięki
+// temu odbiorca może działać w `Update`.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::pause::PauseState;
+use crate::score::Score;
+use crate::{AppState, RunStarted};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerId {
+    // Następny wzór przeszkód (`pipe_spawn_system`).
+    PipeSpawn,
+    // Zmiana biomu co `PIPES_PER_BIOME` punktów.
+    BiomeChange,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum When {
+    // Co `interval`; najbliższy raz o `next`.
+    Every { next: Duration, interval: Duration },
+    // Gdy wynik dojdzie do `next`, potem co `step` punktów.
+    Points { next: i32, step: i32 },
+}
+
+#[derive(Resource, Default)]
+pub struct Scheduler {
+    // Czas w nanosekundach jak w `Timer` – sumowanie f32 rozjechałoby się
+    // z dotychczasowym rytmem przeszkód o krok symulacji.
+    clock: Duration,
+    entries: Vec<(TriggerId, When)>,
+    fired: Vec<TriggerId>,
+}
+
+impl Scheduler {
+    // Co `secs` sekund, pierwszy raz za `secs` sekund od teraz.
+    pub fn every(&mut self, id: TriggerId, secs: f32) {
+        let interval = Duration::from_secs_f32(secs);
+        self.set(
+            id,
+            When::Every {
+                next: self.clock + interval,
+                interval,
+            },
+        );
+    }
+
+    // Przy każdej wielokrotności `step` punktów.
+    pub fn every_points(&mut self, id: TriggerId, step: i32) {
+        self.set(id, When::Points { next: step, step });
+    }
+
+    // Zmienia odstęp powtarzanego wyzwalacza, licząc od jego ostatniego
+    // odpalenia – tak jak `Timer::set_duration` zaraz po `just_finished`.
+    pub fn set_interval(&mut self, id: TriggerId, secs: f32) {
+        let new_interval = Duration::from_secs_f32(secs);
+        for (_, when) in self.entries.iter_mut().filter(|(entry, _)| *entry == id) {
+            if let When::Every { next, interval } = when {
+                *next = next.saturating_sub(*interval) + new_interval;
+                *interval = new_interval;
+            }
+        }
+    }
+
+    // Odstęp i czas od ostatniego odpalenia powtarzanego wyzwalacza (sekundy).
+    pub fn progress(&self, id: TriggerId) -> Option<(f32, f32)> {
+        self.entries.iter().find_map(|(entry, when)| match when {
+            When::Every { next, interval } if *entry == id => {
+                let elapsed = (self.clock + *interval).saturating_sub(*next);
+                Some((interval.as_secs_f32(), elapsed.as_secs_f32()))
+            }
+            _ => None,
+        })
+    }
+
+    // Powtarzany wyzwalacz, który w poprzedniej sesji odliczył już `elapsed`.
+    pub fn resume(&mut self, id: TriggerId, interval_secs: f32, elapsed_secs: f32) {
+        let interval = Duration::from_secs_f32(interval_secs);
+        let elapsed = Duration::from_secs_f32(elapsed_secs);
+        self.set(
+            id,
+            When::Every {
+                next: (self.clock + interval).saturating_sub(elapsed),
+                interval,
+            },
+        );
+    }
+
+    pub fn cancel(&mut self, id: TriggerId) {
+        self.entries.retain(|(entry, _)| *entry != id);
+        self.fired.retain(|entry| *entry != id);
+    }
+
+    // Odbiera odpalony wyzwalacz; `true` najwyżej raz na odpalenie.
+    pub fn take(&mut self, id: TriggerId) -> bool {
+        let before = self.fired.len();
+        self.fired.retain(|entry| *entry != id);
+        self.fired.len() != before
+    }
+
+    fn set(&mut self, id: TriggerId, when: When) {
+        self.cancel(id);
+        self.entries.push((id, when));
+    }
+
+    fn fire(&mut self, id: TriggerId) {
+        if !self.fired.contains(&id) {
+            self.fired.push(id);
+        }
+    }
+
+    fn advance(&mut self, delta: Duration, score: i32) {
+        self.clock += delta;
+        let clock = self.clock;
+        let mut fired = Vec::new();
+        self.entries.retain_mut(|(id, when)| match when {
+            When::Every { next, interval } => {
+                if clock >= *next {
+                    fired.push(*id);
+                    *next += *interval;
+                }
+                true
+            }
+            When::Points { next, step } => {
+                if score < *next {
+                    return true;
+                }
+                fired.push(*id);
+                // Wynik mógł przeskoczyć kilka progów naraz (np. po wznowieniu).
+                *next = (score / *step + 1) * *step;
+                true
+            }
+        });
+        for id in fired {
+            self.fire(id);
+        }
+    }
+}
+
+pub struct SchedulerPlugin;
+
+impl Plugin for SchedulerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Scheduler>()
+            .add_systems(
+                OnEnter(AppState::Playing),
+                reset_scheduler.after(crate::restart_game),
+            )
+            .add_systems(
+                FixedUpdate,
+                tick_scheduler
+                    .before(crate::pipes::pipe_spawn_system)
+                    .run_if(crate::player::bird_released)
+                    .run_if(in_state(PauseState::Running)),
+            );
+    }
+}
+
+// Nowa runda zaczyna z pustym harmonogramem; wyzwalacze rejestrują się po tym
+// systemie (`.after(crate::scheduler::reset_scheduler)`).
+pub fn reset_scheduler(mut run_started: EventReader<RunStarted>, mut scheduler: ResMut<Scheduler>) {
+    if run_started.read().last().is_some() {
+        *scheduler = Scheduler::default();
+    }
+}
+
+fn tick_scheduler(time: Res<Time>, score: Res<Score>, mut scheduler: ResMut<Scheduler>) {
+    scheduler.advance(time.delta(), score.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STEP: Duration = Duration::from_millis(125);
+
+    #[test]
+    fn repeating_triggers_follow_the_run_clock() {
+        let mut scheduler = Scheduler::default();
+        scheduler.every(TriggerId::PipeSpawn, 0.25);
+
+        scheduler.advance(STEP, 0);
+        assert!(!scheduler.take(TriggerId::PipeSpawn));
+        scheduler.advance(STEP, 0);
+        assert!(scheduler.take(TriggerId::PipeSpawn));
+        assert!(!scheduler.take(TriggerId::PipeSpawn));
+
+        scheduler.advance(STEP * 2, 0);
+        assert!(scheduler.take(TriggerId::PipeSpawn));
+
+        scheduler.cancel(TriggerId::PipeSpawn);
+        for _ in 0..10 {
+            scheduler.advance(STEP, 0);
+        }
+        assert!(!scheduler.take(TriggerId::PipeSpawn));
+    }
+
+    #[test]
+    fn new_interval_counts_from_the_last_firing() {
+        let mut scheduler = Scheduler::default();
+        scheduler.every(TriggerId::PipeSpawn, 0.25);
+        scheduler.advance(STEP * 2, 0);
+        assert!(scheduler.take(TriggerId::PipeSpawn));
+        scheduler.set_interval(TriggerId::PipeSpawn, 0.5);
+        scheduler.advance(STEP, 0);
+        assert_eq!(scheduler.progress(TriggerId::PipeSpawn), Some((0.5, 0.125)));
+
+        let (interval, elapsed) = scheduler.progress(TriggerId::PipeSpawn).unwrap();
+        let mut resumed = Scheduler::default();
+        resumed.resume(TriggerId::PipeSpawn, interval, elapsed);
+        resumed.advance(STEP * 2, 0);
+        assert!(!resumed.take(TriggerId::PipeSpawn));
+        resumed.advance(STEP, 0);
+        assert!(resumed.take(TriggerId::PipeSpawn));
+    }
+
+    #[test]
+    fn score_triggers_fire_once_per_threshold() {
+        let mut scheduler = Scheduler::default();
+        scheduler.every_points(TriggerId::BiomeChange, 10);
+        scheduler.advance(STEP, 9);
+        assert!(!scheduler.take(TriggerId::BiomeChange));
+        scheduler.advance(STEP, 10);
+        scheduler.advance(STEP, 11);
+        assert!(scheduler.take(TriggerId::BiomeChange));
+        assert!(!scheduler.take(TriggerId::BiomeChange));
+        // Skok z 11 na 35 odpala raz, następny próg to 40.
+        scheduler.advance(STEP, 35);
+        assert!(scheduler.take(TriggerId::BiomeChange));
+        scheduler.advance(STEP, 39);
+        assert!(!scheduler.take(TriggerId::BiomeChange));
+    }
+}
@@ -0,0 +1,155 @@
+// Punktacja: strefy punktowe w przerwach między rurami, zdarzenie
+// `PointScored`, wynik rundy i licznik na ekranie.
+
+use bevy::prelude::*;
+
+use crate::glide_streak::GlideStreak;
+use crate::interpolation::Interpolated;
+use crate::pause::PauseState;
+use crate::physics::{Collider, LifeTime, Sensor, Velocity, move_system};
+use crate::pipes::PIPE_INTERVAL_SECS;
+use crate::player::Player;
+use crate::scheduler::{Scheduler, TriggerId};
+use crate::score_digits::ScoreDigits;
+use crate::{AppState, RunEntity, RunStarted};
+
+#[derive(Component)]
+pub struct Scoreable {
+    pub passed: bool,
+}
+
+// Niewidzialna strefa w przerwie między rurami – jej przekroczenie daje punkt.
+#[derive(Component)]
+#[require(RunEntity)]
+pub struct ScoreZone;
+
+// Wysyłany, gdy gracz przekroczy strefę punktową.
+#[derive(Event)]
+pub struct PointScored {
+    pub zone: Entity,
+}
+
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource)]
+pub struct Score(pub i32);
+
+#[derive(Component)]
+pub struct ScoreText;
+
+pub struct ScorePlugin;
+
+impl Plugin for ScorePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Score(0))
+            .add_event::<PointScored>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    score_system.after(move_system),
+                    apply_score_events.after(score_system),
+                )
+                    .run_if(in_state(PauseState::Running)),
+            )
+            .add_systems(
+                Update,
+                update_score_display.run_if(in_state(PauseState::Running)),
+            )
+            .add_systems(
+                OnEnter(AppState::Playing),
+                reset_score.after(crate::scheduler::reset_scheduler),
+            );
+    }
+}
+
+// Niewidzialna strefa punktowa o wysokości przerwy `gap`.
+pub fn spawn_score_zone(
+    commands: &mut Commands,
+    position: Vec2,
+    gap: f32,
+    speed: f32,
+    lifetime: f32,
+) -> Entity {
+    commands
+        .spawn((
+            Velocity { dx: speed, dy: 0.0 },
+            Collider {
+                half_size: Vec2::new(2.0, gap / 2.0),
+                offset: Vec2::ZERO,
+            },
+            Sensor,
+            ScoreZone,
+            Scoreable { passed: false },
+            LifeTime(lifetime),
+            Interpolated::default(),
+            Transform::from_xyz(position.x, position.y, 0.0),
+        ))
+        .id()
+}
+
+// Wykrywa wejście gracza w strefę punktową i wysyła zdarzenie.
+pub fn score_system(
+    player_query: Query<(&Transform, &Collider), With<Player>>,
+    mut zone_query: Query<
+        (Entity, &Transform, &Collider, &mut Scoreable),
+        (With<ScoreZone>, With<Sensor>),
+    >,
+    mut scored: EventWriter<PointScored>,
+) {
+    let Ok((player_transform, player_collider)) = player_query.get_single() else {
+        return;
+    };
+
+    for (zone, zone_transform, zone_collider, mut scoreable) in zone_query.iter_mut() {
+        if !scoreable.passed
+            && crate::core::aabb_overlap(
+                player_collider.center(player_transform).into(),
+                player_collider.half_size.into(),
+                zone_collider.center(zone_transform).into(),
+                zone_collider.half_size.into(),
+            )
+        {
+            scoreable.passed = true;
+            scored.send(PointScored { zone });
+        }
+    }
+}
+
+// Punkt jest wart tyle, ile wynosi mnożnik serii szybowania (`glide_streak.rs`).
+pub fn apply_score_events(
+    mut score: ResMut<Score>,
+    mut scored: EventReader<PointScored>,
+    glide: Query<&GlideStreak, With<Player>>,
+) {
+    let points = glide.get_single().map_or(1, GlideStreak::multiplier);
+    for event in scored.read() {
+        debug!("Punkt za strefę {:?} (x{points})", event.zone);
+        score.0 += points;
+    }
+}
+
+fn update_score_display(score: Res<Score>, mut query: Query<&mut ScoreDigits, With<ScoreText>>) {
+    for mut digits in query.iter_mut() {
+        // Bez zbędnej zmiany – cyfry przebudowują się tylko przy nowym wyniku.
+        if digits.0 != score.0 {
+            digits.0 = score.0;
+        }
+    }
+}
+
+// Licznik na ekranie zerujemy od razu – czekając na `update_score_display`,
+// przez klatkę pokazywałby wynik poprzedniej rundy.
+pub fn reset_score(
+    mut run_started: EventReader<RunStarted>,
+    mut score: ResMut<Score>,
+    mut scheduler: ResMut<Scheduler>,
+    mut score_text: Query<&mut ScoreDigits, With<ScoreText>>,
+) {
+    if run_started.read().last().is_none() {
+        return;
+    }
+    score.0 = 0;
+    scheduler.every(TriggerId::PipeSpawn, PIPE_INTERVAL_SECS);
+    for mut digits in score_text.iter_mut() {
+        digits.0 = 0;
+    }
+}
@@ -0,0 +1,111 @@
+// Wynik z obrazków cyfr (`sprites/0.png`…`9.png`) zamiast tekstu. Ten sam
+// komponent działa w świecie gry (sprite'y wyśrodkowane wokół rodzica) i w
+// interfejsie (węzły UI w rzędzie) – korzysta z niego licznik w trakcie rundy
+// i panel końca gry.
+
+use bevy::prelude::*;
+
+use crate::assets::GameAssets;
+
+// Odstęp między cyframi w pikselach.
+const DIGIT_SPACING: f32 = 2.0;
+// Szerokość cyfry, której obrazek jeszcze nie jest dostępny.
+const FALLBACK_DIGIT_WIDTH: f32 = 24.0;
+
+#[derive(Component, Debug, Default)]
+pub struct ScoreDigits(pub i32);
+
+pub struct ScoreDigitsPlugin;
+
+impl Plugin for ScoreDigitsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, compose_score_digits);
+    }
+}
+
+// Cyfry liczby od najbardziej znaczącej; ujemne wyniki pokazujemy jako zero.
+fn digits_of(value: i32) -> Vec<usize> {
+    value
+        .max(0)
+        .to_string()
+        .bytes()
+        .map(|byte| usize::from(byte - b'0'))
+        .collect()
+}
+
+// Środki kolejnych cyfr względem środka całej liczby.
+fn centered_offsets(widths: &[f32]) -> Vec<f32> {
+    let total = widths.iter().sum::<f32>() + DIGIT_SPACING * widths.len().saturating_sub(1) as f32;
+    let mut left = -total / 2.0;
+    widths
+        .iter()
+        .map(|width| {
+            let center = left + width / 2.0;
+            left += width + DIGIT_SPACING;
+            center
+        })
+        .collect()
+}
+
+// Przebudowuje cyfry tylko przy zmianie wartości (i przy dodaniu komponentu).
+pub fn compose_score_digits(
+    mut commands: Commands,
+    assets: Res<GameAssets>,
+    images: Res<Assets<Image>>,
+    widgets: Query<(Entity, &ScoreDigits, Has<Node>), Changed<ScoreDigits>>,
+) {
+    for (entity, score, in_ui) in widgets.iter() {
+        let digits = digits_of(score.0);
+        let mut widget = commands.entity(entity);
+        widget.despawn_descendants();
+
+        if in_ui {
+            widget.with_children(|parent| {
+                for &digit in &digits {
+                    parent.spawn((
+                        ImageNode::new(assets.digits[digit].clone()),
+                        Node {
+                            margin: UiRect::horizontal(Val::Px(DIGIT_SPACING / 2.0)),
+                            ..Default::default()
+                        },
+                    ));
+                }
+            });
+            continue;
+        }
+
+        // Cyfra "1" jest węższa od pozostałych, więc szerokości bierzemy z obrazków.
+        let widths: Vec<f32> = digits
+            .iter()
+            .map(|&digit| {
+                images
+                    .get(&assets.digits[digit])
+                    .map_or(FALLBACK_DIGIT_WIDTH, |image| image.width() as f32)
+            })
+            .collect();
+        widget.with_children(|parent| {
+            for (&digit, x) in digits.iter().zip(centered_offsets(&widths)) {
+                parent.spawn((
+                    Sprite::from_image(assets.digits[digit].clone()),
+                    Transform::from_xyz(x, 0.0, 0.0),
+                ));
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digits_are_centered_around_the_widget() {
+        assert_eq!(digits_of(0), vec![0]);
+        assert_eq!(digits_of(107), vec![1, 0, 7]);
+        assert_eq!(digits_of(-3), vec![0]);
+
+        // "10": wąska jedynka (16) i zero (24) z odstępem 2 – razem 42.
+        assert_eq!(centered_offsets(&[16.0, 24.0]), vec![-13.0, 9.0]);
+        assert_eq!(centered_offsets(&[24.0]), vec![0.0]);
+    }
+}
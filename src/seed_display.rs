@@ -0,0 +1,138 @@
+// Ziarno rundy na ekranie końca gry z przyciskiem "Copy", który kopiuje je do
+// schowka systemowego – ciekawym układem rur można się podzielić.
+
+use bevy::prelude::*;
+
+use crate::bests::RunMode;
+use crate::game_over::GameOverUI;
+use crate::rng::RunRng;
+use crate::toast::Toast;
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+#[derive(Component)]
+struct CopySeedButton;
+
+// Link do wyzwania – tylko tam, gdzie gra ma adres (przeglądarka).
+#[derive(Component)]
+struct CopyLinkButton(String);
+
+pub struct SeedDisplayPlugin;
+
+impl Plugin for SeedDisplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::GameOver), spawn_seed_bar)
+            .add_systems(
+                Update,
+                copy_seed_button_system.run_if(in_state(AppState::GameOver)),
+            );
+    }
+}
+
+pub fn seed_label(seed: u64) -> String {
+    format!("{seed:016x}")
+}
+
+// Pasek u góry ekranu; sprzątany razem z resztą ekranu końca gry.
+fn spawn_seed_bar(mut commands: Commands, rng: Res<RunRng>, mode: Res<RunMode>) {
+    let link = crate::challenge::challenge_link(rng.seed(), mode.0);
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(20.0),
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(12.0),
+                ..Default::default()
+            },
+            GameOverUI,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("Seed: {}", seed_label(rng.seed()))),
+                TextColor(Color::WHITE),
+                TextFont {
+                    font_size: 22.0,
+                    ..Default::default()
+                },
+            ));
+            parent
+                .spawn((
+                    Button,
+                    Interaction::default(),
+                    Node {
+                        width: Val::Px(90.0),
+                        height: Val::Px(40.0),
+                        border: UiRect::all(Val::Px(3.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(NORMAL_BUTTON),
+                    BorderColor(Color::BLACK),
+                    BorderRadius::MAX,
+                    CopySeedButton,
+                ))
+                .with_child((Text::new("Copy"), TextColor(Color::WHITE)));
+            if let Some(link) = link {
+                parent
+                    .spawn((
+                        Button,
+                        Interaction::default(),
+                        Node {
+                            width: Val::Px(120.0),
+                            height: Val::Px(40.0),
+                            border: UiRect::all(Val::Px(3.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        BackgroundColor(NORMAL_BUTTON),
+                        BorderColor(Color::BLACK),
+                        BorderRadius::MAX,
+                        CopyLinkButton(link),
+                    ))
+                    .with_child((Text::new("Copy link"), TextColor(Color::WHITE)));
+            }
+        });
+}
+
+fn copy_seed_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, Option<&CopyLinkButton>),
+        (
+            Changed<Interaction>,
+            Or<(With<CopySeedButton>, With<CopyLinkButton>)>,
+        ),
+    >,
+    rng: Res<RunRng>,
+    mut toasts: EventWriter<Toast>,
+) {
+    for (interaction, mut bg_color, link) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                let (text, copied) = match link {
+                    Some(CopyLinkButton(link)) => (link.clone(), "Challenge link copied"),
+                    None => (seed_label(rng.seed()), "Seed copied to clipboard"),
+                };
+                let message = match crate::clipboard::copy(&text) {
+                    Ok(()) => copied.to_string(),
+                    Err(err) => {
+                        warn!("Nie udało się skopiować do schowka: {err}");
+                        "Couldn't access the clipboard".to_string()
+                    }
+                };
+                toasts.send(Toast(message));
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
@@ -0,0 +1,231 @@
+// Pole ziarna w menu: wpisywanie z klawiatury (cyfry szesnastkowe) albo
+// wklejanie Ctrl+V, z walidacją i podglądem wysokości pierwszych przerw.
+// Puste pole oznacza losowe ziarno dla każdej rundy. Obok przycisk minimapy
+// całego ziarna (`practice.rs`).
+
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+use crate::menu::Menu;
+use crate::pipes::pattern_odds;
+use crate::rng::{SeedChoice, parse_seed, preview_gap_centers};
+use crate::seed_display::seed_label;
+use crate::settings::Settings;
+use crate::toast::Toast;
+use crate::{AppState, NORMAL_BUTTON};
+
+// Ile pierwszych przerw pokazuje podgląd.
+const PREVIEW_GAPS: usize = 5;
+const MAX_SEED_DIGITS: usize = 16;
+
+#[derive(Component)]
+struct SeedField;
+
+#[derive(Component)]
+struct SeedFieldText;
+
+#[derive(Component)]
+struct SeedPreviewText;
+
+#[derive(Resource, Default)]
+struct SeedEntry {
+    text: String,
+    focused: bool,
+}
+
+pub struct SeedEntryPlugin;
+
+impl Plugin for SeedEntryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SeedEntry>()
+            .add_systems(
+                OnEnter(AppState::Menu),
+                spawn_seed_entry.after(crate::menu::setup_menu),
+            )
+            .add_systems(
+                Update,
+                (
+                    focus_seed_field,
+                    seed_typing_system,
+                    refresh_seed_entry
+                        .run_if(resource_changed::<SeedEntry>.or(resource_changed::<Settings>)),
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Menu)),
+            );
+    }
+}
+
+fn spawn_seed_entry(mut commands: Commands, choice: Res<SeedChoice>, mut entry: ResMut<SeedEntry>) {
+    *entry = SeedEntry {
+        text: choice.0.map(seed_label).unwrap_or_default(),
+        focused: false,
+    };
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(20.0),
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(6.0),
+                ..Default::default()
+            },
+            // Znika razem z menu przy starcie rundy.
+            Menu,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(10.0),
+                    ..Default::default()
+                })
+                .with_children(|row| {
+                    row.spawn((Text::new("Seed:"), TextColor(Color::WHITE)));
+                    row.spawn((
+                        Button,
+                        Interaction::default(),
+                        Node {
+                            width: Val::Px(240.0),
+                            height: Val::Px(40.0),
+                            border: UiRect::all(Val::Px(3.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        BackgroundColor(NORMAL_BUTTON),
+                        BorderColor(Color::BLACK),
+                        SeedField,
+                    ))
+                    .with_child((
+                        Text::default(),
+                        TextColor(Color::WHITE),
+                        SeedFieldText,
+                    ));
+                    crate::practice::spawn_practice_button(row);
+                });
+            parent.spawn((
+                Text::default(),
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                TextFont {
+                    font_size: 18.0,
+                    ..Default::default()
+                },
+                SeedPreviewText,
+            ));
+        });
+}
+
+fn focus_seed_field(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<SeedField>)>,
+    mut entry: ResMut<SeedEntry>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            entry.focused = true;
+        }
+    }
+}
+
+fn seed_typing_system(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut entry: ResMut<SeedEntry>,
+    mut choice: ResMut<SeedChoice>,
+    mut toasts: EventWriter<Toast>,
+) {
+    if !entry.focused {
+        keyboard_events.clear();
+        return;
+    }
+
+    let shortcut = keyboard_input.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+
+        if shortcut && event.key_code == KeyCode::KeyV {
+            match crate::clipboard::paste() {
+                Ok(text) => match parse_seed(&text) {
+                    Some(seed) => entry.text = seed_label(seed),
+                    None => {
+                        toasts.send(Toast("Invalid seed in clipboard".to_string()));
+                    }
+                },
+                Err(err) => warn!("Nie udało się odczytać schowka: {err}"),
+            }
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Backspace => {
+                entry.text.pop();
+            }
+            Key::Enter | Key::Escape => entry.focused = false,
+            Key::Character(text) if !shortcut => {
+                for c in text.chars().filter(char::is_ascii_hexdigit) {
+                    if entry.text.len() < MAX_SEED_DIGITS {
+                        entry.text.push(c.to_ascii_lowercase());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let seed = parse_seed(&entry.text);
+    if choice.0 != seed {
+        choice.0 = seed;
+    }
+}
+
+fn refresh_seed_entry(
+    entry: Res<SeedEntry>,
+    choice: Res<SeedChoice>,
+    settings: Res<Settings>,
+    mut field_query: Query<&mut BorderColor, With<SeedField>>,
+    mut field_text: Query<&mut Text, (With<SeedFieldText>, Without<SeedPreviewText>)>,
+    mut preview_text: Query<&mut Text, (With<SeedPreviewText>, Without<SeedFieldText>)>,
+) {
+    for mut border in field_query.iter_mut() {
+        border.0 = if entry.focused {
+            Color::WHITE
+        } else {
+            Color::BLACK
+        };
+    }
+
+    for mut text in field_text.iter_mut() {
+        text.0 = match (entry.text.is_empty(), entry.focused) {
+            (true, false) => "random".to_string(),
+            (_, true) => format!("{}_", entry.text),
+            (false, false) => entry.text.clone(),
+        };
+    }
+
+    let preview = match choice.0 {
+        Some(seed) => {
+            let gaps: Vec<_> =
+                preview_gap_centers(seed, &pattern_odds(settings.difficulty), PREVIEW_GAPS)
+                    .iter()
+                    .map(|center| format!("{center:.0}"))
+                    .collect();
+            format!("First gaps: {}", gaps.join(", "))
+        }
+        None => "First gaps: random".to_string(),
+    };
+    for mut text in preview_text.iter_mut() {
+        text.0 = preview.clone();
+    }
+}
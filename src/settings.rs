@@ -0,0 +1,369 @@
+// Ustawienia gracza zapisywane w pliku `settings.ron`.
+// Plik jest wczytywany w `main` jeszcze przed utworzeniem okna, a zapisywany
+// automatycznie przy każdej zmianie zasobu `Settings`.
+
+use std::fs;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::ai_race::AiRaceSettings;
+use crate::cheats::Cosmetics;
+use crate::controls::ControlScheme;
+use crate::core::DifficultyCurve;
+use crate::effects::VisualEffects;
+use crate::theme::ThemeChoice;
+
+const SETTINGS_PATH: &str = "settings.ron";
+
+// Dozwolone częstotliwości symulacji w `FixedUpdate` (Hz).
+pub const TICK_RATES: [u32; 3] = [60, 120, 240];
+
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub theme: ThemeChoice,
+    pub muted: bool,
+    pub volume: VolumeSettings,
+    pub difficulty: Difficulty,
+    pub keybinds: Keybinds,
+    pub controls: ControlScheme,
+    // Zaawansowane: nacisk prawego spustu pada skaluje siłę machnięcia.
+    pub analog_flap: bool,
+    // Wibracje przy machnięciu i śmierci (tam, gdzie platforma je obsługuje).
+    pub haptics: bool,
+    // Oszczędzanie baterii (laptopy, konsole przenośne).
+    pub low_power: bool,
+    // Częstotliwość logiki gry, jedna z `TICK_RATES`.
+    pub tick_rate: u32,
+    // Ostre piksele: bez wygładzania tekstur i z całkowitą skalą kamery.
+    pub pixel_perfect: bool,
+    // Bloom, winieta i błysk przy śmierci (`effects.rs`).
+    pub visual_effects: VisualEffects,
+    // Filtr starego monitora: wygięcie, linie skanowania, poświata (`crt.rs`).
+    pub crt_filter: bool,
+    // Po śmierci wynik wisi chwilę na ekranie, a potem sama startuje nowa runda.
+    pub auto_restart: bool,
+    // Rywale sterowani przez komputer.
+    pub ai: AiRaceSettings,
+    pub accessibility: Accessibility,
+    pub assists: Assists,
+    pub window: WindowSettings,
+    // Dodatki wyglądu odblokowane kodami w menu. Należą do profilu gracza
+    // (`profiles.rs`); z pliku ustawień czytamy je tylko przy przenoszeniu
+    // starszych zapisów do pierwszego profilu.
+    #[serde(skip_serializing)]
+    pub cosmetics: Cosmetics,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            theme: ThemeChoice::default(),
+            muted: false,
+            volume: VolumeSettings::default(),
+            difficulty: Difficulty::default(),
+            keybinds: Keybinds::default(),
+            controls: ControlScheme::default(),
+            analog_flap: false,
+            haptics: true,
+            low_power: false,
+            tick_rate: TICK_RATES[0],
+            pixel_perfect: false,
+            visual_effects: VisualEffects::default(),
+            crt_filter: false,
+            auto_restart: false,
+            ai: AiRaceSettings::default(),
+            accessibility: Accessibility::default(),
+            assists: Assists::default(),
+            window: WindowSettings::default(),
+            cosmetics: Cosmetics::default(),
+        }
+    }
+}
+
+// Głośności w zakresie 0..=1.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VolumeSettings {
+    pub master: f32,
+    pub music: f32,
+    pub sfx: f32,
+}
+
+impl Default for VolumeSettings {
+    fn default() -> Self {
+        Self {
+            master: 1.0,
+            music: 0.6,
+            sfx: 0.8,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    pub fn curve(self) -> DifficultyCurve {
+        match self {
+            Difficulty::Easy => DifficultyCurve::EASY,
+            Difficulty::Normal => DifficultyCurve::CLASSIC,
+            Difficulty::Hard => DifficultyCurve::HARD,
+        }
+    }
+
+    // Szansa na listwę kolców między parą rur a następną.
+    pub fn spike_chance(self) -> f64 {
+        match self {
+            Difficulty::Easy | Difficulty::Normal => 0.0,
+            Difficulty::Hard => 0.3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Keybinds {
+    pub flap: KeyCode,
+    pub mute: KeyCode,
+    pub dash: KeyCode,
+}
+
+impl Default for Keybinds {
+    fn default() -> Self {
+        Self {
+            flap: KeyCode::Space,
+            mute: KeyCode::KeyM,
+            dash: KeyCode::ShiftLeft,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Accessibility {
+    // Wyłącza animacje "juice" (spłaszczanie, unoszenie się ptaka).
+    pub reduced_motion: bool,
+}
+
+// Ułatwienia zmieniające zasady gry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Assists {
+    // Krótkie otarcie o sufit lub róg rury nie kończy gry.
+    pub leniency: bool,
+    // Maksymalna głębokość otarcia w pikselach.
+    pub leniency_px: f32,
+    // Przerwy i prędkość rur dopasowują się do ostatnich wyników (`adaptive.rs`).
+    pub adaptive: bool,
+}
+
+impl Default for Assists {
+    fn default() -> Self {
+        Self {
+            leniency: false,
+            leniency_px: 4.0,
+            adaptive: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowSettings {
+    pub width: f32,
+    pub height: f32,
+    // Pozycja lewego górnego rogu w pikselach fizycznych.
+    pub position: Option<IVec2>,
+    // Nazwa monitora, na którym okno było ostatnio.
+    pub monitor: Option<String>,
+    pub fullscreen: bool,
+    // Monitor (po nazwie) dla trybu pełnoekranowego; `None` oznacza bieżący.
+    pub fullscreen_monitor: Option<String>,
+    pub vsync: bool,
+}
+
+impl Default for WindowSettings {
+    fn default() -> Self {
+        Self {
+            width: 800.0,
+            height: 600.0,
+            position: None,
+            monitor: None,
+            fullscreen: false,
+            fullscreen_monitor: None,
+            vsync: true,
+        }
+    }
+}
+
+impl Settings {
+    // Czy plik ustawień już istnieje (nie ma go tylko przy pierwszym uruchomieniu).
+    pub fn exists() -> bool {
+        fs::metadata(SETTINGS_PATH).is_ok()
+    }
+
+    // Wczytuje ustawienia z dysku; brak pliku albo błąd oznacza wartości domyślne.
+    pub fn load() -> Self {
+        let Ok(contents) = fs::read_to_string(SETTINGS_PATH) else {
+            return Settings::default();
+        };
+
+        let mut settings: Settings = ron::from_str(&contents).unwrap_or_else(|err| {
+            warn!("Nie udało się odczytać {SETTINGS_PATH}: {err}");
+            Settings::default()
+        });
+        settings.validate();
+        settings
+    }
+
+    // Poprawia wartości spoza dozwolonego zakresu (np. ręcznie edytowany plik).
+    fn validate(&mut self) {
+        if !TICK_RATES.contains(&self.tick_rate) {
+            warn!(
+                "Nieobsługiwana częstotliwość logiki {} Hz, używam {} Hz",
+                self.tick_rate, TICK_RATES[0]
+            );
+            self.tick_rate = TICK_RATES[0];
+        }
+
+        let defaults = VolumeSettings::default();
+        let volume = &mut self.volume;
+        volume.master = clamp_volume(volume.master, defaults.master);
+        volume.music = clamp_volume(volume.music, defaults.music);
+        volume.sfx = clamp_volume(volume.sfx, defaults.sfx);
+
+        let window = &mut self.window;
+        let defaults = WindowSettings::default();
+        if !(valid_window_size(window.width) && valid_window_size(window.height)) {
+            warn!(
+                "Nieprawidłowy rozmiar okna {}x{}, używam {}x{}",
+                window.width, window.height, defaults.width, defaults.height
+            );
+            window.width = defaults.width;
+            window.height = defaults.height;
+        }
+    }
+
+    // Krok symulacji odpowiadający `tick_rate`.
+    pub fn tick_duration(&self) -> Duration {
+        Duration::from_nanos(1_000_000_000 / u64::from(self.tick_rate))
+    }
+
+    pub fn save(&self) {
+        let contents = match ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()) {
+            Ok(contents) => contents,
+            Err(err) => {
+                warn!("Nie udało się zserializować ustawień: {err}");
+                return;
+            }
+        };
+
+        if let Err(err) = fs::write(SETTINGS_PATH, contents) {
+            warn!("Nie udało się zapisać {SETTINGS_PATH}: {err}");
+        }
+    }
+}
+
+// Głośność spoza 0..=1 przycinamy; NaN zastępujemy domyślną.
+fn clamp_volume(volume: f32, default: f32) -> f32 {
+    if volume.is_nan() {
+        default
+    } else {
+        volume.clamp(0.0, 1.0)
+    }
+}
+
+fn valid_window_size(size: f32) -> bool {
+    size.is_finite() && size > 0.0
+}
+
+// Zapis ustawień na dysk przy każdej zmianie. Dodawany tylko w `main`,
+// żeby testy na bezgłowym App nie dotykały pliku.
+pub struct SettingsPersistencePlugin;
+
+impl Plugin for SettingsPersistencePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Last,
+            save_settings.run_if(resource_changed::<Settings>.and(not(resource_added::<Settings>))),
+        );
+    }
+}
+
+fn save_settings(settings: Res<Settings>) {
+    settings.save();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_fields_fall_back_to_defaults() {
+        let settings: Settings = ron::from_str("(muted: true, difficulty: Hard)").unwrap();
+
+        assert!(settings.muted);
+        assert_eq!(settings.difficulty, Difficulty::Hard);
+        assert_eq!(settings.keybinds.flap, KeyCode::Space);
+        assert_eq!(settings.window.width, 800.0);
+    }
+
+    #[test]
+    fn settings_round_trip() {
+        let mut settings = Settings::default();
+        settings.volume.music = 0.25;
+        settings.keybinds.flap = KeyCode::ArrowUp;
+
+        let text = ron::to_string(&settings).unwrap();
+        let loaded: Settings = ron::from_str(&text).unwrap();
+
+        assert_eq!(loaded.volume.music, 0.25);
+        assert_eq!(loaded.keybinds.flap, KeyCode::ArrowUp);
+    }
+
+    #[test]
+    fn invalid_tick_rate_falls_back_to_60() {
+        let mut settings: Settings = ron::from_str("(tick_rate: 100)").unwrap();
+        settings.validate();
+        assert_eq!(settings.tick_rate, 60);
+
+        let mut settings: Settings = ron::from_str("(tick_rate: 240)").unwrap();
+        settings.validate();
+        assert_eq!(settings.tick_rate, 240);
+    }
+
+    #[test]
+    fn out_of_range_volumes_and_window_size_are_fixed() {
+        let mut settings = Settings::default();
+        settings.volume.master = 3.0;
+        settings.volume.music = -0.5;
+        settings.volume.sfx = f32::NAN;
+        settings.window.width = f32::INFINITY;
+        settings.window.height = 0.0;
+        settings.validate();
+
+        assert_eq!(settings.volume.master, 1.0);
+        assert_eq!(settings.volume.music, 0.0);
+        assert_eq!(settings.volume.sfx, VolumeSettings::default().sfx);
+        assert_eq!(settings.window.width, 800.0);
+        assert_eq!(settings.window.height, 600.0);
+    }
+}
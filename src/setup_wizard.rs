@@ -0,0 +1,330 @@
+// Kreator pierwszego uruchomienia: zanim pokażemy menu, kilka stron z
+// najważniejszymi ustawieniami (głośność, sterowanie, dostępność). Pojawia się
+// tylko wtedy, gdy na dysku nie ma jeszcze `settings.ron`; zakończenie lub
+// pominięcie kreatora zapisuje ustawienia, więc przy kolejnym starcie go nie ma.
+
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+use crate::controls::ControlScheme;
+use crate::settings::Settings;
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+// Krok suwaka głośności.
+const VOLUME_STEP: f32 = 0.1;
+
+// Wstawiany w `run()`, gdy nie ma pliku ustawień.
+#[derive(Resource)]
+pub struct FirstLaunch;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum WizardPage {
+    #[default]
+    Sound,
+    Controls,
+    Accessibility,
+}
+
+impl WizardPage {
+    const ALL: [WizardPage; 3] = [
+        WizardPage::Sound,
+        WizardPage::Controls,
+        WizardPage::Accessibility,
+    ];
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|page| *page == self).unwrap_or(0)
+    }
+
+    fn next(self) -> Option<Self> {
+        Self::ALL.get(self.index() + 1).copied()
+    }
+
+    fn previous(self) -> Option<Self> {
+        self.index().checked_sub(1).map(|index| Self::ALL[index])
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            WizardPage::Sound => "Sound",
+            WizardPage::Controls => "Controls",
+            WizardPage::Accessibility => "Accessibility",
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+struct Wizard {
+    page: WizardPage,
+}
+
+#[derive(Component)]
+#[require(StateScoped<AppState>(|| StateScoped(AppState::Setup)))]
+struct WizardUi;
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+enum WizardButton {
+    VolumeDown,
+    VolumeUp,
+    Mute,
+    Controls,
+    ReducedMotion,
+    Leniency,
+    Back,
+    Skip,
+    Next,
+}
+
+pub struct SetupWizardPlugin;
+
+impl Plugin for SetupWizardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Wizard>()
+            .add_systems(OnEnter(AppState::Setup), spawn_wizard_page)
+            .add_systems(
+                Update,
+                (
+                    wizard_button_system,
+                    spawn_wizard_page
+                        .run_if(resource_changed::<Wizard>.or(resource_changed::<Settings>)),
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Setup)),
+            );
+    }
+}
+
+fn on_off(value: bool) -> &'static str {
+    if value { "On" } else { "Off" }
+}
+
+fn controls_description(scheme: ControlScheme) -> &'static str {
+    match scheme {
+        ControlScheme::Classic => "Every press is a single flap.",
+        ControlScheme::Glide => "Hold the button to fall more slowly.",
+        ControlScheme::Charge => "Hold and release: a longer charge flaps harder.",
+        ControlScheme::Dash => "Classic flaps plus a dash forward on Shift.",
+    }
+}
+
+// Strona jest budowana od nowa przy każdej zmianie strony albo ustawień.
+fn spawn_wizard_page(
+    mut commands: Commands,
+    wizard: Res<Wizard>,
+    settings: Res<Settings>,
+    ui: Query<Entity, With<WizardUi>>,
+) {
+    for entity in ui.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let page = wizard.page;
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            FocusPolicy::Block,
+            WizardUi,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!(
+                    "Step {} of {}",
+                    page.index() + 1,
+                    WizardPage::ALL.len()
+                )),
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+            parent.spawn((
+                Text::new(page.title()),
+                TextColor(Color::WHITE),
+                TextFont {
+                    font_size: 44.0,
+                    ..Default::default()
+                },
+            ));
+
+            match page {
+                WizardPage::Sound => {
+                    parent
+                        .spawn(Node {
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(12.0),
+                            ..Default::default()
+                        })
+                        .with_children(|row| {
+                            spawn_button(row, "-", 60.0, WizardButton::VolumeDown);
+                            row.spawn((
+                                Text::new(format!(
+                                    "Volume: {:.0}%",
+                                    settings.volume.master * 100.0
+                                )),
+                                TextColor(Color::WHITE),
+                            ));
+                            spawn_button(row, "+", 60.0, WizardButton::VolumeUp);
+                        });
+                    spawn_button(
+                        parent,
+                        &format!("Sound: {}", on_off(!settings.muted)),
+                        260.0,
+                        WizardButton::Mute,
+                    );
+                }
+                WizardPage::Controls => {
+                    spawn_button(
+                        parent,
+                        &crate::controls::controls_button_label(&settings),
+                        260.0,
+                        WizardButton::Controls,
+                    );
+                    parent.spawn((
+                        Text::new(controls_description(settings.controls)),
+                        TextColor(Color::WHITE),
+                    ));
+                }
+                WizardPage::Accessibility => {
+                    spawn_button(
+                        parent,
+                        &format!(
+                            "Reduced motion: {}",
+                            on_off(settings.accessibility.reduced_motion)
+                        ),
+                        260.0,
+                        WizardButton::ReducedMotion,
+                    );
+                    spawn_button(
+                        parent,
+                        &format!("Forgiving hits: {}", on_off(settings.assists.leniency)),
+                        260.0,
+                        WizardButton::Leniency,
+                    );
+                }
+            }
+
+            parent
+                .spawn(Node {
+                    column_gap: Val::Px(12.0),
+                    margin: UiRect::top(Val::Px(20.0)),
+                    ..Default::default()
+                })
+                .with_children(|row| {
+                    if page.previous().is_some() {
+                        spawn_button(row, "Back", 120.0, WizardButton::Back);
+                    }
+                    spawn_button(row, "Skip", 120.0, WizardButton::Skip);
+                    let next = if page.next().is_some() {
+                        "Next"
+                    } else {
+                        "Finish"
+                    };
+                    spawn_button(row, next, 120.0, WizardButton::Next);
+                });
+        });
+}
+
+fn spawn_button(parent: &mut ChildBuilder, label: &str, width: f32, action: WizardButton) {
+    parent
+        .spawn((
+            Button,
+            Interaction::default(),
+            Node {
+                width: Val::Px(width),
+                height: Val::Px(50.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(NORMAL_BUTTON),
+            BorderRadius::all(Val::Px(8.0)),
+            action,
+        ))
+        .with_child((Text::new(label), TextColor(Color::WHITE)));
+}
+
+fn wizard_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &WizardButton),
+        Changed<Interaction>,
+    >,
+    mut wizard: ResMut<Wizard>,
+    mut settings: ResMut<Settings>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, mut bg_color, button) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                press(*button, &mut wizard, &mut settings, &mut next_state);
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+fn press(
+    button: WizardButton,
+    wizard: &mut Wizard,
+    settings: &mut ResMut<Settings>,
+    next_state: &mut NextState<AppState>,
+) {
+    match button {
+        WizardButton::VolumeDown => {
+            settings.volume.master = (settings.volume.master - VOLUME_STEP).max(0.0);
+        }
+        WizardButton::VolumeUp => {
+            settings.volume.master = (settings.volume.master + VOLUME_STEP).min(1.0);
+        }
+        WizardButton::Mute => settings.muted = !settings.muted,
+        WizardButton::Controls => settings.controls = settings.controls.next(),
+        WizardButton::ReducedMotion => {
+            settings.accessibility.reduced_motion = !settings.accessibility.reduced_motion;
+        }
+        WizardButton::Leniency => settings.assists.leniency = !settings.assists.leniency,
+        WizardButton::Back => {
+            if let Some(page) = wizard.page.previous() {
+                wizard.page = page;
+            }
+        }
+        WizardButton::Next => match wizard.page.next() {
+            Some(page) => wizard.page = page,
+            None => finish(settings, next_state),
+        },
+        WizardButton::Skip => finish(settings, next_state),
+    }
+}
+
+// Plik ustawień zapisuje `SettingsPersistencePlugin` przy zmianie zasobu –
+// oznaczamy go jako zmieniony także wtedy, gdy gracz zostawił wartości domyślne.
+fn finish(settings: &mut ResMut<Settings>, next_state: &mut NextState<AppState>) {
+    settings.set_changed();
+    next_state.set(AppState::Menu);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pages_go_forward_and_back_in_order() {
+        assert_eq!(WizardPage::Sound.previous(), None);
+        assert_eq!(WizardPage::Sound.next(), Some(WizardPage::Controls));
+        assert_eq!(
+            WizardPage::Accessibility.previous(),
+            Some(WizardPage::Controls)
+        );
+        assert_eq!(WizardPage::Accessibility.next(), None);
+    }
+}
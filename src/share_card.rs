@@ -0,0 +1,377 @@
+// Karta do udostępnienia wyniku: przycisk "Share" na ekranie końca gry
+// rysuje poza ekranem (osobna kamera renderująca do tekstury, na własnej
+// warstwie) kartę z wynikiem, medalem, wyglądem ptaka i ziarnem albo linkiem
+// do wyzwania, zapisuje ją jako PNG w `shares/` i kopiuje do schowka.
+// Tylko gra z oknem dodaje ten plugin – bez renderera nie ma czego zrzucić.
+
+use std::path::{Path, PathBuf};
+
+use bevy::asset::RenderAssetUsages;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages};
+use bevy::render::view::RenderLayers;
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured, save_to_disk};
+
+use crate::assets::GameAssets;
+use crate::bests::{RunMode, RunRecord};
+use crate::game_over::GameOverUI;
+use crate::rng::RunRng;
+use crate::score::Score;
+use crate::seed_display::seed_label;
+use crate::settings::Settings;
+use crate::theme::ActiveTheme;
+use crate::toast::Toast;
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+const SHARE_DIR: &str = "shares";
+// Proporcje podglądu linku w serwisach społecznościowych.
+const CARD_SIZE: UVec2 = UVec2::new(600, 315);
+// Warstwa, którą widzi tylko kamera karty.
+const CARD_LAYER: usize = 7;
+// Tyle klatek czekamy, aż tekst karty się ułoży, zanim zrobimy zrzut.
+const SETTLE_FRAMES: u32 = 2;
+const TITLE_COLOR: Color = Color::srgb(1.0, 0.85, 0.25);
+const GOLD: Color = Color::srgb(1.0, 0.84, 0.2);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Medal {
+    Bronze,
+    Silver,
+    Gold,
+    Platinum,
+}
+
+impl Medal {
+    // Progi jak w oryginalnym Flappy Bird.
+    pub fn for_score(score: i32) -> Option<Medal> {
+        match score {
+            40.. => Some(Medal::Platinum),
+            30.. => Some(Medal::Gold),
+            20.. => Some(Medal::Silver),
+            10.. => Some(Medal::Bronze),
+            _ => None,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Medal::Bronze => "Bronze medal",
+            Medal::Silver => "Silver medal",
+            Medal::Gold => "Gold medal",
+            Medal::Platinum => "Platinum medal",
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            Medal::Bronze => Color::srgb(0.8, 0.5, 0.2),
+            Medal::Silver => Color::srgb(0.75, 0.75, 0.8),
+            Medal::Gold => GOLD,
+            Medal::Platinum => Color::srgb(0.85, 0.95, 1.0),
+        }
+    }
+}
+
+#[derive(Component)]
+struct ShareButton;
+
+// Kamera i zawartość karty; sprzątane po zrzucie.
+#[derive(Component)]
+struct ShareCard;
+
+// Karta czeka na zrzut.
+#[derive(Resource)]
+struct PendingShareCard {
+    image: Handle<Image>,
+    path: PathBuf,
+    frames_left: u32,
+}
+
+pub struct ShareCardPlugin;
+
+impl Plugin for ShareCardPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(AppState::GameOver),
+            spawn_share_button.after(crate::game_over::on_enter_game_over),
+        )
+        .add_systems(
+            Update,
+            (
+                share_button_system.run_if(in_state(AppState::GameOver)),
+                capture_share_card.run_if(resource_exists::<PendingShareCard>),
+            ),
+        );
+    }
+}
+
+fn spawn_share_button(mut commands: Commands) {
+    commands
+        .spawn((
+            Button,
+            Interaction::default(),
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(20.0),
+                bottom: Val::Px(20.0),
+                width: Val::Px(120.0),
+                height: Val::Px(50.0),
+                border: UiRect::all(Val::Px(3.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(NORMAL_BUTTON),
+            BorderColor(Color::BLACK),
+            BorderRadius::MAX,
+            ShareButton,
+            GameOverUI,
+        ))
+        .with_child((Text::new("Share"), TextColor(Color::WHITE)));
+}
+
+fn share_button_system(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<ShareButton>),
+    >,
+    pending: Option<Res<PendingShareCard>>,
+    mut images: ResMut<Assets<Image>>,
+    assets: Res<GameAssets>,
+    card: CardContents,
+) {
+    for (interaction, mut bg_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                if pending.is_some() {
+                    continue;
+                }
+                let image = images.add(card_target_image());
+                spawn_card(&mut commands, image.clone(), &assets, &card);
+                commands.insert_resource(PendingShareCard {
+                    image,
+                    path: Path::new(SHARE_DIR).join(format!(
+                        "flappy-{}-{}.png",
+                        card.score.0,
+                        seed_label(card.rng.seed())
+                    )),
+                    frames_left: SETTLE_FRAMES,
+                });
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+// Wszystko, co trafia na kartę.
+#[derive(SystemParam)]
+struct CardContents<'w> {
+    score: Res<'w, Score>,
+    record: Res<'w, RunRecord>,
+    rng: Res<'w, RunRng>,
+    mode: Res<'w, RunMode>,
+    theme: Res<'w, ActiveTheme>,
+    settings: Res<'w, Settings>,
+}
+
+// Tekstura, do której renderuje kamera karty i z której robimy zrzut.
+fn card_target_image() -> Image {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: CARD_SIZE.x,
+            height: CARD_SIZE.y,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 255],
+        TextureFormat::Bgra8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage = TextureUsages::TEXTURE_BINDING
+        | TextureUsages::COPY_DST
+        | TextureUsages::COPY_SRC
+        | TextureUsages::RENDER_ATTACHMENT;
+    image
+}
+
+fn spawn_card(
+    commands: &mut Commands,
+    image: Handle<Image>,
+    assets: &GameAssets,
+    card: &CardContents,
+) {
+    let layer = RenderLayers::layer(CARD_LAYER);
+    let size = CARD_SIZE.as_vec2();
+    let text = |value: String, font_size: f32, color: Color, y: f32| {
+        (
+            Text2d::new(value),
+            TextFont {
+                font_size,
+                ..Default::default()
+            },
+            TextColor(color),
+            Transform::from_xyz(60.0, y, 2.0),
+            layer.clone(),
+            ShareCard,
+        )
+    };
+
+    commands.spawn((
+        Camera2d,
+        Camera {
+            target: RenderTarget::Image(image),
+            order: -1,
+            ..Default::default()
+        },
+        layer.clone(),
+        ShareCard,
+    ));
+    commands.spawn((
+        Sprite {
+            image: assets.theme(&card.theme.0).background.clone(),
+            custom_size: Some(size),
+            ..Default::default()
+        },
+        layer.clone(),
+        ShareCard,
+    ));
+    commands.spawn((
+        Sprite::from_color(Color::srgba(0.0, 0.0, 0.0, 0.55), size - Vec2::splat(40.0)),
+        Transform::from_xyz(0.0, 0.0, 1.0),
+        layer.clone(),
+        ShareCard,
+    ));
+
+    let golden = card.settings.cosmetics.golden_bird;
+    commands.spawn((
+        Sprite {
+            image: assets.bird.clone(),
+            color: if golden { GOLD } else { Color::WHITE },
+            ..Default::default()
+        },
+        Transform::from_xyz(-180.0, 10.0, 2.0).with_scale(Vec3::splat(3.0)),
+        layer.clone(),
+        ShareCard,
+    ));
+    let skin = if golden {
+        "Golden bird"
+    } else {
+        "Classic bird"
+    };
+    commands.spawn((
+        Text2d::new(skin),
+        TextFont {
+            font_size: 18.0,
+            ..Default::default()
+        },
+        TextColor(Color::WHITE),
+        Transform::from_xyz(-180.0, -60.0, 2.0),
+        layer.clone(),
+        ShareCard,
+    ));
+
+    commands.spawn(text("Flappy Bird".to_string(), 36.0, TITLE_COLOR, 105.0));
+    commands.spawn(text(
+        format!("Score: {}", card.score.0),
+        52.0,
+        Color::WHITE,
+        45.0,
+    ));
+    commands.spawn(text(
+        format!("Best: {}", card.record.best),
+        24.0,
+        Color::WHITE,
+        -5.0,
+    ));
+    if let Some(medal) = Medal::for_score(card.score.0) {
+        commands.spawn(text(medal.label().to_string(), 26.0, medal.color(), -40.0));
+    }
+    // Link do wyzwania tam, gdzie gra ma adres; w przeciwnym razie samo ziarno.
+    let seed = crate::challenge::challenge_link(card.rng.seed(), card.mode.0)
+        .unwrap_or_else(|| format!("Seed: {}", seed_label(card.rng.seed())));
+    commands.spawn((
+        Text2d::new(seed),
+        TextFont {
+            font_size: 16.0,
+            ..Default::default()
+        },
+        TextColor(Color::srgb(0.8, 0.8, 0.8)),
+        Transform::from_xyz(0.0, -115.0, 2.0),
+        layer,
+        ShareCard,
+    ));
+}
+
+fn capture_share_card(mut commands: Commands, mut pending: ResMut<PendingShareCard>) {
+    if pending.frames_left > 0 {
+        pending.frames_left -= 1;
+        return;
+    }
+    if let Err(err) = std::fs::create_dir_all(SHARE_DIR) {
+        warn!("Nie udało się utworzyć {SHARE_DIR}: {err}");
+    }
+    commands
+        .spawn(Screenshot::image(pending.image.clone()))
+        .observe(save_to_disk(pending.path.clone()))
+        .observe(finish_share_card);
+}
+
+fn finish_share_card(
+    trigger: Trigger<ScreenshotCaptured>,
+    mut commands: Commands,
+    pending: Option<Res<PendingShareCard>>,
+    cards: Query<Entity, With<ShareCard>>,
+    mut toasts: EventWriter<Toast>,
+) {
+    for entity in cards.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.remove_resource::<PendingShareCard>();
+
+    let path = pending.map_or_else(PathBuf::new, |pending| pending.path.clone());
+    let copied = trigger
+        .event()
+        .0
+        .clone()
+        .try_into_dynamic()
+        .map_err(|err| err.to_string())
+        .and_then(|image| {
+            let image = image.to_rgba8();
+            crate::clipboard::copy_image(
+                image.width() as usize,
+                image.height() as usize,
+                image.as_raw(),
+            )
+        });
+    let message = match copied {
+        Ok(()) => format!("Share card copied and saved to {}", path.display()),
+        Err(err) => {
+            warn!("Nie udało się skopiować karty do schowka: {err}");
+            format!("Share card saved to {}", path.display())
+        }
+    };
+    toasts.send(Toast(message));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn medals_follow_the_classic_thresholds() {
+        assert_eq!(Medal::for_score(9), None);
+        assert_eq!(Medal::for_score(10), Some(Medal::Bronze));
+        assert_eq!(Medal::for_score(29), Some(Medal::Silver));
+        assert_eq!(Medal::for_score(30), Some(Medal::Gold));
+        assert_eq!(Medal::for_score(120), Some(Medal::Platinum));
+    }
+}
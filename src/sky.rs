@@ -0,0 +1,157 @@
+// Motyw "Sky": zamiast obrazka tło rysuje shader (`assets/shaders/sky.wgsl`) –
+// gradient nieba, dryfujące chmury i słońce albo księżyc. Pora dnia idzie za
+// cyklem biomów: trzy dzienne biomy to wędrówka słońca, nocny – księżyca.
+//
+// Tylko gra z oknem dodaje ten plugin; bez renderera tło motywu zostaje
+// zwykłym obrazkiem dziennym.
+
+use bevy::prelude::*;
+use bevy::render::render_resource::{AsBindGroup, ShaderRef};
+use bevy::sprite::{Material2d, Material2dPlugin};
+
+use crate::biome::PIPES_PER_BIOME;
+use crate::display::PlayArea;
+use crate::pause::PauseState;
+use crate::score::Score;
+use crate::settings::Settings;
+use crate::theme::{ActiveTheme, Theme};
+use crate::{AppState, Background};
+
+const SKY_SHADER: &str = "shaders/sky.wgsl";
+
+// Pełny cykl dnia i nocy to cztery biomy; noc zajmuje ostatnią ćwiartkę.
+const PIPES_PER_DAY: i32 = PIPES_PER_BIOME * 4;
+// Koniec dnia w cyklu (jak `DAY_END` w shaderze).
+const DAY_END: f32 = 0.75;
+// Pora dnia na starcie rundy – przedpołudnie zamiast ciemnego świtu.
+const MORNING: f32 = 0.1;
+// Jak szybko niebo dogania porę dnia wynikającą z wyniku (na sekundę).
+const CATCH_UP_RATE: f32 = 0.5;
+// Prędkość dryfu chmur w jednostkach szumu na sekundę.
+const CLOUD_SPEED: f32 = 0.05;
+
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+pub struct SkyMaterial {
+    #[uniform(0)]
+    sky: SkyUniform,
+}
+
+// `ShaderType` dokłada do każdego pola funkcję sprawdzającą typ, której nic
+// nie woła – osobny moduł zawęża wyłączenie `dead_code` do samego uniformu.
+#[allow(dead_code)]
+mod uniform {
+    use bevy::render::render_resource::ShaderType;
+
+    #[derive(ShaderType, Debug, Clone, Copy)]
+    pub(super) struct SkyUniform {
+        pub(super) time_of_day: f32,
+        pub(super) cloud_offset: f32,
+        pub(super) aspect: f32,
+    }
+}
+
+use uniform::SkyUniform;
+
+impl Material2d for SkyMaterial {
+    fn fragment_shader() -> ShaderRef {
+        SKY_SHADER.into()
+    }
+}
+
+#[derive(Component)]
+struct AnimatedSky(Handle<SkyMaterial>);
+
+// Pora dnia (0..1) przy danym wyniku, z tym samym cyklem co biomy.
+fn time_of_day(score: i32) -> f32 {
+    let progress = score.max(0).rem_euclid(PIPES_PER_DAY) as f32 / PIPES_PER_DAY as f32;
+    // Pierwszy biom zaczyna się przed południem, reszta dnia ściska się do zachodu.
+    if progress < DAY_END {
+        MORNING + progress / DAY_END * (DAY_END - MORNING)
+    } else {
+        progress
+    }
+}
+
+pub struct SkyPlugin;
+
+impl Plugin for SkyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(Material2dPlugin::<SkyMaterial>::default())
+            .add_systems(
+                OnEnter(AppState::Playing),
+                paint_sky.after(crate::restart_game),
+            )
+            .add_systems(Update, animate_sky.run_if(in_state(PauseState::Running)));
+    }
+}
+
+// Podmienia obrazek tła rundy na siatkę z materiałem nieba.
+fn paint_sky(
+    mut commands: Commands,
+    theme: Res<ActiveTheme>,
+    play_area: Res<PlayArea>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<SkyMaterial>>,
+    backgrounds: Query<Entity, With<Background>>,
+    skies: Query<Entity, With<AnimatedSky>>,
+) {
+    if theme.0 != Theme::Sky {
+        // Tło przeżywa restart: `restart_game` dało mu już obrazek nowego
+        // motywu, zostaje zdjąć niebo z poprzedniej rundy.
+        for entity in skies.iter() {
+            commands
+                .entity(entity)
+                .remove::<(Mesh2d, MeshMaterial2d<SkyMaterial>, AnimatedSky)>();
+        }
+        return;
+    }
+    let material = materials.add(SkyMaterial {
+        sky: SkyUniform {
+            time_of_day: time_of_day(0),
+            cloud_offset: 0.0,
+            aspect: play_area.size.x / play_area.size.y,
+        },
+    });
+    for entity in backgrounds.iter() {
+        commands.entity(entity).remove::<Sprite>().insert((
+            Mesh2d(meshes.add(Rectangle::from_size(play_area.size))),
+            MeshMaterial2d(material.clone()),
+            AnimatedSky(material.clone()),
+        ));
+    }
+}
+
+fn animate_sky(
+    time: Res<Time>,
+    score: Res<Score>,
+    settings: Res<Settings>,
+    skies: Query<&AnimatedSky>,
+    mut materials: ResMut<Assets<SkyMaterial>>,
+) {
+    for sky in skies.iter() {
+        let Some(material) = materials.get_mut(&sky.0) else {
+            continue;
+        };
+        let uniform = &mut material.sky;
+        // Zawsze do przodu: po nocy niebo przechodzi przez północ w nowy dzień.
+        let behind = (time_of_day(score.0) - uniform.time_of_day).rem_euclid(1.0);
+        let step = (CATCH_UP_RATE * time.delta_secs()).min(behind);
+        uniform.time_of_day = (uniform.time_of_day + step).fract();
+        if !settings.accessibility.reduced_motion {
+            uniform.cloud_offset += CLOUD_SPEED * time.delta_secs();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn night_starts_with_the_night_biome() {
+        assert_eq!(time_of_day(0), MORNING);
+        assert!(time_of_day(PIPES_PER_BIOME * 3 - 1) < DAY_END);
+        assert_eq!(time_of_day(PIPES_PER_BIOME * 3), DAY_END);
+        assert_eq!(time_of_day(PIPES_PER_DAY), MORNING);
+    }
+}
@@ -0,0 +1,392 @@
+// Oglądanie rundy znajomego na żywo. Grający wysyła na bieżąco to, co i tak
+// trafia do powtórki: nagłówek rundy (ziarno i ustawienia fizyki) oraz numery
+// kroków z machnięciami. Widz odtwarza z tego tę samą rundę własną,
+// deterministyczną symulacją – jak weryfikacja powtórek, tylko krok po kroku,
+// w miarę napływu danych.
+//
+// Widz trzyma kilka sekund zapasu: symulacja rusza dopiero z `DELAY_SECS`
+// buforu, a gdy zapas prawie się skończy, zatrzymujemy czas gry i czekamy na
+// kolejne kroki, zamiast zgadywać wejście. Moduł opisuje wiadomości i obie
+// strony strumienia; transport (jak w `lobby`) jedynie je przenosi.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::ai_race::AiRaceSettings;
+use crate::input::FlapBuffer;
+use crate::pause::PauseState;
+use crate::replay::{Replay, ReplayRecorder};
+use crate::rng::SeedChoice;
+use crate::settings::Settings;
+use crate::text_style::OutlinedText;
+use crate::{AppState, RunEntity};
+
+// Opóźnienie oglądania i zapas, poniżej którego wstrzymujemy odtwarzanie.
+const DELAY_SECS: u32 = 3;
+const MIN_BUFFER_DIVISOR: u32 = 4;
+// Co ile kroków grający wysyła nowe machnięcia.
+const STREAM_BATCH_FRAMES: u32 = 6;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WatchMessage {
+    // Powtórka bez kroków – sam nagłówek rundy.
+    Start { replay: Vec<u8> },
+    // Wejście znane dla kroków poniżej `upto`: nowe machnięcia od poprzedniej
+    // wiadomości.
+    Frames { upto: u32, flaps: Vec<u32> },
+    // Runda skończyła się po `frames` krokach.
+    End { frames: u32 },
+}
+
+// Obecny po stronie grającego, gdy ktoś go ogląda; transport opróżnia `outbox`.
+#[derive(Resource, Default)]
+pub struct WatchStream {
+    outbox: Vec<WatchMessage>,
+    // Ile machnięć bieżącej rundy już wysłaliśmy.
+    sent_flaps: usize,
+}
+
+impl WatchStream {
+    // Transport jeszcze nie istnieje – na razie opróżniają go tylko testy.
+    #[cfg(test)]
+    pub fn drain(&mut self) -> Vec<WatchMessage> {
+        std::mem::take(&mut self.outbox)
+    }
+
+    fn send_frames(&mut self, replay: &Replay) {
+        let flaps = replay.flaps[self.sent_flaps..].to_vec();
+        self.sent_flaps = replay.flaps.len();
+        self.outbox.push(WatchMessage::Frames {
+            upto: replay.frames,
+            flaps,
+        });
+    }
+}
+
+// Obecny po stronie widza przez cały czas oglądania.
+#[derive(Resource)]
+pub struct Spectating {
+    header: Replay,
+    flaps: Vec<u32>,
+    next: usize,
+    // Liczba kroków, dla których znamy już wejście.
+    known: u32,
+    ended: bool,
+    // Bieżący krok odtwarzanej rundy.
+    frame: u32,
+    buffering: bool,
+    // Ustawienia widza sprzed oglądania, przywracane po powrocie do menu.
+    previous: Option<(Settings, Option<u64>)>,
+}
+
+impl Spectating {
+    // Budowanie i zasilanie widza należy do transportu; dopóki go nie ma,
+    // korzystają z tego tylko testy.
+    #[cfg(test)]
+    pub fn new(start: &[u8]) -> Result<Self, crate::replay::ReplayError> {
+        Ok(Self {
+            header: Replay::decode(start)?,
+            flaps: Vec::new(),
+            next: 0,
+            known: 0,
+            ended: false,
+            frame: 0,
+            buffering: true,
+            previous: None,
+        })
+    }
+
+    #[cfg(test)]
+    pub fn receive(&mut self, message: &WatchMessage) {
+        match message {
+            // Nowa runda grającego – oglądamy ją od początku.
+            WatchMessage::Start { replay } => {
+                if let Ok(next) = Self::new(replay) {
+                    *self = Self {
+                        previous: self.previous.take(),
+                        ..next
+                    };
+                }
+            }
+            WatchMessage::Frames { upto, flaps } => {
+                self.flaps.extend(flaps.iter().copied());
+                self.known = self.known.max(*upto);
+            }
+            WatchMessage::End { frames } => {
+                self.known = self.known.max(*frames);
+                self.ended = true;
+            }
+        }
+    }
+
+    fn delay_frames(&self) -> u32 {
+        DELAY_SECS * self.header.tick_rate
+    }
+
+    // Zapas kroków, przy którym czas gry się zatrzymuje.
+    fn min_buffer_frames(&self) -> u32 {
+        self.header.tick_rate / MIN_BUFFER_DIVISOR
+    }
+
+    fn ahead(&self) -> u32 {
+        self.known.saturating_sub(self.frame)
+    }
+
+    // Czy teraz trzeba czekać na dane (`Some(true)`), można ruszyć
+    // (`Some(false)`), czy nic się nie zmienia (`None`).
+    fn regulate(&mut self) -> Option<bool> {
+        if self.buffering && (self.ended || self.ahead() >= self.delay_frames()) {
+            self.buffering = false;
+            Some(false)
+        } else if !self.buffering && !self.ended && self.ahead() < self.min_buffer_frames() {
+            self.buffering = true;
+            Some(true)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Component)]
+#[require(RunEntity)]
+struct SpectateLabel;
+
+pub struct SpectatePlugin;
+
+impl Plugin for SpectatePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(AppState::Playing),
+            (
+                start_spectating
+                    .before(crate::rng::reseed_run_rng)
+                    .before(crate::controls::lock_run_controls)
+                    .run_if(resource_exists::<Spectating>),
+                spawn_spectate_label
+                    .after(crate::restart_game)
+                    .run_if(resource_exists::<Spectating>),
+                stream_start
+                    .after(crate::replay::start_recording)
+                    .run_if(resource_exists::<WatchStream>),
+            ),
+        )
+        .add_systems(
+            FixedUpdate,
+            (
+                (
+                    feed_spectated_flaps.before(crate::player::player_input_system),
+                    count_spectated_frame.after(crate::player::player_input_system),
+                )
+                    .run_if(resource_exists::<Spectating>),
+                stream_frames
+                    .after(crate::replay::record_frame)
+                    .run_if(resource_exists::<WatchStream>),
+            )
+                .run_if(in_state(PauseState::Running)),
+        )
+        .add_systems(
+            Update,
+            regulate_spectating
+                .run_if(in_state(AppState::Playing).and(resource_exists::<Spectating>)),
+        )
+        .add_systems(
+            OnEnter(AppState::GameOver),
+            stream_end
+                .before(crate::replay::finish_recording)
+                .run_if(resource_exists::<WatchStream>),
+        )
+        .add_systems(
+            OnExit(AppState::Playing),
+            resume_time.run_if(resource_exists::<Spectating>),
+        )
+        .add_systems(OnEnter(AppState::Menu), stop_spectating);
+    }
+}
+
+// Widz gra z ustawieniami fizyki grającego, bez rywali i handicapu – inaczej
+// runda by się rozjechała. Kolejna runda widza (np. automatyczny restart)
+// odtwarza oglądaną od początku.
+fn start_spectating(
+    mut spectating: ResMut<Spectating>,
+    mut settings: ResMut<Settings>,
+    mut seed_choice: ResMut<SeedChoice>,
+    mut fixed: ResMut<Time<Fixed>>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    if spectating.previous.is_none() {
+        spectating.previous = Some((settings.clone(), seed_choice.0));
+    }
+    let header = &spectating.header;
+    settings.difficulty = header.difficulty;
+    settings.controls = header.controls;
+    settings.tick_rate = header.tick_rate;
+    settings.analog_flap = false;
+    settings.accessibility.reduced_motion = header.reduced_motion;
+    settings.assists.leniency = header.leniency;
+    settings.ai = AiRaceSettings::default();
+    seed_choice.0 = Some(header.seed);
+    fixed.set_timestep(settings.tick_duration());
+
+    spectating.frame = 0;
+    spectating.next = 0;
+    spectating.buffering = true;
+    time.pause();
+}
+
+fn spawn_spectate_label(mut commands: Commands) {
+    commands.spawn((
+        Text::new("Buffering..."),
+        OutlinedText::new(Color::WHITE),
+        TextFont {
+            font_size: 20.0,
+            ..Default::default()
+        },
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(12.0),
+            bottom: Val::Px(12.0),
+            ..Default::default()
+        },
+        SpectateLabel,
+    ));
+}
+
+// Machnięcia grającego zamiast własnego wejścia widza, które odrzucamy.
+fn feed_spectated_flaps(
+    time: Res<Time<Real>>,
+    mut spectating: ResMut<Spectating>,
+    mut buffer: ResMut<FlapBuffer>,
+) {
+    let now = time.elapsed_secs_f64();
+    buffer.take(now);
+    if spectating.flaps.get(spectating.next) == Some(&spectating.frame) {
+        spectating.next += 1;
+        buffer.press(now);
+    }
+}
+
+fn count_spectated_frame(mut spectating: ResMut<Spectating>) {
+    spectating.frame += 1;
+}
+
+fn regulate_spectating(
+    mut spectating: ResMut<Spectating>,
+    mut time: ResMut<Time<Virtual>>,
+    mut label: Query<&mut Text, With<SpectateLabel>>,
+) {
+    match spectating.regulate() {
+        Some(true) => time.pause(),
+        Some(false) => time.unpause(),
+        None => return,
+    }
+    let text = if spectating.buffering {
+        "Buffering..."
+    } else {
+        "Watching live"
+    };
+    for mut label in label.iter_mut() {
+        label.0 = text.to_string();
+    }
+}
+
+fn resume_time(mut time: ResMut<Time<Virtual>>) {
+    if time.is_paused() {
+        time.unpause();
+    }
+}
+
+fn stop_spectating(
+    mut commands: Commands,
+    spectating: Option<ResMut<Spectating>>,
+    mut settings: ResMut<Settings>,
+    mut seed_choice: ResMut<SeedChoice>,
+) {
+    let Some(mut spectating) = spectating else {
+        return;
+    };
+    if let Some((previous, seed)) = spectating.previous.take() {
+        *settings = previous;
+        seed_choice.0 = seed;
+    }
+    commands.remove_resource::<Spectating>();
+}
+
+// Runda, której powtórka nie powstaje (np. wznowiona), nie da się oglądać.
+fn stream_start(recorder: Res<ReplayRecorder>, mut stream: ResMut<WatchStream>) {
+    let Some(replay) = recorder.recording() else {
+        return;
+    };
+    let replay = match replay.encode() {
+        Ok(replay) => replay,
+        Err(err) => {
+            warn!("Nie udało się rozpocząć transmisji: {err}");
+            return;
+        }
+    };
+    stream.sent_flaps = 0;
+    stream.outbox.push(WatchMessage::Start { replay });
+}
+
+fn stream_frames(recorder: Res<ReplayRecorder>, mut stream: ResMut<WatchStream>) {
+    let Some(replay) = recorder.recording() else {
+        return;
+    };
+    if replay.frames % STREAM_BATCH_FRAMES == 0 {
+        stream.send_frames(replay);
+    }
+}
+
+fn stream_end(recorder: Res<ReplayRecorder>, mut stream: ResMut<WatchStream>) {
+    let Some(replay) = recorder.recording() else {
+        return;
+    };
+    stream.send_frames(replay);
+    stream.outbox.push(WatchMessage::End {
+        frames: replay.frames,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controls::ControlScheme;
+    use crate::settings::Difficulty;
+
+    fn spectating() -> Spectating {
+        let header = Replay {
+            seed: 1,
+            tick_rate: 60,
+            difficulty: Difficulty::default(),
+            controls: ControlScheme::Classic,
+            reduced_motion: false,
+            leniency: false,
+            score: 0,
+            frames: 0,
+            flaps: Vec::new(),
+        };
+        Spectating::new(&header.encode().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn playback_waits_for_the_delay_and_stalls_when_data_runs_out() {
+        let mut spectating = spectating();
+        spectating.receive(&WatchMessage::Frames {
+            upto: 60,
+            flaps: vec![10],
+        });
+        assert_eq!(spectating.regulate(), None);
+
+        spectating.receive(&WatchMessage::Frames {
+            upto: 180,
+            flaps: vec![100],
+        });
+        assert_eq!(spectating.regulate(), Some(false));
+
+        spectating.frame = 170;
+        assert_eq!(spectating.regulate(), Some(true));
+
+        spectating.receive(&WatchMessage::End { frames: 200 });
+        assert_eq!(spectating.regulate(), Some(false));
+        assert_eq!(spectating.flaps, [10, 100]);
+    }
+}
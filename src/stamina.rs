@@ -0,0 +1,283 @@
+// Wyzwanie "Stamina": ptak się męczy. Do `FREE_FLAPS` machnięć w ostatniej
+// sekundzie działa z pełną siłą, każde kolejne słabiej (aż do `MIN_SCALE`),
+// a siła wraca, gdy machnięcia wypadną z okna. Przycisk w kolumnie trybów menu
+// włącza wyzwanie dla kolejnych rund; pasek pod paskiem serii pokazuje siłę
+// następnego machnięcia.
+//
+// Powtórka nie zapisuje zmęczenia, więc rund ze staminą nie nagrywamy.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::buttons::button_text;
+use crate::menu::Menu;
+use crate::pause::PauseState;
+use crate::physics::Velocity;
+use crate::player::{Flapped, Player, player_input_system};
+use crate::text_style::OutlinedText;
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON, RunStarted};
+
+// Tyle machnięć na sekundę ptak wykonuje bez zmęczenia.
+const FREE_FLAPS: usize = 3;
+const WINDOW_SECS: f32 = 1.0;
+// Każde machnięcie ponad limit jest o tyle słabsze od poprzedniego.
+const FATIGUE: f32 = 0.7;
+const MIN_SCALE: f32 = 0.3;
+const BAR_WIDTH_PX: f32 = 140.0;
+const BAR_COLOR: Color = Color::srgb(0.45, 0.9, 0.45);
+const TIRED_COLOR: Color = Color::srgb(0.95, 0.35, 0.25);
+
+// Siła machnięcia, gdy w ostatniej sekundzie było już `recent` machnięć.
+pub fn stamina_scale(recent: usize) -> f32 {
+    if recent < FREE_FLAPS {
+        return 1.0;
+    }
+    let excess = (recent + 1 - FREE_FLAPS) as i32;
+    FATIGUE.powi(excess).max(MIN_SCALE)
+}
+
+// Obecny, gdy gracz włączył wyzwanie; pamięta czasy ostatnich machnięć
+// (w czasie symulacji).
+#[derive(Resource, Default)]
+pub struct StaminaRun {
+    clock: f32,
+    flaps: VecDeque<f32>,
+}
+
+impl StaminaRun {
+    // Siła następnego machnięcia (0..=1).
+    pub fn level(&self) -> f32 {
+        stamina_scale(self.flaps.len())
+    }
+
+    fn tick(&mut self, delta: f32) {
+        self.clock += delta;
+        while self
+            .flaps
+            .front()
+            .is_some_and(|&time| self.clock - time >= WINDOW_SECS)
+        {
+            self.flaps.pop_front();
+        }
+    }
+
+    // Zapisuje machnięcie i zwraca jego siłę.
+    fn flap(&mut self) -> f32 {
+        let scale = self.level();
+        self.flaps.push_back(self.clock);
+        scale
+    }
+}
+
+#[derive(Component)]
+struct StaminaButton;
+
+#[derive(Component)]
+#[require(StateScoped<AppState>(|| StateScoped(AppState::Playing)))]
+struct StaminaBar;
+
+#[derive(Component)]
+struct StaminaFill;
+
+pub struct StaminaPlugin;
+
+impl Plugin for StaminaPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(AppState::Menu),
+            spawn_stamina_button.after(crate::menu::setup_menu),
+        )
+        .add_systems(
+            Update,
+            stamina_button_system.run_if(in_state(AppState::Menu)),
+        )
+        .add_systems(
+            FixedUpdate,
+            tire_flaps
+                .after(player_input_system)
+                .before(crate::physics::gravity_system)
+                .run_if(in_state(PauseState::Running))
+                .run_if(resource_exists::<StaminaRun>),
+        )
+        .add_systems(
+            OnEnter(AppState::Playing),
+            (reset_stamina.after(crate::restart_game), spawn_stamina_bar)
+                .run_if(resource_exists::<StaminaRun>),
+        )
+        .add_systems(
+            Update,
+            update_stamina_bar
+                .run_if(in_state(AppState::Playing))
+                .run_if(resource_exists::<StaminaRun>),
+        );
+    }
+}
+
+fn reset_stamina(mut run_started: EventReader<RunStarted>, mut stamina: ResMut<StaminaRun>) {
+    if run_started.read().last().is_some() {
+        *stamina = StaminaRun::default();
+    }
+}
+
+// Machnięcie już ustawiło prędkość; zmęczony ptak dostaje tylko jej część.
+fn tire_flaps(
+    time: Res<Time>,
+    mut flapped: EventReader<Flapped>,
+    mut stamina: ResMut<StaminaRun>,
+    mut player: Query<&mut Velocity, With<Player>>,
+) {
+    stamina.tick(time.delta_secs());
+    for _ in flapped.read() {
+        let scale = stamina.flap();
+        for mut velocity in player.iter_mut() {
+            velocity.dy *= scale;
+        }
+    }
+}
+
+fn stamina_button_label(enabled: bool) -> String {
+    let state = if enabled { "On" } else { "Off" };
+    format!("Stamina: {state}")
+}
+
+fn spawn_stamina_button(mut commands: Commands, stamina: Option<Res<StaminaRun>>) {
+    commands
+        .spawn((
+            Button,
+            Interaction::default(),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(20.0),
+                top: Val::Px(220.0),
+                padding: UiRect::axes(Val::Px(16.0), Val::Px(10.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(NORMAL_BUTTON),
+            BorderRadius::all(Val::Px(8.0)),
+            StaminaButton,
+            Menu,
+        ))
+        .with_child((
+            Text::new(stamina_button_label(stamina.is_some())),
+            TextColor(Color::WHITE),
+        ));
+}
+
+fn stamina_button_system(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, Option<&Children>),
+        (Changed<Interaction>, With<StaminaButton>),
+    >,
+    mut text_query: Query<&mut Text>,
+    stamina: Option<Res<StaminaRun>>,
+) {
+    for (interaction, mut bg_color, children) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                let enabled = stamina.is_none();
+                if enabled {
+                    commands.init_resource::<StaminaRun>();
+                } else {
+                    commands.remove_resource::<StaminaRun>();
+                }
+                if let Some(mut text) = button_text(children, &mut text_query) {
+                    text.0 = stamina_button_label(enabled);
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+// Pod paskiem serii (`glide_streak.rs`) w lewym górnym rogu.
+fn spawn_stamina_bar(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(20.0),
+                top: Val::Px(64.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                ..Default::default()
+            },
+            StaminaBar,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Stamina"),
+                TextFont {
+                    font_size: 16.0,
+                    ..Default::default()
+                },
+                OutlinedText::new(Color::WHITE),
+            ));
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(BAR_WIDTH_PX),
+                        height: Val::Px(8.0),
+                        ..Default::default()
+                    },
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+                    BorderRadius::all(Val::Px(4.0)),
+                ))
+                .with_child((
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..Default::default()
+                    },
+                    BackgroundColor(BAR_COLOR),
+                    BorderRadius::all(Val::Px(4.0)),
+                    StaminaFill,
+                ));
+        });
+}
+
+fn update_stamina_bar(
+    stamina: Res<StaminaRun>,
+    mut fills: Query<(&mut Node, &mut BackgroundColor), With<StaminaFill>>,
+) {
+    let level = stamina.level();
+    for (mut node, mut color) in fills.iter_mut() {
+        node.width = Val::Percent(level * 100.0);
+        color.0 = TIRED_COLOR.mix(&BAR_COLOR, (level - MIN_SCALE) / (1.0 - MIN_SCALE));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flaps_over_the_limit_get_weaker_down_to_the_floor() {
+        assert_eq!(stamina_scale(0), 1.0);
+        assert_eq!(stamina_scale(FREE_FLAPS - 1), 1.0);
+        assert!(stamina_scale(FREE_FLAPS) < 1.0);
+        assert!(stamina_scale(FREE_FLAPS + 1) < stamina_scale(FREE_FLAPS));
+        assert_eq!(stamina_scale(100), MIN_SCALE);
+    }
+
+    #[test]
+    fn stamina_recovers_once_flaps_leave_the_window() {
+        let mut stamina = StaminaRun::default();
+        for _ in 0..6 {
+            stamina.flap();
+            stamina.tick(0.05);
+        }
+        assert!(stamina.level() < 1.0);
+        stamina.tick(WINDOW_SECS);
+        assert_eq!(stamina.level(), 1.0);
+    }
+}
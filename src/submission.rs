@@ -0,0 +1,271 @@
+// Zgłoszenia wyników do internetowej tablicy wyników.
+//
+// Zgłoszenie niesie powtórkę rundy, wersję klienta i podpis HMAC-SHA256.
+// Serwer odrzuca zgłoszenia z obcym podpisem, z innej wersji gry (symulacja
+// mogłaby dać inny wynik) oraz takie, których powtórka nie potwierdza wyniku.
+// Ostatnie zgłoszenie czeka w pliku `submission.ron` na wysłanie.
+
+use std::fmt;
+use std::fs;
+
+use bevy::prelude::*;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::AppState;
+use crate::bests::{GameMode, RunMode};
+use crate::practice::StudiedSeeds;
+use crate::replay::{LastReplay, Replay, ReplayError};
+use crate::replay_verify::verify;
+
+const SUBMISSION_PATH: &str = "submission.ron";
+
+// `flappy-bird --check-submission plik` wypisuje odpowiedź serwera dla zgłoszenia.
+const CHECK_FLAG: &str = "--check-submission";
+
+pub const CLIENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// Klucz wkompilowany w wydania; lokalne buildy podpisują kluczem deweloperskim,
+// którego serwer produkcyjny nie akceptuje.
+const SIGNING_KEY: &[u8] = match option_env!("FLAPPY_SUBMISSION_KEY") {
+    Some(key) => key.as_bytes(),
+    None => b"flappy-bird-dev",
+};
+
+type HmacSha256 = Hmac<Sha256>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreSubmission {
+    pub client_version: String,
+    pub mode: GameMode,
+    // Zakodowana powtórka (`Replay::encode`) z deklarowanym wynikiem w nagłówku.
+    pub replay: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SubmissionResponse {
+    Accepted { score: i32 },
+    Rejected(RejectReason),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RejectReason {
+    // Klient w innej wersji niż serwer – trzeba zaktualizować grę.
+    VersionMismatch { server: String },
+    BadSignature,
+    MalformedReplay(String),
+    // Powtórka nie potwierdza deklarowanego wyniku.
+    Unverifiable(String),
+}
+
+impl fmt::Display for RejectReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RejectReason::VersionMismatch { server } => {
+                write!(f, "serwer wymaga wersji {server}")
+            }
+            RejectReason::BadSignature => write!(f, "nieprawidłowy podpis"),
+            RejectReason::MalformedReplay(err) => write!(f, "uszkodzona powtórka: {err}"),
+            RejectReason::Unverifiable(err) => write!(f, "wynik niepotwierdzony: {err}"),
+        }
+    }
+}
+
+impl ScoreSubmission {
+    pub fn new(replay: &Replay, mode: GameMode) -> Result<Self, ReplayError> {
+        Self::signed(replay, mode, SIGNING_KEY)
+    }
+
+    fn signed(replay: &Replay, mode: GameMode, key: &[u8]) -> Result<Self, ReplayError> {
+        let mut submission = Self {
+            client_version: CLIENT_VERSION.to_string(),
+            mode,
+            replay: replay.encode()?,
+            signature: Vec::new(),
+        };
+        submission.signature = submission.mac(key).finalize().into_bytes().to_vec();
+        Ok(submission)
+    }
+
+    // Podpisujemy wszystkie pola poza samym podpisem; długość wersji jest
+    // częścią wiadomości, żeby nie dało się przesunąć granicy między polami.
+    fn mac(&self, key: &[u8]) -> HmacSha256 {
+        let mut mac =
+            HmacSha256::new_from_slice(key).expect("HMAC przyjmuje klucz dowolnej długości");
+        mac.update(&(self.client_version.len() as u32).to_le_bytes());
+        mac.update(self.client_version.as_bytes());
+        mac.update(self.mode.id().as_bytes());
+        mac.update(&self.replay);
+        mac
+    }
+
+    // Sprawdzenie po stronie serwera.
+    pub fn check(&self) -> SubmissionResponse {
+        match self.validate(SIGNING_KEY) {
+            Ok(score) => SubmissionResponse::Accepted { score },
+            Err(reason) => SubmissionResponse::Rejected(reason),
+        }
+    }
+
+    fn validate(&self, key: &[u8]) -> Result<i32, RejectReason> {
+        if self.mac(key).verify_slice(&self.signature).is_err() {
+            return Err(RejectReason::BadSignature);
+        }
+        if self.client_version != CLIENT_VERSION {
+            return Err(RejectReason::VersionMismatch {
+                server: CLIENT_VERSION.to_string(),
+            });
+        }
+        let replay = Replay::decode(&self.replay)
+            .map_err(|err| RejectReason::MalformedReplay(err.to_string()))?;
+        verify(&replay).map_err(|err| RejectReason::Unverifiable(err.to_string()))
+    }
+
+    fn load(path: &str) -> Result<Self, String> {
+        let contents = fs::read_to_string(path).map_err(|err| err.to_string())?;
+        ron::from_str(&contents).map_err(|err| err.to_string())
+    }
+}
+
+// Ścieżka zgłoszenia do sprawdzenia, jeśli gra została uruchomiona z `--check-submission`.
+pub fn requested() -> Option<String> {
+    let mut args = std::env::args().skip_while(|arg| arg != CHECK_FLAG);
+    args.next()?;
+    args.next()
+}
+
+// Sprawdzenie z linii poleceń; odpowiedź trafia na standardowe wyjście w RON.
+pub fn check_file(path: &str) -> i32 {
+    let response = match ScoreSubmission::load(path) {
+        Ok(submission) => submission.check(),
+        Err(err) => SubmissionResponse::Rejected(RejectReason::MalformedReplay(err)),
+    };
+    if let SubmissionResponse::Rejected(reason) = &response {
+        eprintln!("{path}: {reason}");
+    }
+    match ron::to_string(&response) {
+        Ok(text) => println!("{text}"),
+        Err(err) => eprintln!("{path}: {err}"),
+    }
+    match response {
+        SubmissionResponse::Accepted { .. } => 0,
+        SubmissionResponse::Rejected(_) => 1,
+    }
+}
+
+// Zgłoszenie ostatniej rundy, jeśli miała powtórkę.
+#[derive(Resource, Default)]
+pub struct PendingSubmission(pub Option<ScoreSubmission>);
+
+pub struct SubmissionPlugin;
+
+impl Plugin for SubmissionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PendingSubmission>().add_systems(
+            OnEnter(AppState::GameOver),
+            prepare_submission.after(crate::replay::finish_recording),
+        );
+    }
+}
+
+// Rundy na ziarnie obejrzanym w treningu (`practice.rs`) nie są zgłaszane.
+fn prepare_submission(
+    replay: Res<LastReplay>,
+    mode: Res<RunMode>,
+    studied: Res<StudiedSeeds>,
+    mut pending: ResMut<PendingSubmission>,
+) {
+    pending.0 = replay
+        .0
+        .as_ref()
+        .filter(|replay| replay.score > 0 && !studied.contains(replay.seed))
+        .and_then(|replay| match ScoreSubmission::new(replay, mode.0) {
+            Ok(submission) => Some(submission),
+            Err(err) => {
+                warn!("Nie udało się przygotować zgłoszenia: {err}");
+                None
+            }
+        });
+}
+
+// Zapis zgłoszenia na dysk. Dodawany tylko w `main`, tak jak zapis ustawień.
+pub struct SubmissionOutboxPlugin;
+
+impl Plugin for SubmissionOutboxPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Last,
+            save_submission.run_if(
+                resource_changed::<PendingSubmission>.and(not(resource_added::<PendingSubmission>)),
+            ),
+        );
+    }
+}
+
+fn save_submission(pending: Res<PendingSubmission>) {
+    let Some(submission) = &pending.0 else {
+        return;
+    };
+    let contents = match ron::to_string(submission) {
+        Ok(contents) => contents,
+        Err(err) => {
+            warn!("Nie udało się zserializować zgłoszenia: {err}");
+            return;
+        }
+    };
+    if let Err(err) = fs::write(SUBMISSION_PATH, contents) {
+        warn!("Nie udało się zapisać {SUBMISSION_PATH}: {err}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controls::ControlScheme;
+    use crate::settings::Difficulty;
+
+    fn submission() -> ScoreSubmission {
+        let replay = Replay {
+            seed: 1,
+            tick_rate: 60,
+            difficulty: Difficulty::Normal,
+            controls: ControlScheme::Classic,
+            reduced_motion: false,
+            leniency: false,
+            score: 3,
+            frames: 600,
+            flaps: vec![0, 30, 60],
+        };
+        ScoreSubmission::signed(&replay, GameMode::Classic, b"test-key").unwrap()
+    }
+
+    #[test]
+    fn tampered_submission_is_rejected() {
+        let mut tampered = submission();
+        let last = tampered.replay.len() - 5;
+        tampered.replay[last] ^= 1;
+        assert_eq!(
+            tampered.validate(b"test-key"),
+            Err(RejectReason::BadSignature)
+        );
+
+        assert_eq!(
+            submission().validate(b"other-key"),
+            Err(RejectReason::BadSignature)
+        );
+    }
+
+    #[test]
+    fn submission_from_another_version_is_rejected() {
+        let mut submission = submission();
+        submission.client_version = "0.0.0-old".to_string();
+        submission.signature = submission.mac(b"test-key").finalize().into_bytes().to_vec();
+
+        assert!(matches!(
+            submission.validate(b"test-key"),
+            Err(RejectReason::VersionMismatch { .. })
+        ));
+    }
+}
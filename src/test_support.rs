@@ -0,0 +1,124 @@
+// Bezgłowy harness do testów integracyjnych: App na `MinimalPlugins` z
+// ręcznie krokowanym czasem i wstrzykiwanym wejściem z klawiatury.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::assets::GameAssets;
+use crate::core::DifficultyCurve;
+use crate::pipes::{Pipe, spawn_pipe_pair};
+use crate::player::Player;
+use crate::replay_verify::{headless_app, wait_for_assets};
+use crate::score::Score;
+use crate::settings::Settings;
+use crate::theme::Theme;
+
+// Stały krok symulacji w testach (60 FPS).
+pub const FRAME: Duration = Duration::from_nanos(1_000_000_000 / 60);
+
+pub struct TestApp {
+    pub app: App,
+}
+
+impl TestApp {
+    pub fn new() -> Self {
+        let mut app = headless_app(Settings::default());
+        wait_for_assets(&mut app);
+        Self { app }
+    }
+
+    // Jedna klatka symulacji.
+    pub fn step(&mut self) {
+        self.app.update();
+    }
+
+    pub fn step_n(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.step();
+        }
+    }
+
+    pub fn step_secs(&mut self, secs: f32) {
+        let frames = (secs / FRAME.as_secs_f32()).ceil() as usize;
+        self.step_n(frames);
+    }
+
+    // Wciśnięcie klawisza na dokładnie jedną klatkę.
+    pub fn tap(&mut self, key: KeyCode) {
+        self.app
+            .world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(key);
+        self.step();
+        let mut input = self.app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        input.release(key);
+        input.clear();
+    }
+
+    pub fn set_state(&mut self, state: AppState) {
+        self.app
+            .world_mut()
+            .resource_mut::<NextState<AppState>>()
+            .set(state);
+        self.step();
+    }
+
+    pub fn start_game(&mut self) {
+        self.set_state(AppState::Playing);
+    }
+
+    pub fn state(&self) -> AppState {
+        *self.app.world().resource::<State<AppState>>().get()
+    }
+
+    pub fn score(&self) -> i32 {
+        self.app.world().resource::<Score>().0
+    }
+
+    pub fn player_position(&mut self) -> Option<Vec2> {
+        let world = self.app.world_mut();
+        world
+            .query_filtered::<&Transform, With<Player>>()
+            .get_single(world)
+            .ok()
+            .map(|transform| transform.translation.truncate())
+    }
+
+    pub fn set_player_position(&mut self, position: Vec2) {
+        let world = self.app.world_mut();
+        let mut query = world.query_filtered::<&mut Transform, With<Player>>();
+        for mut transform in query.iter_mut(world) {
+            transform.translation.x = position.x;
+            transform.translation.y = position.y;
+        }
+    }
+
+    pub fn count<C: Component>(&mut self) -> usize {
+        let world = self.app.world_mut();
+        world.query_filtered::<(), With<C>>().iter(world).count()
+    }
+
+    pub fn pipe_count(&mut self) -> usize {
+        self.count::<Pipe>()
+    }
+
+    // Spawnuje parę rur w zadanym miejscu, z pominięciem losowania.
+    pub fn spawn_pipe_pair(&mut self, x: f32, center_y: f32) {
+        let world = self.app.world_mut();
+        let assets = world.resource::<GameAssets>().clone();
+        let mut commands = world.commands();
+        let params = DifficultyCurve::CLASSIC.params_at(0);
+        spawn_pipe_pair(
+            &mut commands,
+            &assets,
+            &Theme::Day,
+            Color::WHITE,
+            x,
+            center_y,
+            params,
+        );
+        world.flush();
+    }
+}
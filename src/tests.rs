@@ -0,0 +1,1064 @@
+// Scenariusze rozgrywki uruchamiane na bezgłowym App (patrz `test_support`).
+
+use bevy::prelude::*;
+
+use crate::adaptive::{AdaptiveDifficulty, AdaptiveRun};
+use crate::ai_race::{RivalOut, RivalsButton, rivals_button_label};
+use crate::assets::GameAssets;
+use crate::audio::Sfx;
+use crate::bests::{GameMode, PersonalBests, RunMode};
+use crate::biome::{Biome, CurrentBiome, PIPES_PER_BIOME};
+use crate::bubble::Bubble;
+use crate::buttons::ButtonAction;
+use crate::celebration::NewBestBanner;
+use crate::controls::ControlScheme;
+use crate::coop::{CoopPartner, CoopRun};
+use crate::core::DrawPurpose;
+use crate::dataset::{DatasetExport, SCHEMA_HEADER};
+use crate::display::WORLD_SIZE;
+use crate::game_over::GameOverUI;
+use crate::ghost::GhostRace;
+use crate::glide_streak::GlideStreak;
+use crate::hammer::{Debris, Hammer};
+use crate::hints::ButtonHint;
+use crate::kids::KidsMode;
+use crate::menu::Menu;
+use crate::milestone::SlowMotion;
+use crate::mirror::MirrorRun;
+use crate::mutators::{MutatedRun, Mutator, Mutators};
+use crate::pause::PauseState;
+use crate::physics::{Collider, Velocity};
+use crate::player::{HoverIdle, Player};
+use crate::practice::{PracticeButton, StudiedSeeds};
+use crate::replay::{LastReplay, ReplayRecorder};
+use crate::replay_verify::{VerifyError, draw_audit, verify};
+use crate::rng::{RunRng, SeedChoice, first_divergence};
+use crate::royale::{ROYALE_RIVALS, RoyaleRun, placement_points};
+use crate::run_save::{PendingResume, capture_run};
+use crate::run_stats::RunStats;
+use crate::scheduler::{Scheduler, TriggerId};
+use crate::score::{Score, ScoreText, ScoreZone};
+use crate::score_digits::ScoreDigits;
+use crate::settings::{Difficulty, Settings};
+use crate::spectate::{Spectating, WatchMessage, WatchStream};
+use crate::stamina::StaminaRun;
+use crate::submission::PendingSubmission;
+use crate::test_support::TestApp;
+use crate::tournament::{Modifier, TournamentWeek, WeeklyRun};
+use crate::tower::TowerRun;
+use crate::{AppState, Background};
+
+#[test]
+fn starts_in_menu() {
+    let mut game = TestApp::new();
+
+    assert_eq!(game.state(), AppState::Menu);
+    assert_eq!(game.score(), 0);
+    assert_eq!(game.count::<Player>(), 1);
+}
+
+#[test]
+fn menu_sits_on_a_fresh_game_scene() {
+    let mut game = TestApp::new();
+    game.start_game();
+    game.spawn_pipe_pair(200.0, 0.0);
+    game.step_n(3);
+    game.set_state(AppState::GameOver);
+
+    game.set_state(AppState::Menu);
+
+    assert_eq!(game.pipe_count(), 0);
+    assert_eq!(game.count::<Player>(), 1);
+    assert_eq!(game.count::<HoverIdle>(), 1);
+    let world = game.app.world_mut();
+    let visibility = world
+        .query_filtered::<&Visibility, With<ScoreText>>()
+        .single(world);
+    assert_eq!(*visibility, Visibility::Hidden);
+}
+
+#[test]
+fn flap_moves_player_up() {
+    let mut game = TestApp::new();
+    game.start_game();
+    let start = game.player_position().unwrap();
+
+    game.tap(KeyCode::Space);
+    game.step_n(5);
+
+    assert!(game.player_position().unwrap().y > start.y);
+}
+
+#[test]
+fn scores_after_passing_pipe() {
+    let mut game = TestApp::new();
+    game.start_game();
+    game.spawn_pipe_pair(25.0, 0.0);
+
+    game.step_secs(0.4);
+
+    assert_eq!(game.state(), AppState::Playing);
+    assert_eq!(game.score(), 1);
+}
+
+#[test]
+fn gameplay_events_request_sound_effects() {
+    let mut game = TestApp::new();
+    let mut cursor = game
+        .app
+        .world()
+        .resource::<Events<Sfx>>()
+        .get_cursor_current();
+    let mut heard = |game: &TestApp| -> Vec<Sfx> {
+        let events = game.app.world().resource::<Events<Sfx>>();
+        cursor.read(events).copied().collect()
+    };
+
+    game.start_game();
+    assert_eq!(heard(&game), [Sfx::Swoosh]);
+
+    game.spawn_pipe_pair(25.0, 0.0);
+    game.tap(KeyCode::Space);
+    assert_eq!(heard(&game), [Sfx::Wing]);
+    game.step_secs(0.4);
+    assert!(heard(&game).contains(&Sfx::Point));
+
+    game.set_state(AppState::GameOver);
+    assert_eq!(heard(&game), [Sfx::Hit, Sfx::Die]);
+}
+
+#[test]
+fn every_25th_point_slows_time_briefly() {
+    let mut game = TestApp::new();
+    game.start_game();
+    game.app.world_mut().resource_mut::<Score>().0 = 24;
+    game.spawn_pipe_pair(25.0, 0.0);
+
+    for _ in 0..30 {
+        if game.score() == 25 {
+            break;
+        }
+        game.step();
+    }
+    assert_eq!(game.score(), 25);
+    assert!(game.app.world().contains_resource::<SlowMotion>());
+    assert!(
+        game.app
+            .world()
+            .resource::<Time<Virtual>>()
+            .relative_speed()
+            < 1.0
+    );
+
+    game.step_secs(0.5);
+    assert!(!game.app.world().contains_resource::<SlowMotion>());
+    assert_eq!(
+        game.app
+            .world()
+            .resource::<Time<Virtual>>()
+            .relative_speed(),
+        1.0
+    );
+}
+
+#[test]
+fn score_counter_shows_one_sprite_per_digit() {
+    let mut game = TestApp::new();
+    game.start_game();
+    game.app.world_mut().resource_mut::<Score>().0 = 42;
+    game.step_n(2);
+
+    let world = game.app.world_mut();
+    let mut counter = world.query_filtered::<&Children, With<ScoreText>>();
+    assert_eq!(counter.single(world).len(), 2);
+}
+
+#[test]
+fn flap_hint_shows_until_the_first_flap() {
+    let mut game = TestApp::new();
+    game.start_game();
+    game.step();
+
+    let world = game.app.world_mut();
+    let mut hints = world.query::<(&ButtonHint, &Text)>();
+    let (_, text) = hints.single(world);
+    assert_eq!(text.0, "Space to flap");
+
+    game.tap(KeyCode::Space);
+    game.step_n(2);
+
+    assert_eq!(game.count::<ButtonHint>(), 0);
+}
+
+#[test]
+fn game_over_on_pipe_collision() {
+    let mut game = TestApp::new();
+    game.start_game();
+    game.spawn_pipe_pair(0.0, 200.0);
+
+    game.step_n(2);
+
+    assert_eq!(game.state(), AppState::GameOver);
+    assert_eq!(game.count::<GameOverUI>(), 1);
+}
+
+#[test]
+fn hammer_smashes_one_pipe_instead_of_ending_the_run() {
+    let mut game = TestApp::new();
+    game.start_game();
+    let world = game.app.world_mut();
+    let player = world.query_filtered::<Entity, With<Player>>().single(world);
+    world.entity_mut(player).insert(Hammer);
+    game.spawn_pipe_pair(0.0, 200.0);
+
+    game.step_n(2);
+
+    assert_eq!(game.state(), AppState::Playing);
+    assert_eq!(game.pipe_count(), 1);
+    assert_eq!(game.count::<Hammer>(), 0);
+    assert!(game.count::<Debris>() > 0);
+
+    // Odłamki znikają po sekundzie.
+    game.step_secs(1.1);
+    assert_eq!(game.count::<Debris>(), 0);
+
+    game.spawn_pipe_pair(0.0, 200.0);
+    game.step_n(2);
+    assert_eq!(game.state(), AppState::GameOver);
+}
+
+#[test]
+fn bubble_shows_while_the_hammer_is_carried() {
+    let mut game = TestApp::new();
+    game.start_game();
+    let world = game.app.world_mut();
+    let player = world.query_filtered::<Entity, With<Player>>().single(world);
+    world.entity_mut(player).insert(Hammer);
+    game.step();
+    assert_eq!(game.count::<Bubble>(), 1);
+
+    game.app.world_mut().entity_mut(player).remove::<Hammer>();
+    game.step();
+    assert_eq!(game.count::<Bubble>(), 0);
+    assert!(game.count::<Debris>() > 0);
+}
+
+#[test]
+fn adaptive_difficulty_widens_gaps_after_repeated_pipe_deaths() {
+    let mut game = TestApp::new();
+    game.app
+        .world_mut()
+        .resource_mut::<Settings>()
+        .assists
+        .adaptive = true;
+    for _ in 0..3 {
+        game.start_game();
+        assert!(!game.app.world().contains_resource::<AdaptiveRun>());
+        game.spawn_pipe_pair(0.0, 200.0);
+        game.step_n(2);
+        assert_eq!(game.state(), AppState::GameOver);
+    }
+    let adjustment = game.app.world().resource::<AdaptiveDifficulty>().current();
+    assert!(adjustment.gap_percent > 0);
+
+    game.start_game();
+    assert_eq!(game.app.world().resource::<AdaptiveRun>().0, adjustment);
+    assert!(
+        game.app
+            .world()
+            .resource::<ReplayRecorder>()
+            .recording()
+            .is_none()
+    );
+}
+
+#[test]
+fn bird_hovers_until_first_flap() {
+    let mut game = TestApp::new();
+    game.start_game();
+
+    game.step_secs(2.0);
+
+    assert_eq!(game.state(), AppState::Playing);
+    assert!(game.player_position().unwrap().y.abs() <= 6.0);
+    assert_eq!(game.pipe_count(), 0);
+}
+
+#[test]
+fn game_over_when_falling_off_screen() {
+    let mut game = TestApp::new();
+    game.start_game();
+    game.tap(KeyCode::Space);
+
+    game.step_secs(3.0);
+
+    assert_eq!(game.state(), AppState::GameOver);
+}
+
+#[test]
+fn kid_bird_bounces_instead_of_losing() {
+    let mut game = TestApp::new();
+    game.app.insert_resource(KidsMode);
+    game.start_game();
+    game.tap(KeyCode::Space);
+
+    game.step_secs(3.0);
+    assert_eq!(game.state(), AppState::Playing);
+    assert!(game.player_position().unwrap().y > -WORLD_SIZE.y / 2.0);
+
+    game.spawn_pipe_pair(0.0, 200.0);
+    game.step_n(2);
+    assert_eq!(game.state(), AppState::Playing);
+}
+
+#[test]
+fn reverse_week_flips_gravity_and_uses_the_week_seed() {
+    let mut game = TestApp::new();
+    game.app.insert_resource(WeeklyRun::new(TournamentWeek {
+        seed: 77,
+        modifier: Modifier::Reverse,
+    }));
+    game.start_game();
+    assert_eq!(game.app.world().resource::<RunRng>().seed(), 77);
+
+    game.tap(KeyCode::Space);
+    game.step_n(5);
+    let after_flap = game.player_position().unwrap().y;
+    assert!(after_flap < 0.0);
+
+    game.step_secs(1.0);
+    assert!(game.player_position().unwrap().y > after_flap);
+}
+
+#[test]
+fn restart_cleans_up_previous_run() {
+    let mut game = TestApp::new();
+    game.start_game();
+    game.spawn_pipe_pair(25.0, 0.0);
+    game.step_secs(0.4);
+    game.spawn_pipe_pair(0.0, 200.0);
+    game.step_n(2);
+    assert_eq!(game.state(), AppState::GameOver);
+
+    game.start_game();
+    game.step();
+
+    assert_eq!(game.state(), AppState::Playing);
+    assert_eq!(game.score(), 0);
+    assert_eq!(game.count::<Player>(), 1);
+    assert_eq!(game.count::<ScoreText>(), 1);
+    assert_eq!(game.pipe_count(), 0);
+    assert_eq!(game.count::<GameOverUI>(), 0);
+    assert_eq!(game.player_position().unwrap().x, 0.0);
+}
+
+#[test]
+fn state_owned_ui_is_cleared_when_its_state_exits() {
+    let mut game = TestApp::new();
+    assert!(game.count::<Menu>() > 0);
+
+    game.start_game();
+    assert_eq!(game.count::<Menu>(), 0);
+
+    game.set_state(AppState::GameOver);
+    assert!(game.count::<GameOverUI>() > 0);
+
+    game.set_state(AppState::Menu);
+    assert_eq!(game.count::<GameOverUI>(), 0);
+    assert!(game.count::<Menu>() > 0);
+}
+
+#[test]
+fn action_buttons_drive_state_through_events() {
+    let mut game = TestApp::new();
+    let press = |game: &mut TestApp, action: ButtonAction| {
+        let world = game.app.world_mut();
+        let button = world
+            .query::<(Entity, &ButtonAction)>()
+            .iter(world)
+            .find_map(|(entity, candidate)| (*candidate == action).then_some(entity))
+            .unwrap();
+        world.entity_mut(button).insert(Interaction::Pressed);
+        game.step();
+    };
+
+    press(&mut game, ButtonAction::StartGame);
+    game.step();
+    assert_eq!(game.state(), AppState::Playing);
+
+    game.set_state(AppState::GameOver);
+    press(&mut game, ButtonAction::Restart);
+    game.step();
+    assert_eq!(game.state(), AppState::Playing);
+}
+
+#[test]
+fn toggle_buttons_find_their_label_or_skip_it() {
+    let mut game = TestApp::new();
+    let world = game.app.world_mut();
+    let button = world
+        .query_filtered::<Entity, With<RivalsButton>>()
+        .single(world);
+    // Ikona przed napisem nie przeszkadza w odnalezieniu etykiety.
+    let icon = world.spawn(Node::default()).id();
+    world.entity_mut(button).insert_children(0, &[icon]);
+    let press = |game: &mut TestApp| {
+        let world = game.app.world_mut();
+        world.entity_mut(button).insert(Interaction::Pressed);
+        game.step();
+        game.app.world().resource::<Settings>().ai.rivals
+    };
+
+    let rivals = press(&mut game);
+    assert_eq!(rivals, 1);
+    let world = game.app.world_mut();
+    let label = world
+        .query::<(&Parent, &Text)>()
+        .iter(world)
+        .find_map(|(parent, text)| (parent.get() == button).then(|| text.0.clone()))
+        .unwrap();
+    let settings = game.app.world().resource::<Settings>();
+    assert_eq!(label, rivals_button_label(settings));
+
+    // Przycisk bez napisu dalej działa, tylko etykieta zostaje bez zmian.
+    game.app
+        .world_mut()
+        .entity_mut(button)
+        .despawn_descendants();
+    assert_ne!(press(&mut game), rivals);
+}
+
+#[test]
+fn restart_keeps_scene_entities() {
+    let mut game = TestApp::new();
+    game.start_game();
+    let scene = |game: &mut TestApp| {
+        let world = game.app.world_mut();
+        let mut entities: Vec<Entity> = world
+            .query_filtered::<Entity, Or<(With<Background>, With<ScoreText>, With<Camera2d>)>>()
+            .iter(world)
+            .collect();
+        entities.sort();
+        entities
+    };
+    let before = scene(&mut game);
+    game.spawn_pipe_pair(25.0, 0.0);
+    game.step_secs(0.7);
+    game.set_state(AppState::GameOver);
+
+    game.start_game();
+    game.step();
+
+    assert_eq!(scene(&mut game), before);
+    assert_eq!(game.score(), 0);
+    assert_eq!(game.pipe_count(), 0);
+    assert_eq!(game.count::<Player>(), 1);
+}
+
+#[test]
+fn new_run_starts_with_fresh_run_state() {
+    let mut game = TestApp::new();
+    game.start_game();
+    game.spawn_pipe_pair(25.0, 0.0);
+    game.step_secs(0.7);
+    assert_eq!(game.score(), 1);
+    game.set_state(AppState::GameOver);
+
+    game.start_game();
+
+    assert_eq!(game.score(), 0);
+    let world = game.app.world_mut();
+    let (stats, glide) = world
+        .query_filtered::<(&RunStats, &GlideStreak), With<Player>>()
+        .single(world);
+    assert_eq!(stats.pipes_passed, 0);
+    assert_eq!(glide.streak, 0);
+    let digits = world
+        .query_filtered::<&ScoreDigits, With<ScoreText>>()
+        .single(world);
+    assert_eq!(digits.0, 0);
+}
+
+#[test]
+fn run_schedule_waits_for_the_first_flap_and_resets_each_run() {
+    let mut game = TestApp::new();
+    game.start_game();
+    game.step_secs(1.0);
+    // Czas od ostatniej rury; stoi w miejscu, dopóki stoi zegar rundy.
+    let elapsed = |game: &TestApp| {
+        let scheduler = game.app.world().resource::<Scheduler>();
+        scheduler.progress(TriggerId::PipeSpawn).unwrap().1
+    };
+    assert_eq!(elapsed(&game), 0.0);
+    assert_eq!(game.pipe_count(), 0);
+
+    game.tap(KeyCode::Space);
+    game.step_secs(0.5);
+    assert!(elapsed(&game) > 0.0);
+
+    game.app.world_mut().resource_mut::<Score>().0 = PIPES_PER_BIOME;
+    game.step_n(2);
+    assert_eq!(game.app.world().resource::<CurrentBiome>().0, Biome::City);
+
+    game.set_state(AppState::GameOver);
+    game.start_game();
+    assert_eq!(elapsed(&game), 0.0);
+    assert_eq!(game.app.world().resource::<CurrentBiome>().0, Biome::Forest);
+}
+
+#[test]
+fn beating_the_best_celebrates_on_game_over() {
+    let mut game = TestApp::new();
+    game.start_game();
+    game.spawn_pipe_pair(25.0, 0.0);
+    game.step_secs(0.7);
+    game.set_state(AppState::GameOver);
+    assert_eq!(game.count::<NewBestBanner>(), 1);
+
+    // Ten sam wynik to już nie rekord.
+    game.start_game();
+    game.spawn_pipe_pair(25.0, 0.0);
+    game.step_secs(0.7);
+    game.set_state(AppState::GameOver);
+    assert_eq!(game.count::<NewBestBanner>(), 0);
+}
+
+#[test]
+fn each_gap_scores_once() {
+    let mut game = TestApp::new();
+    game.start_game();
+    game.spawn_pipe_pair(25.0, 0.0);
+
+    game.step_secs(0.7);
+
+    assert_eq!(game.score(), 1);
+}
+
+#[test]
+fn pause_freezes_the_run() {
+    let mut game = TestApp::new();
+    game.start_game();
+    game.tap(KeyCode::Space);
+    game.tap(KeyCode::Escape);
+    let paused_at = game.player_position().unwrap();
+
+    game.step_secs(1.0);
+
+    assert_eq!(game.player_position(), Some(paused_at));
+    game.tap(KeyCode::Escape);
+    game.step_secs(1.1);
+    assert_ne!(game.player_position(), Some(paused_at));
+}
+
+#[test]
+fn flap_just_before_resume_is_buffered() {
+    let mut game = TestApp::new();
+    game.start_game();
+    game.tap(KeyCode::Escape);
+    game.tap(KeyCode::Escape);
+
+    // Naciśnięcie pod sam koniec odliczania trafia do bufora.
+    game.step_secs(0.95);
+    game.tap(KeyCode::Space);
+    game.step_n(6);
+
+    assert!(game.player_position().unwrap().y > 6.0);
+}
+
+#[test]
+fn dash_pushes_bird_forward_then_drifts_back() {
+    let mut game = TestApp::new();
+    game.app.world_mut().resource_mut::<Settings>().controls = ControlScheme::Dash;
+    game.start_game();
+    game.tap(KeyCode::Space);
+
+    game.tap(KeyCode::ShiftLeft);
+    game.step_secs(0.15);
+    let peak = game.player_position().unwrap().x;
+    assert!(peak > 50.0);
+
+    game.step_secs(0.5);
+    assert!(game.player_position().unwrap().x < peak / 2.0);
+}
+
+#[test]
+fn restart_reuses_preloaded_sprites() {
+    let mut game = TestApp::new();
+    game.start_game();
+    game.spawn_pipe_pair(200.0, 0.0);
+    game.set_state(AppState::GameOver);
+    game.start_game();
+    game.spawn_pipe_pair(200.0, 0.0);
+
+    let cached: Vec<_> = game.app.world().resource::<GameAssets>().ids().collect();
+    let world = game.app.world_mut();
+    let images: Vec<_> = world
+        .query::<&Sprite>()
+        .iter(world)
+        .filter(|sprite| sprite.image != Handle::default())
+        .map(|sprite| sprite.image.id().untyped())
+        .collect();
+
+    assert!(!images.is_empty());
+    assert!(images.iter().all(|id| cached.contains(id)));
+}
+
+#[test]
+fn saved_run_resumes_where_it_stopped() {
+    let mut game = TestApp::new();
+    game.app.world_mut().resource_mut::<Settings>().difficulty = Difficulty::Easy;
+    game.start_game();
+    for _ in 0..4 {
+        game.tap(KeyCode::Space);
+        game.step_secs(0.6);
+    }
+    game.tap(KeyCode::Escape);
+    game.step();
+    assert_eq!(game.pipe_count(), 2);
+
+    let saved = capture_run(game.app.world_mut());
+    let position = game.player_position().unwrap();
+    let draws = game.app.world().resource::<RunRng>().draws();
+    game.set_state(AppState::Menu);
+    game.app.world_mut().resource_mut::<Settings>().difficulty = Difficulty::Hard;
+
+    game.app.insert_resource(PendingResume(saved));
+    game.app
+        .world_mut()
+        .resource_mut::<NextState<PauseState>>()
+        .set(PauseState::Resuming);
+    game.start_game();
+
+    assert_eq!(game.state(), AppState::Playing);
+    assert_eq!(
+        *game.app.world().resource::<State<PauseState>>().get(),
+        PauseState::Resuming
+    );
+    assert_eq!(game.pipe_count(), 2);
+    assert_eq!(game.player_position(), Some(position));
+    assert_eq!(game.app.world().resource::<RunRng>().draws(), draws);
+    // Runda kończy się na swoim poziomie i bez powtórki, której brakuje początku.
+    assert_eq!(
+        game.app.world().resource::<Settings>().difficulty,
+        Difficulty::Easy
+    );
+    game.step_secs(8.0);
+    assert_eq!(game.state(), AppState::GameOver);
+    assert!(game.app.world().resource::<LastReplay>().0.is_none());
+}
+
+#[test]
+fn auto_restart_starts_a_new_run_after_two_seconds() {
+    let mut game = TestApp::new();
+    game.app.world_mut().resource_mut::<Settings>().auto_restart = true;
+    game.start_game();
+    game.spawn_pipe_pair(0.0, 200.0);
+    game.step_n(2);
+    assert_eq!(game.state(), AppState::GameOver);
+
+    game.step_secs(1.5);
+    assert_eq!(game.state(), AppState::GameOver);
+
+    game.step_secs(0.6);
+    assert_eq!(game.state(), AppState::Playing);
+    assert_eq!(game.score(), 0);
+}
+
+#[test]
+fn tight_pass_counts_as_near_miss() {
+    let mut game = TestApp::new();
+    game.app
+        .world_mut()
+        .resource_mut::<Settings>()
+        .accessibility
+        .reduced_motion = true;
+    game.start_game();
+    // Przerwa 100 px wyśrodkowana 30 px pod ptakiem: 4 px zapasu od górnej rury.
+    game.spawn_pipe_pair(25.0, -30.0);
+
+    game.step_secs(0.4);
+
+    assert_eq!(game.state(), AppState::Playing);
+    let world = game.app.world_mut();
+    let stats = world
+        .query_filtered::<&RunStats, With<Player>>()
+        .single(world)
+        .clone();
+    assert_eq!(stats.pipes_passed, 1);
+    assert_eq!(stats.near_misses, 1);
+    assert_eq!(stats.max_combo, 1);
+}
+
+#[test]
+fn chosen_seed_is_used_for_every_run() {
+    let mut game = TestApp::new();
+    game.app.insert_resource(SeedChoice(Some(0xfeed)));
+
+    game.start_game();
+    assert_eq!(game.app.world().resource::<RunRng>().seed(), 0xfeed);
+
+    game.set_state(AppState::GameOver);
+    game.start_game();
+    assert_eq!(game.app.world().resource::<RunRng>().seed(), 0xfeed);
+}
+
+#[test]
+fn recorded_replay_verifies_its_score() {
+    let mut game = TestApp::new();
+    game.app.insert_resource(SeedChoice(Some(0x5eed)));
+    game.start_game();
+    for _ in 0..20 {
+        game.tap(KeyCode::Space);
+        game.step_secs(0.35);
+    }
+    game.step_secs(3.0);
+    assert_eq!(game.state(), AppState::GameOver);
+
+    let mut replay = game.app.world().resource::<LastReplay>().0.clone().unwrap();
+    assert_eq!(verify(&replay), Ok(game.score()));
+
+    replay.score += 1;
+    assert!(matches!(
+        verify(&replay),
+        Err(VerifyError::ScoreMismatch { .. })
+    ));
+    replay.score -= 1;
+
+    // Nagłówek nie może dopisać rundzie długości.
+    replay.frames += 60;
+    assert!(matches!(
+        verify(&replay),
+        Err(VerifyError::EndMismatch { .. })
+    ));
+    replay.frames = u32::MAX;
+    assert_eq!(verify(&replay), Err(VerifyError::TooLong));
+}
+
+#[test]
+fn replayed_run_draws_the_same_numbers_as_the_live_run() {
+    let mut game = TestApp::new();
+    game.app.insert_resource(SeedChoice(Some(0xa0d1)));
+    game.start_game();
+    for _ in 0..20 {
+        game.tap(KeyCode::Space);
+        game.step_secs(0.35);
+    }
+    game.step_secs(3.0);
+    assert_eq!(game.state(), AppState::GameOver);
+
+    let live = game.app.world().resource::<RunRng>().audit().to_vec();
+    assert!(
+        live.iter()
+            .any(|draw| draw.purpose == DrawPurpose::GapCenter)
+    );
+    assert!(
+        live.iter()
+            .any(|draw| draw.purpose == DrawPurpose::HammerRoll)
+    );
+
+    let replay = game.app.world().resource::<LastReplay>().0.clone().unwrap();
+    let replayed = draw_audit(&replay).unwrap();
+    assert_eq!(first_divergence(&live, &replayed), None);
+}
+
+#[test]
+fn studied_seed_runs_are_not_submitted() {
+    let mut game = TestApp::new();
+    game.app.insert_resource(SeedChoice(Some(0x57d7)));
+    let button = game
+        .app
+        .world_mut()
+        .query_filtered::<Entity, With<PracticeButton>>()
+        .single(game.app.world());
+    game.app
+        .world_mut()
+        .entity_mut(button)
+        .insert(Interaction::Pressed);
+    game.step();
+    assert!(game.app.world().resource::<StudiedSeeds>().contains(0x57d7));
+
+    game.start_game();
+    game.app.world_mut().resource_mut::<Score>().0 = 5;
+    game.tap(KeyCode::Space);
+    game.step_secs(3.0);
+    assert_eq!(game.state(), AppState::GameOver);
+    let replay = game.app.world().resource::<LastReplay>().0.clone().unwrap();
+    assert!(replay.score > 0);
+    assert!(game.app.world().resource::<PendingSubmission>().0.is_none());
+}
+
+#[test]
+fn stamina_weakens_rapid_flaps_and_skips_the_replay() {
+    let mut game = TestApp::new();
+    game.app.init_resource::<StaminaRun>();
+    game.start_game();
+
+    let mut impulses = Vec::new();
+    for _ in 0..6 {
+        game.tap(KeyCode::Space);
+        let velocity = game
+            .app
+            .world_mut()
+            .query_filtered::<&Velocity, With<Player>>()
+            .single(game.app.world());
+        impulses.push(velocity.dy);
+        game.step_n(2);
+    }
+    assert!(impulses[0] > impulses[5] * 2.0);
+    assert!(game.app.world().resource::<StaminaRun>().level() < 1.0);
+
+    game.step_secs(3.0);
+    assert_eq!(game.state(), AppState::GameOver);
+    assert!(game.app.world().resource::<LastReplay>().0.is_none());
+}
+
+#[test]
+fn custom_game_mutators_change_the_run_and_label_the_score() {
+    let mut game = TestApp::new();
+    let mutators: Mutators = [Mutator::BigBird, Mutator::NoCeiling].into_iter().collect();
+    game.app.insert_resource(MutatedRun::new(mutators));
+    game.start_game();
+
+    let half_size = game
+        .app
+        .world_mut()
+        .query_filtered::<&Collider, With<Player>>()
+        .single(game.app.world())
+        .half_size;
+    assert!(half_size.y > 16.0);
+
+    // Bez sufitu ptak może wylecieć nad ekran.
+    game.tap(KeyCode::Space);
+    game.set_player_position(Vec2::new(0.0, WORLD_SIZE.y / 2.0 + 40.0));
+    game.step();
+    assert_eq!(game.state(), AppState::Playing);
+
+    game.app.world_mut().resource_mut::<Score>().0 = 3;
+    game.step_secs(4.0);
+    assert_eq!(game.state(), AppState::GameOver);
+    assert!(game.app.world().resource::<LastReplay>().0.is_none());
+
+    let key = crate::bests::current_key(&RunMode::default(), &Settings::default());
+    let bests = game.app.world().resource::<PersonalBests>();
+    let entry = bests.leaderboard(key).next().unwrap();
+    assert_eq!(entry.mutators, mutators);
+}
+
+#[test]
+fn ghost_race_uses_the_ghost_seed() {
+    let mut game = TestApp::new();
+    game.app.insert_resource(SeedChoice(Some(0x5eed)));
+    game.start_game();
+    game.tap(KeyCode::Space);
+    game.step_secs(3.0);
+    assert_eq!(game.state(), AppState::GameOver);
+    let replay = game.app.world().resource::<LastReplay>().0.clone().unwrap();
+
+    game.app.insert_resource(SeedChoice(None));
+    game.app.insert_resource(
+        GhostRace::new(&replay)
+            .unwrap()
+            .restoring_difficulty(Difficulty::Hard),
+    );
+    game.start_game();
+    game.step_secs(3.0);
+
+    assert_eq!(game.app.world().resource::<RunRng>().seed(), 0x5eed);
+    assert_eq!(game.state(), AppState::GameOver);
+
+    // Po wyścigu gracz wraca do swojego poziomu trudności.
+    game.set_state(AppState::Menu);
+    assert_eq!(
+        game.app.world().resource::<Settings>().difficulty,
+        Difficulty::Hard
+    );
+}
+
+#[test]
+fn ai_rivals_fly_the_same_pipes_as_the_player() {
+    let run = |rivals: u8| {
+        let mut game = TestApp::new();
+        game.app.insert_resource(SeedChoice(Some(0xa1)));
+        game.app.world_mut().resource_mut::<Settings>().ai.rivals = rivals;
+        game.start_game();
+        for _ in 0..8 {
+            game.tap(KeyCode::Space);
+            game.step_secs(0.4);
+        }
+        game.app.world().resource::<RunRng>().draws()
+    };
+
+    assert_eq!(run(3), run(0));
+}
+
+#[test]
+fn mirror_mode_alternates_wave_sides() {
+    let mut game = TestApp::new();
+    game.app.init_resource::<MirrorRun>();
+    game.start_game();
+    for _ in 0..14 {
+        game.tap(KeyCode::Space);
+        game.step_secs(0.35);
+    }
+
+    let world = game.app.world_mut();
+    let zones: Vec<(f32, f32)> = world
+        .query_filtered::<(&Transform, &Velocity), With<ScoreZone>>()
+        .iter(world)
+        .map(|(transform, velocity)| (transform.translation.x, velocity.dx))
+        .collect();
+    assert!(zones.iter().any(|&(x, dx)| x > 0.0 && dx < 0.0));
+    assert!(zones.iter().any(|&(x, dx)| x < 0.0 && dx > 0.0));
+}
+
+#[test]
+fn tower_climb_rotates_the_view_for_the_run_only() {
+    let camera_rotation = |game: &mut TestApp| {
+        let world = game.app.world_mut();
+        world
+            .query_filtered::<&Transform, With<Camera2d>>()
+            .single(world)
+            .rotation
+    };
+    let mut game = TestApp::new();
+    game.app.init_resource::<TowerRun>();
+    game.start_game();
+    game.step();
+
+    assert_eq!(game.app.world().resource::<RunMode>().0, GameMode::Tower);
+    assert_ne!(camera_rotation(&mut game), Quat::IDENTITY);
+
+    game.set_state(AppState::Menu);
+    assert_eq!(camera_rotation(&mut game), Quat::IDENTITY);
+    assert!(!game.app.world().contains_resource::<TowerRun>());
+}
+
+#[test]
+fn coop_partner_crash_ends_the_shared_run() {
+    let mut game = TestApp::new();
+    game.app.init_resource::<CoopRun>();
+    game.start_game();
+    assert_eq!(game.count::<CoopPartner>(), 1);
+
+    // Machnięcie drugiego gracza też zaczyna rundę.
+    game.tap(KeyCode::Enter);
+    game.step_secs(0.1);
+    assert_eq!(game.app.world().resource::<RunMode>().0, GameMode::Coop);
+    assert_eq!(game.count::<HoverIdle>(), 0);
+    assert_eq!(game.state(), AppState::Playing);
+
+    let world = game.app.world_mut();
+    world
+        .query_filtered::<&mut Transform, With<CoopPartner>>()
+        .single_mut(world)
+        .translation
+        .y = -WORLD_SIZE.y;
+    game.step();
+    assert_eq!(game.state(), AppState::GameOver);
+
+    game.set_state(AppState::Menu);
+    assert_eq!(game.count::<CoopPartner>(), 0);
+    assert!(!game.app.world().contains_resource::<CoopRun>());
+}
+
+#[test]
+fn spectator_reconstructs_a_streamed_run() {
+    let player_y = |game: &mut TestApp| {
+        let world = game.app.world_mut();
+        world
+            .query_filtered::<&Transform, With<Player>>()
+            .single(world)
+            .translation
+            .y
+    };
+    let mut streamer = TestApp::new();
+    streamer.app.insert_resource(SeedChoice(Some(0x5eed)));
+    streamer.app.init_resource::<WatchStream>();
+    streamer.start_game();
+    for _ in 0..6 {
+        streamer.tap(KeyCode::Space);
+        streamer.step_secs(0.35);
+    }
+    streamer.step_secs(3.0);
+    assert_eq!(streamer.state(), AppState::GameOver);
+    let messages = streamer
+        .app
+        .world_mut()
+        .resource_mut::<WatchStream>()
+        .drain();
+
+    let WatchMessage::Start { replay } = &messages[0] else {
+        panic!("stream does not open with the run header");
+    };
+    let mut spectating = Spectating::new(replay).unwrap();
+    for message in &messages[1..] {
+        spectating.receive(message);
+    }
+    let mut spectator = TestApp::new();
+    spectator.app.insert_resource(spectating);
+    spectator.start_game();
+    // Klawisz widza nie wpływa na oglądaną rundę.
+    spectator.tap(KeyCode::Space);
+    spectator.step_secs(6.0);
+
+    assert_eq!(spectator.state(), AppState::GameOver);
+    assert_eq!(spectator.score(), streamer.score());
+    assert_eq!(player_y(&mut spectator), player_y(&mut streamer));
+}
+
+#[test]
+fn last_bird_standing_wins_the_royale() {
+    let mut game = TestApp::new();
+    game.app.init_resource::<RoyaleRun>();
+    game.start_game();
+    game.tap(KeyCode::Space);
+    assert_eq!(game.app.world().resource::<RunMode>().0, GameMode::Royale);
+
+    for number in 1..=ROYALE_RIVALS {
+        game.app.world_mut().send_event(RivalOut { number });
+    }
+    game.step_n(2);
+
+    let field = u32::from(ROYALE_RIVALS) + 1;
+    assert_eq!(game.state(), AppState::GameOver);
+    assert_eq!(game.score(), placement_points(1, field));
+}
+
+#[test]
+fn dataset_export_writes_one_row_per_tick() {
+    use std::io::Read;
+
+    let dir = std::env::temp_dir().join(format!("flappy-dataset-{}", std::process::id()));
+    let mut game = TestApp::new();
+    game.app.insert_resource(SeedChoice(Some(0xda7a)));
+    game.app.insert_resource(DatasetExport { dir: dir.clone() });
+    game.start_game();
+    game.tap(KeyCode::Space);
+    game.step_secs(3.0);
+    assert_eq!(game.state(), AppState::GameOver);
+
+    let file = std::fs::File::open(dir.join("run-000000000000da7a-1.csv.gz")).unwrap();
+    let mut csv = String::new();
+    flate2::read::GzDecoder::new(file)
+        .read_to_string(&mut csv)
+        .unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let lines: Vec<_> = csv.lines().collect();
+    assert_eq!(lines[0], SCHEMA_HEADER);
+    assert!(lines[1].starts_with("0,"));
+    assert!(lines.last().unwrap().ends_with(",-1,1"));
+}
+
+#[test]
+fn missing_images_are_replaced_with_placeholders() {
+    // Bez loadera PNG żaden obrazek się nie wczytuje.
+    let game = TestApp::new();
+
+    let world = game.app.world();
+    let assets = world.resource::<GameAssets>();
+    let images = world.resource::<Assets<Image>>();
+    let bird = images.get(&assets.bird).expect("placeholder bird");
+    assert_eq!(bird.size(), UVec2::new(34, 24));
+    assert!(assets.ids().all(|id| images.contains(id.typed::<Image>())));
+}
@@ -0,0 +1,201 @@
+// Napisy czytelne na każdym tle: zamiast samego `TextColor` dodajemy
+// `OutlinedText`, a system dorysowuje pod napisem ciemną obwódkę (kopie
+// przesunięte w ośmiu kierunkach) i cień.
+//
+// W świecie gry (`Text2d`) kopie są dziećmi napisu. W interfejsie (`Text`)
+// nie mogą być – węzeł tekstu z dziećmi traci swój rozmiar – więc napis
+// przenosimy do ramki, w której kopie leżą absolutnie pod nim.
+
+use bevy::prelude::*;
+
+const OUTLINE_DIRECTIONS: [Vec2; 8] = [
+    Vec2::new(-1.0, -1.0),
+    Vec2::new(0.0, -1.0),
+    Vec2::new(1.0, -1.0),
+    Vec2::new(-1.0, 0.0),
+    Vec2::new(1.0, 0.0),
+    Vec2::new(-1.0, 1.0),
+    Vec2::new(0.0, 1.0),
+    Vec2::new(1.0, 1.0),
+];
+
+#[derive(Component, Debug, Clone, Copy)]
+#[require(TextColor)]
+pub struct OutlinedText {
+    pub fill: Color,
+    pub outline: Color,
+    pub outline_px: f32,
+    // Przesunięcie cienia w pikselach ekranu (x w prawo, y w dół).
+    pub shadow: Vec2,
+}
+
+impl OutlinedText {
+    pub fn new(fill: Color) -> Self {
+        Self {
+            fill,
+            outline: Color::srgb(0.05, 0.05, 0.08),
+            outline_px: 2.0,
+            shadow: Vec2::new(3.0, 3.0),
+        }
+    }
+
+    // Kopie pod napisem: najpierw cień, potem obwódka.
+    fn layers(&self) -> impl Iterator<Item = (Vec2, Color)> + '_ {
+        let shadow = (self.shadow, self.outline.with_alpha(0.6));
+        std::iter::once(shadow).chain(
+            OUTLINE_DIRECTIONS
+                .iter()
+                .map(|direction| (*direction * self.outline_px, self.outline)),
+        )
+    }
+}
+
+// Kopia napisu `of` tworząca obwódkę lub cień.
+#[derive(Component)]
+struct OutlineLayer {
+    of: Entity,
+}
+
+pub struct TextStylePlugin;
+
+impl Plugin for TextStylePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (outline_world_text, outline_ui_text, sync_outline_layers)
+                .before(bevy::text::update_text2d_layout)
+                .before(bevy::ui::UiSystem::Prepare),
+        );
+    }
+}
+
+fn outline_world_text(
+    mut commands: Commands,
+    added: Query<(Entity, &OutlinedText, &Text2d, Option<&TextFont>), Added<OutlinedText>>,
+) {
+    for (entity, style, text, font) in added.iter() {
+        commands
+            .entity(entity)
+            .insert(TextColor(style.fill))
+            .with_children(|parent| {
+                for (offset, color) in style.layers() {
+                    parent.spawn((
+                        Text2d::new(text.0.clone()),
+                        font.cloned().unwrap_or_default(),
+                        TextColor(color),
+                        // W świecie oś y rośnie w górę, więc cień schodzi w dół przez -y.
+                        Transform::from_xyz(offset.x, -offset.y, -0.01),
+                        OutlineLayer { of: entity },
+                    ));
+                }
+            });
+    }
+}
+
+fn outline_ui_text(
+    mut commands: Commands,
+    added: Query<
+        (
+            Entity,
+            &OutlinedText,
+            &Text,
+            Option<&TextFont>,
+            Option<&TextLayout>,
+            Option<&Parent>,
+        ),
+        Added<OutlinedText>,
+    >,
+    children: Query<&Children>,
+) {
+    for (entity, style, text, font, layout, parent) in added.iter() {
+        commands.entity(entity).insert(TextColor(style.fill));
+
+        // Ramka zajmuje w rodzicu dokładnie miejsce napisu.
+        let frame = commands.spawn(Node::default()).id();
+        if let Some(parent) = parent {
+            let index = children
+                .get(parent.get())
+                .ok()
+                .and_then(|siblings| siblings.iter().position(|child| *child == entity))
+                .unwrap_or(0);
+            commands
+                .entity(parent.get())
+                .insert_children(index, &[frame]);
+        }
+        commands
+            .entity(frame)
+            .with_children(|frame| {
+                for (offset, color) in style.layers() {
+                    frame.spawn((
+                        Text::new(text.0.clone()),
+                        font.cloned().unwrap_or_default(),
+                        layout.copied().unwrap_or_default(),
+                        TextColor(color),
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(offset.x),
+                            top: Val::Px(offset.y),
+                            ..Default::default()
+                        },
+                        ZIndex(-1),
+                        OutlineLayer { of: entity },
+                    ));
+                }
+            })
+            .add_child(entity);
+    }
+}
+
+// Zmiana treści napisu przechodzi na jego kopie.
+fn sync_outline_layers(
+    changed_world: Query<(Entity, &Text2d), (With<OutlinedText>, Changed<Text2d>)>,
+    changed_ui: Query<(Entity, &Text), (With<OutlinedText>, Changed<Text>)>,
+    mut world_layers: Query<(&OutlineLayer, &mut Text2d), Without<OutlinedText>>,
+    mut ui_layers: Query<(&OutlineLayer, &mut Text), Without<OutlinedText>>,
+) {
+    for (entity, text) in changed_world.iter() {
+        for (layer, mut copy) in world_layers.iter_mut() {
+            if layer.of == entity && copy.0 != text.0 {
+                copy.0.clone_from(&text.0);
+            }
+        }
+    }
+    for (entity, text) in changed_ui.iter() {
+        for (layer, mut copy) in ui_layers.iter_mut() {
+            if layer.of == entity && copy.0 != text.0 {
+                copy.0.clone_from(&text.0);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outlined_world_text_gets_shadow_and_outline_layers() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TextStylePlugin));
+        let label = app
+            .world_mut()
+            .spawn((Text2d::new("3"), OutlinedText::new(Color::WHITE)))
+            .id();
+        app.update();
+
+        let world = app.world_mut();
+        assert_eq!(world.get::<TextColor>(label).unwrap().0, Color::WHITE);
+        assert_eq!(world.get::<Children>(label).unwrap().len(), 9);
+
+        world.get_mut::<Text2d>(label).unwrap().0 = "4".to_string();
+        app.update();
+
+        let world = app.world_mut();
+        let mut layers = world.query::<(&OutlineLayer, &Text2d)>();
+        assert!(
+            layers
+                .iter(world)
+                .all(|(layer, text)| layer.of == label && text.0 == "4")
+        );
+    }
+}
@@ -0,0 +1,190 @@
+// Motywy graficzne (tło + rury) oraz wybór motywu na początku każdej rundy.
+// Motyw `Sky` ma tło z shadera (`sky.rs`); obrazek dzienny to tylko zapas.
+
+use std::fs;
+
+use bevy::prelude::*;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+
+use crate::buttons::button_text;
+use crate::settings::Settings;
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+// Katalog z własnymi paczkami motywów: `assets/themes/<nazwa>/{background,pipe}.png`.
+const CUSTOM_THEMES_DIR: &str = "assets/themes";
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Theme {
+    Day,
+    Night,
+    Sunset,
+    Sky,
+    Custom(String),
+}
+
+impl Theme {
+    pub fn background_path(&self) -> String {
+        match self {
+            Theme::Day | Theme::Sunset | Theme::Sky => "sprites/background-day.png".to_string(),
+            Theme::Night => "sprites/background-night.png".to_string(),
+            Theme::Custom(name) => format!("themes/{name}/background.png"),
+        }
+    }
+
+    pub fn pipe_path(&self) -> String {
+        match self {
+            Theme::Day | Theme::Night | Theme::Sky => "sprites/pipe-green.png".to_string(),
+            Theme::Sunset => "sprites/pipe-red.png".to_string(),
+            Theme::Custom(name) => format!("themes/{name}/pipe.png"),
+        }
+    }
+
+    // Zabarwienie tła (zachód słońca to tło dzienne w ciepłych kolorach).
+    pub fn background_tint(&self) -> Color {
+        match self {
+            Theme::Sunset => Color::srgb(1.0, 0.72, 0.55),
+            _ => Color::WHITE,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Theme::Day => "Day".to_string(),
+            Theme::Night => "Night".to_string(),
+            Theme::Sunset => "Sunset".to_string(),
+            Theme::Sky => "Animated sky".to_string(),
+            Theme::Custom(name) => name.clone(),
+        }
+    }
+}
+
+// Ustawienie motywu: konkretny albo losowany przy każdej rundzie.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ThemeChoice {
+    Fixed(Theme),
+    RandomPerRun,
+}
+
+impl Default for ThemeChoice {
+    fn default() -> Self {
+        ThemeChoice::Fixed(Theme::Day)
+    }
+}
+
+impl ThemeChoice {
+    pub fn label(&self) -> String {
+        match self {
+            ThemeChoice::Fixed(theme) => theme.label(),
+            ThemeChoice::RandomPerRun => "Random".to_string(),
+        }
+    }
+}
+
+// Motyw obowiązujący w bieżącej rundzie.
+#[derive(Resource, Debug, Clone)]
+pub struct ActiveTheme(pub Theme);
+
+impl Default for ActiveTheme {
+    fn default() -> Self {
+        ActiveTheme(Theme::Day)
+    }
+}
+
+// Wszystkie dostępne motywy: wbudowane + znalezione paczki użytkownika.
+#[derive(Resource, Debug, Clone)]
+pub struct ThemeCatalog(pub Vec<Theme>);
+
+impl ThemeCatalog {
+    pub fn discover() -> Self {
+        let mut themes = vec![Theme::Day, Theme::Night, Theme::Sunset, Theme::Sky];
+
+        if let Ok(entries) = fs::read_dir(CUSTOM_THEMES_DIR) {
+            let mut custom: Vec<String> = entries
+                .filter_map(Result::ok)
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .collect();
+            custom.sort();
+            themes.extend(custom.into_iter().map(Theme::Custom));
+        }
+
+        ThemeCatalog(themes)
+    }
+
+    // Kolejna opcja w menu: motywy po kolei, a na końcu "Random".
+    pub fn next_choice(&self, current: &ThemeChoice) -> ThemeChoice {
+        match current {
+            ThemeChoice::RandomPerRun => ThemeChoice::Fixed(self.0[0].clone()),
+            ThemeChoice::Fixed(theme) => {
+                let index = self.0.iter().position(|t| t == theme).unwrap_or(0);
+                match self.0.get(index + 1) {
+                    Some(next) => ThemeChoice::Fixed(next.clone()),
+                    None => ThemeChoice::RandomPerRun,
+                }
+            }
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct ThemeButton;
+
+pub struct ThemePlugin;
+
+impl Plugin for ThemePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ThemeCatalog::discover())
+            .init_resource::<ActiveTheme>()
+            .add_systems(OnEnter(AppState::Playing), pick_run_theme)
+            .add_systems(Update, theme_button_system.run_if(in_state(AppState::Menu)));
+    }
+}
+
+// Ustala motyw rundy na podstawie ustawień (losując, jeśli trzeba).
+pub fn pick_run_theme(
+    settings: Res<Settings>,
+    catalog: Res<ThemeCatalog>,
+    mut active: ResMut<ActiveTheme>,
+) {
+    active.0 = match &settings.theme {
+        ThemeChoice::Fixed(theme) => theme.clone(),
+        ThemeChoice::RandomPerRun => catalog
+            .0
+            .choose(&mut rand::thread_rng())
+            .cloned()
+            .unwrap_or(Theme::Day),
+    };
+}
+
+pub fn theme_button_label(choice: &ThemeChoice) -> String {
+    format!("Theme: {}", choice.label())
+}
+
+fn theme_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, Option<&Children>),
+        (Changed<Interaction>, With<ThemeButton>),
+    >,
+    mut text_query: Query<&mut Text>,
+    catalog: Res<ThemeCatalog>,
+    mut settings: ResMut<Settings>,
+) {
+    for (interaction, mut bg_color, children) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                settings.theme = catalog.next_choice(&settings.theme);
+                if let Some(mut text) = button_text(children, &mut text_query) {
+                    text.0 = theme_button_label(&settings.theme);
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+// Logo "Flappy Bird" na górze menu: napis z obwódką i machający skrzydłami
+// ptak obok, całość kołysze się w górę i w dół (`UiBobTween`). W trybie
+// ograniczonego ruchu logo stoi w miejscu, a ptak nie macha.
+
+use bevy::math::curve::EaseFunction;
+use bevy::prelude::*;
+
+use crate::AppState;
+use crate::assets::GameAssets;
+use crate::settings::Settings;
+use crate::text_style::OutlinedText;
+use crate::tween::UiBobTween;
+
+const TITLE_COLOR: Color = Color::srgb(1.0, 0.85, 0.25);
+// Wychylenie kołysania w pikselach i czas przejścia w jedną stronę.
+const BOB_PX: f32 = 8.0;
+const BOB_HALF_PERIOD_SECS: f32 = 0.9;
+const FLAP_FRAME_SECS: f32 = 0.12;
+// Ptak w logo jest dwa razy większy niż w grze.
+const BIRD_SCALE: f32 = 2.0;
+
+#[derive(Component)]
+struct FlappingBird {
+    timer: Timer,
+    frame: usize,
+}
+
+pub struct TitlePlugin;
+
+impl Plugin for TitlePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, flap_title_bird.run_if(in_state(AppState::Menu)));
+    }
+}
+
+// Wstawia logo jako pierwszy element kolumny menu.
+pub fn spawn_title_logo(parent: &mut ChildBuilder, assets: &GameAssets, settings: &Settings) {
+    let animated = !settings.accessibility.reduced_motion;
+    let mut logo = parent.spawn(Node {
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(16.0),
+        margin: UiRect::bottom(Val::Px(10.0)),
+        ..Default::default()
+    });
+    if animated {
+        logo.insert(UiBobTween::new(
+            -BOB_PX,
+            BOB_PX,
+            BOB_HALF_PERIOD_SECS,
+            EaseFunction::SineInOut,
+        ));
+    }
+    logo.with_children(|logo| {
+        logo.spawn((
+            Text::new("Flappy Bird"),
+            OutlinedText::new(TITLE_COLOR),
+            TextFont {
+                font_size: 56.0,
+                ..Default::default()
+            },
+        ));
+        let mut bird = logo.spawn((
+            ImageNode::new(assets.bird_frames[1].clone()),
+            Node {
+                width: Val::Px(34.0 * BIRD_SCALE),
+                height: Val::Px(24.0 * BIRD_SCALE),
+                ..Default::default()
+            },
+        ));
+        if animated {
+            bird.insert(FlappingBird {
+                timer: Timer::from_seconds(FLAP_FRAME_SECS, TimerMode::Repeating),
+                frame: 1,
+            });
+        }
+    });
+}
+
+fn flap_title_bird(
+    time: Res<Time>,
+    assets: Res<GameAssets>,
+    mut birds: Query<(&mut FlappingBird, &mut ImageNode)>,
+) {
+    for (mut bird, mut image) in birds.iter_mut() {
+        if !bird.timer.tick(time.delta()).just_finished() {
+            continue;
+        }
+        bird.frame = (bird.frame + 1) % assets.bird_frames.len();
+        image.image = assets.bird_frames[bird.frame].clone();
+    }
+}
@@ -0,0 +1,94 @@
+// Krótkie powiadomienia ("tosty") wyświetlane na dole ekranu.
+
+use bevy::prelude::*;
+
+// Jak długo tost jest widoczny.
+const TOAST_SECONDS: f32 = 3.0;
+
+// Wyślij to zdarzenie, żeby pokazać komunikat.
+#[derive(Event)]
+pub struct Toast(pub String);
+
+#[derive(Component)]
+struct ToastStack;
+
+#[derive(Component)]
+struct ToastMessage {
+    remaining: f32,
+}
+
+pub struct ToastPlugin;
+
+impl Plugin for ToastPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Toast>()
+            .add_systems(Startup, spawn_toast_stack)
+            .add_systems(Update, (show_toasts, expire_toasts));
+    }
+}
+
+fn spawn_toast_stack(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(20.0),
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::ColumnReverse,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(8.0),
+            ..Default::default()
+        },
+        GlobalZIndex(20),
+        ToastStack,
+    ));
+}
+
+fn show_toasts(
+    mut commands: Commands,
+    mut toasts: EventReader<Toast>,
+    stack_query: Query<Entity, With<ToastStack>>,
+) {
+    let Ok(stack) = stack_query.get_single() else {
+        return;
+    };
+
+    for toast in toasts.read() {
+        info!("{}", toast.0);
+        commands.entity(stack).with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        padding: UiRect::axes(Val::Px(16.0), Val::Px(8.0)),
+                        ..Default::default()
+                    },
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+                    BorderRadius::all(Val::Px(8.0)),
+                    ToastMessage {
+                        remaining: TOAST_SECONDS,
+                    },
+                ))
+                .with_child((
+                    Text::new(toast.0.clone()),
+                    TextColor(Color::WHITE),
+                    TextFont {
+                        font_size: 18.0,
+                        ..Default::default()
+                    },
+                ));
+        });
+    }
+}
+
+// Tosty znikają po czasie liczonym w czasie rzeczywistym (działają też w pauzie).
+fn expire_toasts(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut ToastMessage)>,
+    time: Res<Time<Real>>,
+) {
+    for (entity, mut toast) in query.iter_mut() {
+        toast.remaining -= time.delta_secs();
+        if toast.remaining <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,485 @@
+// Turniej tygodniowy: przez cały tydzień (od poniedziałku, w UTC) wszyscy grają
+// na tym samym ziarnie z jedną zmianą zasad – wiatrem, ciasnymi przerwami albo
+// odwróconą grawitacją. Harmonogram jest wbudowany (`assets/tournament.ron`),
+// a pobrany plik `tournament.ron` obok gry go zastępuje.
+//
+// Przycisk w prawym górnym rogu menu startuje rundę tygodnia i odlicza czas do
+// końca tygodnia; obok osobna tablica wyników bieżącego tygodnia. Rundy
+// turniejowe zapisujemy jako tryb `GameMode::Weekly`, a tydzień rozpoznajemy
+// po ziarnie.
+
+use std::fs;
+
+use bevy::prelude::*;
+use bevy::utils::SystemTime;
+use serde::{Deserialize, Serialize};
+
+use crate::bests::{GameMode, PersonalBests, RunMode, RunRecord};
+use crate::buttons::button_text;
+use crate::core::PipeParams;
+use crate::menu::Menu;
+use crate::physics::{Gravity, Velocity};
+use crate::player::{HoverIdle, Player};
+use crate::rng::RunRng;
+use crate::score::Score;
+use crate::settings::Settings;
+use crate::{AppState, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+const SCHEDULE_PATH: &str = "tournament.ron";
+const BUNDLED_SCHEDULE: &str = include_str!("../assets/tournament.ron");
+
+const DAY_SECS: u64 = 24 * 60 * 60;
+const WEEK_SECS: u64 = 7 * DAY_SECS;
+// 1 stycznia 1970 był czwartkiem – tyle dni brakuje do poniedziałku.
+const EPOCH_WEEKDAY_OFFSET: u64 = 3;
+
+// Przyspieszenie wiatru na szczycie podmuchu i czas pełnego cyklu.
+const WIND_ACCEL: f32 = 140.0;
+const WIND_PERIOD_SECS: f32 = 4.0;
+pub const TINY_GAP_SCALE: f32 = 0.8;
+// Ile pozycji mieści tablica tygodnia.
+const BOARD_ENTRIES: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Modifier {
+    // Ptaka na zmianę podnoszą i spychają podmuchy wiatru.
+    Wind,
+    // Przerwy między rurami są o 20% mniejsze.
+    TinyGaps,
+    // Grawitacja ciągnie w górę, a machnięcie pcha w dół.
+    Reverse,
+}
+
+impl Modifier {
+    pub fn label(self) -> &'static str {
+        match self {
+            Modifier::Wind => "Wind week",
+            Modifier::TinyGaps => "Tiny-gap week",
+            Modifier::Reverse => "Reverse week",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TournamentWeek {
+    pub seed: u64,
+    pub modifier: Modifier,
+}
+
+// Tygodnie turnieju po kolei; lista się zapętla.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct TournamentSchedule {
+    pub weeks: Vec<TournamentWeek>,
+}
+
+impl Default for TournamentSchedule {
+    fn default() -> Self {
+        ron::from_str(BUNDLED_SCHEDULE).unwrap_or_else(|err| {
+            warn!("Nie udało się odczytać wbudowanego harmonogramu turnieju: {err}");
+            TournamentSchedule { weeks: Vec::new() }
+        })
+    }
+}
+
+impl TournamentSchedule {
+    // Pobrany harmonogram albo wbudowany, gdy pliku nie ma lub jest błędny.
+    pub fn load() -> Self {
+        let Ok(contents) = fs::read_to_string(SCHEDULE_PATH) else {
+            return TournamentSchedule::default();
+        };
+        ron::from_str(&contents).unwrap_or_else(|err| {
+            warn!("Nie udało się odczytać {SCHEDULE_PATH}: {err}");
+            TournamentSchedule::default()
+        })
+    }
+
+    pub fn week(&self, index: u64) -> Option<TournamentWeek> {
+        if self.weeks.is_empty() {
+            return None;
+        }
+        Some(self.weeks[(index % self.weeks.len() as u64) as usize])
+    }
+}
+
+// Numer tygodnia (od poniedziałku) i sekundy do jego końca dla czasu uniksowego.
+pub fn week_at(unix_secs: u64) -> (u64, u64) {
+    let shifted = unix_secs + EPOCH_WEEKDAY_OFFSET * DAY_SECS;
+    (shifted / WEEK_SECS, WEEK_SECS - shifted % WEEK_SECS)
+}
+
+fn now_unix_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+// "Ends in 3d 4h", a w ostatnim dniu z minutami.
+pub fn countdown_label(remaining_secs: u64) -> String {
+    let days = remaining_secs / DAY_SECS;
+    let hours = remaining_secs % DAY_SECS / 3600;
+    let minutes = remaining_secs % 3600 / 60;
+    if days > 0 {
+        format!("Ends in {days}d {hours}h")
+    } else {
+        format!("Ends in {hours}h {minutes:02}m")
+    }
+}
+
+// Obecny, gdy gracz wybrał rundę turniejową; znika po powrocie do menu.
+#[derive(Resource, Debug, Clone)]
+pub struct WeeklyRun {
+    pub week: TournamentWeek,
+    // Czas rundy dla podmuchów wiatru – każda runda zaczyna od tej samej fazy.
+    wind_clock: f32,
+}
+
+impl WeeklyRun {
+    pub fn new(week: TournamentWeek) -> Self {
+        Self {
+            week,
+            wind_clock: 0.0,
+        }
+    }
+
+    pub fn is(&self, modifier: Modifier) -> bool {
+        self.week.modifier == modifier
+    }
+}
+
+// Parametry rur po zmianie zasad tygodnia.
+pub fn apply_rules(mut params: PipeParams, weekly: Option<&WeeklyRun>) -> PipeParams {
+    if weekly.is_some_and(|weekly| weekly.is(Modifier::TinyGaps)) {
+        params.gap *= TINY_GAP_SCALE;
+    }
+    params
+}
+
+#[derive(Component)]
+struct TournamentButton;
+
+#[derive(Component)]
+struct CountdownText;
+
+#[derive(Component)]
+struct WeeklyBoardButton;
+
+#[derive(Component)]
+struct WeeklyBoard;
+
+pub struct TournamentPlugin;
+
+impl Plugin for TournamentPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TournamentSchedule>()
+            .add_systems(
+                OnEnter(AppState::Menu),
+                (
+                    end_weekly_run,
+                    spawn_tournament_panel.after(crate::menu::setup_menu),
+                ),
+            )
+            .add_systems(
+                Update,
+                (tournament_buttons_system, update_countdown).run_if(in_state(AppState::Menu)),
+            )
+            .add_systems(
+                OnEnter(AppState::Playing),
+                start_weekly_run
+                    .after(crate::rng::reseed_run_rng)
+                    .before(crate::replay::start_recording)
+                    .run_if(resource_exists::<WeeklyRun>),
+            )
+            .add_systems(
+                FixedUpdate,
+                apply_wind
+                    .before(crate::physics::gravity_system)
+                    .run_if(in_state(crate::pause::PauseState::Running))
+                    .run_if(resource_exists::<WeeklyRun>),
+            )
+            .add_systems(
+                OnEnter(AppState::GameOver),
+                record_weekly_best
+                    .after(crate::bests::record_best)
+                    .before(crate::game_over::on_enter_game_over)
+                    .run_if(resource_exists::<WeeklyRun>),
+            );
+    }
+}
+
+pub fn current_week(schedule: &TournamentSchedule) -> Option<(TournamentWeek, u64)> {
+    let (index, remaining) = week_at(now_unix_secs());
+    schedule.week(index).map(|week| (week, remaining))
+}
+
+// Każda runda tygodnia leci na ziarnie tygodnia, z jego zasadami.
+fn start_weekly_run(
+    mut weekly: ResMut<WeeklyRun>,
+    mut rng: ResMut<RunRng>,
+    mut mode: ResMut<RunMode>,
+    mut gravity: ResMut<Gravity>,
+) {
+    *rng = RunRng::new(weekly.week.seed);
+    mode.0 = GameMode::Weekly;
+    weekly.wind_clock = 0.0;
+    gravity.0 = if weekly.is(Modifier::Reverse) {
+        -crate::core::GRAVITY
+    } else {
+        crate::core::GRAVITY
+    };
+}
+
+fn end_weekly_run(
+    mut commands: Commands,
+    weekly: Option<Res<WeeklyRun>>,
+    mut mode: ResMut<RunMode>,
+    mut gravity: ResMut<Gravity>,
+) {
+    if weekly.is_none() {
+        return;
+    }
+    commands.remove_resource::<WeeklyRun>();
+    mode.0 = GameMode::Classic;
+    gravity.0 = crate::core::GRAVITY;
+}
+
+fn apply_wind(
+    time: Res<Time>,
+    mut weekly: ResMut<WeeklyRun>,
+    mut player: Query<&mut Velocity, (With<Player>, Without<HoverIdle>)>,
+) {
+    if !weekly.is(Modifier::Wind) {
+        return;
+    }
+    let Ok(mut velocity) = player.get_single_mut() else {
+        return;
+    };
+    let dt = time.delta_secs();
+    weekly.wind_clock += dt;
+    velocity.dy += wind_accel(weekly.wind_clock) * dt;
+}
+
+// Przyspieszenie od wiatru po `clock` sekundach rundy (także w mutatorze
+// wiatru z `mutators.rs`).
+pub fn wind_accel(clock: f32) -> f32 {
+    let phase = clock / WIND_PERIOD_SECS * std::f32::consts::TAU;
+    WIND_ACCEL * phase.sin()
+}
+
+// Rekord na ekranie końca gry dotyczy tylko bieżącego tygodnia.
+fn record_weekly_best(
+    weekly: Res<WeeklyRun>,
+    score: Res<Score>,
+    mode: Res<RunMode>,
+    settings: Res<Settings>,
+    bests: Res<PersonalBests>,
+    mut record: ResMut<RunRecord>,
+) {
+    let key = crate::bests::current_key(&mode, &settings);
+    let mut scores = bests
+        .leaderboard(key)
+        .filter(|entry| entry.seed == weekly.week.seed)
+        .map(|entry| entry.score);
+    let best = scores.next().unwrap_or(0).max(score.0);
+    let runner_up = scores.next().unwrap_or(0);
+    record.best = best;
+    record.new_record = score.0 > 0 && score.0 == best && runner_up < best;
+}
+
+fn spawn_tournament_panel(mut commands: Commands, schedule: Res<TournamentSchedule>) {
+    let Some((week, remaining)) = current_week(&schedule) else {
+        return;
+    };
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(20.0),
+                top: Val::Px(20.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::End,
+                row_gap: Val::Px(6.0),
+                ..Default::default()
+            },
+            Menu,
+        ))
+        .with_children(|parent| {
+            spawn_button(
+                parent,
+                format!("Weekly: {}", week.modifier.label()),
+                TournamentButton,
+            );
+            parent.spawn((
+                Text::new(countdown_label(remaining)),
+                TextColor(Color::WHITE),
+                TextFont {
+                    font_size: 16.0,
+                    ..Default::default()
+                },
+                CountdownText,
+            ));
+            spawn_button(parent, "Weekly board".to_string(), WeeklyBoardButton);
+        });
+}
+
+fn spawn_button(parent: &mut ChildBuilder, label: String, marker: impl Bundle) {
+    parent
+        .spawn((
+            Button,
+            Interaction::default(),
+            Node {
+                padding: UiRect::axes(Val::Px(16.0), Val::Px(10.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(NORMAL_BUTTON),
+            BorderRadius::all(Val::Px(8.0)),
+            marker,
+        ))
+        .with_child((Text::new(label), TextColor(Color::WHITE)));
+}
+
+// Co sekundę odświeża odliczanie; po północy w niedzielę także nazwę tygodnia.
+fn update_countdown(
+    schedule: Res<TournamentSchedule>,
+    mut countdowns: Query<&mut Text, With<CountdownText>>,
+    buttons: Query<Option<&Children>, With<TournamentButton>>,
+    mut texts: Query<&mut Text, Without<CountdownText>>,
+) {
+    let Some((week, remaining)) = current_week(&schedule) else {
+        return;
+    };
+    let label = countdown_label(remaining);
+    for mut text in countdowns.iter_mut() {
+        if text.0 != label {
+            text.0 = label.clone();
+        }
+    }
+    let title = format!("Weekly: {}", week.modifier.label());
+    for children in buttons.iter() {
+        if let Some(mut text) = button_text(children, &mut texts) {
+            if text.0 != title {
+                text.0 = title.clone();
+            }
+        }
+    }
+}
+
+fn tournament_buttons_system(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, Has<TournamentButton>),
+        (
+            Changed<Interaction>,
+            Or<(With<TournamentButton>, With<WeeklyBoardButton>)>,
+        ),
+    >,
+    schedule: Res<TournamentSchedule>,
+    settings: Res<Settings>,
+    bests: Res<PersonalBests>,
+    boards: Query<Entity, With<WeeklyBoard>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, mut bg_color, start) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                let Some((week, _)) = current_week(&schedule) else {
+                    continue;
+                };
+                if start {
+                    commands.insert_resource(WeeklyRun::new(week));
+                    next_state.set(AppState::Playing);
+                } else if boards.is_empty() {
+                    spawn_weekly_board(&mut commands, week, &settings, &bests);
+                } else {
+                    for board in boards.iter() {
+                        commands.entity(board).despawn_recursive();
+                    }
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
+// Najlepsze rundy tego tygodnia na bieżącym poziomie trudności.
+fn spawn_weekly_board(
+    commands: &mut Commands,
+    week: TournamentWeek,
+    settings: &Settings,
+    bests: &PersonalBests,
+) {
+    let key = crate::bests::current_key(&RunMode(GameMode::Weekly), settings);
+    let scores: Vec<i32> = bests
+        .leaderboard(key)
+        .filter(|entry| entry.seed == week.seed)
+        .take(BOARD_ENTRIES)
+        .map(|entry| entry.score)
+        .collect();
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(20.0),
+                top: Val::Px(150.0),
+                width: Val::Px(220.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(4.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            BorderRadius::all(Val::Px(8.0)),
+            WeeklyBoard,
+            Menu,
+        ))
+        .with_children(|parent| {
+            parent.spawn((Text::new(week.modifier.label()), TextColor(Color::WHITE)));
+            if scores.is_empty() {
+                parent.spawn((
+                    Text::new("No runs this week yet"),
+                    TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                ));
+            }
+            for (place, score) in scores.iter().enumerate() {
+                parent.spawn((
+                    Text::new(format!("{}. {score}", place + 1)),
+                    TextColor(Color::WHITE),
+                ));
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weeks_start_on_monday() {
+        // Poniedziałek 5 stycznia 1970, północ UTC.
+        let monday = 4 * DAY_SECS;
+        assert_eq!(week_at(monday - 1), (0, 1));
+        assert_eq!(week_at(monday), (1, WEEK_SECS));
+        assert_eq!(countdown_label(3 * DAY_SECS + 4 * 3600), "Ends in 3d 4h");
+        assert_eq!(countdown_label(5 * 3600 + 7 * 60), "Ends in 5h 07m");
+    }
+
+    #[test]
+    fn bundled_schedule_rotates_every_modifier() {
+        let schedule = TournamentSchedule::default();
+        assert!(!schedule.weeks.is_empty());
+        for modifier in [Modifier::Wind, Modifier::TinyGaps, Modifier::Reverse] {
+            assert!(schedule.weeks.iter().any(|week| week.modifier == modifier));
+        }
+        assert_eq!(schedule.week(0), schedule.week(schedule.weeks.len() as u64));
+    }
+}
@@ -0,0 +1,159 @@
+// Tryb "Tower climb": ptak wspina się w górę wieży, a przerwy w poziomych
+// ścianach przesuwają się w bok. Symulacja zostaje ta sama co w zwykłej grze
+// – obracamy tylko kamerę o ćwierć obrotu, więc rury nadlatujące z prawej
+// zjeżdżają z góry ekranu, grawitacja ciągnie w prawo, a machnięcie odpycha
+// w lewo. Dzięki temu wzory przeszkód, kolizje i powtórki działają bez zmian.
+//
+// Tło i licznik wyniku obracamy z powrotem, żeby stały prosto; przejścia
+// między biomami przewijają się wtedy w pionie. Przycisk w lewym górnym rogu
+// menu startuje rundę; rundy zapisujemy jako tryb `GameMode::Tower`.
+
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+
+use crate::bests::{GameMode, RunMode};
+use crate::menu::Menu;
+use crate::score::ScoreText;
+use crate::{AppState, Background, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
+
+// Pozycja licznika wyniku w zwykłej grze i w wieży (u góry obróconego ekranu).
+const SCORE_TEXT_POS: Vec3 = Vec3::new(0.0, 250.0, 10.0);
+const TOWER_SCORE_TEXT_POS: Vec3 = Vec3::new(250.0, 0.0, 10.0);
+
+// Obecny, gdy gracz wybrał wspinaczkę; znika po powrocie do menu.
+#[derive(Resource, Default)]
+pub struct TowerRun;
+
+#[derive(Component)]
+struct TowerButton;
+
+pub struct TowerPlugin;
+
+impl Plugin for TowerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(AppState::Menu),
+            (
+                end_tower_run,
+                spawn_tower_button.after(crate::menu::setup_menu),
+            ),
+        )
+        .add_systems(Update, tower_button_system.run_if(in_state(AppState::Menu)))
+        .add_systems(
+            OnEnter(AppState::Playing),
+            start_tower_run
+                .after(crate::restart_game)
+                .run_if(resource_exists::<TowerRun>),
+        )
+        .add_systems(
+            Update,
+            keep_scene_upright.run_if(in_state(AppState::Playing).and(resource_exists::<TowerRun>)),
+        );
+    }
+}
+
+// Świat widziany przez kamerę obróconą o -90°: +x świata to góra ekranu.
+fn camera_rotation() -> Quat {
+    Quat::from_rotation_z(-FRAC_PI_2)
+}
+
+fn start_tower_run(
+    mut mode: ResMut<RunMode>,
+    mut cameras: Query<&mut Transform, With<Camera2d>>,
+    mut score_text: Query<&mut Transform, (With<ScoreText>, Without<Camera2d>)>,
+) {
+    mode.0 = GameMode::Tower;
+    for mut transform in cameras.iter_mut() {
+        transform.rotation = camera_rotation();
+    }
+    for mut transform in score_text.iter_mut() {
+        transform.translation = TOWER_SCORE_TEXT_POS;
+    }
+}
+
+fn end_tower_run(
+    mut commands: Commands,
+    tower: Option<Res<TowerRun>>,
+    mut mode: ResMut<RunMode>,
+    mut cameras: Query<&mut Transform, With<Camera2d>>,
+    mut backgrounds: Query<
+        &mut Transform,
+        (With<Background>, Without<ScoreText>, Without<Camera2d>),
+    >,
+    mut score_text: Query<&mut Transform, (With<ScoreText>, Without<Camera2d>)>,
+) {
+    if tower.is_none() {
+        return;
+    }
+    commands.remove_resource::<TowerRun>();
+    mode.0 = GameMode::Classic;
+    for mut transform in cameras.iter_mut().chain(backgrounds.iter_mut()) {
+        transform.rotation = Quat::IDENTITY;
+    }
+    for mut transform in score_text.iter_mut() {
+        *transform = Transform::from_translation(SCORE_TEXT_POS);
+    }
+}
+
+// Tło (także nowe, z przejścia biomu) i licznik obracamy przeciwnie do kamery.
+fn keep_scene_upright(
+    mut upright: Query<
+        &mut Transform,
+        (Or<(With<Background>, With<ScoreText>)>, Without<Camera2d>),
+    >,
+) {
+    let rotation = camera_rotation().inverse();
+    for mut transform in upright.iter_mut() {
+        if transform.rotation != rotation {
+            transform.rotation = rotation;
+        }
+    }
+}
+
+fn spawn_tower_button(mut commands: Commands) {
+    commands
+        .spawn((
+            Button,
+            Interaction::default(),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(20.0),
+                top: Val::Px(70.0),
+                padding: UiRect::axes(Val::Px(16.0), Val::Px(10.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(NORMAL_BUTTON),
+            BorderRadius::all(Val::Px(8.0)),
+            TowerButton,
+            Menu,
+        ))
+        .with_child((Text::new("Tower climb"), TextColor(Color::WHITE)));
+}
+
+fn tower_button_system(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<TowerButton>),
+    >,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, mut bg_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PRESSED_BUTTON.into();
+                commands.init_resource::<TowerRun>();
+                next_state.set(AppState::Playing);
+            }
+            Interaction::Hovered => {
+                *bg_color = HOVERED_BUTTON.into();
+            }
+            Interaction::None => {
+                *bg_color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
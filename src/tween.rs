@@ -0,0 +1,109 @@
+// Mały podsystem tweenów – płynne zmiany Transform sterowane krzywymi easing.
+
+use bevy::math::curve::{Curve, EaseFunction, EasingCurve};
+use bevy::prelude::*;
+
+pub struct TweenPlugin;
+
+impl Plugin for TweenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (tween_scale_system, tween_ui_bob_system));
+    }
+}
+
+// Animacja skali od `from` do `to` w czasie `duration` sekund.
+// Komponent usuwa się sam po zakończeniu.
+#[derive(Component)]
+pub struct ScaleTween {
+    curve: EasingCurve<Vec3>,
+    duration: f32,
+    elapsed: f32,
+}
+
+impl ScaleTween {
+    pub fn new(from: Vec3, to: Vec3, duration: f32, ease: EaseFunction) -> Self {
+        Self {
+            curve: EasingCurve::new(from, to, ease),
+            duration,
+            elapsed: 0.0,
+        }
+    }
+
+    pub fn finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return 1.0;
+        }
+        (self.elapsed / self.duration).clamp(0.0, 1.0)
+    }
+}
+
+fn tween_scale_system(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Transform, &mut ScaleTween)>,
+    time: Res<Time>,
+) {
+    for (entity, mut transform, mut tween) in query.iter_mut() {
+        tween.elapsed += time.delta_secs();
+        transform.scale = tween.curve.sample_clamped(tween.progress());
+
+        if tween.finished() {
+            commands.entity(entity).remove::<ScaleTween>();
+        }
+    }
+}
+
+// Zapętlone kołysanie węzła UI: `Node::top` tam i z powrotem między `from`
+// a `to` (w pikselach). Węzłów UI nie przesuwamy przez `Transform`, bo układ
+// nadpisuje jego pozycję w każdej klatce.
+#[derive(Component)]
+pub struct UiBobTween {
+    curve: EasingCurve<f32>,
+    half_period: f32,
+    elapsed: f32,
+}
+
+impl UiBobTween {
+    pub fn new(from: f32, to: f32, half_period: f32, ease: EaseFunction) -> Self {
+        Self {
+            curve: EasingCurve::new(from, to, ease),
+            half_period,
+            elapsed: 0.0,
+        }
+    }
+
+    // Postęp w tę i z powrotem: 0 → 1 → 0 co dwa półokresy.
+    fn progress(&self) -> f32 {
+        if self.half_period <= 0.0 {
+            return 0.0;
+        }
+        let t = self.elapsed / self.half_period;
+        if t > 1.0 { 2.0 - t } else { t }
+    }
+}
+
+fn tween_ui_bob_system(mut query: Query<(&mut Node, &mut UiBobTween)>, time: Res<Time>) {
+    for (mut node, mut tween) in query.iter_mut() {
+        tween.elapsed = (tween.elapsed + time.delta_secs()) % (tween.half_period * 2.0);
+        node.top = Val::Px(tween.curve.sample_clamped(tween.progress()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bob_goes_there_and_back() {
+        let mut tween = UiBobTween::new(0.0, 10.0, 1.0, EaseFunction::Linear);
+        tween.elapsed = 0.5;
+        assert_eq!(tween.curve.sample_clamped(tween.progress()), 5.0);
+        tween.elapsed = 1.0;
+        assert_eq!(tween.curve.sample_clamped(tween.progress()), 10.0);
+        tween.elapsed = 1.5;
+        assert_eq!(tween.curve.sample_clamped(tween.progress()), 5.0);
+    }
+}
@@ -0,0 +1,161 @@
+// Zapamiętywanie rozmiaru, pozycji i monitora okna między sesjami.
+// Przy starcie okno dostaje zapisaną geometrię (patrz `main`), a gdy monitory
+// są już znane, sprawdzamy, czy nadal mieści się ono na którymś z nich.
+
+use bevy::prelude::*;
+use bevy::window::{
+    Monitor, MonitorSelection, PrimaryWindow, WindowMode, WindowMoved, WindowPosition,
+    WindowResized,
+};
+
+use crate::settings::Settings;
+
+// Po tylu sekundach bez ruchu okna zmiany trafiają do ustawień (i na dysk).
+const SAVE_DELAY: f32 = 0.5;
+
+pub struct WindowGeometryPlugin;
+
+impl Plugin for WindowGeometryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PendingGeometry>().add_systems(
+            Update,
+            (
+                clamp_to_monitors.run_if(not(resource_exists::<GeometryRestored>)),
+                track_window_geometry,
+                flush_window_geometry,
+            )
+                .chain(),
+        );
+    }
+}
+
+// Wstawiany po jednorazowym dopasowaniu okna do aktualnego układu monitorów.
+#[derive(Resource)]
+struct GeometryRestored;
+
+#[derive(Resource, Default)]
+struct PendingGeometry {
+    size: Option<Vec2>,
+    position: Option<IVec2>,
+    quiet_for: f32,
+}
+
+fn monitor_rect(monitor: &Monitor) -> IRect {
+    IRect::from_corners(
+        monitor.physical_position,
+        monitor.physical_position
+            + IVec2::new(
+                monitor.physical_width as i32,
+                monitor.physical_height as i32,
+            ),
+    )
+}
+
+// Monitor zawierający dany punkt (w pikselach fizycznych).
+fn monitor_at<'a>(
+    mut monitors: impl Iterator<Item = (usize, &'a Monitor)>,
+    point: IVec2,
+) -> Option<(usize, &'a Monitor)> {
+    monitors.find(|(_, monitor)| monitor_rect(monitor).contains(point))
+}
+
+fn clamp_to_monitors(
+    mut commands: Commands,
+    monitors: Query<&Monitor>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+    settings: Res<Settings>,
+) {
+    // Monitory pojawiają się dopiero po uruchomieniu pętli zdarzeń.
+    if monitors.is_empty() {
+        return;
+    }
+    commands.insert_resource(GeometryRestored);
+
+    let Ok(mut window) = window_query.get_single_mut() else {
+        return;
+    };
+    let Some(saved_position) = settings.window.position else {
+        return;
+    };
+
+    // Najpierw szukamy monitora po nazwie, potem po pozycji okna.
+    let by_name = settings.window.monitor.as_ref().and_then(|name| {
+        monitors
+            .iter()
+            .enumerate()
+            .find(|(_, monitor)| monitor.name.as_ref() == Some(name))
+    });
+    let target = by_name.or_else(|| monitor_at(monitors.iter().enumerate(), saved_position));
+
+    let Some((index, monitor)) = target else {
+        // Układ monitorów się zmienił i okno wylądowałoby poza ekranem.
+        info!("Zapisana pozycja okna jest poza ekranem – centruję na głównym monitorze");
+        window.position = WindowPosition::Centered(MonitorSelection::Primary);
+        return;
+    };
+
+    let rect = monitor_rect(monitor);
+    let scale = monitor.scale_factor as f32;
+    let max_size = rect.size().as_vec2() / scale;
+    let size = Vec2::new(window.resolution.width(), window.resolution.height()).min(max_size);
+    window.resolution.set(size.x, size.y);
+
+    let physical_size = (size * scale).as_ivec2();
+    if rect.contains(saved_position) {
+        let position = saved_position.clamp(rect.min, (rect.max - physical_size).max(rect.min));
+        window.position = WindowPosition::At(position);
+    } else {
+        window.position = WindowPosition::Centered(MonitorSelection::Index(index));
+    }
+}
+
+fn track_window_geometry(
+    mut moved: EventReader<WindowMoved>,
+    mut resized: EventReader<WindowResized>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut pending: ResMut<PendingGeometry>,
+    time: Res<Time>,
+) {
+    let Ok(window) = window_query.get_single() else {
+        return;
+    };
+
+    let mut changed = false;
+    for event in moved.read() {
+        pending.position = Some(event.position);
+        changed = true;
+    }
+    for event in resized.read() {
+        // Rozmiaru pełnego ekranu nie zapamiętujemy jako rozmiaru okna.
+        if matches!(window.mode, WindowMode::Windowed) {
+            pending.size = Some(Vec2::new(event.width, event.height));
+            changed = true;
+        }
+    }
+
+    if changed {
+        pending.quiet_for = 0.0;
+    } else {
+        pending.quiet_for += time.delta_secs();
+    }
+}
+
+fn flush_window_geometry(
+    mut pending: ResMut<PendingGeometry>,
+    monitors: Query<&Monitor>,
+    mut settings: ResMut<Settings>,
+) {
+    if pending.quiet_for < SAVE_DELAY || (pending.size.is_none() && pending.position.is_none()) {
+        return;
+    }
+
+    if let Some(size) = pending.size.take() {
+        settings.window.width = size.x;
+        settings.window.height = size.y;
+    }
+    if let Some(position) = pending.position.take() {
+        settings.window.position = Some(position);
+        settings.window.monitor = monitor_at(monitors.iter().enumerate(), position)
+            .and_then(|(_, monitor)| monitor.name.clone());
+    }
+}
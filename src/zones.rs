@@ -0,0 +1,72 @@
+// Strefy niskiej grawitacji: półprzezroczyste, migoczące obszary, w których
+// grawitacja działa na ptaka o połowę słabiej. Wejście wykrywa
+// `low_gravity_overlap_system`, a `gravity_system` stosuje mnożnik.
+
+use bevy::prelude::*;
+
+use crate::RunEntity;
+use crate::interpolation::Interpolated;
+use crate::pause::PauseState;
+use crate::physics::{Collider, LifeTime, Sensor, Velocity};
+use crate::settings::Settings;
+
+pub const LOW_GRAVITY_SCALE: f32 = 0.5;
+
+const ZONE_SIZE: Vec2 = Vec2::new(120.0, 320.0);
+const ZONE_COLOR: Srgba = Srgba::new(0.55, 0.85, 1.0, 0.18);
+
+#[derive(Component)]
+#[require(RunEntity)]
+pub struct LowGravityZone;
+
+// Ptak jest w tej chwili w strefie niskiej grawitacji.
+#[derive(Component)]
+pub struct InLowGravity;
+
+pub struct ZonesPlugin;
+
+impl Plugin for ZonesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, shimmer_system.run_if(in_state(PauseState::Running)));
+    }
+}
+
+pub fn spawn_low_gravity_zone(
+    commands: &mut Commands,
+    x: f32,
+    center_y: f32,
+    speed: f32,
+    lifetime: f32,
+) {
+    commands.spawn((
+        Sprite::from_color(ZONE_COLOR, ZONE_SIZE),
+        Velocity { dx: speed, dy: 0.0 },
+        Collider {
+            half_size: ZONE_SIZE / 2.0,
+            offset: Vec2::ZERO,
+        },
+        Sensor,
+        LowGravityZone,
+        LifeTime(lifetime),
+        Interpolated::default(),
+        Transform::from_xyz(x, center_y, -0.2),
+    ));
+}
+
+// Delikatne pulsowanie przezroczystości, przesunięte w fazie zależnie od położenia.
+fn shimmer_system(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    mut query: Query<(&Transform, &mut Sprite), With<LowGravityZone>>,
+) {
+    if settings.accessibility.reduced_motion {
+        return;
+    }
+
+    for (transform, mut sprite) in query.iter_mut() {
+        let phase = time.elapsed_secs() * 4.0 + transform.translation.x * 0.05;
+        sprite.color = ZONE_COLOR
+            .with_alpha(ZONE_COLOR.alpha + 0.08 * phase.sin())
+            .into();
+    }
+}